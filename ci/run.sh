@@ -30,3 +30,8 @@ cargo_test() {
 
 cargo_test
 cargo_test "--release"
+
+# Optional integrations
+cargo test --target=$TARGET --features $FEATURES,serde -p stdsimd --test serde
+cargo test --target=$TARGET --features $FEATURES,quickcheck_impls -p stdsimd --test arbitrary
+cargo test --target=$TARGET --features $FEATURES,rand_impls -p stdsimd --test sampling
@@ -15,3 +15,7 @@ pub use self::v8::*;
 mod neon;
 #[cfg(target_feature = "neon")]
 pub use self::neon::*;
+
+// Methods of `arm::Neon` for the AArch64 NEON intrinsics.
+#[cfg(target_feature = "neon")]
+mod token;
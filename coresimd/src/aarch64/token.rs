@@ -0,0 +1,22 @@
+//! ARMv8 ASIMD methods of the NEON target feature token.
+
+use v128::f64x2;
+
+use arm::Neon;
+use super::neon::*;
+
+token_methods! {
+    Neon = "neon" {
+        /// See [`vadd_f64`](fn.vadd_f64.html).
+        fn add_f64(a: f64, b: f64) -> f64 = vadd_f64;
+
+        /// See [`vaddq_f64`](fn.vaddq_f64.html).
+        fn addq_f64(a: f64x2, b: f64x2) -> f64x2 = vaddq_f64;
+
+        /// See [`vaddd_s64`](fn.vaddd_s64.html).
+        fn addd_s64(a: i64, b: i64) -> i64 = vaddd_s64;
+
+        /// See [`vaddd_u64`](fn.vaddd_u64.html).
+        fn addd_u64(a: u64, b: u64) -> u64 = vaddd_u64;
+    }
+}
@@ -18,3 +18,8 @@ pub use self::v7::*;
 mod neon;
 #[cfg(target_feature = "neon")]
 pub use self::neon::*;
+
+#[cfg(target_feature = "neon")]
+mod token;
+#[cfg(target_feature = "neon")]
+pub use self::token::*;
@@ -0,0 +1,117 @@
+//! NEON target feature token.
+//!
+//! See the `x86` target feature tokens for the rationale. Since `coresimd`
+//! cannot detect `neon` at run-time, the token has no `detect` and only
+//! exists when `neon` is enabled at compile-time, where `new` constructs it.
+
+use v64::{f32x2, i16x4, i32x2, i8x8, u16x4, u32x2, u8x8};
+use v128::{f32x4, i16x8, i32x4, i64x2, i8x16, u16x8, u32x4, u64x2, u8x16};
+
+use super::neon::*;
+
+/// `neon` target feature token.
+#[derive(Clone, Copy, Debug)]
+pub struct Neon(());
+
+impl Neon {
+    /// Returns the token of a target feature that is enabled at
+    /// compile-time.
+    #[inline]
+    pub fn new() -> Neon {
+        Neon(())
+    }
+}
+
+impl Default for Neon {
+    #[inline]
+    fn default() -> Neon {
+        Neon::new()
+    }
+}
+
+token_methods! {
+    Neon = "neon" {
+        /// See [`vadd_s8`](fn.vadd_s8.html).
+        fn add_s8(a: i8x8, b: i8x8) -> i8x8 = vadd_s8;
+
+        /// See [`vaddq_s8`](fn.vaddq_s8.html).
+        fn addq_s8(a: i8x16, b: i8x16) -> i8x16 = vaddq_s8;
+
+        /// See [`vadd_s16`](fn.vadd_s16.html).
+        fn add_s16(a: i16x4, b: i16x4) -> i16x4 = vadd_s16;
+
+        /// See [`vaddq_s16`](fn.vaddq_s16.html).
+        fn addq_s16(a: i16x8, b: i16x8) -> i16x8 = vaddq_s16;
+
+        /// See [`vadd_s32`](fn.vadd_s32.html).
+        fn add_s32(a: i32x2, b: i32x2) -> i32x2 = vadd_s32;
+
+        /// See [`vaddq_s32`](fn.vaddq_s32.html).
+        fn addq_s32(a: i32x4, b: i32x4) -> i32x4 = vaddq_s32;
+
+        /// See [`vaddq_s64`](fn.vaddq_s64.html).
+        fn addq_s64(a: i64x2, b: i64x2) -> i64x2 = vaddq_s64;
+
+        /// See [`vadd_u8`](fn.vadd_u8.html).
+        fn add_u8(a: u8x8, b: u8x8) -> u8x8 = vadd_u8;
+
+        /// See [`vaddq_u8`](fn.vaddq_u8.html).
+        fn addq_u8(a: u8x16, b: u8x16) -> u8x16 = vaddq_u8;
+
+        /// See [`vadd_u16`](fn.vadd_u16.html).
+        fn add_u16(a: u16x4, b: u16x4) -> u16x4 = vadd_u16;
+
+        /// See [`vaddq_u16`](fn.vaddq_u16.html).
+        fn addq_u16(a: u16x8, b: u16x8) -> u16x8 = vaddq_u16;
+
+        /// See [`vadd_u32`](fn.vadd_u32.html).
+        fn add_u32(a: u32x2, b: u32x2) -> u32x2 = vadd_u32;
+
+        /// See [`vaddq_u32`](fn.vaddq_u32.html).
+        fn addq_u32(a: u32x4, b: u32x4) -> u32x4 = vaddq_u32;
+
+        /// See [`vaddq_u64`](fn.vaddq_u64.html).
+        fn addq_u64(a: u64x2, b: u64x2) -> u64x2 = vaddq_u64;
+
+        /// See [`vadd_f32`](fn.vadd_f32.html).
+        fn add_f32(a: f32x2, b: f32x2) -> f32x2 = vadd_f32;
+
+        /// See [`vaddq_f32`](fn.vaddq_f32.html).
+        fn addq_f32(a: f32x4, b: f32x4) -> f32x4 = vaddq_f32;
+
+        /// See [`vaddl_s8`](fn.vaddl_s8.html).
+        fn addl_s8(a: i8x8, b: i8x8) -> i16x8 = vaddl_s8;
+
+        /// See [`vaddl_s16`](fn.vaddl_s16.html).
+        fn addl_s16(a: i16x4, b: i16x4) -> i32x4 = vaddl_s16;
+
+        /// See [`vaddl_s32`](fn.vaddl_s32.html).
+        fn addl_s32(a: i32x2, b: i32x2) -> i64x2 = vaddl_s32;
+
+        /// See [`vaddl_u8`](fn.vaddl_u8.html).
+        fn addl_u8(a: u8x8, b: u8x8) -> u16x8 = vaddl_u8;
+
+        /// See [`vaddl_u16`](fn.vaddl_u16.html).
+        fn addl_u16(a: u16x4, b: u16x4) -> u32x4 = vaddl_u16;
+
+        /// See [`vaddl_u32`](fn.vaddl_u32.html).
+        fn addl_u32(a: u32x2, b: u32x2) -> u64x2 = vaddl_u32;
+
+        /// See [`vrsqrte_f32`](fn.vrsqrte_f32.html).
+        fn rsqrte_f32(a: f32x2) -> f32x2 = vrsqrte_f32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use simd::i32x4;
+    use arm::Neon;
+
+    #[test]
+    fn add_s32() {
+        let neon = Neon::new();
+        let a = i32x4::new(1, 2, 3, 4);
+        let r = neon.addq_s32(a, a);
+        assert_eq!(r, i32x4::new(2, 4, 6, 8));
+    }
+}
@@ -0,0 +1,585 @@
+//! Complex-number vector types
+//!
+//! The complex vectors store their lanes interleaved, `[re0, im0, re1, im1,
+//! ...]`, which is the layout used by C99 `_Complex`, C++ `std::complex` and
+//! most FFT libraries, so that they can be loaded from and stored to
+//! interleaved slices directly.
+//!
+//! Multiplication and division use `addsub` (SSE3/AVX) or `fmaddsub` (FMA)
+//! when those target features are enabled at compile time, and portable
+//! shuffles otherwise. Division scales the divisor by the larger magnitude
+//! of its parts first, so that it does not overflow for large operands.
+
+use core::mem;
+use core::ops;
+
+use simd_llvm::*;
+use v128::*;
+use v256::*;
+use v64::*;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.sqrt.v2f32"]
+    fn sqrt_v2f32(a: f32x2) -> f32x2;
+    #[link_name = "llvm.sqrt.v4f32"]
+    fn sqrt_v4f32(a: f32x4) -> f32x4;
+    #[link_name = "llvm.sqrt.v2f64"]
+    fn sqrt_v2f64(a: f64x2) -> f64x2;
+    #[link_name = "llvm.fabs.v4f32"]
+    fn fabs_v4f32(a: f32x4) -> f32x4;
+    #[link_name = "llvm.fabs.v8f32"]
+    fn fabs_v8f32(a: f32x8) -> f32x8;
+    #[link_name = "llvm.fabs.v2f64"]
+    fn fabs_v2f64(a: f64x2) -> f64x2;
+    #[link_name = "llvm.fabs.v4f64"]
+    fn fabs_v4f64(a: f64x4) -> f64x4;
+    #[link_name = "llvm.maxnum.v4f32"]
+    fn maxnum_v4f32(a: f32x4, b: f32x4) -> f32x4;
+    #[link_name = "llvm.maxnum.v8f32"]
+    fn maxnum_v8f32(a: f32x8, b: f32x8) -> f32x8;
+    #[link_name = "llvm.maxnum.v2f64"]
+    fn maxnum_v2f64(a: f64x2, b: f64x2) -> f64x2;
+    #[link_name = "llvm.maxnum.v4f64"]
+    fn maxnum_v4f64(a: f64x4, b: f64x4) -> f64x4;
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "fma"))]
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.fma.vfmaddsub.ps"]
+    fn vfmaddsubps(a: f32x4, b: f32x4, c: f32x4) -> f32x4;
+    #[link_name = "llvm.x86.fma.vfmaddsub.pd"]
+    fn vfmaddsubpd(a: f64x2, b: f64x2, c: f64x2) -> f64x2;
+    #[link_name = "llvm.x86.fma.vfmaddsub.ps.256"]
+    fn vfmaddsubps256(a: f32x8, b: f32x8, c: f32x8) -> f32x8;
+    #[link_name = "llvm.x86.fma.vfmaddsub.pd.256"]
+    fn vfmaddsubpd256(a: f64x4, b: f64x4, c: f64x4) -> f64x4;
+}
+
+/// Fused or unfused `a * b - c` in the even lanes and `a * b + c` in the odd
+/// lanes.
+trait MulAddSub {
+    fn mul_addsub(self, b: Self, c: Self) -> Self;
+}
+
+macro_rules! impl_mul_addsub {
+    ($ty:ident, $fma:ident, $addsub_feature:tt, $addsub:ident,
+     $shuffle:ident, $idx:expr) => {
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "fma"))]
+        impl MulAddSub for $ty {
+            #[inline(always)]
+            fn mul_addsub(self, b: $ty, c: $ty) -> $ty {
+                unsafe { $fma(self, b, c) }
+            }
+        }
+
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = $addsub_feature,
+                  not(target_feature = "fma")))]
+        impl MulAddSub for $ty {
+            #[inline(always)]
+            fn mul_addsub(self, b: $ty, c: $ty) -> $ty {
+                unsafe {
+                    mem::transmute(::x86::$addsub(
+                        mem::transmute(self * b),
+                        mem::transmute(c),
+                    ))
+                }
+            }
+        }
+
+        #[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"),
+                      any(target_feature = $addsub_feature,
+                          target_feature = "fma"))))]
+        impl MulAddSub for $ty {
+            #[inline(always)]
+            fn mul_addsub(self, b: $ty, c: $ty) -> $ty {
+                let ab = self * b;
+                unsafe { $shuffle(ab - c, ab + c, $idx) }
+            }
+        }
+    };
+}
+
+impl_mul_addsub!(
+    f32x4, vfmaddsubps, "sse3", _mm_addsub_ps, simd_shuffle4, [0, 5, 2, 7]
+);
+impl_mul_addsub!(
+    f64x2, vfmaddsubpd, "sse3", _mm_addsub_pd, simd_shuffle2, [0, 3]
+);
+impl_mul_addsub!(
+    f32x8,
+    vfmaddsubps256,
+    "avx",
+    _mm256_addsub_ps,
+    simd_shuffle8,
+    [0, 9, 2, 11, 4, 13, 6, 15]
+);
+impl_mul_addsub!(
+    f64x4,
+    vfmaddsubpd256,
+    "avx",
+    _mm256_addsub_pd,
+    simd_shuffle4,
+    [0, 5, 2, 7]
+);
+
+macro_rules! define_complex {
+    (
+        $(#[$doc:meta])*
+        pub struct $name:ident($vec:ident);
+        elem: $elem:ident, lanes: $nelems:expr,
+        shuffle: $shuffle:ident, fabs: $fabs:ident, maxnum: $maxnum:ident,
+        dup_re: $dup_re:expr, dup_im: $dup_im:expr, swap: $swap:expr,
+        new: ($($re_name:ident, $im_name:ident),+)
+    ) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[allow(non_camel_case_types)]
+        pub struct $name($vec);
+
+        impl $name {
+            /// Creates a new vector from interleaved real and imaginary
+            /// parts.
+            #[inline(always)]
+            pub fn new($($re_name: $elem, $im_name: $elem),+) -> $name {
+                $name($vec::new($($re_name, $im_name),+))
+            }
+
+            #[inline(always)]
+            pub fn len() -> i32 {
+                $nelems
+            }
+
+            /// Creates a new vector from interleaved `[re, im, ...]` lanes.
+            #[inline(always)]
+            pub fn from_interleaved(v: $vec) -> $name {
+                $name(v)
+            }
+
+            /// Returns the interleaved `[re, im, ...]` lanes.
+            #[inline(always)]
+            pub fn interleaved(self) -> $vec {
+                self.0
+            }
+
+            /// Loads a vector from the interleaved `[re, im, ...]` values
+            /// starting at `slice[offset]`.
+            #[inline(always)]
+            pub fn load(slice: &[$elem], offset: usize) -> $name {
+                $name($vec::load(slice, offset))
+            }
+
+            /// Loads a vector from the interleaved `[re, im, ...]` values
+            /// starting at `slice[offset]` without bounds checking.
+            #[inline(always)]
+            pub unsafe fn load_unchecked(
+                slice: &[$elem],
+                offset: usize,
+            ) -> $name {
+                $name($vec::load_unchecked(slice, offset))
+            }
+
+            /// Stores the lanes interleaved as `[re, im, ...]` into `slice`
+            /// starting at `slice[offset]`.
+            #[inline(always)]
+            pub fn store(self, slice: &mut [$elem], offset: usize) {
+                self.0.store(slice, offset)
+            }
+
+            /// Stores the lanes interleaved as `[re, im, ...]` into `slice`
+            /// starting at `slice[offset]` without bounds checking.
+            #[inline(always)]
+            pub unsafe fn store_unchecked(
+                self,
+                slice: &mut [$elem],
+                offset: usize,
+            ) {
+                self.0.store_unchecked(slice, offset)
+            }
+
+            /// Returns the complex conjugate of every lane.
+            #[inline(always)]
+            pub fn conj(self) -> $name {
+                let mut sign = $vec::splat(1 as $elem);
+                for i in 0..$nelems {
+                    sign = sign.replace(2 * i + 1, -1 as $elem);
+                }
+                $name(self.0 * sign)
+            }
+        }
+
+        impl ops::Add for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, other: Self) -> Self {
+                $name(self.0 + other.0)
+            }
+        }
+
+        impl ops::Sub for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, other: Self) -> Self {
+                $name(self.0 - other.0)
+            }
+        }
+
+        impl ops::Mul for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, other: Self) -> Self {
+                // (a + bi)(c + di) = (ac - bd) + (ad + bc)i
+                unsafe {
+                    let re: $vec = $shuffle(self.0, self.0, $dup_re);
+                    let im: $vec = $shuffle(self.0, self.0, $dup_im);
+                    let swapped: $vec = $shuffle(other.0, other.0, $swap);
+                    $name(re.mul_addsub(other.0, im * swapped))
+                }
+            }
+        }
+
+        impl ops::Div for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn div(self, other: Self) -> Self {
+                // x / y = x * conj(y') / |y'|^2 / m, where m is the larger
+                // magnitude of the parts of y and y' = y / m, so that
+                // 1 <= |y'|^2 <= 2 cannot overflow.
+                let m = unsafe {
+                    let abs = $fabs(other.0);
+                    let swapped: $vec = $shuffle(abs, abs, $swap);
+                    $maxnum(abs, swapped)
+                };
+                let y = $name(other.0 / m);
+                let num = self * y.conj();
+                let sq = y.0 * y.0;
+                let den = unsafe {
+                    let swapped: $vec = $shuffle(sq, sq, $swap);
+                    sq + swapped
+                };
+                $name(num.0 / den / m)
+            }
+        }
+
+        impl ops::Neg for $name {
+            type Output = Self;
+            #[inline(always)]
+            fn neg(self) -> Self {
+                $name($vec::splat(-1 as $elem) * self.0)
+            }
+        }
+
+        impl ops::AddAssign for $name {
+            #[inline(always)]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl ops::SubAssign for $name {
+            #[inline(always)]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl ops::MulAssign for $name {
+            #[inline(always)]
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl ops::DivAssign for $name {
+            #[inline(always)]
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+
+        impl From<$vec> for $name {
+            #[inline(always)]
+            fn from(v: $vec) -> $name {
+                $name(v)
+            }
+        }
+
+        impl From<$name> for $vec {
+            #[inline(always)]
+            fn from(v: $name) -> $vec {
+                v.0
+            }
+        }
+    };
+}
+
+/// Implements the methods that return or take the real and imaginary parts
+/// of the lanes as `$real` vectors.
+macro_rules! impl_vector_parts {
+    (
+        $name:ident($vec:ident);
+        elem: $elem:ident, real: $real:ident, lanes: $nelems:expr,
+        real_shuffle: $real_shuffle:ident, sqrt: $sqrt:ident,
+        re: $re:expr, im: $im:expr
+    ) => {
+        impl $name {
+            /// Creates a new vector with all lanes set to `re + im * i`.
+            #[inline(always)]
+            pub fn splat(re: $elem, im: $elem) -> $name {
+                $name::from_parts($real::splat(re), $real::splat(im))
+            }
+
+            /// Creates a new vector from its real and imaginary parts.
+            #[inline(always)]
+            pub fn from_parts(re: $real, im: $real) -> $name {
+                let mut v = $vec::splat(0 as $elem);
+                for i in 0..$nelems {
+                    v = v.replace(2 * i, re.extract(i));
+                    v = v.replace(2 * i + 1, im.extract(i));
+                }
+                $name(v)
+            }
+
+            /// Returns the real parts of the lanes.
+            #[inline(always)]
+            pub fn re(self) -> $real {
+                unsafe { $real_shuffle(self.0, self.0, $re) }
+            }
+
+            /// Returns the imaginary parts of the lanes.
+            #[inline(always)]
+            pub fn im(self) -> $real {
+                unsafe { $real_shuffle(self.0, self.0, $im) }
+            }
+
+            /// Returns the squared magnitude, `re * re + im * im`, of every
+            /// lane.
+            #[inline(always)]
+            pub fn norm(self) -> $real {
+                let sq = self.0 * self.0;
+                unsafe {
+                    let re: $real = $real_shuffle(sq, sq, $re);
+                    let im: $real = $real_shuffle(sq, sq, $im);
+                    re + im
+                }
+            }
+
+            /// Returns the magnitude of every lane.
+            ///
+            /// This is computed as `norm().sqrt()` and may overflow for lanes
+            /// whose squared magnitude is not representable.
+            #[inline(always)]
+            pub fn abs(self) -> $real {
+                unsafe { $sqrt(self.norm()) }
+            }
+        }
+    };
+}
+
+define_complex! {
+    /// A 128-bit vector with 2 complex `f32` lanes.
+    pub struct c32x2(f32x4);
+    elem: f32, lanes: 2,
+    shuffle: simd_shuffle4, fabs: fabs_v4f32, maxnum: maxnum_v4f32,
+    dup_re: [0, 0, 2, 2], dup_im: [1, 1, 3, 3], swap: [1, 0, 3, 2],
+    new: (re0, im0, re1, im1)
+}
+impl_vector_parts! {
+    c32x2(f32x4);
+    elem: f32, real: f32x2, lanes: 2,
+    real_shuffle: simd_shuffle2, sqrt: sqrt_v2f32,
+    re: [0, 2], im: [1, 3]
+}
+
+define_complex! {
+    /// A 256-bit vector with 4 complex `f32` lanes.
+    pub struct c32x4(f32x8);
+    elem: f32, lanes: 4,
+    shuffle: simd_shuffle8, fabs: fabs_v8f32, maxnum: maxnum_v8f32,
+    dup_re: [0, 0, 2, 2, 4, 4, 6, 6], dup_im: [1, 1, 3, 3, 5, 5, 7, 7],
+    swap: [1, 0, 3, 2, 5, 4, 7, 6],
+    new: (re0, im0, re1, im1, re2, im2, re3, im3)
+}
+impl_vector_parts! {
+    c32x4(f32x8);
+    elem: f32, real: f32x4, lanes: 4,
+    real_shuffle: simd_shuffle4, sqrt: sqrt_v4f32,
+    re: [0, 2, 4, 6], im: [1, 3, 5, 7]
+}
+
+define_complex! {
+    /// A 128-bit vector with 1 complex `f64` lane.
+    pub struct c64x1(f64x2);
+    elem: f64, lanes: 1,
+    shuffle: simd_shuffle2, fabs: fabs_v2f64, maxnum: maxnum_v2f64,
+    dup_re: [0, 0], dup_im: [1, 1], swap: [1, 0],
+    new: (re, im)
+}
+
+// With a single lane, the parts are scalars.
+impl c64x1 {
+    /// Creates a new vector set to `re + im * i`.
+    #[inline(always)]
+    pub fn splat(re: f64, im: f64) -> c64x1 {
+        c64x1::new(re, im)
+    }
+
+    /// Creates a new vector from its real and imaginary parts.
+    #[inline(always)]
+    pub fn from_parts(re: f64, im: f64) -> c64x1 {
+        c64x1::new(re, im)
+    }
+
+    /// Returns the real part of the lane.
+    #[inline(always)]
+    pub fn re(self) -> f64 {
+        self.0.extract(0)
+    }
+
+    /// Returns the imaginary part of the lane.
+    #[inline(always)]
+    pub fn im(self) -> f64 {
+        self.0.extract(1)
+    }
+
+    /// Returns the squared magnitude, `re * re + im * im`, of the lane.
+    #[inline(always)]
+    pub fn norm(self) -> f64 {
+        let sq = self.0 * self.0;
+        sq.extract(0) + sq.extract(1)
+    }
+
+    /// Returns the magnitude of the lane.
+    ///
+    /// This is computed as `norm().sqrt()` and may overflow if the squared
+    /// magnitude is not representable.
+    #[inline(always)]
+    pub fn abs(self) -> f64 {
+        unsafe { sqrt_v2f64(f64x2::splat(self.norm())).extract(0) }
+    }
+}
+
+define_complex! {
+    /// A 256-bit vector with 2 complex `f64` lanes.
+    pub struct c64x2(f64x4);
+    elem: f64, lanes: 2,
+    shuffle: simd_shuffle4, fabs: fabs_v4f64, maxnum: maxnum_v4f64,
+    dup_re: [0, 0, 2, 2], dup_im: [1, 1, 3, 3], swap: [1, 0, 3, 2],
+    new: (re0, im0, re1, im1)
+}
+impl_vector_parts! {
+    c64x2(f64x4);
+    elem: f64, real: f64x2, lanes: 2,
+    real_shuffle: simd_shuffle2, sqrt: sqrt_v2f64,
+    re: [0, 2], im: [1, 3]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn c32x2_arithmetic() {
+        let a = c32x2::new(1., 2., -3., 0.5);
+        let b = c32x2::new(3., 4., 2., -1.);
+
+        assert_eq!(a + b, c32x2::new(4., 6., -1., -0.5));
+        assert_eq!(a - b, c32x2::new(-2., -2., -5., 1.5));
+        // (1 + 2i)(3 + 4i) = -5 + 10i, (-3 + 0.5i)(2 - i) = -5.5 + 4i
+        assert_eq!(a * b, c32x2::new(-5., 10., -5.5, 4.));
+        // (1 + 2i) / (3 + 4i) = (11 + 2i) / 25
+        // (-3 + 0.5i) / (2 - i) = (-6.5 - 2i) / 5
+        assert_eq!(
+            a / b,
+            c32x2::new(11. / 25., 2. / 25., -6.5 / 5., -2. / 5.)
+        );
+        assert_eq!(-a, c32x2::new(-1., -2., 3., -0.5));
+        assert_eq!(a.conj(), c32x2::new(1., -2., -3., -0.5));
+
+        let mut c = a;
+        c *= b;
+        c /= b;
+        assert_eq!(c, a);
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn c32x2_parts() {
+        let a = c32x2::new(3., 4., -5., 12.);
+        assert_eq!(a.re(), f32x2::new(3., -5.));
+        assert_eq!(a.im(), f32x2::new(4., 12.));
+        assert_eq!(a.norm(), f32x2::new(25., 169.));
+        assert_eq!(a.abs(), f32x2::new(5., 13.));
+        assert_eq!(c32x2::from_parts(a.re(), a.im()), a);
+        assert_eq!(c32x2::splat(1., -1.), c32x2::new(1., -1., 1., -1.));
+    }
+
+    #[test]
+    fn c32x4_arithmetic() {
+        let a = c32x4::new(1., 2., -3., 0.5, 0., 1., 2., 0.);
+        let b = c32x4::new(3., 4., 2., -1., 0., 1., 0., 2.);
+        assert_eq!(a * b, c32x4::new(-5., 10., -5.5, 4., -1., 0., 0., 4.));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(a.norm(), f32x4::new(5., 9.25, 1., 4.));
+        assert_eq!(b.abs(), f32x4::new(5., 5f32.sqrt(), 1., 2.));
+    }
+
+    #[test]
+    fn c64x1_arithmetic() {
+        let a = c64x1::new(1., 2.);
+        let b = c64x1::new(3., 4.);
+        assert_eq!(a * b, c64x1::new(-5., 10.));
+        assert_eq!(a / b, c64x1::new(11. / 25., 2. / 25.));
+        assert_eq!(b.re(), 3.);
+        assert_eq!(b.im(), 4.);
+        assert_eq!(b.norm(), 25.);
+        assert_eq!(b.abs(), 5.);
+        assert_eq!(c64x1::from_parts(3., 4.), b);
+    }
+
+    #[test]
+    fn division_does_not_overflow() {
+        let a = c64x1::new(1e200, 1e200);
+        assert_eq!(a / a, c64x1::new(1., 0.));
+        let a = c64x2::new(1e300, 1e-300, -1e200, 0.);
+        let b = c64x2::new(1e300, 0., 0., 1e200);
+        assert_eq!(a / b, c64x2::new(1., 0., 0., 1.));
+        let a = c32x2::new(1e30, 1e30, 1e20, 0.);
+        let b = c32x2::new(1e30, -1e30, 1e20, 0.);
+        assert_eq!(a / b, c32x2::new(0., 1., 1., 0.));
+    }
+
+    #[test]
+    fn c64x2_arithmetic() {
+        let a = c64x2::new(1., 2., -3., 0.5);
+        let b = c64x2::new(3., 4., 2., -1.);
+        assert_eq!(a * b, c64x2::new(-5., 10., -5.5, 4.));
+        assert_eq!(
+            a / b,
+            c64x2::new(11. / 25., 2. / 25., -6.5 / 5., -2. / 5.)
+        );
+        assert_eq!(a.conj().im(), f64x2::new(-2., -0.5));
+        assert_eq!(b.abs(), f64x2::new(5., 5f64.sqrt()));
+    }
+
+    #[test]
+    fn interleaved_slices() {
+        let data = [1f32, 2., 3., 4., 5., 6.];
+        let a = c32x2::load(&data, 2);
+        assert_eq!(a, c32x2::new(3., 4., 5., 6.));
+        assert_eq!(a.interleaved(), f32x4::new(3., 4., 5., 6.));
+
+        let mut out = [0f32; 6];
+        a.conj().store(&mut out, 1);
+        assert_eq!(out, [0., 3., -4., 5., -6., 0.]);
+
+        let v = f64x4::new(1., 2., 3., 4.);
+        assert_eq!(f64x4::from(c64x2::from(v)), v);
+    }
+}
@@ -0,0 +1,537 @@
+//! Half-precision (IEEE 754 `binary16`) vector types
+//!
+//! Rust has no `f16` primitive type, so the vectors in this module are
+//! storage-only: each lane holds the raw `binary16` bit pattern in a `u16`.
+//! Arithmetic happens after widening to `f32` lanes, and results are narrowed
+//! back with an explicit rounding mode.
+//!
+//! The conversions use the `F16C` instructions (`vcvtph2ps`/`vcvtps2ph`) on
+//! x86 when the `f16c` target feature is enabled at compile time, the NEON
+//! `vcvt.f32.f16`/`vcvt.f16.f32` instructions on ARM when both `neon` and
+//! `fp16` are enabled, and `fcvtl`/`fcvtn` on AArch64 when `neon` is
+//! enabled. Otherwise a software implementation is used,
+//! which produces bit-for-bit the same results as `vcvtps2ph`/`vcvtph2ps`
+//! for every rounding mode, including `NaN` payloads, denormals and
+//! overflow.
+
+use core::mem;
+
+use simd_llvm::*;
+use v128::*;
+use v256::*;
+use v512::*;
+#[cfg(any(all(target_arch = "aarch64", target_feature = "neon"),
+          all(target_arch = "arm", target_feature = "neon",
+              target_feature = "fp16")))]
+use v64::i16x4;
+
+/// Rounding mode used when narrowing `f32` lanes to `f16` lanes.
+///
+/// The discriminants match the rounding-control immediate of `vcvtps2ph`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum F16RoundingMode {
+    /// Round to nearest, ties to even.
+    Nearest = 0,
+    /// Round toward negative infinity.
+    Down = 1,
+    /// Round toward positive infinity.
+    Up = 2,
+    /// Round toward zero.
+    Truncate = 3,
+    /// Use the rounding mode of the current floating-point environment
+    /// (`MXCSR.RC` on x86, `FPCR.RMode` on AArch64).
+    ///
+    /// Other architectures do not expose their rounding mode, and the
+    /// software fallback rounds to nearest, ties to even, there.
+    Current = 4,
+}
+
+define_ty_doc! {
+    f16x8, u16, u16, u16, u16, u16, u16, u16, u16 |
+    /// A 128-bit vector with 8 `f16` lanes.
+    ///
+    /// Lanes are stored as raw `binary16` bit patterns, and equality
+    /// compares those bit patterns.
+}
+
+define_ty_doc! {
+    f16x16,
+    u16, u16, u16, u16, u16, u16, u16, u16,
+    u16, u16, u16, u16, u16, u16, u16, u16 |
+    /// A 256-bit vector with 16 `f16` lanes.
+    ///
+    /// Lanes are stored as raw `binary16` bit patterns, and equality
+    /// compares those bit patterns.
+}
+
+macro_rules! define_f16_impl {
+    (
+        $name:ident, $bits:ident, $f32:ident, $nelems:expr,
+        $from_f32:ident, $from_f32_round:ident, $to_f32:ident
+    ) => {
+        impl $name {
+            #[inline(always)]
+            pub fn len() -> i32 {
+                $nelems
+            }
+
+            /// Reinterprets the `binary16` bit patterns in `bits` as `f16`
+            /// lanes.
+            #[inline(always)]
+            pub fn from_bits(bits: $bits) -> $name {
+                unsafe { mem::transmute(bits) }
+            }
+
+            /// Returns the `binary16` bit patterns of the lanes.
+            #[inline(always)]
+            pub fn to_bits(self) -> $bits {
+                unsafe { mem::transmute(self) }
+            }
+
+            /// Converts every lane to `f32`.
+            ///
+            /// The conversion is exact; signaling `NaN`s are quieted.
+            #[inline(always)]
+            pub fn $to_f32(self) -> $f32 {
+                unsafe { $to_f32(self) }
+            }
+
+            /// Narrows every lane of `a` to `f16`, rounding to nearest with
+            /// ties to even.
+            #[inline(always)]
+            pub fn $from_f32(a: $f32) -> $name {
+                $name::$from_f32_round(a, F16RoundingMode::Nearest)
+            }
+
+            /// Narrows every lane of `a` to `f16` using the rounding mode
+            /// `rounding`.
+            ///
+            /// Values too large for `f16` become infinity or the largest
+            /// finite `f16`, depending on the rounding direction; signaling
+            /// `NaN`s are quieted.
+            #[inline(always)]
+            pub fn $from_f32_round(
+                a: $f32, rounding: F16RoundingMode
+            ) -> $name {
+                unsafe { $from_f32(a, rounding) }
+            }
+        }
+
+        impl From<$name> for $f32 {
+            #[inline(always)]
+            fn from(a: $name) -> $f32 {
+                a.$to_f32()
+            }
+        }
+    };
+}
+
+define_f16_impl! {
+    f16x8, u16x8, f32x8, 8,
+    from_f32x8, from_f32x8_round, to_f32x8
+}
+define_f16_impl! {
+    f16x16, u16x16, f32x16, 16,
+    from_f32x16, from_f32x16_round, to_f32x16
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.aarch64.neon.vcvthf2fp"]
+    fn vcvthf2fp(a: i16x4) -> f32x4;
+    #[link_name = "llvm.aarch64.neon.vcvtfp2hf"]
+    fn vcvtfp2hf(a: f32x4) -> i16x4;
+}
+
+#[cfg(all(target_arch = "arm", target_feature = "neon",
+          target_feature = "fp16"))]
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.arm.neon.vcvthf2fp"]
+    fn vcvthf2fp(a: i16x4) -> f32x4;
+    #[link_name = "llvm.arm.neon.vcvtfp2hf"]
+    fn vcvtfp2hf(a: f32x4) -> i16x4;
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "f16c"))]
+#[inline(always)]
+unsafe fn to_f32x8(a: f16x8) -> f32x8 {
+    mem::transmute(::x86::_mm256_cvtph_ps(mem::transmute(a)))
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+          target_feature = "f16c"))]
+#[inline(always)]
+unsafe fn from_f32x8(a: f32x8, rounding: F16RoundingMode) -> f16x8 {
+    use x86::{_mm256_cvtps_ph, _MM_FROUND_CUR_DIRECTION,
+              _MM_FROUND_TO_NEAREST_INT, _MM_FROUND_TO_NEG_INF,
+              _MM_FROUND_TO_POS_INF, _MM_FROUND_TO_ZERO};
+
+    // The rounding mode is an immediate operand of `vcvtps2ph`.
+    let a = mem::transmute(a);
+    let r = match rounding {
+        F16RoundingMode::Nearest => {
+            _mm256_cvtps_ph(a, _MM_FROUND_TO_NEAREST_INT)
+        }
+        F16RoundingMode::Down => _mm256_cvtps_ph(a, _MM_FROUND_TO_NEG_INF),
+        F16RoundingMode::Up => _mm256_cvtps_ph(a, _MM_FROUND_TO_POS_INF),
+        F16RoundingMode::Truncate => _mm256_cvtps_ph(a, _MM_FROUND_TO_ZERO),
+        F16RoundingMode::Current => {
+            _mm256_cvtps_ph(a, _MM_FROUND_CUR_DIRECTION)
+        }
+    };
+    mem::transmute(r)
+}
+
+#[cfg(any(all(target_arch = "aarch64", target_feature = "neon"),
+          all(target_arch = "arm", target_feature = "neon",
+              target_feature = "fp16")))]
+#[inline(always)]
+unsafe fn to_f32x8(a: f16x8) -> f32x8 {
+    let a: i16x8 = mem::transmute(a);
+    let lo: i16x4 = simd_shuffle4(a, a, [0, 1, 2, 3]);
+    let hi: i16x4 = simd_shuffle4(a, a, [4, 5, 6, 7]);
+    simd_shuffle8(vcvthf2fp(lo), vcvthf2fp(hi), [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+#[cfg(any(all(target_arch = "aarch64", target_feature = "neon"),
+          all(target_arch = "arm", target_feature = "neon",
+              target_feature = "fp16")))]
+#[inline(always)]
+unsafe fn from_f32x8(a: f32x8, rounding: F16RoundingMode) -> f16x8 {
+    // `vcvt.f16.f32` and `fcvtn` always round according to the
+    // floating-point control register, so they can only be used when the
+    // caller asks for the current rounding mode.
+    if rounding != F16RoundingMode::Current {
+        return soft::from_f32x8(a, rounding);
+    }
+    let lo: f32x4 = simd_shuffle4(a, a, [0, 1, 2, 3]);
+    let hi: f32x4 = simd_shuffle4(a, a, [4, 5, 6, 7]);
+    let r: i16x8 =
+        simd_shuffle8(vcvtfp2hf(lo), vcvtfp2hf(hi), [0, 1, 2, 3, 4, 5, 6, 7]);
+    mem::transmute(r)
+}
+
+#[cfg(not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "f16c"),
+              all(target_arch = "aarch64", target_feature = "neon"),
+              all(target_arch = "arm", target_feature = "neon",
+                  target_feature = "fp16"))))]
+use self::soft::{from_f32x8, to_f32x8};
+
+#[inline(always)]
+unsafe fn to_f32x16(a: f16x16) -> f32x16 {
+    let a: u16x16 = mem::transmute(a);
+    let lo: u16x8 = simd_shuffle8(a, a, [0, 1, 2, 3, 4, 5, 6, 7]);
+    let hi: u16x8 = simd_shuffle8(a, a, [8, 9, 10, 11, 12, 13, 14, 15]);
+    let lo = to_f32x8(f16x8::from_bits(lo));
+    let hi = to_f32x8(f16x8::from_bits(hi));
+    simd_shuffle16(
+        lo,
+        hi,
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    )
+}
+
+#[inline(always)]
+unsafe fn from_f32x16(a: f32x16, rounding: F16RoundingMode) -> f16x16 {
+    let lo: f32x8 = simd_shuffle8(a, a, [0, 1, 2, 3, 4, 5, 6, 7]);
+    let hi: f32x8 = simd_shuffle8(a, a, [8, 9, 10, 11, 12, 13, 14, 15]);
+    let lo = from_f32x8(lo, rounding).to_bits();
+    let hi = from_f32x8(hi, rounding).to_bits();
+    let r: u16x16 = simd_shuffle16(
+        lo,
+        hi,
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    );
+    f16x16::from_bits(r)
+}
+
+/// Software `binary16` conversions, bit-exact with `F16C`.
+///
+/// Only the conversions that the hardware paths above leave out are
+/// compiled, except for the scalar ones, which the tests always check.
+#[cfg(any(test,
+          not(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "f16c"))))]
+mod soft {
+    use core::mem;
+
+    use super::F16RoundingMode;
+    #[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "f16c")))]
+    use super::f16x8;
+    #[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "f16c")))]
+    use v128::u16x8;
+    #[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "f16c")))]
+    use v256::f32x8;
+
+    #[cfg(not(any(all(any(target_arch = "x86", target_arch = "x86_64"),
+                      target_feature = "f16c"),
+                  all(target_arch = "aarch64", target_feature = "neon"),
+                  all(target_arch = "arm", target_feature = "neon",
+                      target_feature = "fp16"))))]
+    #[inline]
+    pub unsafe fn to_f32x8(a: f16x8) -> f32x8 {
+        let a = a.to_bits();
+        let mut r = f32x8::splat(0.);
+        for i in 0..8 {
+            r = r.replace(i, f16_to_f32(a.extract(i)));
+        }
+        r
+    }
+
+    #[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "f16c")))]
+    #[inline]
+    pub unsafe fn from_f32x8(a: f32x8, rounding: F16RoundingMode) -> f16x8 {
+        let rounding = resolve(rounding);
+        let mut r = u16x8::splat(0);
+        for i in 0..8 {
+            r = r.replace(i, f32_to_f16(a.extract(i), rounding));
+        }
+        f16x8::from_bits(r)
+    }
+
+    /// Replaces `F16RoundingMode::Current` with the mode it stands for.
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"),
+              target_feature = "sse", not(target_feature = "f16c")))]
+    fn resolve(rounding: F16RoundingMode) -> F16RoundingMode {
+        if rounding != F16RoundingMode::Current {
+            return rounding;
+        }
+        match (unsafe { ::x86::_mm_getcsr() } >> 13) & 0b11 {
+            0 => F16RoundingMode::Nearest,
+            1 => F16RoundingMode::Down,
+            2 => F16RoundingMode::Up,
+            _ => F16RoundingMode::Truncate,
+        }
+    }
+
+    /// Replaces `F16RoundingMode::Current` with the mode it stands for.
+    #[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"),
+                  target_feature = "sse")))]
+    fn resolve(rounding: F16RoundingMode) -> F16RoundingMode {
+        if rounding == F16RoundingMode::Current {
+            F16RoundingMode::Nearest
+        } else {
+            rounding
+        }
+    }
+
+    /// Widens a `binary16` bit pattern to `f32`.
+    #[cfg(any(test,
+              not(any(all(target_arch = "aarch64", target_feature = "neon"),
+                      all(target_arch = "arm", target_feature = "neon",
+                          target_feature = "fp16")))))]
+    pub fn f16_to_f32(h: u16) -> f32 {
+        let sign = ((h & 0x8000) as u32) << 16;
+        let exp = ((h >> 10) & 0x1f) as u32;
+        let man = (h & 0x3ff) as u32;
+        let bits = if exp == 0x1f {
+            if man == 0 {
+                sign | 0x7f80_0000
+            } else {
+                // Keep the payload and set the quiet bit.
+                sign | 0x7fc0_0000 | (man << 13)
+            }
+        } else if exp == 0 {
+            if man == 0 {
+                sign
+            } else {
+                // Denormals become normal `f32`s.
+                let mut e = 127 - 14;
+                let mut m = man;
+                while m & 0x400 == 0 {
+                    m <<= 1;
+                    e -= 1;
+                }
+                sign | (e << 23) | ((m & 0x3ff) << 13)
+            }
+        } else {
+            sign | ((exp + 127 - 15) << 23) | (man << 13)
+        };
+        unsafe { mem::transmute(bits) }
+    }
+
+    /// Narrows an `f32` to a `binary16` bit pattern.
+    ///
+    /// `rounding` must not be `F16RoundingMode::Current`.
+    pub fn f32_to_f16(x: f32, rounding: F16RoundingMode) -> u16 {
+        let bits: u32 = unsafe { mem::transmute(x) };
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let neg = sign != 0;
+        let exp = ((bits >> 23) & 0xff) as i32;
+        let man = bits & 0x7f_ffff;
+
+        if exp == 0xff {
+            if man == 0 {
+                return sign | 0x7c00;
+            }
+            // Keep the upper payload bits and set the quiet bit.
+            return sign | 0x7e00 | (man >> 13) as u16;
+        }
+
+        // Whether the rounding mode moves inexact results away from zero.
+        let away = |nearest: bool| match rounding {
+            F16RoundingMode::Nearest => nearest,
+            F16RoundingMode::Up => !neg,
+            F16RoundingMode::Down => neg,
+            F16RoundingMode::Truncate | F16RoundingMode::Current => false,
+        };
+
+        let half_exp = exp - 127 + 15;
+        if half_exp >= 0x1f {
+            return if away(true) { sign | 0x7c00 } else { sign | 0x7bff };
+        }
+
+        // Split the magnitude into the truncated `binary16` encoding and the
+        // bits shifted out, then round. A carry out of the mantissa bumps the
+        // exponent, which also turns the largest finite value into infinity
+        // and the largest denormal into the smallest normal.
+        let (base, rem, halfway): (u32, u64, u64) = if half_exp > 0 {
+            let base = ((half_exp as u32) << 10) | (man >> 13);
+            (base, (man & 0x1fff) as u64, 0x1000)
+        } else {
+            let m = (if exp == 0 { man } else { man | 0x80_0000 }) as u64;
+            let shift = (126 - exp.max(1)) as u32;
+            if shift >= 32 {
+                (0, m, 1 << 32)
+            } else {
+                let base = (m >> shift) as u32;
+                (base, m & ((1 << shift) - 1), 1 << (shift - 1))
+            }
+        };
+        let nearest = rem > halfway || (rem == halfway && base & 1 == 1);
+        let round_up = rem != 0 && away(nearest);
+        sign | (base + round_up as u32) as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::soft::{f16_to_f32, f32_to_f16};
+
+    fn bits(x: f32) -> u32 {
+        unsafe { mem::transmute(x) }
+    }
+
+    fn float(x: u32) -> f32 {
+        unsafe { mem::transmute(x) }
+    }
+
+    #[test]
+    fn widen_special_values() {
+        assert_eq!(f16_to_f32(0x0000), 0.);
+        assert_eq!(bits(f16_to_f32(0x8000)), 0x8000_0000);
+        assert_eq!(f16_to_f32(0x3c00), 1.);
+        assert_eq!(f16_to_f32(0xc000), -2.);
+        assert_eq!(f16_to_f32(0x7bff), 65504.);
+        assert_eq!(f16_to_f32(0x0001), float(0x3380_0000)); // 2^-24
+        assert_eq!(f16_to_f32(0x03ff), float(0x387f_c000));
+        assert_eq!(f16_to_f32(0x0400), float(0x3880_0000)); // 2^-14
+        assert_eq!(bits(f16_to_f32(0x7c00)), 0x7f80_0000);
+        assert_eq!(bits(f16_to_f32(0xfc00)), 0xff80_0000);
+        // Signaling NaNs are quieted, payloads are preserved.
+        assert_eq!(bits(f16_to_f32(0x7c01)), 0x7fc0_2000);
+        assert_eq!(bits(f16_to_f32(0xfe00)), 0xffc0_0000);
+    }
+
+    #[test]
+    fn narrow_round_trips() {
+        // Every non-NaN `binary16` survives a round trip in every mode.
+        for h in 0..0x1_0000u32 {
+            let h = h as u16;
+            if h & 0x7c00 == 0x7c00 && h & 0x3ff != 0 {
+                continue;
+            }
+            let x = f16_to_f32(h);
+            for &r in &[
+                F16RoundingMode::Nearest,
+                F16RoundingMode::Down,
+                F16RoundingMode::Up,
+                F16RoundingMode::Truncate,
+            ] {
+                assert_eq!(f32_to_f16(x, r), h);
+            }
+        }
+    }
+
+    #[test]
+    fn narrow_rounding_modes() {
+        use self::F16RoundingMode::*;
+
+        // 1 + 2^-11 is halfway between 1 and the next `f16`.
+        let tie = float(0x3f80_1000);
+        assert_eq!(f32_to_f16(tie, Nearest), 0x3c00);
+        assert_eq!(f32_to_f16(tie, Down), 0x3c00);
+        assert_eq!(f32_to_f16(tie, Up), 0x3c01);
+        assert_eq!(f32_to_f16(tie, Truncate), 0x3c00);
+        assert_eq!(f32_to_f16(-tie, Down), 0xbc01);
+        assert_eq!(f32_to_f16(-tie, Up), 0xbc00);
+        // Ties round to even.
+        let tie = float(0x3f80_3000);
+        assert_eq!(f32_to_f16(tie, Nearest), 0x3c02);
+
+        // Overflow.
+        assert_eq!(f32_to_f16(1e6, Nearest), 0x7c00);
+        assert_eq!(f32_to_f16(1e6, Down), 0x7bff);
+        assert_eq!(f32_to_f16(1e6, Up), 0x7c00);
+        assert_eq!(f32_to_f16(1e6, Truncate), 0x7bff);
+        assert_eq!(f32_to_f16(-1e6, Down), 0xfc00);
+        assert_eq!(f32_to_f16(-1e6, Up), 0xfbff);
+        // 65520 is halfway between 65504 and 2^16.
+        assert_eq!(f32_to_f16(65520., Nearest), 0x7c00);
+        assert_eq!(f32_to_f16(65519., Nearest), 0x7bff);
+
+        // Underflow.
+        let tiny = float(0x0000_0001);
+        assert_eq!(f32_to_f16(tiny, Nearest), 0x0000);
+        assert_eq!(f32_to_f16(tiny, Up), 0x0001);
+        assert_eq!(f32_to_f16(-tiny, Down), 0x8001);
+        assert_eq!(f32_to_f16(-tiny, Truncate), 0x8000);
+        // 2^-25 ties to zero, anything above rounds to 2^-24.
+        assert_eq!(f32_to_f16(float(0x3300_0000), Nearest), 0x0000);
+        assert_eq!(f32_to_f16(float(0x3300_0001), Nearest), 0x0001);
+        // The largest denormal rounds up into the smallest normal.
+        assert_eq!(f32_to_f16(float(0x387f_f000), Nearest), 0x0400);
+
+        // NaNs.
+        assert_eq!(f32_to_f16(float(0x7f80_0001), Nearest), 0x7e00);
+        assert_eq!(f32_to_f16(float(0xffc0_2000), Nearest), 0xfe01);
+    }
+
+    #[test]
+    fn vectors() {
+        let a = f32x8::new(0., -1., 0.5, 65504., 1e6, -2., 0.25, 3.);
+        let h = f16x8::from_f32x8(a);
+        assert_eq!(
+            h.to_bits(),
+            u16x8::new(
+                0x0000, 0xbc00, 0x3800, 0x7bff, 0x7c00, 0xc000, 0x3400, 0x4200
+            )
+        );
+        let e = f32x8::new(
+            0., -1., 0.5, 65504., ::core::f32::INFINITY, -2., 0.25, 3.
+        );
+        assert_eq!(h.to_f32x8(), e);
+        assert_eq!(f32x8::from(h), e);
+
+        let h = f16x8::from_f32x8_round(
+            f32x8::splat(1e6),
+            F16RoundingMode::Truncate,
+        );
+        assert_eq!(h.to_bits(), u16x8::splat(0x7bff));
+
+        let a = f32x16::new(
+            1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15.,
+            16.,
+        );
+        assert_eq!(f16x16::from_f32x16(a).to_f32x16(), a);
+    }
+}
@@ -14,7 +14,7 @@
 #![feature(const_fn, link_llvm_intrinsics, platform_intrinsics, repr_simd,
            simd_ffi, target_feature, cfg_target_feature, i128_type, asm,
            const_atomic_usize_new, stmt_expr_attributes, core_intrinsics,
-           crate_in_paths)]
+           crate_in_paths, abi_unadjusted, rustc_attrs)]
 #![cfg_attr(test, feature(proc_macro, test, attr_literals))]
 #![cfg_attr(feature = "cargo-clippy",
             allow(inline_always, too_many_arguments, cast_sign_loss,
@@ -34,12 +34,27 @@ extern crate stdsimd_test;
 #[cfg(test)]
 extern crate test;
 
+#[cfg(all(not(test), feature = "quickcheck"))]
+extern crate std;
+
+#[cfg(feature = "quickcheck")]
+extern crate quickcheck;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "serde")]
+extern crate serde;
+
 /// Platform independent SIMD vector types and operations.
 pub mod simd {
+    pub use complex::*;
+    pub use f16::*;
     pub use v128::*;
     pub use v256::*;
     pub use v512::*;
     pub use v64::*;
+
+    #[cfg(feature = "rand")]
+    pub use rand_impls::LaneRange;
 }
 
 /// Platform dependent vendor intrinsics.
@@ -88,6 +103,14 @@ mod runtime;
 
 #[macro_use]
 mod macros;
+mod complex;
+mod f16;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls;
+#[cfg(feature = "rand")]
+mod rand_impls;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod simd_llvm;
 mod v128;
 mod v256;
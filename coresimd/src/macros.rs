@@ -392,6 +392,37 @@ macro_rules! define_casts {
     }
 }
 
+/// Implements safe methods on a target feature token.
+///
+/// Each `fn name(args) -> ret = intrinsic;` entry becomes a method that
+/// calls `intrinsic` from an inner function compiled with the target feature
+/// of the token. This is sound because the token can only be constructed
+/// when the target feature is available.
+macro_rules! token_methods {
+    ($token:ident = $feature:tt {
+        $(
+            $(#[$attr:meta])*
+            fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)*
+                = $intrinsic:ident;
+        )*
+    }) => {
+        impl $token {
+            $(
+                $(#[$attr])*
+                #[inline]
+                pub fn $name(self, $($arg: $ty),*) $(-> $ret)* {
+                    #[inline]
+                    #[target_feature(enable = $feature)]
+                    unsafe fn __imp($($arg: $ty),*) $(-> $ret)* {
+                        $intrinsic($($arg),*)
+                    }
+                    unsafe { __imp($($arg),*) }
+                }
+            )*
+        }
+    }
+}
+
 #[cfg(test)]
 #[macro_export]
 macro_rules! test_arithmetic_ {
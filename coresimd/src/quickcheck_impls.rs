@@ -0,0 +1,118 @@
+//! `quickcheck` support for the portable vector types
+//!
+//! Lanes are generated independently with their element type's `Arbitrary`
+//! implementation. Shrinking shrinks one lane at a time, so a failing input
+//! is reduced lane by lane while the other lanes keep their values.
+
+use std::boxed::Box;
+
+use quickcheck::{Arbitrary, Gen};
+
+use simd::*;
+
+macro_rules! impl_arbitrary {
+    ($(($ty:ident, $elem:ident, $nelems:expr)),+) => {
+        $(
+            impl Arbitrary for $ty {
+                fn arbitrary<G: Gen>(g: &mut G) -> $ty {
+                    let mut v = $ty::splat(0 as $elem);
+                    for i in 0..$nelems {
+                        v = v.replace(i, $elem::arbitrary(g));
+                    }
+                    v
+                }
+
+                fn shrink(&self) -> Box<Iterator<Item = $ty>> {
+                    let v = *self;
+                    Box::new((0..$nelems).flat_map(move |i| {
+                        v.extract(i).shrink().map(move |x| v.replace(i, x))
+                    }))
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_arbitrary_via {
+    ($(($ty:ident, $repr:ident, $into:ident, $from:ident)),+) => {
+        $(
+            impl Arbitrary for $ty {
+                fn arbitrary<G: Gen>(g: &mut G) -> $ty {
+                    $ty::$from($repr::arbitrary(g))
+                }
+
+                fn shrink(&self) -> Box<Iterator<Item = $ty>> {
+                    Box::new(self.$into().shrink().map($ty::$from))
+                }
+            }
+        )+
+    };
+}
+
+// 64-bit wide vector types
+impl_arbitrary!(
+    (f32x2, f32, 2),
+    (u32x2, u32, 2),
+    (i32x2, i32, 2),
+    (u16x4, u16, 4),
+    (i16x4, i16, 4),
+    (u8x8, u8, 8),
+    (i8x8, i8, 8)
+);
+
+// 128-bit wide vector types
+impl_arbitrary!(
+    (f64x2, f64, 2),
+    (f32x4, f32, 4),
+    (u128x1, u128, 1),
+    (i128x1, i128, 1),
+    (u64x2, u64, 2),
+    (i64x2, i64, 2),
+    (u32x4, u32, 4),
+    (i32x4, i32, 4),
+    (u16x8, u16, 8),
+    (i16x8, i16, 8),
+    (u8x16, u8, 16),
+    (i8x16, i8, 16)
+);
+
+// 256-bit wide vector types
+impl_arbitrary!(
+    (f64x4, f64, 4),
+    (f32x8, f32, 8),
+    (u128x2, u128, 2),
+    (i128x2, i128, 2),
+    (u64x4, u64, 4),
+    (i64x4, i64, 4),
+    (u32x8, u32, 8),
+    (i32x8, i32, 8),
+    (u16x16, u16, 16),
+    (i16x16, i16, 16),
+    (u8x32, u8, 32),
+    (i8x32, i8, 32)
+);
+
+// 512-bit wide vector types
+impl_arbitrary!(
+    (f64x8, f64, 8),
+    (f32x16, f32, 16),
+    (u128x4, u128, 4),
+    (i128x4, i128, 4),
+    (u64x8, u64, 8),
+    (i64x8, i64, 8),
+    (u32x16, u32, 16),
+    (i32x16, i32, 16),
+    (u16x32, u16, 32),
+    (i16x32, i16, 32),
+    (u8x64, u8, 64),
+    (i8x64, i8, 64)
+);
+
+impl_arbitrary_via!(
+    (f16x8, f32x8, to_f32x8, from_f32x8),
+    (f16x16, f32x16, to_f32x16, from_f32x16),
+    (c32x2, f32x4, interleaved, from_interleaved),
+    (c32x4, f32x8, interleaved, from_interleaved),
+    (c64x1, f64x2, interleaved, from_interleaved),
+    (c64x2, f64x4, interleaved, from_interleaved)
+);
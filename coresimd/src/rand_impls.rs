@@ -0,0 +1,167 @@
+//! `rand` support for the portable vector types
+//!
+//! Every vector type implements `Rand` by sampling each lane independently
+//! with its element type's `Rand` implementation; float lanes are therefore
+//! uniformly distributed in `[0, 1)`.
+//!
+//! `LaneRange` samples vectors whose lanes are uniformly distributed in
+//! per-lane half-open ranges.
+
+use rand::{Rand, Rng};
+use rand::distributions::{IndependentSample, Range, Sample};
+
+use simd::*;
+
+/// Samples vectors whose lanes are uniformly distributed in `[low, high)`,
+/// lane-wise.
+///
+/// This is the vector counterpart of `rand::distributions::Range`: lane `i`
+/// is sampled as if by `Range::new(low.extract(i), high.extract(i))`.
+#[derive(Clone, Copy, Debug)]
+pub struct LaneRange<T> {
+    low: T,
+    high: T,
+}
+
+macro_rules! impl_rand {
+    ($(($ty:ident, $elem:ident, $nelems:expr)),+) => {
+        $(
+            impl Rand for $ty {
+                #[inline]
+                fn rand<R: Rng>(rng: &mut R) -> $ty {
+                    let mut v = $ty::splat(0 as $elem);
+                    for i in 0..$nelems {
+                        v = v.replace(i, rng.gen());
+                    }
+                    v
+                }
+            }
+
+            impl LaneRange<$ty> {
+                /// Creates a new `LaneRange` whose lane `i` is sampled from
+                /// `[low.extract(i), high.extract(i))`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `low.extract(i) >= high.extract(i)` for any
+                /// lane `i`.
+                pub fn new(low: $ty, high: $ty) -> LaneRange<$ty> {
+                    for i in 0..$nelems {
+                        assert!(
+                            low.extract(i) < high.extract(i),
+                            "LaneRange::new called with `low >= high`"
+                        );
+                    }
+                    LaneRange { low: low, high: high }
+                }
+            }
+
+            impl Sample<$ty> for LaneRange<$ty> {
+                #[inline]
+                fn sample<R: Rng>(&mut self, rng: &mut R) -> $ty {
+                    self.ind_sample(rng)
+                }
+            }
+
+            impl IndependentSample<$ty> for LaneRange<$ty> {
+                fn ind_sample<R: Rng>(&self, rng: &mut R) -> $ty {
+                    let mut v = self.low;
+                    for i in 0..$nelems {
+                        let range = Range::new(
+                            self.low.extract(i),
+                            self.high.extract(i),
+                        );
+                        v = v.replace(i, range.ind_sample(rng));
+                    }
+                    v
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_rand_via {
+    ($(($ty:ident, $repr:ident, $from:expr)),+) => {
+        $(
+            impl Rand for $ty {
+                #[inline]
+                fn rand<R: Rng>(rng: &mut R) -> $ty {
+                    $from($repr::rand(rng))
+                }
+            }
+        )+
+    };
+}
+
+// 64-bit wide vector types
+impl_rand!(
+    (f32x2, f32, 2),
+    (u32x2, u32, 2),
+    (i32x2, i32, 2),
+    (u16x4, u16, 4),
+    (i16x4, i16, 4),
+    (u8x8, u8, 8),
+    (i8x8, i8, 8)
+);
+
+// 128-bit wide vector types
+impl_rand!(
+    (f64x2, f64, 2),
+    (f32x4, f32, 4),
+    (u128x1, u128, 1),
+    (i128x1, i128, 1),
+    (u64x2, u64, 2),
+    (i64x2, i64, 2),
+    (u32x4, u32, 4),
+    (i32x4, i32, 4),
+    (u16x8, u16, 8),
+    (i16x8, i16, 8),
+    (u8x16, u8, 16),
+    (i8x16, i8, 16)
+);
+
+// 256-bit wide vector types
+impl_rand!(
+    (f64x4, f64, 4),
+    (f32x8, f32, 8),
+    (u128x2, u128, 2),
+    (i128x2, i128, 2),
+    (u64x4, u64, 4),
+    (i64x4, i64, 4),
+    (u32x8, u32, 8),
+    (i32x8, i32, 8),
+    (u16x16, u16, 16),
+    (i16x16, i16, 16),
+    (u8x32, u8, 32),
+    (i8x32, i8, 32)
+);
+
+// 512-bit wide vector types
+impl_rand!(
+    (f64x8, f64, 8),
+    (f32x16, f32, 16),
+    (u128x4, u128, 4),
+    (i128x4, i128, 4),
+    (u64x8, u64, 8),
+    (i64x8, i64, 8),
+    (u32x16, u32, 16),
+    (i32x16, i32, 16),
+    (u16x32, u16, 32),
+    (i16x32, i16, 32),
+    (u8x64, u8, 64),
+    (i8x64, i8, 64)
+);
+
+// Truncation keeps the narrowed lanes in `[0, 1)`.
+impl_rand_via!(
+    (f16x8, f32x8, |v| {
+        f16x8::from_f32x8_round(v, F16RoundingMode::Truncate)
+    }),
+    (f16x16, f32x16, |v| {
+        f16x16::from_f32x16_round(v, F16RoundingMode::Truncate)
+    }),
+    (c32x2, f32x4, c32x2::from_interleaved),
+    (c32x4, f32x8, c32x4::from_interleaved),
+    (c64x1, f64x2, c64x1::from_interleaved),
+    (c64x2, f64x4, c64x2::from_interleaved)
+);
@@ -1,7 +1,7 @@
 //! Bit manipulation utilities
 
 /// Sets the `bit` of `x`.
-pub const fn set(x: usize, bit: u32) -> usize {
+pub const fn set(x: u64, bit: u32) -> u64 {
     x | 1 << bit
 }
 
@@ -1,20 +1,48 @@
 //! Cache of run-time feature detection
 
 use core::sync::atomic::{AtomicUsize, Ordering};
-use core::usize;
 
 use super::bit;
 
+/// Number of features stored in each word of the cache.
+///
+/// This is 31 so that the feature bits of a word fit into a 32-bit `usize`
+/// and leave its most significant bit free to mark the word as initialized.
+const WORD_FEATURES: u32 = 31;
+
+/// Maximum number of features that the cache can hold.
+const CAPACITY: u32 = 2 * WORD_FEATURES;
+
+/// Bit that is set in every word of the cache once it has been initialized.
+const INITIALIZED: usize = 1 << WORD_FEATURES;
+
 /// This global variable is a bitset used to cache the features supported by
-/// the
-/// CPU.
-static CACHE: AtomicUsize = AtomicUsize::new(usize::MAX);
+/// the CPU.
+///
+/// The bitset is split over two `AtomicUsize`s so that more than 32 features
+/// can be cached on targets without 64-bit atomics. A word equal to zero has
+/// not been initialized yet.
+static CACHE: [AtomicUsize; 2] = [AtomicUsize::new(0), AtomicUsize::new(0)];
+
+/// Stores the feature bitset `value` into the cache.
+fn initialize(value: u64) {
+    debug_assert!(
+        value >> CAPACITY == 0,
+        "the feature cache can hold at most {} features",
+        CAPACITY
+    );
+    let mask = (1_u64 << WORD_FEATURES) - 1;
+    let lo = (value & mask) as usize | INITIALIZED;
+    let hi = ((value >> WORD_FEATURES) & mask) as usize | INITIALIZED;
+    CACHE[0].store(lo, Ordering::Relaxed);
+    CACHE[1].store(hi, Ordering::Relaxed);
+}
 
 /// Test the `bit` of the storage. If the storage has not been initialized,
 /// initializes it with the result of `f()`.
 ///
 /// On its first invocation, it detects the CPU features and caches them in the
-/// `FEATURES` global variable as an `AtomicUsize`.
+/// `CACHE` global variable.
 ///
 /// It uses the `__Feature` variant to index into this variable as a bitset. If
 /// the bit is set, the feature is enabled, and otherwise it is disabled.
@@ -22,10 +50,18 @@ static CACHE: AtomicUsize = AtomicUsize::new(usize::MAX);
 /// PLEASE: do not use this, it is an implementation detail subject to change.
 pub fn test<F>(bit: u32, f: F) -> bool
 where
-    F: FnOnce() -> usize,
+    F: FnOnce() -> u64,
 {
-    if CACHE.load(Ordering::Relaxed) == usize::MAX {
-        CACHE.store(f(), Ordering::Relaxed);
+    debug_assert!(bit < CAPACITY);
+    let (word, bit) = if bit < WORD_FEATURES {
+        (&CACHE[0], bit)
+    } else {
+        (&CACHE[1], bit - WORD_FEATURES)
+    };
+    let mut cached = word.load(Ordering::Relaxed);
+    if cached == 0 {
+        initialize(f());
+        cached = word.load(Ordering::Relaxed);
     }
-    bit::test(CACHE.load(Ordering::Relaxed), bit)
+    bit::test(cached, bit)
 }
@@ -33,8 +33,8 @@ pub enum __Feature {
     power8,
 }
 
-pub fn detect_features<T: HasFeature>(mut x: T) -> usize {
-    let mut value: usize = 0;
+pub fn detect_features<T: HasFeature>(mut x: T) -> u64 {
+    let mut value: u64 = 0;
     {
         let mut enable_feature = |f| {
             if x.has_feature(&f) {
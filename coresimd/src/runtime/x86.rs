@@ -2,7 +2,7 @@
 //!
 //! The features are detected using the `detect_features` function below.
 //! This function uses the CPUID instruction to read the feature flags from the
-//! CPU and encodes them in an `usize` where each bit position represents
+//! CPU and encodes them in a `u64` where each bit position represents
 //! whether a feature is available (bit is set) or unavaiable (bit is cleared).
 //!
 //! The enum `__Feature` is used to map bit positions to feature names, and the
@@ -155,6 +155,78 @@ macro_rules! __unstable_detect_feature {
         $unstable_detect_feature(
             $crate::__vendor_runtime::__Feature::xsavec{})
     };
+    ("aes", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::aes{})
+    };
+    ("pclmulqdq", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::pclmulqdq{})
+    };
+    ("sha", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::sha{})
+    };
+    ("f16c", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::f16c{})
+    };
+    ("rdrand", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::rdrand{})
+    };
+    ("rdseed", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::rdseed{})
+    };
+    ("adx", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::adx{})
+    };
+    ("gfni", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::gfni{})
+    };
+    ("vaes", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::vaes{})
+    };
+    ("vpclmulqdq", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::vpclmulqdq{})
+    };
+    ("rtm", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::rtm{})
+    };
+    ("hle", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::hle{})
+    };
+    ("clflushopt", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::clflushopt{})
+    };
+    ("clwb", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::clwb{})
+    };
+    ("prfchw", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::prfchw{})
+    };
+    ("monitor", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::monitor{})
+    };
+    ("xop", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::xop{})
+    };
+    ("fma4", $unstable_detect_feature:path) => {
+        $unstable_detect_feature(
+            $crate::__vendor_runtime::__Feature::fma4{})
+    };
     ($t:tt, $unstable_detect_feature:path) => {
         compile_error!(concat!("unknown target feature: ", $t))
     };
@@ -232,6 +304,42 @@ pub enum __Feature {
     xsaves,
     /// XSAVEC (Save Processor Extended States Compacted)
     xsavec,
+    /// AES (Advanced Encryption Standard New Instructions AES-NI)
+    aes,
+    /// CLMUL (Carry-less Multiplication)
+    pclmulqdq,
+    /// SHA (Secure Hash Algorithm Extensions)
+    sha,
+    /// F16C (Conversions between IEEE-754 `binary16` and `binary32` formats)
+    f16c,
+    /// RDRAND (Read Random Number)
+    rdrand,
+    /// RDSEED (Read Random Seed)
+    rdseed,
+    /// ADX (Multi-Precision Add-Carry Instruction Extensions)
+    adx,
+    /// GFNI (Galois Field New Instructions)
+    gfni,
+    /// VAES (Vector AES Instructions)
+    vaes,
+    /// VPCLMULQDQ (Vector Carry-less Multiplication)
+    vpclmulqdq,
+    /// RTM (Restricted Transactional Memory)
+    rtm,
+    /// HLE (Hardware Lock Elision)
+    hle,
+    /// CLFLUSHOPT (Optimized Cache Line Flush)
+    clflushopt,
+    /// CLWB (Cache Line Write Back)
+    clwb,
+    /// PRFCHW (Prefetch for Write)
+    prfchw,
+    /// MONITOR/MWAIT
+    monitor,
+    /// XOP (eXtended Operations)
+    xop,
+    /// FMA4 (Four-operand Fused Multiply-Add)
+    fma4,
     #[doc(hidden)] __NonExhaustive,
 }
 
@@ -250,10 +358,10 @@ pub enum __Feature {
 /// [wiki_cpuid]: https://en.wikipedia.org/wiki/CPUID
 /// [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
 /// [amd64_ref]: http://support.amd.com/TechDocs/24594.pdf
-pub fn detect_features() -> usize {
+pub fn detect_features() -> u64 {
     use vendor::{__cpuid, __cpuid_count, has_cpuid, CpuidResult};
     use vendor::_xgetbv;
-    let mut value: usize = 0;
+    let mut value: u64 = 0;
 
     // If the x86 CPU does not support the CPUID instruction then it is too
     // old to support any of the currently-detectable features.
@@ -334,18 +442,30 @@ pub fn detect_features() -> usize {
         };
 
         enable(proc_info_ecx, 0, __Feature::sse3);
+        enable(proc_info_ecx, 1, __Feature::pclmulqdq);
+        enable(proc_info_ecx, 3, __Feature::monitor);
         enable(proc_info_ecx, 9, __Feature::ssse3);
         enable(proc_info_ecx, 12, __Feature::fma);
         enable(proc_info_ecx, 19, __Feature::sse4_1);
         enable(proc_info_ecx, 20, __Feature::sse4_2);
         enable(proc_info_ecx, 23, __Feature::popcnt);
+        enable(proc_info_ecx, 25, __Feature::aes);
+        enable(proc_info_ecx, 30, __Feature::rdrand);
         enable(proc_info_edx, 24, __Feature::fxsr);
         enable(proc_info_edx, 23, __Feature::mmx);
         enable(proc_info_edx, 25, __Feature::sse);
         enable(proc_info_edx, 26, __Feature::sse2);
 
         enable(extended_features_ebx, 3, __Feature::bmi);
+        enable(extended_features_ebx, 4, __Feature::hle);
         enable(extended_features_ebx, 8, __Feature::bmi2);
+        enable(extended_features_ebx, 11, __Feature::rtm);
+        enable(extended_features_ebx, 18, __Feature::rdseed);
+        enable(extended_features_ebx, 19, __Feature::adx);
+        enable(extended_features_ebx, 23, __Feature::clflushopt);
+        enable(extended_features_ebx, 24, __Feature::clwb);
+        enable(extended_features_ebx, 29, __Feature::sha);
+        enable(extended_features_ecx, 8, __Feature::gfni);
 
         // `XSAVE` and `AVX` support:
         let cpu_xsave = bit::test(proc_info_ecx as usize, 26);
@@ -403,6 +523,19 @@ pub fn detect_features() -> usize {
                 // And AVX/AVX2:
                 enable(proc_info_ecx, 28, __Feature::avx);
                 enable(extended_features_ebx, 5, __Feature::avx2);
+                enable(proc_info_ecx, 29, __Feature::f16c);
+
+                // The vector forms of AES and CLMUL are VEX/EVEX encoded
+                // and thus also depend on the OS saving the AVX registers:
+                enable(extended_features_ecx, 9, __Feature::vaes);
+                enable(extended_features_ecx, 10, __Feature::vpclmulqdq);
+
+                // XOP and FMA4 are VEX encoded as well, and only available
+                // on AMD CPUs:
+                if vendor_id == *b"AuthenticAMD" {
+                    enable(extended_proc_info_ecx, 11, __Feature::xop);
+                    enable(extended_proc_info_ecx, 16, __Feature::fma4);
+                }
 
                 // For AVX-512 the OS also needs to support saving/restoring
                 // the extended state, only then we enable AVX-512 support:
@@ -433,6 +566,7 @@ pub fn detect_features() -> usize {
         // The `cfg_feature_enabled!("lzcnt")` macro then
         // internally maps to __Feature::abm.
         enable(extended_proc_info_ecx, 5, __Feature::abm);
+        enable(extended_proc_info_ecx, 8, __Feature::prfchw);
         if vendor_id == *b"AuthenticAMD" {
             // These features are only available on AMD CPUs:
             enable(extended_proc_info_ecx, 6, __Feature::sse4a);
@@ -483,6 +617,24 @@ mod tests {
         println!("xsaveopt: {:?}", cfg_feature_enabled!("xsaveopt"));
         println!("xsaves: {:?}", cfg_feature_enabled!("xsaves"));
         println!("xsavec: {:?}", cfg_feature_enabled!("xsavec"));
+        println!("aes: {:?}", cfg_feature_enabled!("aes"));
+        println!("pclmulqdq: {:?}", cfg_feature_enabled!("pclmulqdq"));
+        println!("sha: {:?}", cfg_feature_enabled!("sha"));
+        println!("f16c: {:?}", cfg_feature_enabled!("f16c"));
+        println!("rdrand: {:?}", cfg_feature_enabled!("rdrand"));
+        println!("rdseed: {:?}", cfg_feature_enabled!("rdseed"));
+        println!("adx: {:?}", cfg_feature_enabled!("adx"));
+        println!("gfni: {:?}", cfg_feature_enabled!("gfni"));
+        println!("vaes: {:?}", cfg_feature_enabled!("vaes"));
+        println!("vpclmulqdq: {:?}", cfg_feature_enabled!("vpclmulqdq"));
+        println!("rtm: {:?}", cfg_feature_enabled!("rtm"));
+        println!("hle: {:?}", cfg_feature_enabled!("hle"));
+        println!("clflushopt: {:?}", cfg_feature_enabled!("clflushopt"));
+        println!("clwb: {:?}", cfg_feature_enabled!("clwb"));
+        println!("prfchw: {:?}", cfg_feature_enabled!("prfchw"));
+        println!("monitor: {:?}", cfg_feature_enabled!("monitor"));
+        println!("xop: {:?}", cfg_feature_enabled!("xop"));
+        println!("fma4: {:?}", cfg_feature_enabled!("fma4"));
     }
 
     #[test]
@@ -533,5 +685,15 @@ mod tests {
             cfg_feature_enabled!("xsaves"),
             information.xsaves_xrstors_and_ia32_xss()
         );
+        assert_eq!(cfg_feature_enabled!("aes"), information.aesni());
+        assert_eq!(
+            cfg_feature_enabled!("pclmulqdq"),
+            information.pclmulqdq()
+        );
+        assert_eq!(cfg_feature_enabled!("sha"), information.sha());
+        assert_eq!(cfg_feature_enabled!("f16c"), information.f16c());
+        assert_eq!(cfg_feature_enabled!("rdrand"), information.rdrand());
+        assert_eq!(cfg_feature_enabled!("rdseed"), information.rdseed());
+        assert_eq!(cfg_feature_enabled!("adx"), information.adx());
     }
 }
@@ -0,0 +1,172 @@
+//! `serde` support for the portable vector types
+//!
+//! Vectors are serialized as fixed-size tuples of their lanes, which is the
+//! same representation `serde` uses for arrays, so a `f32x4` round-trips
+//! through `[f32; 4]`. The `f16` vectors serialize the `binary16` bit
+//! patterns of their lanes, and the complex vectors their interleaved
+//! `[re, im, ...]` lanes.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use simd::*;
+
+/// Visitor for a tuple of `len` lanes of a vector type `T`.
+struct LanesVisitor<T> {
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+macro_rules! impl_serde {
+    ($(($ty:ident, $elem:ident, $nelems:expr)),+) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let mut tuple = serializer.serialize_tuple($nelems)?;
+                    for i in 0..$nelems {
+                        tuple.serialize_element(&self.extract(i))?;
+                    }
+                    tuple.end()
+                }
+            }
+
+            impl<'de> Visitor<'de> for LanesVisitor<$ty> {
+                type Value = $ty;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(
+                        f,
+                        "a tuple of {} `{}` lanes",
+                        self.len,
+                        stringify!($elem)
+                    )
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<$ty, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut v = $ty::splat(0 as $elem);
+                    for i in 0..$nelems {
+                        let x = seq.next_element()?.ok_or_else(|| {
+                            de::Error::invalid_length(i as usize, &self)
+                        })?;
+                        v = v.replace(i, x);
+                    }
+                    Ok(v)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let visitor = LanesVisitor::<$ty> {
+                        len: $nelems,
+                        _marker: PhantomData,
+                    };
+                    deserializer.deserialize_tuple($nelems, visitor)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_serde_via {
+    ($(($ty:ident, $repr:ident, $into:ident, $from:ident)),+) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    self.$into().serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    $repr::deserialize(deserializer).map($ty::$from)
+                }
+            }
+        )+
+    };
+}
+
+// 64-bit wide vector types
+impl_serde!(
+    (f32x2, f32, 2),
+    (u32x2, u32, 2),
+    (i32x2, i32, 2),
+    (u16x4, u16, 4),
+    (i16x4, i16, 4),
+    (u8x8, u8, 8),
+    (i8x8, i8, 8)
+);
+
+// 128-bit wide vector types
+impl_serde!(
+    (f64x2, f64, 2),
+    (f32x4, f32, 4),
+    (u128x1, u128, 1),
+    (i128x1, i128, 1),
+    (u64x2, u64, 2),
+    (i64x2, i64, 2),
+    (u32x4, u32, 4),
+    (i32x4, i32, 4),
+    (u16x8, u16, 8),
+    (i16x8, i16, 8),
+    (u8x16, u8, 16),
+    (i8x16, i8, 16)
+);
+
+// 256-bit wide vector types
+impl_serde!(
+    (f64x4, f64, 4),
+    (f32x8, f32, 8),
+    (u128x2, u128, 2),
+    (i128x2, i128, 2),
+    (u64x4, u64, 4),
+    (i64x4, i64, 4),
+    (u32x8, u32, 8),
+    (i32x8, i32, 8),
+    (u16x16, u16, 16),
+    (i16x16, i16, 16),
+    (u8x32, u8, 32),
+    (i8x32, i8, 32)
+);
+
+// 512-bit wide vector types
+impl_serde!(
+    (f64x8, f64, 8),
+    (f32x16, f32, 16),
+    (u128x4, u128, 4),
+    (i128x4, i128, 4),
+    (u64x8, u64, 8),
+    (i64x8, i64, 8),
+    (u32x16, u32, 16),
+    (i32x16, i32, 16),
+    (u16x32, u16, 32),
+    (i16x32, i16, 32),
+    (u8x64, u8, 64),
+    (i8x64, i8, 64)
+);
+
+impl_serde_via!(
+    (f16x8, u16x8, to_bits, from_bits),
+    (f16x16, u16x16, to_bits, from_bits),
+    (c32x2, f32x4, interleaved, from_interleaved),
+    (c32x4, f32x8, interleaved, from_interleaved),
+    (c64x1, f64x2, interleaved, from_interleaved),
+    (c64x2, f64x4, interleaved, from_interleaved)
+);
@@ -8,6 +8,12 @@ define_impl! { f64x2, f64, 2, i64x2, x0, x1 }
 define_ty! { f32x4, f32, f32, f32, f32 }
 define_impl! { f32x4, f32, 4, i32x4, x0, x1, x2, x3 }
 
+define_ty! { u128x1, u128 }
+define_impl! { u128x1, u128, 1, i128x1, x0 }
+
+define_ty! { i128x1, i128 }
+define_impl! { i128x1, i128, 1, i128x1, x0 }
+
 define_ty! { u64x2, u64, u64 }
 define_impl! { u64x2, u64, 2, i64x2, x0, x1 }
 
@@ -42,8 +48,34 @@ define_impl! {
     x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15
 }
 
+define_from!(
+    u128x1,
+    i128x1,
+    u64x2,
+    i64x2,
+    u32x4,
+    i32x4,
+    u16x8,
+    i16x8,
+    u8x16,
+    i8x16
+);
+define_from!(
+    i128x1,
+    u128x1,
+    u64x2,
+    i64x2,
+    u32x4,
+    i32x4,
+    u16x8,
+    i16x8,
+    u8x16,
+    i8x16
+);
 define_from!(
     u64x2,
+    u128x1,
+    i128x1,
     i64x2,
     u32x4,
     i32x4,
@@ -54,6 +86,8 @@ define_from!(
 );
 define_from!(
     i64x2,
+    u128x1,
+    i128x1,
     u64x2,
     u32x4,
     i32x4,
@@ -64,6 +98,8 @@ define_from!(
 );
 define_from!(
     u32x4,
+    u128x1,
+    i128x1,
     u64x2,
     i64x2,
     i32x4,
@@ -74,6 +110,8 @@ define_from!(
 );
 define_from!(
     i32x4,
+    u128x1,
+    i128x1,
     u64x2,
     i64x2,
     u32x4,
@@ -84,6 +122,8 @@ define_from!(
 );
 define_from!(
     u16x8,
+    u128x1,
+    i128x1,
     u64x2,
     i64x2,
     u32x4,
@@ -94,6 +134,8 @@ define_from!(
 );
 define_from!(
     i16x8,
+    u128x1,
+    i128x1,
     u64x2,
     i64x2,
     u32x4,
@@ -104,6 +146,8 @@ define_from!(
 );
 define_from!(
     u8x16,
+    u128x1,
+    i128x1,
     u64x2,
     i64x2,
     u32x4,
@@ -114,6 +158,8 @@ define_from!(
 );
 define_from!(
     i8x16,
+    u128x1,
+    i128x1,
     u64x2,
     i64x2,
     u32x4,
@@ -126,6 +172,8 @@ define_from!(
 define_common_ops!(
     f64x2,
     f32x4,
+    u128x1,
+    i128x1,
     u64x2,
     i64x2,
     u32x4,
@@ -137,6 +185,8 @@ define_common_ops!(
 );
 define_float_ops!(f64x2, f32x4);
 define_integer_ops!(
+    (u128x1, u128),
+    (i128x1, i128),
     (u64x2, u64),
     (i64x2, i64),
     (u32x4, u32),
@@ -146,7 +196,7 @@ define_integer_ops!(
     (u8x16, u8),
     (i8x16, i8)
 );
-define_signed_integer_ops!(i64x2, i32x4, i16x8, i8x16);
+define_signed_integer_ops!(i128x1, i64x2, i32x4, i16x8, i8x16);
 define_casts!(
     (f64x2, f32x2, as_f32x2),
     (f64x2, u64x2, as_u64x2),
@@ -154,6 +204,8 @@ define_casts!(
     (f32x4, f64x4, as_f64x4),
     (f32x4, u32x4, as_u32x4),
     (f32x4, i32x4, as_i32x4),
+    (u128x1, i128x1, as_i128x1),
+    (i128x1, u128x1, as_u128x1),
     (u64x2, f64x2, as_f64x2),
     (u64x2, i64x2, as_i64x2),
     (i64x2, f64x2, as_f64x2),
@@ -174,8 +226,8 @@ mod tests {
 
     #[test]
     fn operators() {
-        test_ops_si!(i8x16, i16x8, i32x4, i64x2);
-        test_ops_ui!(u8x16, u16x8, u32x4, u64x2);
+        test_ops_si!(i8x16, i16x8, i32x4, i64x2, i128x1);
+        test_ops_ui!(u8x16, u16x8, u32x4, u64x2, u128x1);
         test_ops_f!(f32x4, f64x2);
     }
 }
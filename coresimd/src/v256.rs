@@ -8,6 +8,12 @@ define_impl! { f64x4, f64, 4, i64x4, x0, x1, x2, x3 }
 define_ty! { f32x8, f32, f32, f32, f32, f32, f32, f32, f32 }
 define_impl! { f32x8, f32, 8, i32x8, x0, x1, x2, x3, x4, x5, x6, x7 }
 
+define_ty! { u128x2, u128, u128 }
+define_impl! { u128x2, u128, 2, i128x2, x0, x1 }
+
+define_ty! { i128x2, i128, i128 }
+define_impl! { i128x2, i128, 2, i128x2, x0, x1 }
+
 define_ty! { u64x4, u64, u64, u64, u64 }
 define_impl! { u64x4, u64, 4, i64x4, x0, x1, x2, x3 }
 
@@ -66,8 +72,34 @@ define_impl! {
     x24, x25, x26, x27, x28, x29, x30, x31
 }
 
+define_from!(
+    u128x2,
+    i128x2,
+    u64x4,
+    i64x4,
+    u32x8,
+    i32x8,
+    u16x16,
+    i16x16,
+    u8x32,
+    i8x32
+);
+define_from!(
+    i128x2,
+    u128x2,
+    u64x4,
+    i64x4,
+    u32x8,
+    i32x8,
+    u16x16,
+    i16x16,
+    u8x32,
+    i8x32
+);
 define_from!(
     u64x4,
+    u128x2,
+    i128x2,
     i64x4,
     u32x8,
     i32x8,
@@ -78,6 +110,8 @@ define_from!(
 );
 define_from!(
     i64x4,
+    u128x2,
+    i128x2,
     u64x4,
     u32x8,
     i32x8,
@@ -88,6 +122,8 @@ define_from!(
 );
 define_from!(
     u32x8,
+    u128x2,
+    i128x2,
     u64x4,
     i64x4,
     i32x8,
@@ -98,6 +134,8 @@ define_from!(
 );
 define_from!(
     i32x8,
+    u128x2,
+    i128x2,
     u64x4,
     i64x4,
     u32x8,
@@ -108,6 +146,8 @@ define_from!(
 );
 define_from!(
     u16x16,
+    u128x2,
+    i128x2,
     u64x4,
     i64x4,
     u32x8,
@@ -118,6 +158,8 @@ define_from!(
 );
 define_from!(
     i16x16,
+    u128x2,
+    i128x2,
     u64x4,
     i64x4,
     u32x8,
@@ -128,6 +170,8 @@ define_from!(
 );
 define_from!(
     u8x32,
+    u128x2,
+    i128x2,
     u64x4,
     i64x4,
     u32x8,
@@ -138,6 +182,8 @@ define_from!(
 );
 define_from!(
     i8x32,
+    u128x2,
+    i128x2,
     u64x4,
     i64x4,
     u32x8,
@@ -150,6 +196,8 @@ define_from!(
 define_common_ops!(
     f64x4,
     f32x8,
+    u128x2,
+    i128x2,
     u64x4,
     i64x4,
     u32x8,
@@ -161,6 +209,8 @@ define_common_ops!(
 );
 define_float_ops!(f64x4, f32x8);
 define_integer_ops!(
+    (u128x2, u128),
+    (i128x2, i128),
     (u64x4, u64),
     (i64x4, i64),
     (u32x8, u32),
@@ -170,13 +220,15 @@ define_integer_ops!(
     (u8x32, u8),
     (i8x32, i8)
 );
-define_signed_integer_ops!(i64x4, i32x8, i16x16, i8x32);
+define_signed_integer_ops!(i128x2, i64x4, i32x8, i16x16, i8x32);
 define_casts!(
     (f64x4, f32x4, as_f32x4),
     (f64x4, u64x4, as_u64x4),
     (f64x4, i64x4, as_i64x4),
     (f32x8, u32x8, as_u32x8),
     (f32x8, i32x8, as_i32x8),
+    (u128x2, i128x2, as_i128x2),
+    (i128x2, u128x2, as_u128x2),
     (u64x4, f64x4, as_f64x4),
     (u64x4, i64x4, as_i64x4),
     (i64x4, f64x4, as_f64x4),
@@ -197,8 +249,8 @@ mod tests {
 
     #[test]
     fn operators() {
-        test_ops_si!(i8x32, i16x16, i32x8, i64x4);
-        test_ops_ui!(u8x32, u16x16, u32x8, u64x4);
+        test_ops_si!(i8x32, i16x16, i32x8, i64x4, i128x2);
+        test_ops_ui!(u8x32, u16x16, u32x8, u64x4, u128x2);
         test_ops_f!(f32x8, f64x4);
     }
 }
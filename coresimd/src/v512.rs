@@ -16,6 +16,12 @@ define_impl! {
     x8, x9, x10, x11, x12, x13, x14, x15
 }
 
+define_ty! { u128x4, u128, u128, u128, u128 }
+define_impl! { u128x4, u128, 4, i128x4, x0, x1, x2, x3 }
+
+define_ty! { i128x4, i128, i128, i128, i128 }
+define_impl! { i128x4, i128, 4, i128x4, x0, x1, x2, x3 }
+
 define_ty! { u64x8, u64, u64, u64, u64, u64, u64, u64, u64 }
 define_impl! { u64x8, u64, 8, i64x8, x0, x1, x2, x3, x4, x5, x6, x7 }
 
@@ -112,18 +118,132 @@ define_impl! {
     x56, x57, x58, x59, x60, x61, x62, x63
 }
 
-define_from!(u64x8, i64x8, u32x16, i32x16, u16x32, i16x32, u8x64, i8x64);
-define_from!(i64x8, u64x8, u32x16, i32x16, u16x32, i16x32, u8x64, i8x64);
-define_from!(u32x16, u64x8, i64x8, i32x16, u16x32, i16x32, u8x64, i8x64);
-define_from!(i32x16, u64x8, i64x8, u32x16, u16x32, i16x32, u8x64, i8x64);
-define_from!(u16x32, u64x8, i64x8, u32x16, i32x16, i16x32, u8x64, i8x64);
-define_from!(i16x32, u64x8, i64x8, u32x16, i32x16, u16x32, u8x64, i8x64);
-define_from!(u8x64, u64x8, i64x8, u32x16, i32x16, u16x32, i16x32, i8x64);
-define_from!(i8x64, u64x8, i64x8, u32x16, i32x16, u16x32, i16x32, u8x64);
+define_from!(
+    u128x4,
+    i128x4,
+    u64x8,
+    i64x8,
+    u32x16,
+    i32x16,
+    u16x32,
+    i16x32,
+    u8x64,
+    i8x64
+);
+define_from!(
+    i128x4,
+    u128x4,
+    u64x8,
+    i64x8,
+    u32x16,
+    i32x16,
+    u16x32,
+    i16x32,
+    u8x64,
+    i8x64
+);
+define_from!(
+    u64x8,
+    u128x4,
+    i128x4,
+    i64x8,
+    u32x16,
+    i32x16,
+    u16x32,
+    i16x32,
+    u8x64,
+    i8x64
+);
+define_from!(
+    i64x8,
+    u128x4,
+    i128x4,
+    u64x8,
+    u32x16,
+    i32x16,
+    u16x32,
+    i16x32,
+    u8x64,
+    i8x64
+);
+define_from!(
+    u32x16,
+    u128x4,
+    i128x4,
+    u64x8,
+    i64x8,
+    i32x16,
+    u16x32,
+    i16x32,
+    u8x64,
+    i8x64
+);
+define_from!(
+    i32x16,
+    u128x4,
+    i128x4,
+    u64x8,
+    i64x8,
+    u32x16,
+    u16x32,
+    i16x32,
+    u8x64,
+    i8x64
+);
+define_from!(
+    u16x32,
+    u128x4,
+    i128x4,
+    u64x8,
+    i64x8,
+    u32x16,
+    i32x16,
+    i16x32,
+    u8x64,
+    i8x64
+);
+define_from!(
+    i16x32,
+    u128x4,
+    i128x4,
+    u64x8,
+    i64x8,
+    u32x16,
+    i32x16,
+    u16x32,
+    u8x64,
+    i8x64
+);
+define_from!(
+    u8x64,
+    u128x4,
+    i128x4,
+    u64x8,
+    i64x8,
+    u32x16,
+    i32x16,
+    u16x32,
+    i16x32,
+    i8x64
+);
+define_from!(
+    i8x64,
+    u128x4,
+    i128x4,
+    u64x8,
+    i64x8,
+    u32x16,
+    i32x16,
+    u16x32,
+    i16x32,
+    u8x64
+);
 
 define_common_ops!(
     f64x8,
     f32x16,
+    u128x4,
+    i128x4,
     u64x8,
     i64x8,
     u32x16,
@@ -135,6 +255,8 @@ define_common_ops!(
 );
 define_float_ops!(f64x8, f32x16);
 define_integer_ops!(
+    (u128x4, u128),
+    (i128x4, i128),
     (u64x8, u64),
     (i64x8, i64),
     (u32x16, u32),
@@ -144,13 +266,15 @@ define_integer_ops!(
     (u8x64, u8),
     (i8x64, i8)
 );
-define_signed_integer_ops!(i64x8, i32x16, i16x32, i8x64);
+define_signed_integer_ops!(i128x4, i64x8, i32x16, i16x32, i8x64);
 define_casts!(
     (f64x8, f32x8, as_f32x8),
     (f64x8, u64x8, as_u64x8),
     (f64x8, i64x8, as_i64x8),
     (f32x16, u32x16, as_u32x16),
     (f32x16, i32x16, as_i32x16),
+    (u128x4, i128x4, as_i128x4),
+    (i128x4, u128x4, as_u128x4),
     (u64x8, f64x8, as_f64x8),
     (u64x8, i64x8, as_i64x8),
     (i64x8, f64x8, as_f64x8),
@@ -171,8 +295,8 @@ mod tests {
 
     #[test]
     fn operators() {
-        test_ops_si!(i8x64, i16x32, i32x16, i64x8);
-        test_ops_ui!(u8x64, u16x32, u32x16, u64x8);
+        test_ops_si!(i8x64, i16x32, i32x16, i64x8, i128x4);
+        test_ops_ui!(u8x64, u16x32, u32x16, u64x8, u128x4);
         test_ops_f!(f32x16, f64x8);
     }
 }
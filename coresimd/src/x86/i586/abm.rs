@@ -38,6 +38,14 @@ pub unsafe fn _popcnt32(x: i32) -> i32 {
     x.count_ones() as i32
 }
 
+/// Counts the bits that are set.
+#[inline]
+#[target_feature(enable = "popcnt")]
+#[cfg_attr(test, assert_instr(popcnt))]
+pub unsafe fn _mm_popcnt_u32(x: u32) -> i32 {
+    x.count_ones() as i32
+}
+
 #[cfg(test)]
 mod tests {
     use stdsimd_test::simd_test;
@@ -53,4 +61,10 @@ mod tests {
     unsafe fn _popcnt32() {
         assert_eq!(abm::_popcnt32(0b0101_1010), 4);
     }
+
+    #[simd_test = "popcnt"]
+    unsafe fn _mm_popcnt_u32() {
+        assert_eq!(abm::_mm_popcnt_u32(0b0101_1010), 4);
+        assert_eq!(abm::_mm_popcnt_u32(!0), 32);
+    }
 }
@@ -0,0 +1,164 @@
+//! Multi-Precision Add-Carry Instruction Extensions (ADX)
+//!
+//! The `_addcarry` and `_subborrow` intrinsics compile to the `adc` and `sbb`
+//! instructions, which every x86 CPU supports. The `_addcarryx` intrinsics
+//! require the `adx` target feature, which lets LLVM use `adcx` and `adox`
+//! to interleave two independent carry chains.
+//!
+//! All of them take the incoming carry (or borrow) in `c_in`, write the sum
+//! (or difference) to `out`, and return the outgoing carry (or borrow), so
+//! that multi-limb integers can be processed one limb at a time.
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.addcarry.u32"]
+    fn llvm_addcarry_u32(c_in: u8, a: u32, b: u32, out: *mut u8) -> u8;
+    #[link_name = "llvm.x86.addcarryx.u32"]
+    fn llvm_addcarryx_u32(c_in: u8, a: u32, b: u32, out: *mut u8) -> u8;
+    #[link_name = "llvm.x86.subborrow.u32"]
+    fn llvm_subborrow_u32(c_in: u8, a: u32, b: u32, out: *mut u8) -> u8;
+}
+
+/// Add unsigned 32-bit integers `a` and `b` with unsigned 8-bit carry-in
+/// `c_in` (carry flag), and store the unsigned 32-bit result in `out`, and
+/// the carry-out is returned (carry or overflow flag).
+#[inline]
+#[cfg_attr(test, assert_instr(adc))]
+pub unsafe fn _addcarry_u32(c_in: u8, a: u32, b: u32, out: &mut u32) -> u8 {
+    llvm_addcarry_u32(c_in, a, b, out as *mut u32 as *mut u8)
+}
+
+/// Add unsigned 32-bit integers `a` and `b` with unsigned 8-bit carry-in
+/// `c_in` (carry or overflow flag), and store the unsigned 32-bit result in
+/// `out`, and the carry-out is returned (carry or overflow flag).
+#[inline]
+#[target_feature(enable = "adx")]
+#[cfg_attr(test, assert_instr(adc))]
+pub unsafe fn _addcarryx_u32(c_in: u8, a: u32, b: u32, out: &mut u32) -> u8 {
+    llvm_addcarryx_u32(c_in, a, b, out as *mut u32 as *mut u8)
+}
+
+/// Subtract unsigned 32-bit integer `b` and unsigned 8-bit borrow-in `c_in`
+/// (carry flag) from unsigned 32-bit integer `a`, and store the unsigned
+/// 32-bit result in `out`, and the borrow-out is returned (carry flag).
+#[inline]
+#[cfg_attr(test, assert_instr(sbb))]
+pub unsafe fn _subborrow_u32(c_in: u8, a: u32, b: u32, out: &mut u32) -> u8 {
+    llvm_subborrow_u32(c_in, a, b, out as *mut u32 as *mut u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    /// Splits `x` into 32-bit limbs, least significant limb first.
+    fn limbs(x: u128) -> [u32; 4] {
+        [
+            x as u32,
+            (x >> 32) as u32,
+            (x >> 64) as u32,
+            (x >> 96) as u32,
+        ]
+    }
+
+    /// Operands whose limbs exercise carries and borrows in every position.
+    const OPERANDS: [u128; 8] = [
+        0,
+        1,
+        0xffff_ffff,
+        0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+        0x8000_0000_0000_0000_0000_0000_0000_0000,
+        0x0000_0001_ffff_ffff_0000_0000_ffff_ffff,
+        0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+        0xfedc_ba98_7654_3210_0123_4567_89ab_cdef,
+    ];
+
+    /// Adds `a` and `b` limb by limb with `add`, returning the sum and the
+    /// final carry.
+    fn chain<F>(a: u128, b: u128, add: F) -> (u128, u8)
+    where
+        F: Fn(u8, u32, u32, &mut u32) -> u8,
+    {
+        let (a, b) = (limbs(a), limbs(b));
+        let mut r = [0_u32; 4];
+        let mut c = 0;
+        for i in 0..4 {
+            c = add(c, a[i], b[i], &mut r[i]);
+        }
+        let r = r.iter().rev().fold(0_u128, |acc, &x| acc << 32 | x as u128);
+        (r, c)
+    }
+
+    #[test]
+    fn test_addcarry_u32() {
+        unsafe {
+            let mut out = 0;
+            assert_eq!(_addcarry_u32(0, 1, 2, &mut out), 0);
+            assert_eq!(out, 3);
+            assert_eq!(_addcarry_u32(1, 1, 2, &mut out), 0);
+            assert_eq!(out, 4);
+            assert_eq!(_addcarry_u32(0, !0, 1, &mut out), 1);
+            assert_eq!(out, 0);
+            assert_eq!(_addcarry_u32(1, !0, !0, &mut out), 1);
+            assert_eq!(out, !0);
+
+            for &a in OPERANDS.iter() {
+                for &b in OPERANDS.iter() {
+                    let (r, c) = chain(a, b, |c, a, b, out| {
+                        _addcarry_u32(c, a, b, out)
+                    });
+                    let (e, overflow) = a.overflowing_add(b);
+                    assert_eq!((r, c), (e, overflow as u8));
+                }
+            }
+        }
+    }
+
+    #[simd_test = "adx"]
+    unsafe fn test_addcarryx_u32() {
+        let mut out = 0;
+        assert_eq!(_addcarryx_u32(0, 1, 2, &mut out), 0);
+        assert_eq!(out, 3);
+        assert_eq!(_addcarryx_u32(1, !0, 0, &mut out), 1);
+        assert_eq!(out, 0);
+
+        for &a in OPERANDS.iter() {
+            for &b in OPERANDS.iter() {
+                let (r, c) =
+                    chain(a, b, |c, a, b, out| _addcarryx_u32(c, a, b, out));
+                let (e, overflow) = a.overflowing_add(b);
+                assert_eq!((r, c), (e, overflow as u8));
+            }
+        }
+    }
+
+    #[test]
+    fn test_subborrow_u32() {
+        unsafe {
+            let mut out = 0;
+            assert_eq!(_subborrow_u32(0, 3, 2, &mut out), 0);
+            assert_eq!(out, 1);
+            assert_eq!(_subborrow_u32(1, 3, 2, &mut out), 0);
+            assert_eq!(out, 0);
+            assert_eq!(_subborrow_u32(0, 0, 1, &mut out), 1);
+            assert_eq!(out, !0);
+            assert_eq!(_subborrow_u32(1, 0, !0, &mut out), 1);
+            assert_eq!(out, 0);
+
+            for &a in OPERANDS.iter() {
+                for &b in OPERANDS.iter() {
+                    let (r, c) = chain(a, b, |c, a, b, out| {
+                        _subborrow_u32(c, a, b, out)
+                    });
+                    let (e, overflow) = a.overflowing_sub(b);
+                    assert_eq!((r, c), (e, overflow as u8));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! AES New Instructions (AES-NI)
+//!
+//! The intrinsics here correspond to those in the `wmmintrin.h` C header.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use x86::__m128i;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.aesni.aesdec"]
+    fn aesdec(a: __m128i, round_key: __m128i) -> __m128i;
+    #[link_name = "llvm.x86.aesni.aesdeclast"]
+    fn aesdeclast(a: __m128i, round_key: __m128i) -> __m128i;
+    #[link_name = "llvm.x86.aesni.aesenc"]
+    fn aesenc(a: __m128i, round_key: __m128i) -> __m128i;
+    #[link_name = "llvm.x86.aesni.aesenclast"]
+    fn aesenclast(a: __m128i, round_key: __m128i) -> __m128i;
+    #[link_name = "llvm.x86.aesni.aesimc"]
+    fn aesimc(a: __m128i) -> __m128i;
+    #[link_name = "llvm.x86.aesni.aeskeygenassist"]
+    fn aeskeygenassist(a: __m128i, imm8: u8) -> __m128i;
+}
+
+/// Perform one round of an AES decryption flow on data (state) in `a`.
+#[inline]
+#[target_feature(enable = "aes")]
+#[cfg_attr(test, assert_instr(aesdec))]
+pub unsafe fn _mm_aesdec_si128(a: __m128i, round_key: __m128i) -> __m128i {
+    aesdec(a, round_key)
+}
+
+/// Perform the last round of an AES decryption flow on data (state) in `a`.
+#[inline]
+#[target_feature(enable = "aes")]
+#[cfg_attr(test, assert_instr(aesdeclast))]
+pub unsafe fn _mm_aesdeclast_si128(a: __m128i, round_key: __m128i) -> __m128i {
+    aesdeclast(a, round_key)
+}
+
+/// Perform one round of an AES encryption flow on data (state) in `a`.
+#[inline]
+#[target_feature(enable = "aes")]
+#[cfg_attr(test, assert_instr(aesenc))]
+pub unsafe fn _mm_aesenc_si128(a: __m128i, round_key: __m128i) -> __m128i {
+    aesenc(a, round_key)
+}
+
+/// Perform the last round of an AES encryption flow on data (state) in `a`.
+#[inline]
+#[target_feature(enable = "aes")]
+#[cfg_attr(test, assert_instr(aesenclast))]
+pub unsafe fn _mm_aesenclast_si128(a: __m128i, round_key: __m128i) -> __m128i {
+    aesenclast(a, round_key)
+}
+
+/// Perform the `InvMixColumns` transformation on `a`.
+///
+/// This converts an encryption round key into the round key for the
+/// equivalent inverse cipher used by `_mm_aesdec_si128`.
+#[inline]
+#[target_feature(enable = "aes")]
+#[cfg_attr(test, assert_instr(aesimc))]
+pub unsafe fn _mm_aesimc_si128(a: __m128i) -> __m128i {
+    aesimc(a)
+}
+
+/// Assist in expanding the AES cipher key.
+///
+/// Assist in expanding the AES cipher key by computing steps towards
+/// generating a round key for encryption cipher using data from `a` and an
+/// 8-bit round constant `imm8`.
+#[inline]
+#[target_feature(enable = "aes")]
+#[cfg_attr(test, assert_instr(aeskeygenassist, imm8 = 0))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm_aeskeygenassist_si128(a: __m128i, imm8: i32) -> __m128i {
+    macro_rules! call {
+        ($imm8:expr) => {
+            aeskeygenassist(a, $imm8)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+#[cfg(test)]
+mod tests {
+    // The constants in the single-instruction tests are taken from Intel's
+    // "Advanced Encryption Standard (AES) New Instructions Set" white paper,
+    // and the full cipher is checked against the example vectors of
+    // FIPS-197, Appendix C.1 (AES-128).
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "aes"]
+    unsafe fn test_mm_aesenc_si128() {
+        let a = _mm_set_epi64x(0x7b5b546573745665, 0x63746f725d53475d);
+        let k = _mm_set_epi64x(0x4869285368617929, 0x5b477565726f6e5d);
+        let e = _mm_set_epi64x(
+            0xa8311c2f9fdba3c5u64 as i64,
+            0x8b104b58ded7e595u64 as i64,
+        );
+        assert_eq_m128i(_mm_aesenc_si128(a, k), e);
+    }
+
+    #[simd_test = "aes"]
+    unsafe fn test_mm_aesenclast_si128() {
+        let a = _mm_set_epi64x(0x7b5b546573745665, 0x63746f725d53475d);
+        let k = _mm_set_epi64x(0x4869285368617929, 0x5b477565726f6e5d);
+        let e =
+            _mm_set_epi64x(0xc7fb881e938c5964u64 as i64, 0x177ec42553fdc611);
+        assert_eq_m128i(_mm_aesenclast_si128(a, k), e);
+    }
+
+    #[simd_test = "aes"]
+    unsafe fn test_mm_aesdec_si128() {
+        let a = _mm_set_epi64x(0x7b5b546573745665, 0x63746f725d53475d);
+        let k = _mm_set_epi64x(0x4869285368617929, 0x5b477565726f6e5d);
+        let e =
+            _mm_set_epi64x(0x138ac342faea2787, 0xb58eb95eb730392au64 as i64);
+        assert_eq_m128i(_mm_aesdec_si128(a, k), e);
+    }
+
+    #[simd_test = "aes"]
+    unsafe fn test_mm_aesdeclast_si128() {
+        let a = _mm_set_epi64x(0x7b5b546573745665, 0x63746f725d53475d);
+        let k = _mm_set_epi64x(0x4869285368617929, 0x5b477565726f6e5d);
+        let e = _mm_set_epi64x(
+            0xc5a391ef6b317f95u64 as i64,
+            0xd410637b72a593d0u64 as i64,
+        );
+        assert_eq_m128i(_mm_aesdeclast_si128(a, k), e);
+    }
+
+    #[simd_test = "aes"]
+    unsafe fn test_mm_aesimc_si128() {
+        let a = _mm_set_epi64x(0x4869285368617929, 0x5b477565726f6e5d);
+        let e =
+            _mm_set_epi64x(0x597f8df109efaa15, 0xf39bc5a119d859b6u64 as i64);
+        assert_eq_m128i(_mm_aesimc_si128(a), e);
+    }
+
+    #[simd_test = "aes"]
+    unsafe fn test_mm_aeskeygenassist_si128() {
+        let a =
+            _mm_set_epi64x(0x3c4fcf098815f7ab, 0xa6d2ae2816157e2bu64 as i64);
+        let e = _mm_set_epi64x(0x01eb848beb848a01, 0x3424b5e524b5e434);
+        assert_eq_m128i(_mm_aeskeygenassist_si128(a, 0x01), e);
+    }
+
+    /// Computes the next AES-128 round key from `key` and the output of
+    /// `_mm_aeskeygenassist_si128`.
+    #[target_feature(enable = "aes")]
+    unsafe fn expand_round_key(key: __m128i, assist: __m128i) -> __m128i {
+        let assist = _mm_shuffle_epi32(assist, 0xff);
+        let key = _mm_xor_si128(key, _mm_slli_si128(key, 4));
+        let key = _mm_xor_si128(key, _mm_slli_si128(key, 4));
+        let key = _mm_xor_si128(key, _mm_slli_si128(key, 4));
+        _mm_xor_si128(key, assist)
+    }
+
+    #[target_feature(enable = "aes")]
+    unsafe fn expand_key_128(key: __m128i) -> [__m128i; 11] {
+        macro_rules! round {
+            ($prev:expr, $rcon:expr) => {
+                expand_round_key(
+                    $prev,
+                    _mm_aeskeygenassist_si128($prev, $rcon),
+                )
+            };
+        }
+        let k0 = key;
+        let k1 = round!(k0, 0x01);
+        let k2 = round!(k1, 0x02);
+        let k3 = round!(k2, 0x04);
+        let k4 = round!(k3, 0x08);
+        let k5 = round!(k4, 0x10);
+        let k6 = round!(k5, 0x20);
+        let k7 = round!(k6, 0x40);
+        let k8 = round!(k7, 0x80);
+        let k9 = round!(k8, 0x1b);
+        let k10 = round!(k9, 0x36);
+        [k0, k1, k2, k3, k4, k5, k6, k7, k8, k9, k10]
+    }
+
+    #[simd_test = "aes"]
+    unsafe fn test_fips197_aes128() {
+        let key: [u8; 16] = [
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a,
+            0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        ];
+        let plaintext: [u8; 16] = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa,
+            0xbb, 0xcc, 0xdd, 0xee, 0xff,
+        ];
+        let ciphertext: [u8; 16] = [
+            0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7,
+            0x80, 0x70, 0xb4, 0xc5, 0x5a,
+        ];
+        let key = _mm_loadu_si128(key.as_ptr() as *const _);
+        let plaintext = _mm_loadu_si128(plaintext.as_ptr() as *const _);
+        let ciphertext = _mm_loadu_si128(ciphertext.as_ptr() as *const _);
+
+        let keys = expand_key_128(key);
+        // `round[10].k_sch` of FIPS-197, Appendix C.1.
+        assert_eq_m128i(
+            keys[10],
+            _mm_set_epi64x(0xc5302b4d8ba707f3u64 as i64, 0x174a94e37f1d1113),
+        );
+
+        let mut state = _mm_xor_si128(plaintext, keys[0]);
+        for key in &keys[1..10] {
+            state = _mm_aesenc_si128(state, *key);
+        }
+        state = _mm_aesenclast_si128(state, keys[10]);
+        assert_eq_m128i(state, ciphertext);
+
+        let mut state = _mm_xor_si128(ciphertext, keys[10]);
+        for key in keys[1..10].iter().rev() {
+            state = _mm_aesdec_si128(state, _mm_aesimc_si128(*key));
+        }
+        state = _mm_aesdeclast_si128(state, keys[0]);
+        assert_eq_m128i(state, plaintext);
+    }
+}
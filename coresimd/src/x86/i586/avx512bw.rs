@@ -0,0 +1,1522 @@
+//! AVX-512 Byte and Word (AVX-512BW) intrinsics.
+//!
+//! AVX-512BW extends the 512-bit integer instructions of AVX-512F to 8-bit
+//! and 16-bit elements, which in turn need the 32-bit and 64-bit opmask
+//! types `__mmask32` and `__mmask64`.
+//!
+//! The 128-bit and 256-bit forms additionally require AVX-512VL.
+//!
+//! [Intel Intrinsics Guide][intel_guide].
+//!
+//! [intel_guide]: https://software.intel.com/sites/landingpage/IntrinsicsGuide/#techs=AVX_512&avx512techs=AVX512BW
+
+use core::mem;
+
+use simd_llvm::*;
+use v128::*;
+use v256::*;
+use v512::*;
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Add packed 8-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddb))]
+pub unsafe fn _mm512_add_epi8(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_add(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// Add packed 16-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpaddw))]
+pub unsafe fn _mm512_add_epi16(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_add(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Subtract packed 8-bit integers in `b` from `a`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubb))]
+pub unsafe fn _mm512_sub_epi8(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_sub(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// Subtract packed 16-bit integers in `b` from `a`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsubw))]
+pub unsafe fn _mm512_sub_epi16(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_sub(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Multiply the packed 16-bit integers in `a` and `b`, producing intermediate
+/// 32-bit integers, and return the low 16 bits of the intermediate integers.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmullw))]
+pub unsafe fn _mm512_mullo_epi16(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_mul(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Multiply the packed 16-bit integers in `a` and `b`, producing intermediate
+/// 32-bit integers, and return the high 16 bits of the intermediate integers.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhw))]
+pub unsafe fn _mm512_mulhi_epi16(a: __m512i, b: __m512i) -> __m512i {
+    let src = _mm512_setzero_si512().as_i16x32();
+    mem::transmute(pmulhw(a.as_i16x32(), b.as_i16x32(), src, -1))
+}
+
+/// Multiply the packed unsigned 16-bit integers in `a` and `b`, producing
+/// intermediate 32-bit integers, and return the high 16 bits of the
+/// intermediate integers.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhuw))]
+pub unsafe fn _mm512_mulhi_epu16(a: __m512i, b: __m512i) -> __m512i {
+    let src = _mm512_setzero_si512().as_u16x32();
+    mem::transmute(pmulhuw(a.as_u16x32(), b.as_u16x32(), src, -1))
+}
+
+/// Multiply packed 16-bit integers in `a` and `b`, producing intermediate
+/// signed 32-bit integers. Truncate each intermediate integer to the 18 most
+/// significant bits, round by adding 1, and return bits `[16:1]`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmulhrsw))]
+pub unsafe fn _mm512_mulhrs_epi16(a: __m512i, b: __m512i) -> __m512i {
+    let src = _mm512_setzero_si512().as_i16x32();
+    mem::transmute(pmulhrsw(a.as_i16x32(), b.as_i16x32(), src, -1))
+}
+
+/// Multiply packed signed 16-bit integers in `a` and `b`, producing
+/// intermediate signed 32-bit integers. Horizontally add adjacent pairs of
+/// intermediate 32-bit integers.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaddwd))]
+pub unsafe fn _mm512_madd_epi16(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(pmaddwd(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Vertically multiply each unsigned 8-bit integer from `a` with the
+/// corresponding signed 8-bit integer from `b`, producing intermediate signed
+/// 16-bit integers. Horizontally add adjacent pairs of intermediate signed
+/// 16-bit integers with signed saturation.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmaddubsw))]
+pub unsafe fn _mm512_maddubs_epi16(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(pmaddubsw(a.as_u8x64(), b.as_u8x64()))
+}
+
+/// Compute the absolute differences of packed unsigned 8-bit integers in `a`
+/// and `b`, then horizontally sum each consecutive 8 differences to produce
+/// eight unsigned 16-bit integers, and pack these unsigned 16-bit integers in
+/// the low 16 bits of the 64-bit elements of the returned vector.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpsadbw))]
+pub unsafe fn _mm512_sad_epu8(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(psadbw(a.as_u8x64(), b.as_u8x64()))
+}
+
+/// Convert packed 32-bit integers from `a` and `b` to packed 16-bit integers
+/// using signed saturation. As with the 256-bit form, each 128-bit lane is
+/// packed independently.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackssdw))]
+pub unsafe fn _mm512_packs_epi32(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(packssdw(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Convert packed 16-bit integers from `a` and `b` to packed 8-bit integers
+/// using signed saturation. As with the 256-bit form, each 128-bit lane is
+/// packed independently.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpacksswb))]
+pub unsafe fn _mm512_packs_epi16(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(packsswb(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Convert packed 32-bit integers from `a` and `b` to packed 16-bit integers
+/// using unsigned saturation. As with the 256-bit form, each 128-bit lane is
+/// packed independently.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackusdw))]
+pub unsafe fn _mm512_packus_epi32(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(packusdw(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Convert packed 16-bit integers from `a` and `b` to packed 8-bit integers
+/// using unsigned saturation. As with the 256-bit form, each 128-bit lane is
+/// packed independently.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpackuswb))]
+pub unsafe fn _mm512_packus_epi16(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(packuswb(a.as_i16x32(), b.as_i16x32()))
+}
+
+/// Shuffle packed 8-bit integers in `a` according to the shuffle control mask
+/// in the corresponding 8-bit element of `b`. As with the 256-bit form, bytes
+/// are only shuffled within each 128-bit lane.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpshufb))]
+pub unsafe fn _mm512_shuffle_epi8(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(pshufb(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// Average packed unsigned 8-bit integers in `a` and `b`, rounding up.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpavgb))]
+pub unsafe fn _mm512_avg_epu8(a: __m512i, b: __m512i) -> __m512i {
+    let src = _mm512_setzero_si512().as_u8x64();
+    mem::transmute(pavgb(a.as_u8x64(), b.as_u8x64(), src, -1))
+}
+
+/// Average packed unsigned 16-bit integers in `a` and `b`, rounding up.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpavgw))]
+pub unsafe fn _mm512_avg_epu16(a: __m512i, b: __m512i) -> __m512i {
+    let src = _mm512_setzero_si512().as_u16x32();
+    mem::transmute(pavgw(a.as_u16x32(), b.as_u16x32(), src, -1))
+}
+
+/// Compare packed 8-bit integers in `a` and `b` for equality, and return the
+/// results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmpeqb))]
+pub unsafe fn _mm512_cmpeq_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpb(a.as_i8x64(), b.as_i8x64(), _MM_CMPINT_EQ, -1) as __mmask64
+}
+
+/// Compare packed 8-bit integers in `a` and `b` for inequality, and return the
+/// results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpb(a.as_i8x64(), b.as_i8x64(), _MM_CMPINT_NE, -1) as __mmask64
+}
+
+/// Compare packed 8-bit integers in `a` and `b` for less-than, and return the
+/// results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpb(a.as_i8x64(), b.as_i8x64(), _MM_CMPINT_LT, -1) as __mmask64
+}
+
+/// Compare packed 8-bit integers in `a` and `b` for less-than-or-equal, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpb(a.as_i8x64(), b.as_i8x64(), _MM_CMPINT_LE, -1) as __mmask64
+}
+
+/// Compare packed 8-bit integers in `a` and `b` for greater-than, and return
+/// the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmpgtb))]
+pub unsafe fn _mm512_cmpgt_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpb(a.as_i8x64(), b.as_i8x64(), _MM_CMPINT_NLE, -1) as __mmask64
+}
+
+/// Compare packed 8-bit integers in `a` and `b` for greater-than-or-equal, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epi8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpb(a.as_i8x64(), b.as_i8x64(), _MM_CMPINT_NLT, -1) as __mmask64
+}
+
+/// Compare packed 8-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epi8_mask(
+    a: __m512i, b: __m512i, imm8: i32
+) -> __mmask64 {
+    let a = a.as_i8x64();
+    let b = b.as_i8x64();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpb(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask64
+}
+
+/// Compare packed 8-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epi8_mask(
+    a: __m256i, b: __m256i, imm8: i32
+) -> __mmask32 {
+    let a = a.as_i8x32();
+    let b = b.as_i8x32();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpb256(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask32
+}
+
+/// Compare packed 8-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epi8_mask(
+    a: __m128i, b: __m128i, imm8: i32
+) -> __mmask16 {
+    let a = a.as_i8x16();
+    let b = b.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpb128(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask16
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` for equality, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpeq_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpub(a.as_u8x64(), b.as_u8x64(), _MM_CMPINT_EQ, -1) as __mmask64
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` for inequality, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpub(a.as_u8x64(), b.as_u8x64(), _MM_CMPINT_NE, -1) as __mmask64
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` for less-than, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpub(a.as_u8x64(), b.as_u8x64(), _MM_CMPINT_LT, -1) as __mmask64
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` for less-than-or-
+/// equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpub(a.as_u8x64(), b.as_u8x64(), _MM_CMPINT_LE, -1) as __mmask64
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` for greater-than, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpgt_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpub(a.as_u8x64(), b.as_u8x64(), _MM_CMPINT_NLE, -1) as __mmask64
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` for greater-than-or-
+/// equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epu8_mask(a: __m512i, b: __m512i) -> __mmask64 {
+    vpcmpub(a.as_u8x64(), b.as_u8x64(), _MM_CMPINT_NLT, -1) as __mmask64
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epu8_mask(
+    a: __m512i, b: __m512i, imm8: i32
+) -> __mmask64 {
+    let a = a.as_u8x64();
+    let b = b.as_u8x64();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpub(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask64
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epu8_mask(
+    a: __m256i, b: __m256i, imm8: i32
+) -> __mmask32 {
+    let a = a.as_u8x32();
+    let b = b.as_u8x32();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpub256(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask32
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epu8_mask(
+    a: __m128i, b: __m128i, imm8: i32
+) -> __mmask16 {
+    let a = a.as_u8x16();
+    let b = b.as_u8x16();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpub128(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask16
+}
+
+/// Compare packed 16-bit integers in `a` and `b` for equality, and return the
+/// results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmpeqw))]
+pub unsafe fn _mm512_cmpeq_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpw(a.as_i16x32(), b.as_i16x32(), _MM_CMPINT_EQ, -1) as __mmask32
+}
+
+/// Compare packed 16-bit integers in `a` and `b` for inequality, and return
+/// the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpw(a.as_i16x32(), b.as_i16x32(), _MM_CMPINT_NE, -1) as __mmask32
+}
+
+/// Compare packed 16-bit integers in `a` and `b` for less-than, and return the
+/// results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpw(a.as_i16x32(), b.as_i16x32(), _MM_CMPINT_LT, -1) as __mmask32
+}
+
+/// Compare packed 16-bit integers in `a` and `b` for less-than-or-equal, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpw(a.as_i16x32(), b.as_i16x32(), _MM_CMPINT_LE, -1) as __mmask32
+}
+
+/// Compare packed 16-bit integers in `a` and `b` for greater-than, and return
+/// the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmpgtw))]
+pub unsafe fn _mm512_cmpgt_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpw(a.as_i16x32(), b.as_i16x32(), _MM_CMPINT_NLE, -1) as __mmask32
+}
+
+/// Compare packed 16-bit integers in `a` and `b` for greater-than-or-equal,
+/// and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epi16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpw(a.as_i16x32(), b.as_i16x32(), _MM_CMPINT_NLT, -1) as __mmask32
+}
+
+/// Compare packed 16-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epi16_mask(
+    a: __m512i, b: __m512i, imm8: i32
+) -> __mmask32 {
+    let a = a.as_i16x32();
+    let b = b.as_i16x32();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpw(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask32
+}
+
+/// Compare packed 16-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epi16_mask(
+    a: __m256i, b: __m256i, imm8: i32
+) -> __mmask16 {
+    let a = a.as_i16x16();
+    let b = b.as_i16x16();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpw256(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask16
+}
+
+/// Compare packed 16-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epi16_mask(
+    a: __m128i, b: __m128i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_i16x8();
+    let b = b.as_i16x8();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpw128(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` for equality, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpeq_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpuw(a.as_u16x32(), b.as_u16x32(), _MM_CMPINT_EQ, -1) as __mmask32
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` for inequality, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpuw(a.as_u16x32(), b.as_u16x32(), _MM_CMPINT_NE, -1) as __mmask32
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` for less-than, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpuw(a.as_u16x32(), b.as_u16x32(), _MM_CMPINT_LT, -1) as __mmask32
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` for less-than-or-
+/// equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpuw(a.as_u16x32(), b.as_u16x32(), _MM_CMPINT_LE, -1) as __mmask32
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` for greater-than,
+/// and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpgt_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpuw(a.as_u16x32(), b.as_u16x32(), _MM_CMPINT_NLE, -1) as __mmask32
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` for greater-than-or-
+/// equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epu16_mask(a: __m512i, b: __m512i) -> __mmask32 {
+    vpcmpuw(a.as_u16x32(), b.as_u16x32(), _MM_CMPINT_NLT, -1) as __mmask32
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epu16_mask(
+    a: __m512i, b: __m512i, imm8: i32
+) -> __mmask32 {
+    let a = a.as_u16x32();
+    let b = b.as_u16x32();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpuw(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask32
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epu16_mask(
+    a: __m256i, b: __m256i, imm8: i32
+) -> __mmask16 {
+    let a = a.as_u16x16();
+    let b = b.as_u16x16();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpuw256(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask16
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epu16_mask(
+    a: __m128i, b: __m128i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_u16x8();
+    let b = b.as_u16x8();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpuw128(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 8-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovb2m))]
+pub unsafe fn _mm512_movepi8_mask(a: __m512i) -> __mmask64 {
+    vpmovb2m512(a.as_i8x64()) as __mmask64
+}
+
+/// Set each packed 8-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovm2b))]
+pub unsafe fn _mm512_movm_epi8(k: __mmask64) -> __m512i {
+    mem::transmute(vpmovm2b512(k as i64))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 8-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovb2m))]
+pub unsafe fn _mm256_movepi8_mask(a: __m256i) -> __mmask32 {
+    vpmovb2m256(a.as_i8x32()) as __mmask32
+}
+
+/// Set each packed 8-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2b))]
+pub unsafe fn _mm256_movm_epi8(k: __mmask32) -> __m256i {
+    mem::transmute(vpmovm2b256(k as i32))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 8-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovb2m))]
+pub unsafe fn _mm_movepi8_mask(a: __m128i) -> __mmask16 {
+    vpmovb2m128(a.as_i8x16()) as __mmask16
+}
+
+/// Set each packed 8-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2b))]
+pub unsafe fn _mm_movm_epi8(k: __mmask16) -> __m128i {
+    mem::transmute(vpmovm2b128(k as i16))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 16-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovw2m))]
+pub unsafe fn _mm512_movepi16_mask(a: __m512i) -> __mmask32 {
+    vpmovw2m512(a.as_i16x32()) as __mmask32
+}
+
+/// Set each packed 16-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vpmovm2w))]
+pub unsafe fn _mm512_movm_epi16(k: __mmask32) -> __m512i {
+    mem::transmute(vpmovm2w512(k as i32))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 16-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovw2m))]
+pub unsafe fn _mm256_movepi16_mask(a: __m256i) -> __mmask16 {
+    vpmovw2m256(a.as_i16x16()) as __mmask16
+}
+
+/// Set each packed 16-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2w))]
+pub unsafe fn _mm256_movm_epi16(k: __mmask16) -> __m256i {
+    mem::transmute(vpmovm2w256(k as i16))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 16-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovw2m))]
+pub unsafe fn _mm_movepi16_mask(a: __m128i) -> __mmask8 {
+    vpmovw2m128(a.as_i16x8()) as __mmask8
+}
+
+/// Set each packed 16-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512bw,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2w))]
+pub unsafe fn _mm_movm_epi16(k: __mmask8) -> __m128i {
+    mem::transmute(vpmovm2w128(k as i8))
+}
+
+/// Move packed 8-bit integers from `a` into the returned vector using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_mask_mov_epi8(
+    src: __m512i, k: __mmask64, a: __m512i
+) -> __m512i {
+    let m = vpmovm2b512(k as i64);
+    let not_m = simd_xor(m, i8x64::splat(-1));
+    let a = simd_and(m, a.as_i8x64());
+    mem::transmute(simd_or(a, simd_and(not_m, src.as_i8x64())))
+}
+
+/// Move packed 8-bit integers from `a` into the returned vector using zeromask
+/// `k` (elements are zeroed out when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_maskz_mov_epi8(k: __mmask64, a: __m512i) -> __m512i {
+    mem::transmute(simd_and(vpmovm2b512(k as i64), a.as_i8x64()))
+}
+
+/// Load packed 8-bit integers from memory into the returned vector using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set). Memory is not accessed for masked-off elements. `mem_addr`
+/// does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_mask_loadu_epi8(
+    src: __m512i, k: __mmask64, mem_addr: *const i8
+) -> __m512i {
+    masked_load!("vmovdqu8", src, k, mem_addr)
+}
+
+/// Load packed 8-bit integers from memory into the returned vector using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set). Memory is not accessed for masked-off elements. `mem_addr` does
+/// not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_maskz_loadu_epi8(
+    k: __mmask64, mem_addr: *const i8
+) -> __m512i {
+    _mm512_mask_loadu_epi8(_mm512_setzero_si512(), k, mem_addr)
+}
+
+/// Store packed 8-bit integers from `a` into memory using writemask `k`.
+/// Elements whose mask bit is not set are not written. `mem_addr` does not
+/// need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu8))]
+pub unsafe fn _mm512_mask_storeu_epi8(
+    mem_addr: *mut i8, k: __mmask64, a: __m512i
+) {
+    masked_store!("vmovdqu8", mem_addr, k, a);
+}
+
+/// Move packed 16-bit integers from `a` into the returned vector using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_mask_mov_epi16(
+    src: __m512i, k: __mmask32, a: __m512i
+) -> __m512i {
+    let m = vpmovm2w512(k as i32);
+    let not_m = simd_xor(m, i16x32::splat(-1));
+    let a = simd_and(m, a.as_i16x32());
+    mem::transmute(simd_or(a, simd_and(not_m, src.as_i16x32())))
+}
+
+/// Move packed 16-bit integers from `a` into the returned vector using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_maskz_mov_epi16(k: __mmask32, a: __m512i) -> __m512i {
+    mem::transmute(simd_and(vpmovm2w512(k as i32), a.as_i16x32()))
+}
+
+/// Load packed 16-bit integers from memory into the returned vector using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set). Memory is not accessed for masked-off elements. `mem_addr`
+/// does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_mask_loadu_epi16(
+    src: __m512i, k: __mmask32, mem_addr: *const i16
+) -> __m512i {
+    masked_load!("vmovdqu16", src, k, mem_addr)
+}
+
+/// Load packed 16-bit integers from memory into the returned vector using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set). Memory is not accessed for masked-off elements. `mem_addr` does
+/// not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_maskz_loadu_epi16(
+    k: __mmask32, mem_addr: *const i16
+) -> __m512i {
+    _mm512_mask_loadu_epi16(_mm512_setzero_si512(), k, mem_addr)
+}
+
+/// Store packed 16-bit integers from `a` into memory using writemask `k`.
+/// Elements whose mask bit is not set are not written. `mem_addr` does not
+/// need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+#[cfg_attr(test, assert_instr(vmovdqu16))]
+pub unsafe fn _mm512_mask_storeu_epi16(
+    mem_addr: *mut i16, k: __mmask32, a: __m512i
+) {
+    masked_store!("vmovdqu16", mem_addr, k, a);
+}
+
+/// Compute the bitwise AND of 32-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kand_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
+    a & b
+}
+
+/// Compute the bitwise NOT of 32-bit mask `a` and then AND with `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kandn_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
+    !a & b
+}
+
+/// Compute the bitwise OR of 32-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kor_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
+    a | b
+}
+
+/// Compute the bitwise XOR of 32-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kxor_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
+    a ^ b
+}
+
+/// Compute the bitwise XNOR of 32-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kxnor_mask32(a: __mmask32, b: __mmask32) -> __mmask32 {
+    !(a ^ b)
+}
+
+/// Compute the bitwise NOT of 32-bit mask `a`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _knot_mask32(a: __mmask32) -> __mmask32 {
+    !a
+}
+
+/// Compute the bitwise OR of 32-bit masks `a` and `b`, and return 1 if the
+/// result is all zeros, otherwise return 0.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kortestz_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    ((a | b) == 0) as u8
+}
+
+/// Compute the bitwise OR of 32-bit masks `a` and `b`, and return 1 if the
+/// result is all ones, otherwise return 0.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kortestc_mask32_u8(a: __mmask32, b: __mmask32) -> u8 {
+    ((a | b) == !0) as u8
+}
+
+/// Compute the bitwise AND of 64-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kand_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
+    a & b
+}
+
+/// Compute the bitwise NOT of 64-bit mask `a` and then AND with `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kandn_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
+    !a & b
+}
+
+/// Compute the bitwise OR of 64-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kor_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
+    a | b
+}
+
+/// Compute the bitwise XOR of 64-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kxor_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
+    a ^ b
+}
+
+/// Compute the bitwise XNOR of 64-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kxnor_mask64(a: __mmask64, b: __mmask64) -> __mmask64 {
+    !(a ^ b)
+}
+
+/// Compute the bitwise NOT of 64-bit mask `a`.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _knot_mask64(a: __mmask64) -> __mmask64 {
+    !a
+}
+
+/// Compute the bitwise OR of 64-bit masks `a` and `b`, and return 1 if the
+/// result is all zeros, otherwise return 0.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kortestz_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    ((a | b) == 0) as u8
+}
+
+/// Compute the bitwise OR of 64-bit masks `a` and `b`, and return 1 if the
+/// result is all ones, otherwise return 0.
+#[inline]
+#[target_feature(enable = "avx512bw")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kortestc_mask64_u8(a: __mmask64, b: __mmask64) -> u8 {
+    ((a | b) == !0) as u8
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.mask.pmulh.w.512"]
+    fn pmulhw(a: i16x32, b: i16x32, src: i16x32, k: i32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.mask.pmulhu.w.512"]
+    fn pmulhuw(a: u16x32, b: u16x32, src: u16x32, k: i32) -> u16x32;
+    #[link_name = "llvm.x86.avx512.mask.pmul.hr.sw.512"]
+    fn pmulhrsw(a: i16x32, b: i16x32, src: i16x32, k: i32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.pmaddw.d.512"]
+    fn pmaddwd(a: i16x32, b: i16x32) -> i32x16;
+    #[link_name = "llvm.x86.avx512.pmaddubs.w.512"]
+    fn pmaddubsw(a: u8x64, b: u8x64) -> i16x32;
+    #[link_name = "llvm.x86.avx512.psad.bw.512"]
+    fn psadbw(a: u8x64, b: u8x64) -> u64x8;
+    #[link_name = "llvm.x86.avx512.packssdw.512"]
+    fn packssdw(a: i32x16, b: i32x16) -> i16x32;
+    #[link_name = "llvm.x86.avx512.packsswb.512"]
+    fn packsswb(a: i16x32, b: i16x32) -> i8x64;
+    #[link_name = "llvm.x86.avx512.packusdw.512"]
+    fn packusdw(a: i32x16, b: i32x16) -> u16x32;
+    #[link_name = "llvm.x86.avx512.packuswb.512"]
+    fn packuswb(a: i16x32, b: i16x32) -> u8x64;
+    #[link_name = "llvm.x86.avx512.pshuf.b.512"]
+    fn pshufb(a: i8x64, b: i8x64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.mask.pavg.b.512"]
+    fn pavgb(a: u8x64, b: u8x64, src: u8x64, k: i64) -> u8x64;
+    #[link_name = "llvm.x86.avx512.mask.pavg.w.512"]
+    fn pavgw(a: u16x32, b: u16x32, src: u16x32, k: i32) -> u16x32;
+    #[link_name = "llvm.x86.avx512.mask.cmp.b.512"]
+    fn vpcmpb(a: i8x64, b: i8x64, op: i32, mask: i64) -> i64;
+    #[link_name = "llvm.x86.avx512.mask.cmp.b.256"]
+    fn vpcmpb256(a: i8x32, b: i8x32, op: i32, mask: i32) -> i32;
+    #[link_name = "llvm.x86.avx512.mask.cmp.b.128"]
+    fn vpcmpb128(a: i8x16, b: i8x16, op: i32, mask: i16) -> i16;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.b.512"]
+    fn vpcmpub(a: u8x64, b: u8x64, op: i32, mask: i64) -> i64;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.b.256"]
+    fn vpcmpub256(a: u8x32, b: u8x32, op: i32, mask: i32) -> i32;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.b.128"]
+    fn vpcmpub128(a: u8x16, b: u8x16, op: i32, mask: i16) -> i16;
+    #[link_name = "llvm.x86.avx512.mask.cmp.w.512"]
+    fn vpcmpw(a: i16x32, b: i16x32, op: i32, mask: i32) -> i32;
+    #[link_name = "llvm.x86.avx512.mask.cmp.w.256"]
+    fn vpcmpw256(a: i16x16, b: i16x16, op: i32, mask: i16) -> i16;
+    #[link_name = "llvm.x86.avx512.mask.cmp.w.128"]
+    fn vpcmpw128(a: i16x8, b: i16x8, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.w.512"]
+    fn vpcmpuw(a: u16x32, b: u16x32, op: i32, mask: i32) -> i32;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.w.256"]
+    fn vpcmpuw256(a: u16x16, b: u16x16, op: i32, mask: i16) -> i16;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.w.128"]
+    fn vpcmpuw128(a: u16x8, b: u16x8, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.cvtb2mask.512"]
+    fn vpmovb2m512(a: i8x64) -> i64;
+    #[link_name = "llvm.x86.avx512.cvtmask2b.512"]
+    fn vpmovm2b512(k: i64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.cvtb2mask.256"]
+    fn vpmovb2m256(a: i8x32) -> i32;
+    #[link_name = "llvm.x86.avx512.cvtmask2b.256"]
+    fn vpmovm2b256(k: i32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.cvtb2mask.128"]
+    fn vpmovb2m128(a: i8x16) -> i16;
+    #[link_name = "llvm.x86.avx512.cvtmask2b.128"]
+    fn vpmovm2b128(k: i16) -> i8x16;
+    #[link_name = "llvm.x86.avx512.cvtw2mask.512"]
+    fn vpmovw2m512(a: i16x32) -> i32;
+    #[link_name = "llvm.x86.avx512.cvtmask2w.512"]
+    fn vpmovm2w512(k: i32) -> i16x32;
+    #[link_name = "llvm.x86.avx512.cvtw2mask.256"]
+    fn vpmovw2m256(a: i16x16) -> i16;
+    #[link_name = "llvm.x86.avx512.cvtmask2w.256"]
+    fn vpmovm2w256(k: i16) -> i16x16;
+    #[link_name = "llvm.x86.avx512.cvtw2mask.128"]
+    fn vpmovw2m128(a: i16x8) -> i8;
+    #[link_name = "llvm.x86.avx512.cvtmask2w.128"]
+    fn vpmovm2w128(k: i8) -> i16x8;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_add_epi8() {
+        let a = _mm512_set1_epi8(100);
+        let b = _mm512_set1_epi8(-101);
+        let r = _mm512_add_epi8(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi8(-1));
+        let r = _mm512_add_epi8(_mm512_set1_epi8(127), _mm512_set1_epi8(1));
+        assert_eq_m512i(r, _mm512_set1_epi8(-128));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_add_epi16() {
+        let a = _mm512_set1_epi16(1000);
+        let b = _mm512_set1_epi16(-1001);
+        let r = _mm512_add_epi16(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi16(-1));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_sub_epi8() {
+        let a = _mm512_set1_epi8(-128);
+        let b = _mm512_set1_epi8(1);
+        let r = _mm512_sub_epi8(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi8(127));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_sub_epi16() {
+        let a = _mm512_set1_epi16(3);
+        let b = _mm512_set1_epi16(5);
+        let r = _mm512_sub_epi16(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi16(-2));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_mullo_epi16() {
+        let a = _mm512_set1_epi16(0x1234);
+        let b = _mm512_set1_epi16(0x100);
+        let r = _mm512_mullo_epi16(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi16(0x3400));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_mulhi_epi16() {
+        let a = _mm512_set1_epi16(-2);
+        let b = _mm512_set1_epi16(0x4000);
+        let r = _mm512_mulhi_epi16(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi16(-1));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_mulhi_epu16() {
+        let a = _mm512_set1_epi16(-2);
+        let b = _mm512_set1_epi16(0x4000);
+        let r = _mm512_mulhi_epu16(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi16(0x3fff));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_mulhrs_epi16() {
+        let a = _mm512_set1_epi16(0x4000);
+        let b = _mm512_set1_epi16(0x2000);
+        let r = _mm512_mulhrs_epi16(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi16(0x1000));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_madd_epi16() {
+        let a = _mm512_set1_epi16(2);
+        let b = _mm512_set1_epi16(-3);
+        let r = _mm512_madd_epi16(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi32(-12));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_maddubs_epi16() {
+        let a = _mm512_set1_epi8(-1);
+        let b = _mm512_set1_epi8(127);
+        let r = _mm512_maddubs_epi16(a, b);
+        // 2 * 255 * 127 saturates
+        assert_eq_m512i(r, _mm512_set1_epi16(i16::max_value()));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_sad_epu8() {
+        let a = _mm512_set1_epi8(2);
+        let b = _mm512_set1_epi8(-1);
+        let r = _mm512_sad_epu8(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi64(8 * 253));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_avg_epu8() {
+        let a = _mm512_set1_epi8(3);
+        let b = _mm512_set1_epi8(-1);
+        let r = _mm512_avg_epu8(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi8(-127));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_avg_epu16() {
+        let a = _mm512_set1_epi16(1);
+        let b = _mm512_set1_epi16(2);
+        let r = _mm512_avg_epu16(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi16(2));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_packs_epi32() {
+        let a = _mm512_set1_epi32(100_000);
+        let b = _mm512_set1_epi32(-100_000);
+        let r: [i16; 32] = mem::transmute(_mm512_packs_epi32(a, b));
+        for i in 0..32 {
+            let e = if i % 8 < 4 {
+                i16::max_value()
+            } else {
+                i16::min_value()
+            };
+            assert_eq!(r[i], e);
+        }
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_packs_epi16() {
+        let a = _mm512_set1_epi16(1000);
+        let b = _mm512_set1_epi16(-5);
+        let r: [i8; 64] = mem::transmute(_mm512_packs_epi16(a, b));
+        for i in 0..64 {
+            assert_eq!(r[i], if i % 16 < 8 { 127 } else { -5 });
+        }
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_packus_epi32() {
+        let a = _mm512_set1_epi32(-1);
+        let b = _mm512_set1_epi32(100_000);
+        let r: [u16; 32] = mem::transmute(_mm512_packus_epi32(a, b));
+        for i in 0..32 {
+            assert_eq!(r[i], if i % 8 < 4 { 0 } else { 0xffff });
+        }
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_packus_epi16() {
+        let a = _mm512_set1_epi16(300);
+        let b = _mm512_set1_epi16(7);
+        let r: [u8; 64] = mem::transmute(_mm512_packus_epi16(a, b));
+        for i in 0..64 {
+            assert_eq!(r[i], if i % 16 < 8 { 255 } else { 7 });
+        }
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_shuffle_epi8() {
+        let mut a = [0u8; 64];
+        for i in 0..64 {
+            a[i] = i as u8;
+        }
+        let a: __m512i = mem::transmute(a);
+        // select byte 15 of each lane, or zero when the top bit is set
+        let b = _mm512_set1_epi16(0x800f);
+        let r: [u8; 64] = mem::transmute(_mm512_shuffle_epi8(a, b));
+        for i in 0..64 {
+            let e = if i % 2 == 0 {
+                (i / 16 * 16 + 15) as u8
+            } else {
+                0
+            };
+            assert_eq!(r[i], e);
+        }
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_cmp_epi8_mask() {
+        let mut a = [0i8; 64];
+        for i in 0..64 {
+            a[i] = i as i8 - 32;
+        }
+        let a: __m512i = mem::transmute(a);
+        let b = _mm512_setzero_si512();
+        let lt = 0x0000_0000_ffff_ffff;
+        assert_eq!(_mm512_cmplt_epi8_mask(a, b), lt);
+        assert_eq!(_mm512_cmpge_epi8_mask(a, b), !lt);
+        assert_eq!(_mm512_cmpeq_epi8_mask(a, b), 1 << 32);
+        assert_eq!(_mm512_cmpneq_epi8_mask(a, b), !(1 << 32));
+        assert_eq!(_mm512_cmple_epi8_mask(a, b), lt | 1 << 32);
+        assert_eq!(_mm512_cmpgt_epi8_mask(a, b), !(lt | 1 << 32));
+        assert_eq!(_mm512_cmp_epi8_mask(a, b, _MM_CMPINT_LT), lt);
+        assert_eq!(_mm512_cmp_epi8_mask(a, b, _MM_CMPINT_FALSE), 0);
+        assert_eq!(_mm512_cmp_epi8_mask(a, b, _MM_CMPINT_TRUE), !0);
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_cmp_epu8_mask() {
+        let a = _mm512_set1_epi8(-1);
+        let b = _mm512_set1_epi8(1);
+        assert_eq!(_mm512_cmpgt_epu8_mask(a, b), !0);
+        assert_eq!(_mm512_cmplt_epu8_mask(a, b), 0);
+        assert_eq!(_mm512_cmpeq_epu8_mask(a, a), !0);
+        assert_eq!(_mm512_cmpneq_epu8_mask(a, a), 0);
+        assert_eq!(_mm512_cmple_epu8_mask(b, a), !0);
+        assert_eq!(_mm512_cmpge_epu8_mask(b, a), 0);
+        assert_eq!(_mm512_cmp_epu8_mask(a, b, _MM_CMPINT_NLE), !0);
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_cmp_epi16_mask() {
+        let a = _mm512_set1_epi16(-1);
+        let b = _mm512_set1_epi16(1);
+        assert_eq!(_mm512_cmplt_epi16_mask(a, b), !0);
+        assert_eq!(_mm512_cmpgt_epi16_mask(a, b), 0);
+        assert_eq!(_mm512_cmpeq_epi16_mask(a, a), !0);
+        assert_eq!(_mm512_cmpneq_epi16_mask(a, b), !0);
+        assert_eq!(_mm512_cmple_epi16_mask(a, a), !0);
+        assert_eq!(_mm512_cmpge_epi16_mask(a, b), 0);
+        assert_eq!(_mm512_cmp_epi16_mask(a, b, _MM_CMPINT_NLT), 0);
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_cmp_epu16_mask() {
+        let a = _mm512_set1_epi16(-1);
+        let b = _mm512_set1_epi16(1);
+        assert_eq!(_mm512_cmplt_epu16_mask(a, b), 0);
+        assert_eq!(_mm512_cmpgt_epu16_mask(a, b), !0);
+        assert_eq!(_mm512_cmpeq_epu16_mask(a, b), 0);
+        assert_eq!(_mm512_cmpneq_epu16_mask(a, b), !0);
+        assert_eq!(_mm512_cmple_epu16_mask(b, a), !0);
+        assert_eq!(_mm512_cmpge_epu16_mask(b, a), 0);
+        assert_eq!(_mm512_cmp_epu16_mask(a, b, _MM_CMPINT_LE), 0);
+    }
+
+    #[simd_test = "avx512bw,avx512vl"]
+    unsafe fn test_mm256_cmp_epi8_mask() {
+        let a = _mm256_set1_epi8(-1);
+        let b = _mm256_set1_epi8(1);
+        assert_eq!(_mm256_cmp_epi8_mask(a, b, _MM_CMPINT_LT), !0);
+        assert_eq!(_mm256_cmp_epu8_mask(a, b, _MM_CMPINT_LT), 0);
+    }
+
+    #[simd_test = "avx512bw,avx512vl"]
+    unsafe fn test_mm256_cmp_epi16_mask() {
+        let a = _mm256_set1_epi16(-1);
+        let b = _mm256_set1_epi16(1);
+        assert_eq!(_mm256_cmp_epi16_mask(a, b, _MM_CMPINT_LT), !0);
+        assert_eq!(_mm256_cmp_epu16_mask(a, b, _MM_CMPINT_LT), 0);
+    }
+
+    #[simd_test = "avx512bw,avx512vl"]
+    unsafe fn test_mm_cmp_epi8_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm_setr_epi8(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, -1,
+        );
+        let b = _mm_set1_epi8(8);
+        assert_eq!(_mm_cmp_epi8_mask(a, b, _MM_CMPINT_LT), 0x80ff);
+        assert_eq!(_mm_cmp_epu8_mask(a, b, _MM_CMPINT_LT), 0x00ff);
+    }
+
+    #[simd_test = "avx512bw,avx512vl"]
+    unsafe fn test_mm_cmp_epi16_mask() {
+        let a = _mm_setr_epi16(0, 1, 2, 3, 4, 5, 6, -1);
+        let b = _mm_set1_epi16(4);
+        assert_eq!(_mm_cmp_epi16_mask(a, b, _MM_CMPINT_LT), 0x8f);
+        assert_eq!(_mm_cmp_epu16_mask(a, b, _MM_CMPINT_LT), 0x0f);
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_movepi8_mask() {
+        let mut a = [0i8; 64];
+        a[0] = -1;
+        a[9] = -128;
+        a[63] = -2;
+        let a: __m512i = mem::transmute(a);
+        let r = _mm512_movepi8_mask(a);
+        assert_eq!(r, 1 << 63 | 1 << 9 | 1);
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_movepi16_mask() {
+        let a = _mm512_set1_epi16(-0x8000);
+        assert_eq!(_mm512_movepi16_mask(a), !0);
+        let a = _mm512_set1_epi16(0x7fff);
+        assert_eq!(_mm512_movepi16_mask(a), 0);
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_movm_epi8() {
+        let k = 0x8000_0000_0000_0001;
+        let r: [i8; 64] = mem::transmute(_mm512_movm_epi8(k));
+        for i in 0..64 {
+            assert_eq!(r[i], if i == 0 || i == 63 { -1 } else { 0 });
+        }
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_movm_epi16() {
+        let k = 0x5555_5555;
+        let r: [i16; 32] = mem::transmute(_mm512_movm_epi16(k));
+        for i in 0..32 {
+            assert_eq!(r[i], if i % 2 == 0 { -1 } else { 0 });
+        }
+    }
+
+    #[simd_test = "avx512bw,avx512vl"]
+    unsafe fn test_mm256_movepi8_mask() {
+        let a = _mm256_set1_epi16(0x80);
+        assert_eq!(_mm256_movepi8_mask(a), 0x5555_5555);
+        let r = _mm256_movm_epi8(0x5555_5555);
+        assert_eq_m256i(r, _mm256_set1_epi16(0xff));
+    }
+
+    #[simd_test = "avx512bw,avx512vl"]
+    unsafe fn test_mm256_movepi16_mask() {
+        let a = _mm256_set1_epi32(-0x1_0000);
+        assert_eq!(_mm256_movepi16_mask(a), 0xaaaa);
+        let r = _mm256_movm_epi16(0xaaaa);
+        assert_eq_m256i(r, a);
+    }
+
+    #[simd_test = "avx512bw,avx512vl"]
+    unsafe fn test_mm_movepi8_mask() {
+        let a = _mm_set1_epi16(0x80);
+        assert_eq!(_mm_movepi8_mask(a), 0x5555);
+        let r = _mm_movm_epi8(0x5555);
+        assert_eq_m128i(r, _mm_set1_epi16(0xff));
+    }
+
+    #[simd_test = "avx512bw,avx512vl"]
+    unsafe fn test_mm_movepi16_mask() {
+        let a = _mm_set1_epi32(-0x1_0000);
+        assert_eq!(_mm_movepi16_mask(a), 0xaa);
+        let r = _mm_movm_epi16(0xaa);
+        assert_eq_m128i(r, a);
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_mask_mov_epi8() {
+        let src = _mm512_set1_epi8(1);
+        let a = _mm512_set1_epi8(2);
+        let r = _mm512_mask_mov_epi8(src, 0x5555_5555_5555_5555, a);
+        assert_eq_m512i(r, _mm512_set1_epi16(0x0102));
+        let r = _mm512_maskz_mov_epi8(0xaaaa_aaaa_aaaa_aaaa, a);
+        assert_eq_m512i(r, _mm512_set1_epi16(0x0200));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_mask_mov_epi16() {
+        let src = _mm512_set1_epi16(1);
+        let a = _mm512_set1_epi16(2);
+        let r = _mm512_mask_mov_epi16(src, 0x5555_5555, a);
+        assert_eq_m512i(r, _mm512_set1_epi32(0x0001_0002));
+        let r = _mm512_maskz_mov_epi16(0xaaaa_aaaa, a);
+        assert_eq_m512i(r, _mm512_set1_epi32(0x0002_0000));
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_mask_loadu_epi8() {
+        let mut buf = [0i8; 65];
+        for i in 0..65 {
+            buf[i] = i as i8;
+        }
+        let src = _mm512_set1_epi8(-1);
+        let p = buf.as_ptr().offset(1);
+        let k = 0xffff_ffff_0000_0001;
+        let r: [i8; 64] = mem::transmute(_mm512_mask_loadu_epi8(src, k, p));
+        let z: [i8; 64] = mem::transmute(_mm512_maskz_loadu_epi8(k, p));
+        for i in 0..64 {
+            let set = k & (1 << i) != 0;
+            assert_eq!(r[i], if set { i as i8 + 1 } else { -1 });
+            assert_eq!(z[i], if set { i as i8 + 1 } else { 0 });
+        }
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_mask_loadu_epi16() {
+        let mut buf = [0i16; 33];
+        for i in 0..33 {
+            buf[i] = i as i16;
+        }
+        let src = _mm512_set1_epi16(-1);
+        let p = buf.as_ptr().offset(1);
+        let k = 0xffff_0001;
+        let r: [i16; 32] = mem::transmute(_mm512_mask_loadu_epi16(src, k, p));
+        let z: [i16; 32] = mem::transmute(_mm512_maskz_loadu_epi16(k, p));
+        for i in 0..32 {
+            let set = k & (1 << i) != 0;
+            assert_eq!(r[i], if set { i as i16 + 1 } else { -1 });
+            assert_eq!(z[i], if set { i as i16 + 1 } else { 0 });
+        }
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_mask_storeu_epi8() {
+        let mut buf = [0i8; 65];
+        let a = _mm512_set1_epi8(7);
+        _mm512_mask_storeu_epi8(buf.as_mut_ptr().offset(1), 1 << 63 | 1, a);
+        for i in 0..65 {
+            assert_eq!(buf[i], if i == 1 || i == 64 { 7 } else { 0 });
+        }
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mm512_mask_storeu_epi16() {
+        let mut buf = [0i16; 33];
+        let a = _mm512_set1_epi16(7);
+        _mm512_mask_storeu_epi16(buf.as_mut_ptr().offset(1), 1 << 31 | 1, a);
+        for i in 0..33 {
+            assert_eq!(buf[i], if i == 1 || i == 32 { 7 } else { 0 });
+        }
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mask32_ops() {
+        let a: __mmask32 = 0b1100;
+        let b: __mmask32 = 0b1010;
+        assert_eq!(_kand_mask32(a, b), 0b1000);
+        assert_eq!(_kandn_mask32(a, b), 0b0010);
+        assert_eq!(_kor_mask32(a, b), 0b1110);
+        assert_eq!(_kxor_mask32(a, b), 0b0110);
+        assert_eq!(_kxnor_mask32(a, b), !0b0110);
+        assert_eq!(_knot_mask32(a), !0b1100);
+        assert_eq!(_kortestz_mask32_u8(0, 0), 1);
+        assert_eq!(_kortestz_mask32_u8(a, 0), 0);
+        assert_eq!(_kortestc_mask32_u8(a, !a), 1);
+        assert_eq!(_kortestc_mask32_u8(a, b), 0);
+    }
+
+    #[simd_test = "avx512bw"]
+    unsafe fn test_mask64_ops() {
+        let a: __mmask64 = 0b1100 << 60;
+        let b: __mmask64 = 0b1010 << 60;
+        assert_eq!(_kand_mask64(a, b), 0b1000 << 60);
+        assert_eq!(_kandn_mask64(a, b), 0b0010 << 60);
+        assert_eq!(_kor_mask64(a, b), 0b1110 << 60);
+        assert_eq!(_kxor_mask64(a, b), 0b0110 << 60);
+        assert_eq!(_kxnor_mask64(a, b), !(0b0110 << 60));
+        assert_eq!(_knot_mask64(a), !(0b1100 << 60));
+        assert_eq!(_kortestz_mask64_u8(0, 0), 1);
+        assert_eq!(_kortestz_mask64_u8(a, 0), 0);
+        assert_eq!(_kortestc_mask64_u8(a, !a), 1);
+        assert_eq!(_kortestc_mask64_u8(a, b), 0);
+    }
+}
@@ -0,0 +1,288 @@
+//! AVX-512 Conflict Detection (AVX-512CD) intrinsics.
+//!
+//! Conflict detection finds lanes of a vector that hold the same value as an
+//! earlier lane, which is what makes it possible to vectorize scatter loops
+//! such as histograms. The extension also adds a vector leading zero count.
+//!
+//! The 128-bit and 256-bit forms additionally require AVX-512VL.
+//!
+//! [Intel Intrinsics Guide][intel_guide].
+//!
+//! [intel_guide]: https://software.intel.com/sites/landingpage/IntrinsicsGuide/#techs=AVX_512&avx512techs=AVX512CD
+
+use core::mem;
+
+use v128::*;
+use v256::*;
+use v512::*;
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Test each 32-bit element of `a` for equality with all other elements
+/// in `a` closer to the least significant bit. Each element's comparison
+/// forms a zero extended bit vector in the returned vector.
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm512_conflict_epi32(a: __m512i) -> __m512i {
+    let src = _mm512_setzero_si512().as_i32x16();
+    mem::transmute(vpconflictd512(a.as_i32x16(), src, -1))
+}
+
+/// Test each 32-bit element of `a` for equality with all other elements
+/// in `a` closer to the least significant bit. Each element's comparison
+/// forms a zero extended bit vector in the returned vector.
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm256_conflict_epi32(a: __m256i) -> __m256i {
+    let src = _mm256_setzero_si256().as_i32x8();
+    mem::transmute(vpconflictd256(a.as_i32x8(), src, -1))
+}
+
+/// Test each 32-bit element of `a` for equality with all other elements
+/// in `a` closer to the least significant bit. Each element's comparison
+/// forms a zero extended bit vector in the returned vector.
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictd))]
+pub unsafe fn _mm_conflict_epi32(a: __m128i) -> __m128i {
+    let src = _mm_setzero_si128().as_i32x4();
+    mem::transmute(vpconflictd128(a.as_i32x4(), src, -1))
+}
+
+/// Test each 64-bit element of `a` for equality with all other elements
+/// in `a` closer to the least significant bit. Each element's comparison
+/// forms a zero extended bit vector in the returned vector.
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm512_conflict_epi64(a: __m512i) -> __m512i {
+    let src = _mm512_setzero_si512().as_i64x8();
+    mem::transmute(vpconflictq512(a.as_i64x8(), src, -1))
+}
+
+/// Test each 64-bit element of `a` for equality with all other elements
+/// in `a` closer to the least significant bit. Each element's comparison
+/// forms a zero extended bit vector in the returned vector.
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm256_conflict_epi64(a: __m256i) -> __m256i {
+    let src = _mm256_setzero_si256().as_i64x4();
+    mem::transmute(vpconflictq256(a.as_i64x4(), src, -1))
+}
+
+/// Test each 64-bit element of `a` for equality with all other elements
+/// in `a` closer to the least significant bit. Each element's comparison
+/// forms a zero extended bit vector in the returned vector.
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vpconflictq))]
+pub unsafe fn _mm_conflict_epi64(a: __m128i) -> __m128i {
+    let src = _mm_setzero_si128().as_i64x2();
+    mem::transmute(vpconflictq128(a.as_i64x2(), src, -1))
+}
+
+/// Count the number of leading zero bits in each packed 32-bit integer in
+/// `a`.
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm512_lzcnt_epi32(a: __m512i) -> __m512i {
+    mem::transmute(vplzcntd512(a.as_i32x16(), false))
+}
+
+/// Count the number of leading zero bits in each packed 32-bit integer in
+/// `a`.
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm256_lzcnt_epi32(a: __m256i) -> __m256i {
+    mem::transmute(vplzcntd256(a.as_i32x8(), false))
+}
+
+/// Count the number of leading zero bits in each packed 32-bit integer in
+/// `a`.
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntd))]
+pub unsafe fn _mm_lzcnt_epi32(a: __m128i) -> __m128i {
+    mem::transmute(vplzcntd128(a.as_i32x4(), false))
+}
+
+/// Count the number of leading zero bits in each packed 64-bit integer in
+/// `a`.
+#[inline]
+#[target_feature(enable = "avx512cd")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm512_lzcnt_epi64(a: __m512i) -> __m512i {
+    mem::transmute(vplzcntq512(a.as_i64x8(), false))
+}
+
+/// Count the number of leading zero bits in each packed 64-bit integer in
+/// `a`.
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm256_lzcnt_epi64(a: __m256i) -> __m256i {
+    mem::transmute(vplzcntq256(a.as_i64x4(), false))
+}
+
+/// Count the number of leading zero bits in each packed 64-bit integer in
+/// `a`.
+#[inline]
+#[target_feature(enable = "avx512cd,avx512vl")]
+#[cfg_attr(test, assert_instr(vplzcntq))]
+pub unsafe fn _mm_lzcnt_epi64(a: __m128i) -> __m128i {
+    mem::transmute(vplzcntq128(a.as_i64x2(), false))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.mask.conflict.d.512"]
+    fn vpconflictd512(a: i32x16, src: i32x16, k: i16) -> i32x16;
+    #[link_name = "llvm.x86.avx512.mask.conflict.d.256"]
+    fn vpconflictd256(a: i32x8, src: i32x8, k: i8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.mask.conflict.d.128"]
+    fn vpconflictd128(a: i32x4, src: i32x4, k: i8) -> i32x4;
+    #[link_name = "llvm.x86.avx512.mask.conflict.q.512"]
+    fn vpconflictq512(a: i64x8, src: i64x8, k: i8) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.conflict.q.256"]
+    fn vpconflictq256(a: i64x4, src: i64x4, k: i8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.conflict.q.128"]
+    fn vpconflictq128(a: i64x2, src: i64x2, k: i8) -> i64x2;
+
+    #[link_name = "llvm.ctlz.v16i32"]
+    fn vplzcntd512(a: i32x16, zero_is_undef: bool) -> i32x16;
+    #[link_name = "llvm.ctlz.v8i32"]
+    fn vplzcntd256(a: i32x8, zero_is_undef: bool) -> i32x8;
+    #[link_name = "llvm.ctlz.v4i32"]
+    fn vplzcntd128(a: i32x4, zero_is_undef: bool) -> i32x4;
+    #[link_name = "llvm.ctlz.v8i64"]
+    fn vplzcntq512(a: i64x8, zero_is_undef: bool) -> i64x8;
+    #[link_name = "llvm.ctlz.v4i64"]
+    fn vplzcntq256(a: i64x4, zero_is_undef: bool) -> i64x4;
+    #[link_name = "llvm.ctlz.v2i64"]
+    fn vplzcntq128(a: i64x2, zero_is_undef: bool) -> i64x2;
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "avx512cd"]
+    unsafe fn test_mm512_conflict_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            1, 2, 1, 3, 2, 1, 4, 5, 6, 7, 8, 9, 10, 11, 12, 1,
+        );
+        let r = _mm512_conflict_epi32(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            0, 0, 0b1, 0, 0b10, 0b101, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0b100101,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512cd,avx512vl"]
+    unsafe fn test_mm256_conflict_epi32() {
+        let a = _mm256_setr_epi32(7, 7, 7, 0, 7, 0, 1, 2);
+        let r = _mm256_conflict_epi32(a);
+        let e = _mm256_setr_epi32(0, 0b1, 0b11, 0, 0b111, 0b1000, 0, 0);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "avx512cd,avx512vl"]
+    unsafe fn test_mm_conflict_epi32() {
+        let a = _mm_setr_epi32(5, 5, 6, 5);
+        let r = _mm_conflict_epi32(a);
+        let e = _mm_setr_epi32(0, 0b1, 0, 0b11);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "avx512cd"]
+    unsafe fn test_mm512_conflict_epi64() {
+        let a = _mm512_setr_epi64(3, 1, 3, 3, 2, 1, 0, -1);
+        let r = _mm512_conflict_epi64(a);
+        let e = _mm512_setr_epi64(0, 0, 0b1, 0b101, 0, 0b10, 0, 0);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512cd,avx512vl"]
+    unsafe fn test_mm256_conflict_epi64() {
+        let a = _mm256_setr_epi64x(-1, -1, 0, -1);
+        let r = _mm256_conflict_epi64(a);
+        let e = _mm256_setr_epi64x(0, 0b1, 0, 0b11);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "avx512cd,avx512vl"]
+    unsafe fn test_mm_conflict_epi64() {
+        let a = _mm_set1_epi64x(42);
+        let r = _mm_conflict_epi64(a);
+        let e = _mm_setr_epi64x(0, 0b1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "avx512cd"]
+    unsafe fn test_mm512_lzcnt_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, -1, 0x7fff_ffff, 0x0001_0000,
+            0xff, 0x100, 0x8000, 0x1_0000, 0x10_0000, 5, 6, 7,
+        );
+        let r = _mm512_lzcnt_epi32(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            32, 31, 30, 30, 29, 0, 1, 15,
+            24, 23, 16, 15, 11, 29, 29, 29,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512cd,avx512vl"]
+    unsafe fn test_mm256_lzcnt_epi32() {
+        let a = _mm256_setr_epi32(0, 1, 2, 3, 4, -1, 0x7fff_ffff, 0xff);
+        let r = _mm256_lzcnt_epi32(a);
+        let e = _mm256_setr_epi32(32, 31, 30, 30, 29, 0, 1, 24);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "avx512cd,avx512vl"]
+    unsafe fn test_mm_lzcnt_epi32() {
+        let a = _mm_setr_epi32(0, 1, -1, 0x100);
+        let r = _mm_lzcnt_epi32(a);
+        let e = _mm_setr_epi32(32, 31, 0, 23);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "avx512cd"]
+    unsafe fn test_mm512_lzcnt_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, -1, 1 << 32, 1 << 62, 0xff, 3);
+        let r = _mm512_lzcnt_epi64(a);
+        let e = _mm512_setr_epi64(64, 63, 62, 0, 31, 1, 56, 62);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512cd,avx512vl"]
+    unsafe fn test_mm256_lzcnt_epi64() {
+        let a = _mm256_setr_epi64x(0, 1, -1, 1 << 32);
+        let r = _mm256_lzcnt_epi64(a);
+        let e = _mm256_setr_epi64x(64, 63, 0, 31);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "avx512cd,avx512vl"]
+    unsafe fn test_mm_lzcnt_epi64() {
+        let a = _mm_setr_epi64x(0, i64::max_value());
+        let r = _mm_lzcnt_epi64(a);
+        let e = _mm_setr_epi64x(64, 1);
+        assert_eq_m128i(r, e);
+    }
+}
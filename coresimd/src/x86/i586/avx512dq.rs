@@ -0,0 +1,724 @@
+//! AVX-512 Doubleword and Quadword (AVX-512DQ) intrinsics.
+//!
+//! AVX-512DQ adds floating-point bitwise logic, 64-bit integer
+//! multiplication and conversions between 64-bit integers and
+//! floating-point values, along with the 8-bit opmask instructions.
+//!
+//! The 128-bit and 256-bit forms additionally require AVX-512VL.
+//!
+//! [Intel Intrinsics Guide][intel_guide].
+//!
+//! [intel_guide]: https://software.intel.com/sites/landingpage/IntrinsicsGuide/#techs=AVX_512&avx512techs=AVX512DQ
+
+use core::mem;
+
+use simd_llvm::*;
+use v128::*;
+use v256::*;
+use v512::*;
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Compute the bitwise AND of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandps))]
+pub unsafe fn _mm512_and_ps(a: __m512, b: __m512) -> __m512 {
+    let a: i32x16 = mem::transmute(a);
+    let b: i32x16 = mem::transmute(b);
+    mem::transmute(simd_and(a, b))
+}
+
+/// Compute the bitwise AND of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandpd))]
+pub unsafe fn _mm512_and_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a: i64x8 = mem::transmute(a);
+    let b: i64x8 = mem::transmute(b);
+    mem::transmute(simd_and(a, b))
+}
+
+/// Compute the bitwise OR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorps))]
+pub unsafe fn _mm512_or_ps(a: __m512, b: __m512) -> __m512 {
+    let a: i32x16 = mem::transmute(a);
+    let b: i32x16 = mem::transmute(b);
+    mem::transmute(simd_or(a, b))
+}
+
+/// Compute the bitwise OR of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vorpd))]
+pub unsafe fn _mm512_or_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a: i64x8 = mem::transmute(a);
+    let b: i64x8 = mem::transmute(b);
+    mem::transmute(simd_or(a, b))
+}
+
+/// Compute the bitwise XOR of packed single-precision (32-bit) floating-point
+/// elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_xor_ps(a: __m512, b: __m512) -> __m512 {
+    let a: i32x16 = mem::transmute(a);
+    let b: i32x16 = mem::transmute(b);
+    mem::transmute(simd_xor(a, b))
+}
+
+/// Compute the bitwise XOR of packed double-precision (64-bit) floating-point
+/// elements in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vxorpd))]
+pub unsafe fn _mm512_xor_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a: i64x8 = mem::transmute(a);
+    let b: i64x8 = mem::transmute(b);
+    mem::transmute(simd_xor(a, b))
+}
+
+/// Compute the bitwise NOT of packed single-precision (32-bit) floating-point
+/// elements in `a` and then AND with `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnps))]
+pub unsafe fn _mm512_andnot_ps(a: __m512, b: __m512) -> __m512 {
+    let a: i32x16 = mem::transmute(a);
+    let b: i32x16 = mem::transmute(b);
+    let all_ones = i32x16::splat(-1);
+    mem::transmute(simd_and(simd_xor(a, all_ones), b))
+}
+
+/// Compute the bitwise NOT of packed double-precision (64-bit) floating-point
+/// elements in `a` and then AND with `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vandnpd))]
+pub unsafe fn _mm512_andnot_pd(a: __m512d, b: __m512d) -> __m512d {
+    let a: i64x8 = mem::transmute(a);
+    let b: i64x8 = mem::transmute(b);
+    let all_ones = i64x8::splat(-1);
+    mem::transmute(simd_and(simd_xor(a, all_ones), b))
+}
+
+/// Multiply the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and return the low 64 bits of the intermediate integers.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm512_mullo_epi64(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_mul(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Multiply the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and return the low 64 bits of the intermediate integers.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm256_mullo_epi64(a: __m256i, b: __m256i) -> __m256i {
+    mem::transmute(simd_mul(a.as_i64x4(), b.as_i64x4()))
+}
+
+/// Multiply the packed 64-bit integers in `a` and `b`, producing intermediate
+/// 128-bit integers, and return the low 64 bits of the intermediate integers.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmullq))]
+pub unsafe fn _mm_mullo_epi64(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(simd_mul(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Convert packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm512_cvtepi64_pd(a: __m512i) -> __m512d {
+    simd_cast(a.as_i64x8())
+}
+
+/// Convert packed unsigned 64-bit integers in `a` to packed double-precision
+/// (64-bit) floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm512_cvtepu64_pd(a: __m512i) -> __m512d {
+    simd_cast(a.as_u64x8())
+}
+
+/// Convert packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm256_cvtepi64_pd(a: __m256i) -> __m256d {
+    simd_cast(a.as_i64x4())
+}
+
+/// Convert packed unsigned 64-bit integers in `a` to packed double-precision
+/// (64-bit) floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm256_cvtepu64_pd(a: __m256i) -> __m256d {
+    simd_cast(a.as_u64x4())
+}
+
+/// Convert packed 64-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtqq2pd))]
+pub unsafe fn _mm_cvtepi64_pd(a: __m128i) -> __m128d {
+    simd_cast(a.as_i64x2())
+}
+
+/// Convert packed unsigned 64-bit integers in `a` to packed double-precision
+/// (64-bit) floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vcvtuqq2pd))]
+pub unsafe fn _mm_cvtepu64_pd(a: __m128i) -> __m128d {
+    simd_cast(a.as_u64x2())
+}
+
+/// Convert packed 64-bit integers in `a` to packed single-precision (32-bit)
+/// floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtqq2ps))]
+pub unsafe fn _mm512_cvtepi64_ps(a: __m512i) -> __m256 {
+    simd_cast(a.as_i64x8())
+}
+
+/// Convert packed unsigned 64-bit integers in `a` to packed single-precision
+/// (32-bit) floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtuqq2ps))]
+pub unsafe fn _mm512_cvtepu64_ps(a: __m512i) -> __m256 {
+    simd_cast(a.as_u64x8())
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a` to
+/// packed 64-bit integers, using the rounding mode in `MXCSR`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2qq))]
+pub unsafe fn _mm512_cvtpd_epi64(a: __m512d) -> __m512i {
+    let src = _mm512_setzero_si512().as_i64x8();
+    mem::transmute(vcvtpd2qq(a, src, -1, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers, using the rounding mode in `MXCSR`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvtpd2uqq))]
+pub unsafe fn _mm512_cvtpd_epu64(a: __m512d) -> __m512i {
+    let src = _mm512_setzero_si512().as_i64x8();
+    mem::transmute(vcvtpd2uqq(a, src, -1, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a` to
+/// packed 64-bit integers with truncation.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2qq))]
+pub unsafe fn _mm512_cvttpd_epi64(a: __m512d) -> __m512i {
+    let src = _mm512_setzero_si512().as_i64x8();
+    mem::transmute(vcvttpd2qq(a, src, -1, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a` to
+/// packed unsigned 64-bit integers with truncation.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vcvttpd2uqq))]
+pub unsafe fn _mm512_cvttpd_epu64(a: __m512d) -> __m512i {
+    let src = _mm512_setzero_si512().as_i64x8();
+    mem::transmute(vcvttpd2uqq(a, src, -1, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 32-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovd2m))]
+pub unsafe fn _mm512_movepi32_mask(a: __m512i) -> __mmask16 {
+    vpmovd2m512(a.as_i32x16()) as __mmask16
+}
+
+/// Set each packed 32-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovm2d))]
+pub unsafe fn _mm512_movm_epi32(k: __mmask16) -> __m512i {
+    mem::transmute(vpmovm2d512(k as i16))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 64-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovq2m))]
+pub unsafe fn _mm512_movepi64_mask(a: __m512i) -> __mmask8 {
+    vpmovq2m512(a.as_i64x8()) as __mmask8
+}
+
+/// Set each packed 64-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+#[cfg_attr(test, assert_instr(vpmovm2q))]
+pub unsafe fn _mm512_movm_epi64(k: __mmask8) -> __m512i {
+    mem::transmute(vpmovm2q512(k as i8))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 32-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovd2m))]
+pub unsafe fn _mm256_movepi32_mask(a: __m256i) -> __mmask8 {
+    vpmovd2m256(a.as_i32x8()) as __mmask8
+}
+
+/// Set each packed 32-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2d))]
+pub unsafe fn _mm256_movm_epi32(k: __mmask8) -> __m256i {
+    mem::transmute(vpmovm2d256(k as i8))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 64-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovq2m))]
+pub unsafe fn _mm256_movepi64_mask(a: __m256i) -> __mmask8 {
+    vpmovq2m256(a.as_i64x4()) as __mmask8
+}
+
+/// Set each packed 64-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2q))]
+pub unsafe fn _mm256_movm_epi64(k: __mmask8) -> __m256i {
+    mem::transmute(vpmovm2q256(k as i8))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 32-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovd2m))]
+pub unsafe fn _mm_movepi32_mask(a: __m128i) -> __mmask8 {
+    vpmovd2m128(a.as_i32x4()) as __mmask8
+}
+
+/// Set each packed 32-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2d))]
+pub unsafe fn _mm_movm_epi32(k: __mmask8) -> __m128i {
+    mem::transmute(vpmovm2d128(k as i8))
+}
+
+/// Set each bit of the returned mask based on the most significant bit of the
+/// corresponding packed 64-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovq2m))]
+pub unsafe fn _mm_movepi64_mask(a: __m128i) -> __mmask8 {
+    vpmovq2m128(a.as_i64x2()) as __mmask8
+}
+
+/// Set each packed 64-bit integer in the returned vector to all ones or all
+/// zeros based on the value of the corresponding bit in `k`.
+#[inline]
+#[target_feature(enable = "avx512dq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmovm2q))]
+pub unsafe fn _mm_movm_epi64(k: __mmask8) -> __m128i {
+    mem::transmute(vpmovm2q128(k as i8))
+}
+
+/// Compute the bitwise AND of 8-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kand_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a & b
+}
+
+/// Compute the bitwise NOT of 8-bit mask `a` and then AND with `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kandn_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    !a & b
+}
+
+/// Compute the bitwise OR of 8-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a | b
+}
+
+/// Compute the bitwise XOR of 8-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kxor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    a ^ b
+}
+
+/// Compute the bitwise XNOR of 8-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kxnor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 {
+    !(a ^ b)
+}
+
+/// Compute the bitwise NOT of 8-bit mask `a`.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _knot_mask8(a: __mmask8) -> __mmask8 {
+    !a
+}
+
+/// Compute the bitwise OR of 8-bit masks `a` and `b`, and return 1 if the
+/// result is all zeros, otherwise return 0.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kortestz_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    ((a | b) == 0) as u8
+}
+
+/// Compute the bitwise OR of 8-bit masks `a` and `b`, and return 1 if the
+/// result is all ones, otherwise return 0.
+#[inline]
+#[target_feature(enable = "avx512dq")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kortestc_mask8_u8(a: __mmask8, b: __mmask8) -> u8 {
+    ((a | b) == !0) as u8
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2qq.512"]
+    fn vcvtpd2qq(a: __m512d, src: i64x8, k: i8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvtpd2uqq.512"]
+    fn vcvtpd2uqq(a: __m512d, src: i64x8, k: i8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2qq.512"]
+    fn vcvttpd2qq(a: __m512d, src: i64x8, k: i8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.cvttpd2uqq.512"]
+    fn vcvttpd2uqq(a: __m512d, src: i64x8, k: i8, rounding: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.cvtd2mask.512"]
+    fn vpmovd2m512(a: i32x16) -> i16;
+    #[link_name = "llvm.x86.avx512.cvtmask2d.512"]
+    fn vpmovm2d512(k: i16) -> i32x16;
+    #[link_name = "llvm.x86.avx512.cvtq2mask.512"]
+    fn vpmovq2m512(a: i64x8) -> i8;
+    #[link_name = "llvm.x86.avx512.cvtmask2q.512"]
+    fn vpmovm2q512(k: i8) -> i64x8;
+    #[link_name = "llvm.x86.avx512.cvtd2mask.256"]
+    fn vpmovd2m256(a: i32x8) -> i8;
+    #[link_name = "llvm.x86.avx512.cvtmask2d.256"]
+    fn vpmovm2d256(k: i8) -> i32x8;
+    #[link_name = "llvm.x86.avx512.cvtq2mask.256"]
+    fn vpmovq2m256(a: i64x4) -> i8;
+    #[link_name = "llvm.x86.avx512.cvtmask2q.256"]
+    fn vpmovm2q256(k: i8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.cvtd2mask.128"]
+    fn vpmovd2m128(a: i32x4) -> i8;
+    #[link_name = "llvm.x86.avx512.cvtmask2d.128"]
+    fn vpmovm2d128(k: i8) -> i32x4;
+    #[link_name = "llvm.x86.avx512.cvtq2mask.128"]
+    fn vpmovq2m128(a: i64x2) -> i8;
+    #[link_name = "llvm.x86.avx512.cvtmask2q.128"]
+    fn vpmovm2q128(k: i8) -> i64x2;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_and_ps() {
+        let a = _mm512_set1_ps(-1.5);
+        let b = _mm512_set1_ps(f32::from_bits(0x7fff_ffff));
+        assert_eq_m512(_mm512_and_ps(a, b), _mm512_set1_ps(1.5));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_and_pd() {
+        let a = _mm512_set1_pd(-1.5);
+        let b = _mm512_set1_pd(f64::from_bits(0x7fff_ffff_ffff_ffff));
+        assert_eq_m512d(_mm512_and_pd(a, b), _mm512_set1_pd(1.5));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_or_ps() {
+        let a = _mm512_set1_ps(1.5);
+        let b = _mm512_set1_ps(-0.0);
+        assert_eq_m512(_mm512_or_ps(a, b), _mm512_set1_ps(-1.5));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_or_pd() {
+        let a = _mm512_set1_pd(1.5);
+        let b = _mm512_set1_pd(-0.0);
+        assert_eq_m512d(_mm512_or_pd(a, b), _mm512_set1_pd(-1.5));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_xor_ps() {
+        let a = _mm512_set1_ps(-1.5);
+        let b = _mm512_set1_ps(-0.0);
+        assert_eq_m512(_mm512_xor_ps(a, b), _mm512_set1_ps(1.5));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_xor_pd() {
+        let a = _mm512_set1_pd(-1.5);
+        let b = _mm512_set1_pd(-0.0);
+        assert_eq_m512d(_mm512_xor_pd(a, b), _mm512_set1_pd(1.5));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_andnot_ps() {
+        let a = _mm512_set1_ps(-0.0);
+        let b = _mm512_set1_ps(-2.5);
+        assert_eq_m512(_mm512_andnot_ps(a, b), _mm512_set1_ps(2.5));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_andnot_pd() {
+        let a = _mm512_set1_pd(-0.0);
+        let b = _mm512_set1_pd(-2.5);
+        assert_eq_m512d(_mm512_andnot_pd(a, b), _mm512_set1_pd(2.5));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_mullo_epi64() {
+        let a =
+            _mm512_setr_epi64(0, 1, -1, 3, 1 << 32, 5, i64::max_value(), 7);
+        let b = _mm512_set1_epi64(-3);
+        let r = _mm512_mullo_epi64(a, b);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi64(
+            0, -3, 3, -9, -3 << 32, -15, i64::min_value() + 3, -21,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512dq,avx512vl"]
+    unsafe fn test_mm256_mullo_epi64() {
+        let a = _mm256_setr_epi64x(1, -2, 1 << 40, 4);
+        let b = _mm256_setr_epi64x(5, 6, 1 << 30, -4);
+        let r = _mm256_mullo_epi64(a, b);
+        assert_eq_m256i(r, _mm256_setr_epi64x(5, -12, 0, -16));
+    }
+
+    #[simd_test = "avx512dq,avx512vl"]
+    unsafe fn test_mm_mullo_epi64() {
+        let a = _mm_setr_epi64x(3, -1 << 33);
+        let b = _mm_setr_epi64x(-7, 3);
+        let r = _mm_mullo_epi64(a, b);
+        assert_eq_m128i(r, _mm_setr_epi64x(-21, -3 << 33));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_cvtepi64_pd() {
+        let a = _mm512_setr_epi64(0, 1, -1, 2, -2, 1 << 52, -(1 << 52), 7);
+        let r = _mm512_cvtepi64_pd(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_pd(
+            0., 1., -1., 2., -2., 4503599627370496., -4503599627370496., 7.,
+        );
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_cvtepu64_pd() {
+        let a = _mm512_set1_epi64(-1);
+        let r = _mm512_cvtepu64_pd(a);
+        assert_eq_m512d(r, _mm512_set1_pd(18446744073709551615.));
+    }
+
+    #[simd_test = "avx512dq,avx512vl"]
+    unsafe fn test_mm256_cvtepi64_pd() {
+        let a = _mm256_setr_epi64x(0, 1, -1, 1 << 40);
+        let r = _mm256_cvtepi64_pd(a);
+        let e = _mm256_setr_pd(0., 1., -1., 1099511627776.);
+        assert_eq_m256d(r, e);
+        let r = _mm256_cvtepu64_pd(a);
+        let e = _mm256_setr_pd(0., 1., 18446744073709551615., 1099511627776.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "avx512dq,avx512vl"]
+    unsafe fn test_mm_cvtepi64_pd() {
+        let a = _mm_setr_epi64x(-3, -1);
+        assert_eq_m128d(_mm_cvtepi64_pd(a), _mm_setr_pd(-3., -1.));
+        let e = _mm_setr_pd(18446744073709551613., 18446744073709551615.);
+        assert_eq_m128d(_mm_cvtepu64_pd(a), e);
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_cvtepi64_ps() {
+        let a = _mm512_setr_epi64(0, 1, -1, 2, -2, 1 << 24, -(1 << 24), 7);
+        let r = _mm512_cvtepi64_ps(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm256_setr_ps(
+            0., 1., -1., 2., -2., 16777216., -16777216., 7.,
+        );
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_cvtepu64_ps() {
+        let a = _mm512_set1_epi64(1 << 63);
+        let r = _mm512_cvtepu64_ps(a);
+        assert_eq_m256(r, _mm256_set1_ps(9223372036854775808.));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_cvtpd_epi64() {
+        let a = _mm512_setr_pd(0., 1.4, 1.5, 2.5, -1.5, -2.6, 1e10, 1e20);
+        let r = _mm512_cvtpd_epi64(a);
+        // out of range values convert to the "integer indefinite" value
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi64(
+            0, 1, 2, 2, -2, -3, 10_000_000_000, i64::min_value(),
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_cvttpd_epi64() {
+        let a = _mm512_setr_pd(0., 1.4, 1.5, 2.5, -1.5, -2.6, 1e10, 1e20);
+        let r = _mm512_cvttpd_epi64(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi64(
+            0, 1, 1, 2, -1, -2, 10_000_000_000, i64::min_value(),
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_cvtpd_epu64() {
+        let a = _mm512_setr_pd(0., 1.4, 1.5, 2.5, 1e19, 3.5, -1., 1e20);
+        let r: [u64; 8] = mem::transmute(_mm512_cvtpd_epu64(a));
+        let e = [0, 1, 2, 2, 10_000_000_000_000_000_000, 4, !0, !0];
+        assert_eq!(r, e);
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_cvttpd_epu64() {
+        let a = _mm512_setr_pd(0., 1.4, 1.5, 2.5, 1e19, 3.5, -1., 1e20);
+        let r: [u64; 8] = mem::transmute(_mm512_cvttpd_epu64(a));
+        let e = [0, 1, 1, 2, 10_000_000_000_000_000_000, 3, !0, !0];
+        assert_eq!(r, e);
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_movepi32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            -1, 0, 1, i32::min_value(), 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, -5,
+        );
+        assert_eq!(_mm512_movepi32_mask(a), 0b1000_0000_0000_1001);
+        let r = _mm512_movm_epi32(0b1000_0000_0000_1001);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            -1, 0, 0, -1, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, -1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mm512_movepi64_mask() {
+        let a = _mm512_setr_epi64(-1, 0, 1, i64::min_value(), 0, 0, 0, -5);
+        assert_eq!(_mm512_movepi64_mask(a), 0b1000_1001);
+        let r = _mm512_movm_epi64(0b1000_1001);
+        let e = _mm512_setr_epi64(-1, 0, 0, -1, 0, 0, 0, -1);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512dq,avx512vl"]
+    unsafe fn test_mm256_movepi32_mask() {
+        let a = _mm256_setr_epi32(-1, 0, 1, -2, 0, 0, 0, -5);
+        assert_eq!(_mm256_movepi32_mask(a), 0b1000_1001);
+        let r = _mm256_movm_epi32(0b1000_1001);
+        let e = _mm256_setr_epi32(-1, 0, 0, -1, 0, 0, 0, -1);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "avx512dq,avx512vl"]
+    unsafe fn test_mm256_movepi64_mask() {
+        let a = _mm256_setr_epi64x(-1, 0, 1, -2);
+        assert_eq!(_mm256_movepi64_mask(a), 0b1001);
+        let r = _mm256_movm_epi64(0b1001);
+        assert_eq_m256i(r, _mm256_setr_epi64x(-1, 0, 0, -1));
+    }
+
+    #[simd_test = "avx512dq,avx512vl"]
+    unsafe fn test_mm_movepi32_mask() {
+        let a = _mm_setr_epi32(-1, 0, 1, -2);
+        assert_eq!(_mm_movepi32_mask(a), 0b1001);
+        let r = _mm_movm_epi32(0b1001);
+        assert_eq_m128i(r, _mm_setr_epi32(-1, 0, 0, -1));
+    }
+
+    #[simd_test = "avx512dq,avx512vl"]
+    unsafe fn test_mm_movepi64_mask() {
+        let a = _mm_setr_epi64x(1, -2);
+        assert_eq!(_mm_movepi64_mask(a), 0b10);
+        let r = _mm_movm_epi64(0b10);
+        assert_eq_m128i(r, _mm_setr_epi64x(0, -1));
+    }
+
+    #[simd_test = "avx512dq"]
+    unsafe fn test_mask8_ops() {
+        let a: __mmask8 = 0b1100;
+        let b: __mmask8 = 0b1010;
+        assert_eq!(_kand_mask8(a, b), 0b1000);
+        assert_eq!(_kandn_mask8(a, b), 0b0010);
+        assert_eq!(_kor_mask8(a, b), 0b1110);
+        assert_eq!(_kxor_mask8(a, b), 0b0110);
+        assert_eq!(_kxnor_mask8(a, b), !0b0110);
+        assert_eq!(_knot_mask8(a), !0b1100);
+        assert_eq!(_kortestz_mask8_u8(0, 0), 1);
+        assert_eq!(_kortestz_mask8_u8(a, 0), 0);
+        assert_eq!(_kortestc_mask8_u8(a, !a), 1);
+        assert_eq!(_kortestc_mask8_u8(a, b), 0);
+    }
+}
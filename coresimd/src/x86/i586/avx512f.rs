@@ -0,0 +1,5100 @@
+//! AVX-512 Foundation (AVX-512F) intrinsics.
+//!
+//! AVX-512F widens the vector registers to 512 bits and adds opmask
+//! registers that hold the result of comparisons. This module contains the
+//! unmasked core of the extension: loading, storing and building vectors,
+//! arithmetic, bitwise logic, shifts, conversions and comparisons returning
+//! an opmask.
+//!
+//! The references are:
+//!
+//! - [Intel 64 and IA-32 Architectures Software Developer's Manual Volume 2:
+//!   Instruction Set Reference, A-Z][intel64_ref].
+//! - [Intel Intrinsics Guide][intel_guide].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+//! [intel_guide]: https://software.intel.com/sites/landingpage/IntrinsicsGuide/#techs=AVX_512
+
+use core::mem;
+use core::ptr;
+
+use simd_llvm::*;
+use v128::*;
+use v256::*;
+use v512::*;
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Return vector of type `__m512` with all elements set to zero.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_setzero_ps() -> __m512 {
+    _mm512_set1_ps(0.0)
+}
+
+/// Return vector of type `__m512d` with all elements set to zero.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_setzero_pd() -> __m512d {
+    _mm512_set1_pd(0.0)
+}
+
+/// Return vector of type `__m512i` with all elements set to zero.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vxorps))]
+pub unsafe fn _mm512_setzero_si512() -> __m512i {
+    _mm512_set1_epi32(0)
+}
+
+/// Set packed 32-bit integers in returned vector with the supplied values.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_set_epi32(
+    e00: i32, e01: i32, e02: i32, e03: i32, e04: i32, e05: i32, e06: i32,
+    e07: i32, e08: i32, e09: i32, e10: i32, e11: i32, e12: i32, e13: i32,
+    e14: i32, e15: i32
+) -> __m512i {
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    _mm512_setr_epi32(
+        e15, e14, e13, e12, e11, e10, e09, e08,
+        e07, e06, e05, e04, e03, e02, e01, e00,
+    )
+}
+
+/// Set packed 32-bit integers in returned vector with the supplied values in
+/// reverse order.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_setr_epi32(
+    e00: i32, e01: i32, e02: i32, e03: i32, e04: i32, e05: i32, e06: i32,
+    e07: i32, e08: i32, e09: i32, e10: i32, e11: i32, e12: i32, e13: i32,
+    e14: i32, e15: i32
+) -> __m512i {
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    mem::transmute(i32x16::new(
+        e00, e01, e02, e03, e04, e05, e06, e07,
+        e08, e09, e10, e11, e12, e13, e14, e15,
+    ))
+}
+
+/// Set packed 64-bit integers in returned vector with the supplied values.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_set_epi64(
+    e0: i64, e1: i64, e2: i64, e3: i64, e4: i64, e5: i64, e6: i64, e7: i64
+) -> __m512i {
+    _mm512_setr_epi64(e7, e6, e5, e4, e3, e2, e1, e0)
+}
+
+/// Set packed 64-bit integers in returned vector with the supplied values in
+/// reverse order.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_setr_epi64(
+    e0: i64, e1: i64, e2: i64, e3: i64, e4: i64, e5: i64, e6: i64, e7: i64
+) -> __m512i {
+    mem::transmute(i64x8::new(e0, e1, e2, e3, e4, e5, e6, e7))
+}
+
+/// Set packed single-precision (32-bit) floating-point elements in returned
+/// vector with the supplied values.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_set_ps(
+    e00: f32, e01: f32, e02: f32, e03: f32, e04: f32, e05: f32, e06: f32,
+    e07: f32, e08: f32, e09: f32, e10: f32, e11: f32, e12: f32, e13: f32,
+    e14: f32, e15: f32
+) -> __m512 {
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    _mm512_setr_ps(
+        e15, e14, e13, e12, e11, e10, e09, e08,
+        e07, e06, e05, e04, e03, e02, e01, e00,
+    )
+}
+
+/// Set packed single-precision (32-bit) floating-point elements in returned
+/// vector with the supplied values in reverse order.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_setr_ps(
+    e00: f32, e01: f32, e02: f32, e03: f32, e04: f32, e05: f32, e06: f32,
+    e07: f32, e08: f32, e09: f32, e10: f32, e11: f32, e12: f32, e13: f32,
+    e14: f32, e15: f32
+) -> __m512 {
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    mem::transmute(f32x16::new(
+        e00, e01, e02, e03, e04, e05, e06, e07,
+        e08, e09, e10, e11, e12, e13, e14, e15,
+    ))
+}
+
+/// Set packed double-precision (64-bit) floating-point elements in returned
+/// vector with the supplied values.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_set_pd(
+    e0: f64, e1: f64, e2: f64, e3: f64, e4: f64, e5: f64, e6: f64, e7: f64
+) -> __m512d {
+    _mm512_setr_pd(e7, e6, e5, e4, e3, e2, e1, e0)
+}
+
+/// Set packed double-precision (64-bit) floating-point elements in returned
+/// vector with the supplied values in reverse order.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_setr_pd(
+    e0: f64, e1: f64, e2: f64, e3: f64, e4: f64, e5: f64, e6: f64, e7: f64
+) -> __m512d {
+    mem::transmute(f64x8::new(e0, e1, e2, e3, e4, e5, e6, e7))
+}
+
+/// Broadcast single-precision (32-bit) floating-point value `a` to all
+/// elements of returned vector.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_set1_ps(a: f32) -> __m512 {
+    mem::transmute(f32x16::splat(a))
+}
+
+/// Broadcast double-precision (64-bit) floating-point value `a` to all
+/// elements of returned vector.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_set1_pd(a: f64) -> __m512d {
+    mem::transmute(f64x8::splat(a))
+}
+
+/// Broadcast 8-bit integer `a` to all elements of returned vector.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_set1_epi8(a: i8) -> __m512i {
+    mem::transmute(i8x64::splat(a))
+}
+
+/// Broadcast 16-bit integer `a` to all elements of returned vector.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_set1_epi16(a: i16) -> __m512i {
+    mem::transmute(i16x32::splat(a))
+}
+
+/// Broadcast 32-bit integer `a` to all elements of returned vector.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_set1_epi32(a: i32) -> __m512i {
+    mem::transmute(i32x16::splat(a))
+}
+
+/// Broadcast 64-bit integer `a` to all elements of returned vector.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_set1_epi64(a: i64) -> __m512i {
+    mem::transmute(i64x8::splat(a))
+}
+
+/// Return vector of type `__m512` with undefined elements.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_undefined_ps() -> __m512 {
+    _mm512_set1_ps(mem::uninitialized())
+}
+
+/// Return vector of type `__m512d` with undefined elements.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_undefined_pd() -> __m512d {
+    _mm512_set1_pd(mem::uninitialized())
+}
+
+/// Return vector of type `__m512i` with undefined elements.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_undefined_epi32() -> __m512i {
+    _mm512_set1_epi32(mem::uninitialized())
+}
+
+/// Cast vector of type `__m512d` to type `__m512`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castpd_ps(a: __m512d) -> __m512 {
+    mem::transmute(a)
+}
+
+/// Cast vector of type `__m512` to type `__m512d`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castps_pd(a: __m512) -> __m512d {
+    mem::transmute(a)
+}
+
+/// Cast vector of type `__m512` to type `__m512i`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castps_si512(a: __m512) -> __m512i {
+    mem::transmute(a)
+}
+
+/// Cast vector of type `__m512d` to type `__m512i`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castpd_si512(a: __m512d) -> __m512i {
+    mem::transmute(a)
+}
+
+/// Cast vector of type `__m512i` to type `__m512`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castsi512_ps(a: __m512i) -> __m512 {
+    mem::transmute(a)
+}
+
+/// Cast vector of type `__m512i` to type `__m512d`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castsi512_pd(a: __m512i) -> __m512d {
+    mem::transmute(a)
+}
+
+/// Cast vector of type `__m512` to type `__m128`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castps512_ps128(a: __m512) -> __m128 {
+    simd_shuffle4(a, a, [0, 1, 2, 3])
+}
+
+/// Cast vector of type `__m512` to type `__m256`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castps512_ps256(a: __m512) -> __m256 {
+    simd_shuffle8(a, a, [0, 1, 2, 3, 4, 5, 6, 7])
+}
+
+/// Cast vector of type `__m512d` to type `__m128d`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castpd512_pd128(a: __m512d) -> __m128d {
+    simd_shuffle2(a, a, [0, 1])
+}
+
+/// Cast vector of type `__m512d` to type `__m256d`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castpd512_pd256(a: __m512d) -> __m256d {
+    simd_shuffle4(a, a, [0, 1, 2, 3])
+}
+
+/// Cast vector of type `__m512i` to type `__m128i`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castsi512_si128(a: __m512i) -> __m128i {
+    let a = a.as_i64x8();
+    let dst: i64x2 = simd_shuffle2(a, a, [0, 1]);
+    mem::transmute(dst)
+}
+
+/// Cast vector of type `__m512i` to type `__m256i`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castsi512_si256(a: __m512i) -> __m256i {
+    let a = a.as_i64x8();
+    let dst: i64x4 = simd_shuffle4(a, a, [0, 1, 2, 3]);
+    mem::transmute(dst)
+}
+
+/// Cast vector of type `__m256` to type `__m512`;
+/// the upper 256 bits of the result are undefined.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castps256_ps512(a: __m256) -> __m512 {
+    // FIXME the upper 256 bits should be undefined
+    simd_shuffle16(
+        a,
+        _mm256_setzero_ps(),
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 8, 8, 8, 8, 8, 8, 8],
+    )
+}
+
+/// Cast vector of type `__m256d` to type `__m512d`;
+/// the upper 256 bits of the result are undefined.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castpd256_pd512(a: __m256d) -> __m512d {
+    // FIXME the upper 256 bits should be undefined
+    simd_shuffle8(a, _mm256_setzero_pd(), [0, 1, 2, 3, 4, 4, 4, 4])
+}
+
+/// Cast vector of type `__m256i` to type `__m512i`;
+/// the upper 256 bits of the result are undefined.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic is only used for compilation and does not generate any
+// instructions, thus it has zero latency.
+pub unsafe fn _mm512_castsi256_si512(a: __m256i) -> __m512i {
+    // FIXME the upper 256 bits should be undefined
+    let a = a.as_i64x4();
+    let zero = _mm256_setzero_si256().as_i64x4();
+    let dst: i64x8 = simd_shuffle8(a, zero, [0, 1, 2, 3, 4, 4, 4, 4]);
+    mem::transmute(dst)
+}
+
+/// Load 512-bits (composed of 16 packed single-precision (32-bit)
+/// floating-point elements) from memory into result.
+/// `mem_addr` must be aligned on a 64-byte boundary or a
+/// general-protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm512_load_ps(mem_addr: *const f32) -> __m512 {
+    *(mem_addr as *const __m512)
+}
+
+/// Load 512-bits (composed of 16 packed single-precision (32-bit)
+/// floating-point elements) from memory into result.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm512_loadu_ps(mem_addr: *const f32) -> __m512 {
+    let mut dst = _mm512_undefined_ps();
+    ptr::copy_nonoverlapping(
+        mem_addr as *const u8,
+        &mut dst as *mut __m512 as *mut u8,
+        mem::size_of::<__m512>(),
+    );
+    dst
+}
+
+/// Store 512-bits (composed of 16 packed single-precision (32-bit)
+/// floating-point elements) from `a` into memory.
+/// `mem_addr` must be aligned on a 64-byte boundary or a
+/// general-protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm512_store_ps(mem_addr: *mut f32, a: __m512) {
+    *(mem_addr as *mut __m512) = a;
+}
+
+/// Store 512-bits (composed of 16 packed single-precision (32-bit)
+/// floating-point elements) from `a` into memory.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm512_storeu_ps(mem_addr: *mut f32, a: __m512) {
+    ptr::copy_nonoverlapping(
+        &a as *const __m512 as *const u8,
+        mem_addr as *mut u8,
+        mem::size_of::<__m512>(),
+    );
+}
+
+/// Load 512-bits (composed of 8 packed double-precision (64-bit)
+/// floating-point elements) from memory into result.
+/// `mem_addr` must be aligned on a 64-byte boundary or a
+/// general-protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovaps))] // FIXME vmovapd expected
+pub unsafe fn _mm512_load_pd(mem_addr: *const f64) -> __m512d {
+    *(mem_addr as *const __m512d)
+}
+
+/// Load 512-bits (composed of 8 packed double-precision (64-bit)
+/// floating-point elements) from memory into result.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovups))] // FIXME vmovupd expected
+pub unsafe fn _mm512_loadu_pd(mem_addr: *const f64) -> __m512d {
+    let mut dst = _mm512_undefined_pd();
+    ptr::copy_nonoverlapping(
+        mem_addr as *const u8,
+        &mut dst as *mut __m512d as *mut u8,
+        mem::size_of::<__m512d>(),
+    );
+    dst
+}
+
+/// Store 512-bits (composed of 8 packed double-precision (64-bit)
+/// floating-point elements) from `a` into memory.
+/// `mem_addr` must be aligned on a 64-byte boundary or a
+/// general-protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovaps))] // FIXME vmovapd expected
+pub unsafe fn _mm512_store_pd(mem_addr: *mut f64, a: __m512d) {
+    *(mem_addr as *mut __m512d) = a;
+}
+
+/// Store 512-bits (composed of 8 packed double-precision (64-bit)
+/// floating-point elements) from `a` into memory.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovups))] // FIXME vmovupd expected
+pub unsafe fn _mm512_storeu_pd(mem_addr: *mut f64, a: __m512d) {
+    ptr::copy_nonoverlapping(
+        &a as *const __m512d as *const u8,
+        mem_addr as *mut u8,
+        mem::size_of::<__m512d>(),
+    );
+}
+
+/// Load 512-bits of integer data from memory into result.
+/// `mem_addr` must be aligned on a 64-byte boundary or a
+/// general-protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovaps))] // FIXME vmovdqa32 expected
+pub unsafe fn _mm512_load_si512(mem_addr: *const i32) -> __m512i {
+    *(mem_addr as *const __m512i)
+}
+
+/// Load 512-bits of integer data from memory into result.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovups))] // FIXME vmovdqu32 expected
+pub unsafe fn _mm512_loadu_si512(mem_addr: *const i32) -> __m512i {
+    let mut dst = _mm512_undefined_epi32();
+    ptr::copy_nonoverlapping(
+        mem_addr as *const u8,
+        &mut dst as *mut __m512i as *mut u8,
+        mem::size_of::<__m512i>(),
+    );
+    dst
+}
+
+/// Store 512-bits of integer data from `a` into memory.
+/// `mem_addr` must be aligned on a 64-byte boundary or a
+/// general-protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovaps))] // FIXME vmovdqa32 expected
+pub unsafe fn _mm512_store_si512(mem_addr: *mut i32, a: __m512i) {
+    *(mem_addr as *mut __m512i) = a;
+}
+
+/// Store 512-bits of integer data from `a` into memory.
+/// `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovups))] // FIXME vmovdqu32 expected
+pub unsafe fn _mm512_storeu_si512(mem_addr: *mut i32, a: __m512i) {
+    ptr::copy_nonoverlapping(
+        &a as *const __m512i as *const u8,
+        mem_addr as *mut u8,
+        mem::size_of::<__m512i>(),
+    );
+}
+
+/// Add packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vaddps))]
+pub unsafe fn _mm512_add_ps(a: __m512, b: __m512) -> __m512 {
+    simd_add(a, b)
+}
+
+/// Add packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vaddpd))]
+pub unsafe fn _mm512_add_pd(a: __m512d, b: __m512d) -> __m512d {
+    simd_add(a, b)
+}
+
+/// Subtract packed single-precision (32-bit) floating-point elements in `b`
+/// from `a`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsubps))]
+pub unsafe fn _mm512_sub_ps(a: __m512, b: __m512) -> __m512 {
+    simd_sub(a, b)
+}
+
+/// Subtract packed double-precision (64-bit) floating-point elements in `b`
+/// from `a`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsubpd))]
+pub unsafe fn _mm512_sub_pd(a: __m512d, b: __m512d) -> __m512d {
+    simd_sub(a, b)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmulps))]
+pub unsafe fn _mm512_mul_ps(a: __m512, b: __m512) -> __m512 {
+    simd_mul(a, b)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmulpd))]
+pub unsafe fn _mm512_mul_pd(a: __m512d, b: __m512d) -> __m512d {
+    simd_mul(a, b)
+}
+
+/// Divide packed single-precision (32-bit) floating-point elements in `a` by
+/// `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vdivps))]
+pub unsafe fn _mm512_div_ps(a: __m512, b: __m512) -> __m512 {
+    simd_div(a, b)
+}
+
+/// Divide packed double-precision (64-bit) floating-point elements in `a` by
+/// `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vdivpd))]
+pub unsafe fn _mm512_div_pd(a: __m512d, b: __m512d) -> __m512d {
+    simd_div(a, b)
+}
+
+/// Return the square root of packed single-precision (32-bit) floating point
+/// elements in `a`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsqrtps))]
+pub unsafe fn _mm512_sqrt_ps(a: __m512) -> __m512 {
+    sqrtps512(a)
+}
+
+/// Return the square root of packed double-precision (64-bit) floating point
+/// elements in `a`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsqrtpd))]
+pub unsafe fn _mm512_sqrt_pd(a: __m512d) -> __m512d {
+    sqrtpd512(a)
+}
+
+/// Add packed 32-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpaddd))]
+pub unsafe fn _mm512_add_epi32(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_add(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Add packed 64-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpaddq))]
+pub unsafe fn _mm512_add_epi64(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_add(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Subtract packed 32-bit integers in `b` from `a`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsubd))]
+pub unsafe fn _mm512_sub_epi32(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_sub(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Subtract packed 64-bit integers in `b` from `a`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsubq))]
+pub unsafe fn _mm512_sub_epi64(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_sub(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Multiply the packed 32-bit integers in `a` and `b`, producing intermediate
+/// 64-bit integers, and return the low 32 bits of the intermediate integers.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpmulld))]
+pub unsafe fn _mm512_mullo_epi32(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_mul(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Compute the bitwise AND of 512 bits (representing integer data)
+/// in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vandps))] // FIXME vpandq expected
+pub unsafe fn _mm512_and_si512(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_and(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Compute the bitwise OR of 512 bits (representing integer data)
+/// in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vorps))] // FIXME vporq expected
+pub unsafe fn _mm512_or_si512(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_or(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Compute the bitwise XOR of 512 bits (representing integer data)
+/// in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vxorps))] // FIXME vpxorq expected
+pub unsafe fn _mm512_xor_si512(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_xor(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Compute the bitwise NOT of 512 bits (representing integer data)
+/// in `a` and then AND with `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vandnps))] // FIXME vpandnq expected
+pub unsafe fn _mm512_andnot_si512(a: __m512i, b: __m512i) -> __m512i {
+    let all_ones = _mm512_set1_epi64(-1).as_i64x8();
+    let a = simd_xor(a.as_i64x8(), all_ones);
+    mem::transmute(simd_and(a, b.as_i64x8()))
+}
+
+/// Compute the bitwise AND of packed 32-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpandd))]
+pub unsafe fn _mm512_and_epi32(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_and(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Compute the bitwise OR of packed 32-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpord))]
+pub unsafe fn _mm512_or_epi32(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_or(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Compute the bitwise XOR of packed 32-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpxord))]
+pub unsafe fn _mm512_xor_epi32(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_xor(a.as_i32x16(), b.as_i32x16()))
+}
+
+/// Compute the bitwise NOT of packed 32-bit integers in `a` and then AND
+/// with `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpandnd))]
+pub unsafe fn _mm512_andnot_epi32(a: __m512i, b: __m512i) -> __m512i {
+    let all_ones = i32x16::splat(-1);
+    let a = simd_xor(a.as_i32x16(), all_ones);
+    mem::transmute(simd_and(a, b.as_i32x16()))
+}
+
+/// Compute the bitwise AND of packed 64-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpandq))]
+pub unsafe fn _mm512_and_epi64(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_and(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Compute the bitwise OR of packed 64-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vporq))]
+pub unsafe fn _mm512_or_epi64(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_or(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Compute the bitwise XOR of packed 64-bit integers in `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpxorq))]
+pub unsafe fn _mm512_xor_epi64(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(simd_xor(a.as_i64x8(), b.as_i64x8()))
+}
+
+/// Compute the bitwise NOT of packed 64-bit integers in `a` and then AND
+/// with `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpandnq))]
+pub unsafe fn _mm512_andnot_epi64(a: __m512i, b: __m512i) -> __m512i {
+    let all_ones = i64x8::splat(-1);
+    let a = simd_xor(a.as_i64x8(), all_ones);
+    mem::transmute(simd_and(a, b.as_i64x8()))
+}
+
+/// Shift packed 32-bit integers in `a` left by `imm8` while
+/// shifting in zeros, return the results.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpslld, imm8 = 1))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_slli_epi32(a: __m512i, imm8: u32) -> __m512i {
+    mem::transmute(vpsllid(a.as_i32x16(), imm8 as i32))
+}
+
+/// Shift packed 32-bit integers in `a` right by `imm8` while
+/// shifting in zeros, return the results.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsrld, imm8 = 1))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_srli_epi32(a: __m512i, imm8: u32) -> __m512i {
+    mem::transmute(vpsrlid(a.as_i32x16(), imm8 as i32))
+}
+
+/// Shift packed 32-bit integers in `a` right by `imm8` while
+/// shifting in sign bits, return the results.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsrad, imm8 = 1))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_srai_epi32(a: __m512i, imm8: u32) -> __m512i {
+    mem::transmute(vpsraid(a.as_i32x16(), imm8 as i32))
+}
+
+/// Shift packed 64-bit integers in `a` left by `imm8` while
+/// shifting in zeros, return the results.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsllq, imm8 = 1))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_slli_epi64(a: __m512i, imm8: u32) -> __m512i {
+    mem::transmute(vpslliq(a.as_i64x8(), imm8 as i32))
+}
+
+/// Shift packed 64-bit integers in `a` right by `imm8` while
+/// shifting in zeros, return the results.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsrlq, imm8 = 1))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_srli_epi64(a: __m512i, imm8: u32) -> __m512i {
+    mem::transmute(vpsrliq(a.as_i64x8(), imm8 as i32))
+}
+
+/// Shift packed 64-bit integers in `a` right by `imm8` while
+/// shifting in sign bits, return the results.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsraq, imm8 = 1))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm512_srai_epi64(a: __m512i, imm8: u32) -> __m512i {
+    mem::transmute(vpsraiq(a.as_i64x8(), imm8 as i32))
+}
+
+/// Convert packed 32-bit integers in `a` to packed single-precision (32-bit)
+/// floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcvtdq2ps))]
+pub unsafe fn _mm512_cvtepi32_ps(a: __m512i) -> __m512 {
+    simd_cast(a.as_i32x16())
+}
+
+/// Convert packed 32-bit integers in `a` to packed double-precision (64-bit)
+/// floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcvtdq2pd))]
+pub unsafe fn _mm512_cvtepi32_pd(a: __m256i) -> __m512d {
+    simd_cast(a.as_i32x8())
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in `a`
+/// to packed double-precision (64-bit) floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcvtps2pd))]
+pub unsafe fn _mm512_cvtps_pd(a: __m256) -> __m512d {
+    simd_cast(a)
+}
+
+/// Convert packed double-precision (64-bit) floating-point elements in `a`
+/// to packed single-precision (32-bit) floating-point elements.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcvtpd2ps))]
+pub unsafe fn _mm512_cvtpd_ps(a: __m512d) -> __m256 {
+    simd_cast(a)
+}
+
+/// Sign-extend 32-bit integers to 64-bit integers.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpmovsxdq))]
+pub unsafe fn _mm512_cvtepi32_epi64(a: __m256i) -> __m512i {
+    mem::transmute::<i64x8, _>(simd_cast(a.as_i32x8()))
+}
+
+/// Zero-extend unsigned 32-bit integers in `a` to 64-bit integers.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpmovzxdq))]
+pub unsafe fn _mm512_cvtepu32_epi64(a: __m256i) -> __m512i {
+    mem::transmute::<i64x8, _>(simd_cast(a.as_u32x8()))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in `a`
+/// to packed 32-bit integers, using the rounding mode in `MXCSR`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcvtps2dq))]
+pub unsafe fn _mm512_cvtps_epi32(a: __m512) -> __m512i {
+    let src = _mm512_setzero_si512().as_i32x16();
+    mem::transmute(vcvtps2dq(a, src, -1, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Convert packed single-precision (32-bit) floating-point elements in `a`
+/// to packed 32-bit integers with truncation.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcvttps2dq))]
+pub unsafe fn _mm512_cvttps_epi32(a: __m512) -> __m512i {
+    let src = _mm512_setzero_si512().as_i32x16();
+    mem::transmute(vcvttps2dq(a, src, -1, _MM_FROUND_CUR_DIRECTION))
+}
+
+/// Equal
+pub const _MM_CMPINT_EQ: i32 = 0x00;
+/// Less-than
+pub const _MM_CMPINT_LT: i32 = 0x01;
+/// Less-than-or-equal
+pub const _MM_CMPINT_LE: i32 = 0x02;
+/// False
+pub const _MM_CMPINT_FALSE: i32 = 0x03;
+/// Not-equal
+pub const _MM_CMPINT_NE: i32 = 0x04;
+/// Not less-than
+pub const _MM_CMPINT_NLT: i32 = 0x05;
+/// Not less-than-or-equal
+pub const _MM_CMPINT_NLE: i32 = 0x06;
+/// True
+pub const _MM_CMPINT_TRUE: i32 = 0x07;
+
+/// Compare packed 32-bit integers in `a` and `b` for equality, and return the
+/// results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmpeqd))]
+pub unsafe fn _mm512_cmpeq_epi32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpd(a.as_i32x16(), b.as_i32x16(), _MM_CMPINT_EQ, -1) as __mmask16
+}
+
+/// Compare packed 32-bit integers in `a` and `b` for inequality, and return
+/// the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epi32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpd(a.as_i32x16(), b.as_i32x16(), _MM_CMPINT_NE, -1) as __mmask16
+}
+
+/// Compare packed 32-bit integers in `a` and `b` for less-than, and return the
+/// results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epi32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpd(a.as_i32x16(), b.as_i32x16(), _MM_CMPINT_LT, -1) as __mmask16
+}
+
+/// Compare packed 32-bit integers in `a` and `b` for less-than-or-equal, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epi32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpd(a.as_i32x16(), b.as_i32x16(), _MM_CMPINT_LE, -1) as __mmask16
+}
+
+/// Compare packed 32-bit integers in `a` and `b` for greater-than, and return
+/// the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmpgtd))]
+pub unsafe fn _mm512_cmpgt_epi32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpd(a.as_i32x16(), b.as_i32x16(), _MM_CMPINT_NLE, -1) as __mmask16
+}
+
+/// Compare packed 32-bit integers in `a` and `b` for greater-than-or-equal,
+/// and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epi32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpd(a.as_i32x16(), b.as_i32x16(), _MM_CMPINT_NLT, -1) as __mmask16
+}
+
+/// Compare packed 32-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epi32_mask(
+    a: __m512i, b: __m512i, imm8: i32
+) -> __mmask16 {
+    let a = a.as_i32x16();
+    let b = b.as_i32x16();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpd(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask16
+}
+
+/// Compare packed 32-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epi32_mask(
+    a: __m256i, b: __m256i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_i32x8();
+    let b = b.as_i32x8();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpd256(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed 32-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epi32_mask(
+    a: __m128i, b: __m128i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_i32x4();
+    let b = b.as_i32x4();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpd128(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` for equality, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpeq_epu32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpud(a.as_u32x16(), b.as_u32x16(), _MM_CMPINT_EQ, -1) as __mmask16
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` for inequality, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epu32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpud(a.as_u32x16(), b.as_u32x16(), _MM_CMPINT_NE, -1) as __mmask16
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` for less-than, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epu32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpud(a.as_u32x16(), b.as_u32x16(), _MM_CMPINT_LT, -1) as __mmask16
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` for less-than-or-
+/// equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epu32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpud(a.as_u32x16(), b.as_u32x16(), _MM_CMPINT_LE, -1) as __mmask16
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` for greater-than,
+/// and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpgt_epu32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpud(a.as_u32x16(), b.as_u32x16(), _MM_CMPINT_NLE, -1) as __mmask16
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` for greater-than-or-
+/// equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epu32_mask(a: __m512i, b: __m512i) -> __mmask16 {
+    vpcmpud(a.as_u32x16(), b.as_u32x16(), _MM_CMPINT_NLT, -1) as __mmask16
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epu32_mask(
+    a: __m512i, b: __m512i, imm8: i32
+) -> __mmask16 {
+    let a = a.as_u32x16();
+    let b = b.as_u32x16();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpud(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask16
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epu32_mask(
+    a: __m256i, b: __m256i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_u32x8();
+    let b = b.as_u32x8();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpud256(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epu32_mask(
+    a: __m128i, b: __m128i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_u32x4();
+    let b = b.as_u32x4();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpud128(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed 64-bit integers in `a` and `b` for equality, and return the
+/// results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmpeqq))]
+pub unsafe fn _mm512_cmpeq_epi64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpq(a.as_i64x8(), b.as_i64x8(), _MM_CMPINT_EQ, -1) as __mmask8
+}
+
+/// Compare packed 64-bit integers in `a` and `b` for inequality, and return
+/// the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epi64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpq(a.as_i64x8(), b.as_i64x8(), _MM_CMPINT_NE, -1) as __mmask8
+}
+
+/// Compare packed 64-bit integers in `a` and `b` for less-than, and return the
+/// results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epi64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpq(a.as_i64x8(), b.as_i64x8(), _MM_CMPINT_LT, -1) as __mmask8
+}
+
+/// Compare packed 64-bit integers in `a` and `b` for less-than-or-equal, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epi64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpq(a.as_i64x8(), b.as_i64x8(), _MM_CMPINT_LE, -1) as __mmask8
+}
+
+/// Compare packed 64-bit integers in `a` and `b` for greater-than, and return
+/// the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmpgtq))]
+pub unsafe fn _mm512_cmpgt_epi64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpq(a.as_i64x8(), b.as_i64x8(), _MM_CMPINT_NLE, -1) as __mmask8
+}
+
+/// Compare packed 64-bit integers in `a` and `b` for greater-than-or-equal,
+/// and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epi64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpq(a.as_i64x8(), b.as_i64x8(), _MM_CMPINT_NLT, -1) as __mmask8
+}
+
+/// Compare packed 64-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epi64_mask(
+    a: __m512i, b: __m512i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_i64x8();
+    let b = b.as_i64x8();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpq(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed 64-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epi64_mask(
+    a: __m256i, b: __m256i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_i64x4();
+    let b = b.as_i64x4();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpq256(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed 64-bit integers in `a` and `b` based on the comparison
+/// predicate specified by `imm8`, which is one of the `_MM_CMPINT_*`
+/// constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epi64_mask(
+    a: __m128i, b: __m128i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_i64x2();
+    let b = b.as_i64x2();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpq128(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` for equality, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpeq_epu64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpuq(a.as_u64x8(), b.as_u64x8(), _MM_CMPINT_EQ, -1) as __mmask8
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` for inequality, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpneq_epu64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpuq(a.as_u64x8(), b.as_u64x8(), _MM_CMPINT_NE, -1) as __mmask8
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` for less-than, and
+/// return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmplt_epu64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpuq(a.as_u64x8(), b.as_u64x8(), _MM_CMPINT_LT, -1) as __mmask8
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` for less-than-or-
+/// equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmple_epu64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpuq(a.as_u64x8(), b.as_u64x8(), _MM_CMPINT_LE, -1) as __mmask8
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` for greater-than,
+/// and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpgt_epu64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpuq(a.as_u64x8(), b.as_u64x8(), _MM_CMPINT_NLE, -1) as __mmask8
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` for greater-than-or-
+/// equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp))]
+pub unsafe fn _mm512_cmpge_epu64_mask(a: __m512i, b: __m512i) -> __mmask8 {
+    vpcmpuq(a.as_u64x8(), b.as_u64x8(), _MM_CMPINT_NLT, -1) as __mmask8
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_epu64_mask(
+    a: __m512i, b: __m512i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_u64x8();
+    let b = b.as_u64x8();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpuq(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_cmp_epu64_mask(
+    a: __m256i, b: __m256i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_u64x4();
+    let b = b.as_u64x4();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpuq256(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` based on the
+/// comparison predicate specified by `imm8`, which is one of the
+/// `_MM_CMPINT_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+#[cfg_attr(test, assert_instr(vpcmp, imm8 = 1))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_cmp_epu64_mask(
+    a: __m128i, b: __m128i, imm8: i32
+) -> __mmask8 {
+    let a = a.as_u64x2();
+    let b = b.as_u64x2();
+    macro_rules! call {
+        ($imm8:expr) => { vpcmpuq128(a, b, $imm8, -1) }
+    }
+    constify_imm3!(imm8, call) as __mmask8
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` based on the comparison operand specified by `imm8`, which is one of
+/// the `_CMP_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpeqps, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_ps_mask(
+    a: __m512, b: __m512, imm8: i32
+) -> __mmask16 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vcmpps(a, b, $imm8, -1, _MM_FROUND_CUR_DIRECTION)
+        }
+    }
+    constify_imm6!(imm8, call) as __mmask16
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` for equality, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpeqps))]
+pub unsafe fn _mm512_cmpeq_ps_mask(a: __m512, b: __m512) -> __mmask16 {
+    vcmpps(a, b, _CMP_EQ_OQ, -1, _MM_FROUND_CUR_DIRECTION) as __mmask16
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` for inequality, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpneqps))]
+pub unsafe fn _mm512_cmpneq_ps_mask(a: __m512, b: __m512) -> __mmask16 {
+    vcmpps(a, b, _CMP_NEQ_UQ, -1, _MM_FROUND_CUR_DIRECTION) as __mmask16
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` for less-than, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpltps))]
+pub unsafe fn _mm512_cmplt_ps_mask(a: __m512, b: __m512) -> __mmask16 {
+    vcmpps(a, b, _CMP_LT_OS, -1, _MM_FROUND_CUR_DIRECTION) as __mmask16
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` for less-than-or-equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpleps))]
+pub unsafe fn _mm512_cmple_ps_mask(a: __m512, b: __m512) -> __mmask16 {
+    vcmpps(a, b, _CMP_LE_OS, -1, _MM_FROUND_CUR_DIRECTION) as __mmask16
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` for not-less-than, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpnltps))]
+pub unsafe fn _mm512_cmpnlt_ps_mask(a: __m512, b: __m512) -> __mmask16 {
+    vcmpps(a, b, _CMP_NLT_US, -1, _MM_FROUND_CUR_DIRECTION) as __mmask16
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` for not-less-than-or-equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpnleps))]
+pub unsafe fn _mm512_cmpnle_ps_mask(a: __m512, b: __m512) -> __mmask16 {
+    vcmpps(a, b, _CMP_NLE_US, -1, _MM_FROUND_CUR_DIRECTION) as __mmask16
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` for ordered (neither is NaN), and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpordps))]
+pub unsafe fn _mm512_cmpord_ps_mask(a: __m512, b: __m512) -> __mmask16 {
+    vcmpps(a, b, _CMP_ORD_Q, -1, _MM_FROUND_CUR_DIRECTION) as __mmask16
+}
+
+/// Compare packed single-precision (32-bit) floating-point elements in `a` and
+/// `b` for unordered (either is NaN), and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpunordps))]
+pub unsafe fn _mm512_cmpunord_ps_mask(a: __m512, b: __m512) -> __mmask16 {
+    vcmpps(a, b, _CMP_UNORD_Q, -1, _MM_FROUND_CUR_DIRECTION) as __mmask16
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a` and
+/// `b` based on the comparison operand specified by `imm8`, which is one of
+/// the `_CMP_*` constants, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpeqpd, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_cmp_pd_mask(
+    a: __m512d, b: __m512d, imm8: i32
+) -> __mmask8 {
+    macro_rules! call {
+        ($imm8:expr) => {
+            vcmppd(a, b, $imm8, -1, _MM_FROUND_CUR_DIRECTION)
+        }
+    }
+    constify_imm6!(imm8, call) as __mmask8
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a` and
+/// `b` for equality, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpeqpd))]
+pub unsafe fn _mm512_cmpeq_pd_mask(a: __m512d, b: __m512d) -> __mmask8 {
+    vcmppd(a, b, _CMP_EQ_OQ, -1, _MM_FROUND_CUR_DIRECTION) as __mmask8
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a` and
+/// `b` for inequality, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpneqpd))]
+pub unsafe fn _mm512_cmpneq_pd_mask(a: __m512d, b: __m512d) -> __mmask8 {
+    vcmppd(a, b, _CMP_NEQ_UQ, -1, _MM_FROUND_CUR_DIRECTION) as __mmask8
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a` and
+/// `b` for less-than, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpltpd))]
+pub unsafe fn _mm512_cmplt_pd_mask(a: __m512d, b: __m512d) -> __mmask8 {
+    vcmppd(a, b, _CMP_LT_OS, -1, _MM_FROUND_CUR_DIRECTION) as __mmask8
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a` and
+/// `b` for less-than-or-equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmplepd))]
+pub unsafe fn _mm512_cmple_pd_mask(a: __m512d, b: __m512d) -> __mmask8 {
+    vcmppd(a, b, _CMP_LE_OS, -1, _MM_FROUND_CUR_DIRECTION) as __mmask8
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a` and
+/// `b` for not-less-than, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpnltpd))]
+pub unsafe fn _mm512_cmpnlt_pd_mask(a: __m512d, b: __m512d) -> __mmask8 {
+    vcmppd(a, b, _CMP_NLT_US, -1, _MM_FROUND_CUR_DIRECTION) as __mmask8
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a` and
+/// `b` for not-less-than-or-equal, and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpnlepd))]
+pub unsafe fn _mm512_cmpnle_pd_mask(a: __m512d, b: __m512d) -> __mmask8 {
+    vcmppd(a, b, _CMP_NLE_US, -1, _MM_FROUND_CUR_DIRECTION) as __mmask8
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a` and
+/// `b` for ordered (neither is NaN), and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpordpd))]
+pub unsafe fn _mm512_cmpord_pd_mask(a: __m512d, b: __m512d) -> __mmask8 {
+    vcmppd(a, b, _CMP_ORD_Q, -1, _MM_FROUND_CUR_DIRECTION) as __mmask8
+}
+
+/// Compare packed double-precision (64-bit) floating-point elements in `a` and
+/// `b` for unordered (either is NaN), and return the results in a mask.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vcmpunordpd))]
+pub unsafe fn _mm512_cmpunord_pd_mask(a: __m512d, b: __m512d) -> __mmask8 {
+    vcmppd(a, b, _CMP_UNORD_Q, -1, _MM_FROUND_CUR_DIRECTION) as __mmask8
+}
+
+/// Returns a vector whose lane `i` is all ones if bit `i` of `k` is set and
+/// all zeros otherwise.
+#[inline]
+#[target_feature(enable = "avx512f")]
+unsafe fn mask16_to_i32x16(k: __mmask16) -> i32x16 {
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    let bits = i32x16::new(
+        1 << 0, 1 << 1, 1 << 2, 1 << 3, 1 << 4, 1 << 5, 1 << 6, 1 << 7,
+        1 << 8, 1 << 9, 1 << 10, 1 << 11, 1 << 12, 1 << 13, 1 << 14, 1 << 15,
+    );
+    let k = i32x16::splat(k as i32);
+    simd_ne(simd_and(k, bits), i32x16::splat(0))
+}
+
+/// Returns a vector whose lane `i` is all ones if bit `i` of `k` is set and
+/// all zeros otherwise.
+#[inline]
+#[target_feature(enable = "avx512f")]
+unsafe fn mask8_to_i64x8(k: __mmask8) -> i64x8 {
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    let bits = i64x8::new(
+        1 << 0, 1 << 1, 1 << 2, 1 << 3, 1 << 4, 1 << 5, 1 << 6, 1 << 7,
+    );
+    let k = i64x8::splat(k as i64);
+    simd_ne(simd_and(k, bits), i64x8::splat(0))
+}
+
+/// Selects lane `i` from `a` if bit `i` of `k` is set, and from `b`
+/// otherwise.
+#[inline]
+#[target_feature(enable = "avx512f")]
+unsafe fn select_i32x16(k: __mmask16, a: i32x16, b: i32x16) -> i32x16 {
+    let m = mask16_to_i32x16(k);
+    simd_or(simd_and(m, a), simd_and(simd_xor(m, i32x16::splat(-1)), b))
+}
+
+/// Selects lane `i` from `a` if bit `i` of `k` is set, and from `b`
+/// otherwise.
+#[inline]
+#[target_feature(enable = "avx512f")]
+unsafe fn select_i64x8(k: __mmask8, a: i64x8, b: i64x8) -> i64x8 {
+    let m = mask8_to_i64x8(k);
+    simd_or(simd_and(m, a), simd_and(simd_xor(m, i64x8::splat(-1)), b))
+}
+
+/// Move packed single-precision (32-bit) floating-point elements from `a` into
+/// the returned vector using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vblendmps))]
+pub unsafe fn _mm512_mask_mov_ps(
+    src: __m512, k: __mmask16, a: __m512
+) -> __m512 {
+    let r: i32x16 = mem::transmute(a);
+    let src: i32x16 = mem::transmute(src);
+    mem::transmute(select_i32x16(k, r, src))
+}
+
+/// Move packed single-precision (32-bit) floating-point elements from `a` into
+/// the returned vector using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm512_maskz_mov_ps(k: __mmask16, a: __m512) -> __m512 {
+    _mm512_mask_mov_ps(_mm512_setzero_ps(), k, a)
+}
+
+/// Move packed double-precision (64-bit) floating-point elements from `a` into
+/// the returned vector using writemask `k` (elements are copied from `src`
+/// when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vblendmpd))]
+pub unsafe fn _mm512_mask_mov_pd(
+    src: __m512d, k: __mmask8, a: __m512d
+) -> __m512d {
+    let r: i64x8 = mem::transmute(a);
+    let src: i64x8 = mem::transmute(src);
+    mem::transmute(select_i64x8(k, r, src))
+}
+
+/// Move packed double-precision (64-bit) floating-point elements from `a` into
+/// the returned vector using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovapd))]
+pub unsafe fn _mm512_maskz_mov_pd(k: __mmask8, a: __m512d) -> __m512d {
+    _mm512_mask_mov_pd(_mm512_setzero_pd(), k, a)
+}
+
+/// Move packed 32-bit integers from `a` into the returned vector using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpblendmd))]
+pub unsafe fn _mm512_mask_mov_epi32(
+    src: __m512i, k: __mmask16, a: __m512i
+) -> __m512i {
+    let r = a.as_i32x16();
+    let src = src.as_i32x16();
+    mem::transmute(select_i32x16(k, r, src))
+}
+
+/// Move packed 32-bit integers from `a` into the returned vector using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqa32))]
+pub unsafe fn _mm512_maskz_mov_epi32(k: __mmask16, a: __m512i) -> __m512i {
+    _mm512_mask_mov_epi32(_mm512_setzero_si512(), k, a)
+}
+
+/// Move packed 64-bit integers from `a` into the returned vector using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpblendmq))]
+pub unsafe fn _mm512_mask_mov_epi64(
+    src: __m512i, k: __mmask8, a: __m512i
+) -> __m512i {
+    let r = a.as_i64x8();
+    let src = src.as_i64x8();
+    mem::transmute(select_i64x8(k, r, src))
+}
+
+/// Move packed 64-bit integers from `a` into the returned vector using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqa64))]
+pub unsafe fn _mm512_maskz_mov_epi64(k: __mmask8, a: __m512i) -> __m512i {
+    _mm512_mask_mov_epi64(_mm512_setzero_si512(), k, a)
+}
+
+/// Add packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and return the results using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vaddps))]
+pub unsafe fn _mm512_mask_add_ps(
+    src: __m512, k: __mmask16, a: __m512, b: __m512
+) -> __m512 {
+    _mm512_mask_mov_ps(src, k, _mm512_add_ps(a, b))
+}
+
+/// Add packed single-precision (32-bit) floating-point elements in `a` and
+/// `b`, and return the results using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vaddps))]
+pub unsafe fn _mm512_maskz_add_ps(
+    k: __mmask16, a: __m512, b: __m512
+) -> __m512 {
+    _mm512_maskz_mov_ps(k, _mm512_add_ps(a, b))
+}
+
+/// Add packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and return the results using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vaddpd))]
+pub unsafe fn _mm512_mask_add_pd(
+    src: __m512d, k: __mmask8, a: __m512d, b: __m512d
+) -> __m512d {
+    _mm512_mask_mov_pd(src, k, _mm512_add_pd(a, b))
+}
+
+/// Add packed double-precision (64-bit) floating-point elements in `a` and
+/// `b`, and return the results using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vaddpd))]
+pub unsafe fn _mm512_maskz_add_pd(
+    k: __mmask8, a: __m512d, b: __m512d
+) -> __m512d {
+    _mm512_maskz_mov_pd(k, _mm512_add_pd(a, b))
+}
+
+/// Subtract packed single-precision (32-bit) floating-point elements in `b`
+/// from `a`, and return the results using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsubps))]
+pub unsafe fn _mm512_mask_sub_ps(
+    src: __m512, k: __mmask16, a: __m512, b: __m512
+) -> __m512 {
+    _mm512_mask_mov_ps(src, k, _mm512_sub_ps(a, b))
+}
+
+/// Subtract packed single-precision (32-bit) floating-point elements in `b`
+/// from `a`, and return the results using zeromask `k` (elements are zeroed
+/// out when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsubps))]
+pub unsafe fn _mm512_maskz_sub_ps(
+    k: __mmask16, a: __m512, b: __m512
+) -> __m512 {
+    _mm512_maskz_mov_ps(k, _mm512_sub_ps(a, b))
+}
+
+/// Subtract packed double-precision (64-bit) floating-point elements in `b`
+/// from `a`, and return the results using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsubpd))]
+pub unsafe fn _mm512_mask_sub_pd(
+    src: __m512d, k: __mmask8, a: __m512d, b: __m512d
+) -> __m512d {
+    _mm512_mask_mov_pd(src, k, _mm512_sub_pd(a, b))
+}
+
+/// Subtract packed double-precision (64-bit) floating-point elements in `b`
+/// from `a`, and return the results using zeromask `k` (elements are zeroed
+/// out when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsubpd))]
+pub unsafe fn _mm512_maskz_sub_pd(
+    k: __mmask8, a: __m512d, b: __m512d
+) -> __m512d {
+    _mm512_maskz_mov_pd(k, _mm512_sub_pd(a, b))
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and return the results using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmulps))]
+pub unsafe fn _mm512_mask_mul_ps(
+    src: __m512, k: __mmask16, a: __m512, b: __m512
+) -> __m512 {
+    _mm512_mask_mov_ps(src, k, _mm512_mul_ps(a, b))
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and return the results using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmulps))]
+pub unsafe fn _mm512_maskz_mul_ps(
+    k: __mmask16, a: __m512, b: __m512
+) -> __m512 {
+    _mm512_maskz_mov_ps(k, _mm512_mul_ps(a, b))
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and return the results using writemask `k` (elements are copied
+/// from `src` when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmulpd))]
+pub unsafe fn _mm512_mask_mul_pd(
+    src: __m512d, k: __mmask8, a: __m512d, b: __m512d
+) -> __m512d {
+    _mm512_mask_mov_pd(src, k, _mm512_mul_pd(a, b))
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and return the results using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmulpd))]
+pub unsafe fn _mm512_maskz_mul_pd(
+    k: __mmask8, a: __m512d, b: __m512d
+) -> __m512d {
+    _mm512_maskz_mov_pd(k, _mm512_mul_pd(a, b))
+}
+
+/// Divide packed single-precision (32-bit) floating-point elements in `a` by
+/// `b`, and return the results using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vdivps))]
+pub unsafe fn _mm512_mask_div_ps(
+    src: __m512, k: __mmask16, a: __m512, b: __m512
+) -> __m512 {
+    _mm512_mask_mov_ps(src, k, _mm512_div_ps(a, b))
+}
+
+/// Divide packed single-precision (32-bit) floating-point elements in `a` by
+/// `b`, and return the results using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vdivps))]
+pub unsafe fn _mm512_maskz_div_ps(
+    k: __mmask16, a: __m512, b: __m512
+) -> __m512 {
+    _mm512_maskz_mov_ps(k, _mm512_div_ps(a, b))
+}
+
+/// Divide packed double-precision (64-bit) floating-point elements in `a` by
+/// `b`, and return the results using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vdivpd))]
+pub unsafe fn _mm512_mask_div_pd(
+    src: __m512d, k: __mmask8, a: __m512d, b: __m512d
+) -> __m512d {
+    _mm512_mask_mov_pd(src, k, _mm512_div_pd(a, b))
+}
+
+/// Divide packed double-precision (64-bit) floating-point elements in `a` by
+/// `b`, and return the results using zeromask `k` (elements are zeroed out
+/// when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vdivpd))]
+pub unsafe fn _mm512_maskz_div_pd(
+    k: __mmask8, a: __m512d, b: __m512d
+) -> __m512d {
+    _mm512_maskz_mov_pd(k, _mm512_div_pd(a, b))
+}
+
+/// Add packed 32-bit integers in `a` and `b`, and return the results using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpaddd))]
+pub unsafe fn _mm512_mask_add_epi32(
+    src: __m512i, k: __mmask16, a: __m512i, b: __m512i
+) -> __m512i {
+    _mm512_mask_mov_epi32(src, k, _mm512_add_epi32(a, b))
+}
+
+/// Add packed 32-bit integers in `a` and `b`, and return the results using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpaddd))]
+pub unsafe fn _mm512_maskz_add_epi32(
+    k: __mmask16, a: __m512i, b: __m512i
+) -> __m512i {
+    _mm512_maskz_mov_epi32(k, _mm512_add_epi32(a, b))
+}
+
+/// Add packed 64-bit integers in `a` and `b`, and return the results using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpaddq))]
+pub unsafe fn _mm512_mask_add_epi64(
+    src: __m512i, k: __mmask8, a: __m512i, b: __m512i
+) -> __m512i {
+    _mm512_mask_mov_epi64(src, k, _mm512_add_epi64(a, b))
+}
+
+/// Add packed 64-bit integers in `a` and `b`, and return the results using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpaddq))]
+pub unsafe fn _mm512_maskz_add_epi64(
+    k: __mmask8, a: __m512i, b: __m512i
+) -> __m512i {
+    _mm512_maskz_mov_epi64(k, _mm512_add_epi64(a, b))
+}
+
+/// Subtract packed 32-bit integers in `b` from `a`, and return the results
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsubd))]
+pub unsafe fn _mm512_mask_sub_epi32(
+    src: __m512i, k: __mmask16, a: __m512i, b: __m512i
+) -> __m512i {
+    _mm512_mask_mov_epi32(src, k, _mm512_sub_epi32(a, b))
+}
+
+/// Subtract packed 32-bit integers in `b` from `a`, and return the results
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsubd))]
+pub unsafe fn _mm512_maskz_sub_epi32(
+    k: __mmask16, a: __m512i, b: __m512i
+) -> __m512i {
+    _mm512_maskz_mov_epi32(k, _mm512_sub_epi32(a, b))
+}
+
+/// Subtract packed 64-bit integers in `b` from `a`, and return the results
+/// using writemask `k` (elements are copied from `src` when the corresponding
+/// mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsubq))]
+pub unsafe fn _mm512_mask_sub_epi64(
+    src: __m512i, k: __mmask8, a: __m512i, b: __m512i
+) -> __m512i {
+    _mm512_mask_mov_epi64(src, k, _mm512_sub_epi64(a, b))
+}
+
+/// Subtract packed 64-bit integers in `b` from `a`, and return the results
+/// using zeromask `k` (elements are zeroed out when the corresponding mask bit
+/// is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpsubq))]
+pub unsafe fn _mm512_maskz_sub_epi64(
+    k: __mmask8, a: __m512i, b: __m512i
+) -> __m512i {
+    _mm512_maskz_mov_epi64(k, _mm512_sub_epi64(a, b))
+}
+
+/// Multiply the packed 32-bit integers in `a` and `b` and keep the low 32 bits
+/// of each intermediate 64-bit product, and return the results using writemask
+/// `k` (elements are copied from `src` when the corresponding mask bit is not
+/// set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpmulld))]
+pub unsafe fn _mm512_mask_mullo_epi32(
+    src: __m512i, k: __mmask16, a: __m512i, b: __m512i
+) -> __m512i {
+    _mm512_mask_mov_epi32(src, k, _mm512_mullo_epi32(a, b))
+}
+
+/// Multiply the packed 32-bit integers in `a` and `b` and keep the low 32 bits
+/// of each intermediate 64-bit product, and return the results using zeromask
+/// `k` (elements are zeroed out when the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vpmulld))]
+pub unsafe fn _mm512_maskz_mullo_epi32(
+    k: __mmask16, a: __m512i, b: __m512i
+) -> __m512i {
+    _mm512_maskz_mov_epi32(k, _mm512_mullo_epi32(a, b))
+}
+
+/// Return the square root of packed single-precision (32-bit) floating-point
+/// elements in `a` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsqrtps))]
+pub unsafe fn _mm512_mask_sqrt_ps(
+    src: __m512, k: __mmask16, a: __m512
+) -> __m512 {
+    _mm512_mask_mov_ps(src, k, _mm512_sqrt_ps(a))
+}
+
+/// Return the square root of packed single-precision (32-bit) floating-point
+/// elements in `a` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsqrtps))]
+pub unsafe fn _mm512_maskz_sqrt_ps(k: __mmask16, a: __m512) -> __m512 {
+    _mm512_maskz_mov_ps(k, _mm512_sqrt_ps(a))
+}
+
+/// Return the square root of packed double-precision (64-bit) floating-point
+/// elements in `a` using writemask `k` (elements are copied from `src` when
+/// the corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsqrtpd))]
+pub unsafe fn _mm512_mask_sqrt_pd(
+    src: __m512d, k: __mmask8, a: __m512d
+) -> __m512d {
+    _mm512_mask_mov_pd(src, k, _mm512_sqrt_pd(a))
+}
+
+/// Return the square root of packed double-precision (64-bit) floating-point
+/// elements in `a` using zeromask `k` (elements are zeroed out when the
+/// corresponding mask bit is not set).
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vsqrtpd))]
+pub unsafe fn _mm512_maskz_sqrt_pd(k: __mmask8, a: __m512d) -> __m512d {
+    _mm512_maskz_mov_pd(k, _mm512_sqrt_pd(a))
+}
+
+/// Load packed single-precision (32-bit) floating-point elements from memory
+/// into the returned vector using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set). Memory is not accessed
+/// for masked-off elements. `mem_addr` does not need to be aligned on any
+/// particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm512_mask_loadu_ps(
+    src: __m512, k: __mmask16, mem_addr: *const f32
+) -> __m512 {
+    masked_load!("vmovups", src, k, mem_addr)
+}
+
+/// Load packed single-precision (32-bit) floating-point elements from memory
+/// into the returned vector using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set). Memory is not accessed for masked-
+/// off elements. `mem_addr` does not need to be aligned on any particular
+/// boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm512_maskz_loadu_ps(
+    k: __mmask16, mem_addr: *const f32
+) -> __m512 {
+    _mm512_mask_loadu_ps(_mm512_setzero_ps(), k, mem_addr)
+}
+
+/// Store packed single-precision (32-bit) floating-point elements from `a`
+/// into memory using writemask `k`. Elements whose mask bit is not set are not
+/// written. `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovups))]
+pub unsafe fn _mm512_mask_storeu_ps(
+    mem_addr: *mut f32, k: __mmask16, a: __m512
+) {
+    masked_store!("vmovups", mem_addr, k, a);
+}
+
+/// Load packed single-precision (32-bit) floating-point elements from memory
+/// into the returned vector using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set). Memory is not accessed
+/// for masked-off elements. `mem_addr` must be aligned on a 64-byte boundary
+/// or a general-protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm512_mask_load_ps(
+    src: __m512, k: __mmask16, mem_addr: *const f32
+) -> __m512 {
+    masked_load!("vmovaps", src, k, mem_addr)
+}
+
+/// Load packed single-precision (32-bit) floating-point elements from memory
+/// into the returned vector using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set). Memory is not accessed for masked-
+/// off elements. `mem_addr` must be aligned on a 64-byte boundary or a
+/// general-protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm512_maskz_load_ps(
+    k: __mmask16, mem_addr: *const f32
+) -> __m512 {
+    _mm512_mask_load_ps(_mm512_setzero_ps(), k, mem_addr)
+}
+
+/// Store packed single-precision (32-bit) floating-point elements from `a`
+/// into memory using writemask `k`. Elements whose mask bit is not set are not
+/// written. `mem_addr` must be aligned on a 64-byte boundary or a general-
+/// protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovaps))]
+pub unsafe fn _mm512_mask_store_ps(
+    mem_addr: *mut f32, k: __mmask16, a: __m512
+) {
+    masked_store!("vmovaps", mem_addr, k, a);
+}
+
+/// Load packed double-precision (64-bit) floating-point elements from memory
+/// into the returned vector using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set). Memory is not accessed
+/// for masked-off elements. `mem_addr` does not need to be aligned on any
+/// particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovupd))]
+pub unsafe fn _mm512_mask_loadu_pd(
+    src: __m512d, k: __mmask8, mem_addr: *const f64
+) -> __m512d {
+    masked_load!("vmovupd", src, k, mem_addr)
+}
+
+/// Load packed double-precision (64-bit) floating-point elements from memory
+/// into the returned vector using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set). Memory is not accessed for masked-
+/// off elements. `mem_addr` does not need to be aligned on any particular
+/// boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovupd))]
+pub unsafe fn _mm512_maskz_loadu_pd(
+    k: __mmask8, mem_addr: *const f64
+) -> __m512d {
+    _mm512_mask_loadu_pd(_mm512_setzero_pd(), k, mem_addr)
+}
+
+/// Store packed double-precision (64-bit) floating-point elements from `a`
+/// into memory using writemask `k`. Elements whose mask bit is not set are not
+/// written. `mem_addr` does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovupd))]
+pub unsafe fn _mm512_mask_storeu_pd(
+    mem_addr: *mut f64, k: __mmask8, a: __m512d
+) {
+    masked_store!("vmovupd", mem_addr, k, a);
+}
+
+/// Load packed double-precision (64-bit) floating-point elements from memory
+/// into the returned vector using writemask `k` (elements are copied from
+/// `src` when the corresponding mask bit is not set). Memory is not accessed
+/// for masked-off elements. `mem_addr` must be aligned on a 64-byte boundary
+/// or a general-protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovapd))]
+pub unsafe fn _mm512_mask_load_pd(
+    src: __m512d, k: __mmask8, mem_addr: *const f64
+) -> __m512d {
+    masked_load!("vmovapd", src, k, mem_addr)
+}
+
+/// Load packed double-precision (64-bit) floating-point elements from memory
+/// into the returned vector using zeromask `k` (elements are zeroed out when
+/// the corresponding mask bit is not set). Memory is not accessed for masked-
+/// off elements. `mem_addr` must be aligned on a 64-byte boundary or a
+/// general-protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovapd))]
+pub unsafe fn _mm512_maskz_load_pd(
+    k: __mmask8, mem_addr: *const f64
+) -> __m512d {
+    _mm512_mask_load_pd(_mm512_setzero_pd(), k, mem_addr)
+}
+
+/// Store packed double-precision (64-bit) floating-point elements from `a`
+/// into memory using writemask `k`. Elements whose mask bit is not set are not
+/// written. `mem_addr` must be aligned on a 64-byte boundary or a general-
+/// protection exception may be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovapd))]
+pub unsafe fn _mm512_mask_store_pd(
+    mem_addr: *mut f64, k: __mmask8, a: __m512d
+) {
+    masked_store!("vmovapd", mem_addr, k, a);
+}
+
+/// Load packed 32-bit integers from memory into the returned vector using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set). Memory is not accessed for masked-off elements. `mem_addr`
+/// does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm512_mask_loadu_epi32(
+    src: __m512i, k: __mmask16, mem_addr: *const i32
+) -> __m512i {
+    masked_load!("vmovdqu32", src, k, mem_addr)
+}
+
+/// Load packed 32-bit integers from memory into the returned vector using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set). Memory is not accessed for masked-off elements. `mem_addr` does
+/// not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm512_maskz_loadu_epi32(
+    k: __mmask16, mem_addr: *const i32
+) -> __m512i {
+    _mm512_mask_loadu_epi32(_mm512_setzero_si512(), k, mem_addr)
+}
+
+/// Store packed 32-bit integers from `a` into memory using writemask `k`.
+/// Elements whose mask bit is not set are not written. `mem_addr` does not
+/// need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu32))]
+pub unsafe fn _mm512_mask_storeu_epi32(
+    mem_addr: *mut i32, k: __mmask16, a: __m512i
+) {
+    masked_store!("vmovdqu32", mem_addr, k, a);
+}
+
+/// Load packed 32-bit integers from memory into the returned vector using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set). Memory is not accessed for masked-off elements. `mem_addr`
+/// must be aligned on a 64-byte boundary or a general-protection exception may
+/// be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqa32))]
+pub unsafe fn _mm512_mask_load_epi32(
+    src: __m512i, k: __mmask16, mem_addr: *const i32
+) -> __m512i {
+    masked_load!("vmovdqa32", src, k, mem_addr)
+}
+
+/// Load packed 32-bit integers from memory into the returned vector using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set). Memory is not accessed for masked-off elements. `mem_addr` must
+/// be aligned on a 64-byte boundary or a general-protection exception may be
+/// generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqa32))]
+pub unsafe fn _mm512_maskz_load_epi32(
+    k: __mmask16, mem_addr: *const i32
+) -> __m512i {
+    _mm512_mask_load_epi32(_mm512_setzero_si512(), k, mem_addr)
+}
+
+/// Store packed 32-bit integers from `a` into memory using writemask `k`.
+/// Elements whose mask bit is not set are not written. `mem_addr` must be
+/// aligned on a 64-byte boundary or a general-protection exception may be
+/// generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqa32))]
+pub unsafe fn _mm512_mask_store_epi32(
+    mem_addr: *mut i32, k: __mmask16, a: __m512i
+) {
+    masked_store!("vmovdqa32", mem_addr, k, a);
+}
+
+/// Load packed 64-bit integers from memory into the returned vector using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set). Memory is not accessed for masked-off elements. `mem_addr`
+/// does not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm512_mask_loadu_epi64(
+    src: __m512i, k: __mmask8, mem_addr: *const i64
+) -> __m512i {
+    masked_load!("vmovdqu64", src, k, mem_addr)
+}
+
+/// Load packed 64-bit integers from memory into the returned vector using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set). Memory is not accessed for masked-off elements. `mem_addr` does
+/// not need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm512_maskz_loadu_epi64(
+    k: __mmask8, mem_addr: *const i64
+) -> __m512i {
+    _mm512_mask_loadu_epi64(_mm512_setzero_si512(), k, mem_addr)
+}
+
+/// Store packed 64-bit integers from `a` into memory using writemask `k`.
+/// Elements whose mask bit is not set are not written. `mem_addr` does not
+/// need to be aligned on any particular boundary.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqu64))]
+pub unsafe fn _mm512_mask_storeu_epi64(
+    mem_addr: *mut i64, k: __mmask8, a: __m512i
+) {
+    masked_store!("vmovdqu64", mem_addr, k, a);
+}
+
+/// Load packed 64-bit integers from memory into the returned vector using
+/// writemask `k` (elements are copied from `src` when the corresponding mask
+/// bit is not set). Memory is not accessed for masked-off elements. `mem_addr`
+/// must be aligned on a 64-byte boundary or a general-protection exception may
+/// be generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqa64))]
+pub unsafe fn _mm512_mask_load_epi64(
+    src: __m512i, k: __mmask8, mem_addr: *const i64
+) -> __m512i {
+    masked_load!("vmovdqa64", src, k, mem_addr)
+}
+
+/// Load packed 64-bit integers from memory into the returned vector using
+/// zeromask `k` (elements are zeroed out when the corresponding mask bit is
+/// not set). Memory is not accessed for masked-off elements. `mem_addr` must
+/// be aligned on a 64-byte boundary or a general-protection exception may be
+/// generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqa64))]
+pub unsafe fn _mm512_maskz_load_epi64(
+    k: __mmask8, mem_addr: *const i64
+) -> __m512i {
+    _mm512_mask_load_epi64(_mm512_setzero_si512(), k, mem_addr)
+}
+
+/// Store packed 64-bit integers from `a` into memory using writemask `k`.
+/// Elements whose mask bit is not set are not written. `mem_addr` must be
+/// aligned on a 64-byte boundary or a general-protection exception may be
+/// generated.
+#[inline]
+#[target_feature(enable = "avx512f")]
+#[cfg_attr(test, assert_instr(vmovdqa64))]
+pub unsafe fn _mm512_mask_store_epi64(
+    mem_addr: *mut i64, k: __mmask8, a: __m512i
+) {
+    masked_store!("vmovdqa64", mem_addr, k, a);
+}
+
+/// Compute the bitwise AND of 16-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_kand(a: __mmask16, b: __mmask16) -> __mmask16 {
+    a & b
+}
+
+/// Compute the bitwise AND of 16-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kand_mask16(a: __mmask16, b: __mmask16) -> __mmask16 {
+    _mm512_kand(a, b)
+}
+
+/// Compute the bitwise NOT of 16-bit mask `a` and then AND with `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_kandn(a: __mmask16, b: __mmask16) -> __mmask16 {
+    !a & b
+}
+
+/// Compute the bitwise NOT of 16-bit mask `a` and then AND with `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kandn_mask16(a: __mmask16, b: __mmask16) -> __mmask16 {
+    _mm512_kandn(a, b)
+}
+
+/// Compute the bitwise OR of 16-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_kor(a: __mmask16, b: __mmask16) -> __mmask16 {
+    a | b
+}
+
+/// Compute the bitwise OR of 16-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kor_mask16(a: __mmask16, b: __mmask16) -> __mmask16 {
+    _mm512_kor(a, b)
+}
+
+/// Compute the bitwise XOR of 16-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_kxor(a: __mmask16, b: __mmask16) -> __mmask16 {
+    a ^ b
+}
+
+/// Compute the bitwise XOR of 16-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kxor_mask16(a: __mmask16, b: __mmask16) -> __mmask16 {
+    _mm512_kxor(a, b)
+}
+
+/// Compute the bitwise XNOR of 16-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_kxnor(a: __mmask16, b: __mmask16) -> __mmask16 {
+    !(a ^ b)
+}
+
+/// Compute the bitwise XNOR of 16-bit masks `a` and `b`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kxnor_mask16(a: __mmask16, b: __mmask16) -> __mmask16 {
+    _mm512_kxnor(a, b)
+}
+
+/// Compute the bitwise NOT of 16-bit mask `a`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_knot(a: __mmask16) -> __mmask16 {
+    !a
+}
+
+/// Compute the bitwise NOT of 16-bit mask `a`.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _knot_mask16(a: __mmask16) -> __mmask16 {
+    _mm512_knot(a)
+}
+
+/// Compute the bitwise OR of 16-bit masks `a` and `b`, and return 1 if the
+/// result is all zeros, otherwise return 0.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_kortestz(a: __mmask16, b: __mmask16) -> i32 {
+    ((a | b) == 0) as i32
+}
+
+/// Compute the bitwise OR of 16-bit masks `a` and `b`, and return 1 if the
+/// result is all ones, otherwise return 0.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm512_kortestc(a: __mmask16, b: __mmask16) -> i32 {
+    ((a | b) == 0xffff) as i32
+}
+
+/// Compute the bitwise OR of 16-bit masks `a` and `b`, and return 1 if the
+/// result is all zeros, otherwise return 0.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kortestz_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    _mm512_kortestz(a, b) as u8
+}
+
+/// Compute the bitwise OR of 16-bit masks `a` and `b`, and return 1 if the
+/// result is all ones, otherwise return 0.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kortestc_mask16_u8(a: __mmask16, b: __mmask16) -> u8 {
+    _mm512_kortestc(a, b) as u8
+}
+
+/// Compute the bitwise OR of 16-bit masks `a` and `b`. Return 1 if the result
+/// is all zeros, otherwise 0, and store in `all_ones` whether the result is
+/// all ones.
+#[inline]
+#[target_feature(enable = "avx512f")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _kortest_mask16_u8(
+    a: __mmask16, b: __mmask16, all_ones: &mut u8
+) -> u8 {
+    *all_ones = _kortestc_mask16_u8(a, b);
+    _kortestz_mask16_u8(a, b)
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.sqrt.v16f32"]
+    fn sqrtps512(a: __m512) -> __m512;
+    #[link_name = "llvm.sqrt.v8f64"]
+    fn sqrtpd512(a: __m512d) -> __m512d;
+
+    #[link_name = "llvm.x86.avx512.pslli.d.512"]
+    fn vpsllid(a: i32x16, imm8: i32) -> i32x16;
+    #[link_name = "llvm.x86.avx512.psrli.d.512"]
+    fn vpsrlid(a: i32x16, imm8: i32) -> i32x16;
+    #[link_name = "llvm.x86.avx512.psrai.d.512"]
+    fn vpsraid(a: i32x16, imm8: i32) -> i32x16;
+    #[link_name = "llvm.x86.avx512.pslli.q.512"]
+    fn vpslliq(a: i64x8, imm8: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.psrli.q.512"]
+    fn vpsrliq(a: i64x8, imm8: i32) -> i64x8;
+    #[link_name = "llvm.x86.avx512.psrai.q.512"]
+    fn vpsraiq(a: i64x8, imm8: i32) -> i64x8;
+
+    #[link_name = "llvm.x86.avx512.mask.cvtps2dq.512"]
+    fn vcvtps2dq(a: __m512, src: i32x16, mask: i16, rounding: i32) -> i32x16;
+    #[link_name = "llvm.x86.avx512.mask.cvttps2dq.512"]
+    fn vcvttps2dq(a: __m512, src: i32x16, mask: i16, rounding: i32) -> i32x16;
+
+    #[link_name = "llvm.x86.avx512.mask.cmp.d.512"]
+    fn vpcmpd(a: i32x16, b: i32x16, op: i32, mask: i16) -> i16;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.d.512"]
+    fn vpcmpud(a: u32x16, b: u32x16, op: i32, mask: i16) -> i16;
+    #[link_name = "llvm.x86.avx512.mask.cmp.q.512"]
+    fn vpcmpq(a: i64x8, b: i64x8, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.q.512"]
+    fn vpcmpuq(a: u64x8, b: u64x8, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.cmp.d.256"]
+    fn vpcmpd256(a: i32x8, b: i32x8, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.cmp.d.128"]
+    fn vpcmpd128(a: i32x4, b: i32x4, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.d.256"]
+    fn vpcmpud256(a: u32x8, b: u32x8, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.d.128"]
+    fn vpcmpud128(a: u32x4, b: u32x4, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.cmp.q.256"]
+    fn vpcmpq256(a: i64x4, b: i64x4, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.cmp.q.128"]
+    fn vpcmpq128(a: i64x2, b: i64x2, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.q.256"]
+    fn vpcmpuq256(a: u64x4, b: u64x4, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.ucmp.q.128"]
+    fn vpcmpuq128(a: u64x2, b: u64x2, op: i32, mask: i8) -> i8;
+    #[link_name = "llvm.x86.avx512.mask.cmp.ps.512"]
+    fn vcmpps(a: __m512, b: __m512, op: i32, mask: i16, sae: i32) -> i16;
+    #[link_name = "llvm.x86.avx512.mask.cmp.pd.512"]
+    fn vcmppd(a: __m512d, b: __m512d, op: i32, mask: i8, sae: i32) -> i8;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32, f64, i32, i64, mem, u32};
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_setzero_ps() {
+        let r = _mm512_setzero_ps();
+        assert_eq_m512(r, _mm512_set1_ps(0.));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_setzero_pd() {
+        let r = _mm512_setzero_pd();
+        assert_eq_m512d(r, _mm512_set1_pd(0.));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_setzero_si512() {
+        let r = _mm512_setzero_si512();
+        assert_eq_m512i(r, _mm512_set1_epi32(0));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_set_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let r = _mm512_set_epi32(
+            15, 14, 13, 12, 11, 10, 9, 8,
+            7, 6, 5, 4, 3, 2, 1, 0
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_setr_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let r = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15
+        );
+        let e = _mm512_setr_epi64(
+            1 << 32,
+            3 | 2 << 32,
+            5 | 4 << 32,
+            7 | 6 << 32,
+            9 | 8 << 32,
+            11 | 10 << 32,
+            13 | 12 << 32,
+            15 | 14 << 32,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_set_epi64() {
+        let r = _mm512_set_epi64(7, 6, 5, 4, 3, 2, 1, 0);
+        let e = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_setr_epi64() {
+        let r = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let p = &r as *const _ as *const i64;
+        for i in 0..8 {
+            assert_eq!(*p.offset(i), i as i64);
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_set_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let r = _mm512_set_ps(
+            15., 14., 13., 12., 11., 10., 9., 8.,
+            7., 6., 5., 4., 3., 2., 1., 0.
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_setr_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let r = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.
+        );
+        for i in 0..16 {
+            assert_eq!(get_m512(r, i), i as f32);
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_set_pd() {
+        let r = _mm512_set_pd(7., 6., 5., 4., 3., 2., 1., 0.);
+        let e = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_setr_pd() {
+        let r = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        for i in 0..8 {
+            assert_eq!(get_m512d(r, i), i as f64);
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_set1_ps() {
+        let r = _mm512_set1_ps(7.);
+        for i in 0..16 {
+            assert_eq!(get_m512(r, i), 7.);
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_set1_pd() {
+        let r = _mm512_set1_pd(7.);
+        for i in 0..8 {
+            assert_eq!(get_m512d(r, i), 7.);
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_set1_epi8() {
+        let r = _mm512_set1_epi8(-2);
+        let e = _mm512_set1_epi64(0xfefe_fefe_fefe_fefeu64 as i64);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_set1_epi16() {
+        let r = _mm512_set1_epi16(-2);
+        let e = _mm512_set1_epi64(0xfffe_fffe_fffe_fffeu64 as i64);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_set1_epi32() {
+        let r = _mm512_set1_epi32(-2);
+        let e = _mm512_set1_epi64(0xffff_fffe_ffff_fffeu64 as i64);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_set1_epi64() {
+        let r = _mm512_set1_epi64(-2);
+        let e = _mm512_setr_epi64(-2, -2, -2, -2, -2, -2, -2, -2);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castpd_ps() {
+        let a = _mm512_set1_pd(1.);
+        let r = _mm512_castpd_ps(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_ps(
+            0., 1.875, 0., 1.875, 0., 1.875, 0., 1.875,
+            0., 1.875, 0., 1.875, 0., 1.875, 0., 1.875
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castps_pd() {
+        let a = _mm512_set1_ps(1.);
+        let r = _mm512_castps_pd(a);
+        let e = _mm512_set1_pd(0.007812501848093234);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castps_si512() {
+        let a = _mm512_set1_ps(1.);
+        let r = _mm512_castps_si512(a);
+        let e = _mm512_set1_epi32(0x3f80_0000);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castpd_si512() {
+        let a = _mm512_set1_pd(1.);
+        let r = _mm512_castpd_si512(a);
+        let e = _mm512_set1_epi64(0x3ff0_0000_0000_0000);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castsi512_ps() {
+        let a = _mm512_set1_epi32(0x3f80_0000);
+        let r = _mm512_castsi512_ps(a);
+        assert_eq_m512(r, _mm512_set1_ps(1.));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castsi512_pd() {
+        let a = _mm512_set1_epi64(0x3ff0_0000_0000_0000);
+        let r = _mm512_castsi512_pd(a);
+        assert_eq_m512d(r, _mm512_set1_pd(1.));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castps512_ps128() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.
+        );
+        let r = _mm512_castps512_ps128(a);
+        assert_eq_m128(r, _mm_setr_ps(0., 1., 2., 3.));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castps512_ps256() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.
+        );
+        let r = _mm512_castps512_ps256(a);
+        let e = _mm256_setr_ps(0., 1., 2., 3., 4., 5., 6., 7.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castpd512_pd128() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let r = _mm512_castpd512_pd128(a);
+        assert_eq_m128d(r, _mm_setr_pd(0., 1.));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castpd512_pd256() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let r = _mm512_castpd512_pd256(a);
+        assert_eq_m256d(r, _mm256_setr_pd(0., 1., 2., 3.));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castsi512_si128() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let r = _mm512_castsi512_si128(a);
+        assert_eq_m128i(r, _mm_set_epi64x(1, 0));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castsi512_si256() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let r = _mm512_castsi512_si256(a);
+        assert_eq_m256i(r, _mm256_setr_epi64x(0, 1, 2, 3));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castps256_ps512() {
+        let a = _mm256_setr_ps(0., 1., 2., 3., 4., 5., 6., 7.);
+        let r = _mm512_castps256_ps512(a);
+        assert_eq_m256(_mm512_castps512_ps256(r), a);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castpd256_pd512() {
+        let a = _mm256_setr_pd(0., 1., 2., 3.);
+        let r = _mm512_castpd256_pd512(a);
+        assert_eq_m256d(_mm512_castpd512_pd256(r), a);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_castsi256_si512() {
+        let a = _mm256_setr_epi64x(0, 1, 2, 3);
+        let r = _mm512_castsi256_si512(a);
+        assert_eq_m256i(_mm512_castsi512_si256(r), a);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_load_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.
+        );
+        let p = &a as *const _ as *const f32;
+        let r = _mm512_load_ps(p);
+        assert_eq_m512(r, a);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_loadu_ps() {
+        let mut a = [0f32; 17];
+        for i in 0..17 {
+            a[i] = i as f32;
+        }
+        let r = _mm512_loadu_ps(a[1..].as_ptr());
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8.,
+            9., 10., 11., 12., 13., 14., 15., 16.
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_store_ps() {
+        let a = _mm512_set1_ps(9.);
+        let mut r = _mm512_undefined_ps();
+        _mm512_store_ps(&mut r as *mut _ as *mut f32, a);
+        assert_eq_m512(r, a);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_storeu_ps() {
+        let a = _mm512_set1_ps(9.);
+        let mut r = [0f32; 17];
+        _mm512_storeu_ps(r[1..].as_mut_ptr(), a);
+        assert_eq!(r[0], 0.);
+        for i in 1..17 {
+            assert_eq!(r[i], 9.);
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_load_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let p = &a as *const _ as *const f64;
+        let r = _mm512_load_pd(p);
+        assert_eq_m512d(r, a);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_loadu_pd() {
+        let mut a = [0f64; 9];
+        for i in 0..9 {
+            a[i] = i as f64;
+        }
+        let r = _mm512_loadu_pd(a[1..].as_ptr());
+        let e = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_store_pd() {
+        let a = _mm512_set1_pd(9.);
+        let mut r = _mm512_undefined_pd();
+        _mm512_store_pd(&mut r as *mut _ as *mut f64, a);
+        assert_eq_m512d(r, a);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_storeu_pd() {
+        let a = _mm512_set1_pd(9.);
+        let mut r = [0f64; 9];
+        _mm512_storeu_pd(r[1..].as_mut_ptr(), a);
+        assert_eq!(r[0], 0.);
+        for i in 1..9 {
+            assert_eq!(r[i], 9.);
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_load_si512() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let p = &a as *const _ as *const i32;
+        let r = _mm512_load_si512(p);
+        assert_eq_m512i(r, a);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_loadu_si512() {
+        let mut a = [0i32; 17];
+        for i in 0..17 {
+            a[i] = i as i32;
+        }
+        let r = _mm512_loadu_si512(a[1..].as_ptr());
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            1, 2, 3, 4, 5, 6, 7, 8,
+            9, 10, 11, 12, 13, 14, 15, 16
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_store_si512() {
+        let a = _mm512_set1_epi32(9);
+        let mut r = _mm512_undefined_epi32();
+        _mm512_store_si512(&mut r as *mut _ as *mut i32, a);
+        assert_eq_m512i(r, a);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_storeu_si512() {
+        let a = _mm512_set1_epi32(9);
+        let mut r = [0i32; 17];
+        _mm512_storeu_si512(r[1..].as_mut_ptr(), a);
+        assert_eq!(r[0], 0);
+        for i in 1..17 {
+            assert_eq!(r[i], 9);
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_add_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8.,
+            -1., -2., -3., -4., 0.5, 0.25, 100., 1000000.
+        );
+        let b = _mm512_setr_ps(
+            2., 2., 2., 2., 4., 4., 4., 4., 8., 8., 8., 8., 0.5, 0.5, 0.5, 0.5,
+        );
+        let r = _mm512_add_ps(a, b);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_ps(
+            3., 4., 5., 6., 9., 10., 11., 12.,
+            7., 6., 5., 4., 1., 0.75, 100.5, 1000000.5
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_add_pd() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm512_setr_pd(8., 8., 8., 8., 0.5, 0.5, 0.5, 0.5);
+        let r = _mm512_add_pd(a, b);
+        let e = _mm512_setr_pd(9., 10., 11., 12., 5.5, 6.5, 7.5, 8.5);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_sub_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8.,
+            -1., -2., -3., -4., 0.5, 0.25, 100., 1000000.
+        );
+        let b = _mm512_setr_ps(
+            2., 2., 2., 2., 4., 4., 4., 4., 8., 8., 8., 8., 0.5, 0.5, 0.5, 0.5,
+        );
+        let r = _mm512_sub_ps(a, b);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_ps(
+            -1., 0., 1., 2., 1., 2., 3., 4.,
+            -9., -10., -11., -12., 0., -0.25, 99.5, 999999.5
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_sub_pd() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm512_setr_pd(8., 8., 8., 8., 0.5, 0.5, 0.5, 0.5);
+        let r = _mm512_sub_pd(a, b);
+        let e = _mm512_setr_pd(-7., -6., -5., -4., 4.5, 5.5, 6.5, 7.5);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mul_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8.,
+            -1., -2., -3., -4., 0.5, 0.25, 100., 1000000.
+        );
+        let b = _mm512_setr_ps(
+            2., 2., 2., 2., 4., 4., 4., 4., 8., 8., 8., 8., 0.5, 0.5, 0.5, 0.5,
+        );
+        let r = _mm512_mul_ps(a, b);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_ps(
+            2., 4., 6., 8., 20., 24., 28., 32.,
+            -8., -16., -24., -32., 0.25, 0.125, 50., 500000.
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mul_pd() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm512_setr_pd(8., 8., 8., 8., 0.5, 0.5, 0.5, 0.5);
+        let r = _mm512_mul_pd(a, b);
+        let e = _mm512_setr_pd(8., 16., 24., 32., 2.5, 3., 3.5, 4.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_div_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            1., 2., 3., 4., 5., 6., 7., 8.,
+            -1., -2., -3., -4., 0.5, 0.25, 100., 1000000.
+        );
+        let b = _mm512_setr_ps(
+            2., 2., 2., 2., 4., 4., 4., 4., 8., 8., 8., 8., 0.5, 0.5, 0.5, 0.5,
+        );
+        let r = _mm512_div_ps(a, b);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_ps(
+            0.5, 1., 1.5, 2., 1.25, 1.5, 1.75, 2.,
+            -0.125, -0.25, -0.375, -0.5, 1., 0.5, 200., 2000000.
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_div_pd() {
+        let a = _mm512_setr_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm512_setr_pd(8., 8., 8., 8., 0.5, 0.5, 0.5, 0.5);
+        let r = _mm512_div_pd(a, b);
+        let e = _mm512_setr_pd(0.125, 0.25, 0.375, 0.5, 10., 12., 14., 16.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_sqrt_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 4., 9., 16., 25., 36., 49.,
+            64., 81., 100., 121., 144., 169., 196., 225.
+        );
+        let r = _mm512_sqrt_ps(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_sqrt_pd() {
+        let a = _mm512_setr_pd(0., 1., 4., 9., 16., 25., 36., 2.);
+        let r = _mm512_sqrt_pd(a);
+        let e =
+            _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., f64::consts::SQRT_2);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_add_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        let b = _mm512_set1_epi32(1);
+        let r = _mm512_add_epi32(a, b);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            1, 2, 0, i32::MIN, i32::MIN + 1, 101, -99, 14,
+            1, 2, 0, i32::MIN, i32::MIN + 1, 101, -99, 14
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_sub_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        let b = _mm512_set1_epi32(1);
+        let r = _mm512_sub_epi32(a, b);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            -1, 0, -2, i32::MAX - 1, i32::MAX, 99, -101, 12,
+            -1, 0, -2, i32::MAX - 1, i32::MAX, 99, -101, 12
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_add_epi64() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_set1_epi64(1);
+        let r = _mm512_add_epi64(a, b);
+        let e =
+            _mm512_setr_epi64(1, 2, 0, i64::MIN, i64::MIN + 1, 101, -99, 14);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_sub_epi64() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_set1_epi64(1);
+        let r = _mm512_sub_epi64(a, b);
+        let e =
+            _mm512_setr_epi64(-1, 0, -2, i64::MAX - 1, i64::MAX, 99, -101, 12);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mullo_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        let b = _mm512_set1_epi32(2);
+        let r = _mm512_mullo_epi32(a, b);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            0, 2, -2, -2, 0, 200, -200, 26,
+            0, 2, -2, -2, 0, 200, -200, 26
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_and_si512() {
+        let a = _mm512_set1_epi32(0b0011);
+        let b = _mm512_set1_epi32(0b0101);
+        let r = _mm512_and_si512(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi32(0b0001));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_and_epi32() {
+        let a = _mm512_set1_epi32(0b0011);
+        let b = _mm512_set1_epi32(0b0101);
+        let r = _mm512_and_epi32(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi32(0b0001));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_and_epi64() {
+        let a = _mm512_set1_epi64(0b0011);
+        let b = _mm512_set1_epi64(0b0101);
+        let r = _mm512_and_epi64(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi64(0b0001));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_or_si512() {
+        let a = _mm512_set1_epi32(0b0011);
+        let b = _mm512_set1_epi32(0b0101);
+        let r = _mm512_or_si512(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi32(0b0111));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_or_epi32() {
+        let a = _mm512_set1_epi32(0b0011);
+        let b = _mm512_set1_epi32(0b0101);
+        let r = _mm512_or_epi32(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi32(0b0111));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_or_epi64() {
+        let a = _mm512_set1_epi64(0b0011);
+        let b = _mm512_set1_epi64(0b0101);
+        let r = _mm512_or_epi64(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi64(0b0111));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_xor_si512() {
+        let a = _mm512_set1_epi32(0b0011);
+        let b = _mm512_set1_epi32(0b0101);
+        let r = _mm512_xor_si512(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi32(0b0110));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_xor_epi32() {
+        let a = _mm512_set1_epi32(0b0011);
+        let b = _mm512_set1_epi32(0b0101);
+        let r = _mm512_xor_epi32(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi32(0b0110));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_xor_epi64() {
+        let a = _mm512_set1_epi64(0b0011);
+        let b = _mm512_set1_epi64(0b0101);
+        let r = _mm512_xor_epi64(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi64(0b0110));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_andnot_si512() {
+        let a = _mm512_set1_epi32(0b0011);
+        let b = _mm512_set1_epi32(0b0101);
+        let r = _mm512_andnot_si512(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi32(0b0100));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_andnot_epi32() {
+        let a = _mm512_set1_epi32(0b0011);
+        let b = _mm512_set1_epi32(0b0101);
+        let r = _mm512_andnot_epi32(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi32(0b0100));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_andnot_epi64() {
+        let a = _mm512_set1_epi64(0b0011);
+        let b = _mm512_set1_epi64(-1);
+        let r = _mm512_andnot_epi64(a, b);
+        assert_eq_m512i(r, _mm512_set1_epi64(!0b0011));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_slli_epi32() {
+        let a = _mm512_set1_epi32(0x7fff_ffff);
+        let r = _mm512_slli_epi32(a, 4);
+        assert_eq_m512i(r, _mm512_set1_epi32(-16));
+        let r = _mm512_slli_epi32(a, 32);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_srli_epi32() {
+        let a = _mm512_set1_epi32(-16);
+        let r = _mm512_srli_epi32(a, 4);
+        assert_eq_m512i(r, _mm512_set1_epi32(0x0fff_ffff));
+        let r = _mm512_srli_epi32(a, 32);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_srai_epi32() {
+        let a = _mm512_set1_epi32(-16);
+        let r = _mm512_srai_epi32(a, 4);
+        assert_eq_m512i(r, _mm512_set1_epi32(-1));
+        let r = _mm512_srai_epi32(a, 32);
+        assert_eq_m512i(r, _mm512_set1_epi32(-1));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_slli_epi64() {
+        let a = _mm512_set1_epi64(i64::MAX);
+        let r = _mm512_slli_epi64(a, 4);
+        assert_eq_m512i(r, _mm512_set1_epi64(-16));
+        let r = _mm512_slli_epi64(a, 64);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_srli_epi64() {
+        let a = _mm512_set1_epi64(-16);
+        let r = _mm512_srli_epi64(a, 4);
+        assert_eq_m512i(r, _mm512_set1_epi64(0x0fff_ffff_ffff_ffff));
+        let r = _mm512_srli_epi64(a, 64);
+        assert_eq_m512i(r, _mm512_setzero_si512());
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_srai_epi64() {
+        let a = _mm512_set1_epi64(-16);
+        let r = _mm512_srai_epi64(a, 4);
+        assert_eq_m512i(r, _mm512_set1_epi64(-1));
+        let r = _mm512_srai_epi64(a, 64);
+        assert_eq_m512i(r, _mm512_set1_epi64(-1));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cvtepi32_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, 2, -2, 100, -100, 13,
+            0, 1, -1, 2, -2, 100, -100, 13
+        );
+        let r = _mm512_cvtepi32_ps(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_ps(
+            0., 1., -1., 2., -2., 100., -100., 13.,
+            0., 1., -1., 2., -2., 100., -100., 13.
+        );
+        assert_eq_m512(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cvtepi32_pd() {
+        let a = _mm256_setr_epi32(0, 1, -1, 2, -2, 100, -100, i32::MIN);
+        let r = _mm512_cvtepi32_pd(a);
+        let e =
+            _mm512_setr_pd(0., 1., -1., 2., -2., 100., -100., -2147483648.);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cvtps_pd() {
+        let a = _mm256_setr_ps(0., 1., -1., 0.5, -2., 100., -100., 1e10);
+        let r = _mm512_cvtps_pd(a);
+        let e = _mm512_setr_pd(0., 1., -1., 0.5, -2., 100., -100., 1e10);
+        assert_eq_m512d(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cvtpd_ps() {
+        let a = _mm512_setr_pd(0., 1., -1., 0.5, -2., 100., -100., 1e300);
+        let r = _mm512_cvtpd_ps(a);
+        let e =
+            _mm256_setr_ps(0., 1., -1., 0.5, -2., 100., -100., f32::INFINITY);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cvtepi32_epi64() {
+        let a = _mm256_setr_epi32(0, 1, -1, 2, -2, 100, -100, i32::MIN);
+        let r = _mm512_cvtepi32_epi64(a);
+        let e = _mm512_setr_epi64(0, 1, -1, 2, -2, 100, -100, i32::MIN as i64);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cvtepu32_epi64() {
+        let a = _mm256_setr_epi32(0, 1, -1, 2, -2, 100, -100, i32::MIN);
+        let r = _mm512_cvtepu32_epi64(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi64(
+            0, 1, u32::MAX as i64, 2, u32::MAX as i64 - 1, 100,
+            -100i32 as u32 as i64, 1 << 31,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cvtps_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1.5, -1.5, 2.5, -2.5, 0.4, -0.6, 13.,
+            1e10, -1e10, f32::NAN, 100., 3.5, -3.5, 0.5, -0.5
+        );
+        let r = _mm512_cvtps_epi32(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            0, 2, -2, 2, -2, 0, -1, 13,
+            i32::MIN, i32::MIN, i32::MIN, 100, 4, -4, 0, 0
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cvttps_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1.5, -1.5, 2.5, -2.5, 0.4, -0.6, 13.,
+            1e10, -1e10, f32::NAN, 100., 3.5, -3.5, 0.5, -0.5
+        );
+        let r = _mm512_cvttps_epi32(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            0, 1, -1, 2, -2, 0, 0, 13,
+            i32::MIN, i32::MIN, i32::MIN, 100, 3, -3, 0, 0
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpeq_epi32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmpeq_epi32_mask(a, b);
+        assert_eq!(m, 0b10000010_00000001);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpneq_epi32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmpneq_epi32_mask(a, b);
+        assert_eq!(m, 0b01111101_11111110);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmplt_epi32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmplt_epi32_mask(a, b);
+        assert_eq!(m, 0b01010101_01010100);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmple_epi32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmple_epi32_mask(a, b);
+        assert_eq!(m, 0b11010111_01010101);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpgt_epi32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmpgt_epi32_mask(a, b);
+        assert_eq!(m, 0b00101000_10101010);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpge_epi32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmpge_epi32_mask(a, b);
+        assert_eq!(m, 0b10101010_10101011);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmp_epi32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmp_epi32_mask(a, b, _MM_CMPINT_EQ);
+        assert_eq!(m, _mm512_cmpeq_epi32_mask(a, b));
+        let m = _mm512_cmp_epi32_mask(a, b, _MM_CMPINT_NE);
+        assert_eq!(m, _mm512_cmpneq_epi32_mask(a, b));
+        let m = _mm512_cmp_epi32_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, _mm512_cmplt_epi32_mask(a, b));
+        let m = _mm512_cmp_epi32_mask(a, b, _MM_CMPINT_LE);
+        assert_eq!(m, _mm512_cmple_epi32_mask(a, b));
+        let m = _mm512_cmp_epi32_mask(a, b, _MM_CMPINT_NLE);
+        assert_eq!(m, _mm512_cmpgt_epi32_mask(a, b));
+        let m = _mm512_cmp_epi32_mask(a, b, _MM_CMPINT_NLT);
+        assert_eq!(m, _mm512_cmpge_epi32_mask(a, b));
+        assert_eq!(_mm512_cmp_epi32_mask(a, b, _MM_CMPINT_FALSE), 0);
+        assert_eq!(
+            _mm512_cmp_epi32_mask(a, b, _MM_CMPINT_TRUE),
+            0b11111111_11111111
+        );
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpeq_epu32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmpeq_epu32_mask(a, b);
+        assert_eq!(m, 0b10000010_00000001);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpneq_epu32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmpneq_epu32_mask(a, b);
+        assert_eq!(m, 0b01111101_11111110);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmplt_epu32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmplt_epu32_mask(a, b);
+        assert_eq!(m, 0b01110001_00000000);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmple_epu32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmple_epu32_mask(a, b);
+        assert_eq!(m, 0b11110011_00000001);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpgt_epu32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmpgt_epu32_mask(a, b);
+        assert_eq!(m, 0b00001100_11111110);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpge_epu32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmpge_epu32_mask(a, b);
+        assert_eq!(m, 0b10001110_11111111);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmp_epu32_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13,
+            0, 1, -1, i32::MAX, i32::MIN, 100, -100, 13
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_epi32(
+            0, 0, 0, 0, 0, 0, 0, 0,
+            1, 1, 1, 1, -1, -1, -1, 13
+        );
+        let m = _mm512_cmp_epu32_mask(a, b, _MM_CMPINT_EQ);
+        assert_eq!(m, _mm512_cmpeq_epu32_mask(a, b));
+        let m = _mm512_cmp_epu32_mask(a, b, _MM_CMPINT_NE);
+        assert_eq!(m, _mm512_cmpneq_epu32_mask(a, b));
+        let m = _mm512_cmp_epu32_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, _mm512_cmplt_epu32_mask(a, b));
+        let m = _mm512_cmp_epu32_mask(a, b, _MM_CMPINT_LE);
+        assert_eq!(m, _mm512_cmple_epu32_mask(a, b));
+        let m = _mm512_cmp_epu32_mask(a, b, _MM_CMPINT_NLE);
+        assert_eq!(m, _mm512_cmpgt_epu32_mask(a, b));
+        let m = _mm512_cmp_epu32_mask(a, b, _MM_CMPINT_NLT);
+        assert_eq!(m, _mm512_cmpge_epu32_mask(a, b));
+        assert_eq!(_mm512_cmp_epu32_mask(a, b, _MM_CMPINT_FALSE), 0);
+        assert_eq!(
+            _mm512_cmp_epu32_mask(a, b, _MM_CMPINT_TRUE),
+            0b11111111_11111111
+        );
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpeq_epi64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmpeq_epi64_mask(a, b);
+        assert_eq!(m, 0b10000001);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpneq_epi64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmpneq_epi64_mask(a, b);
+        assert_eq!(m, 0b01111110);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmplt_epi64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmplt_epi64_mask(a, b);
+        assert_eq!(m, 0b01010100);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmple_epi64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmple_epi64_mask(a, b);
+        assert_eq!(m, 0b11010101);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpgt_epi64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmpgt_epi64_mask(a, b);
+        assert_eq!(m, 0b00101010);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpge_epi64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmpge_epi64_mask(a, b);
+        assert_eq!(m, 0b10101011);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmp_epi64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmp_epi64_mask(a, b, _MM_CMPINT_EQ);
+        assert_eq!(m, _mm512_cmpeq_epi64_mask(a, b));
+        let m = _mm512_cmp_epi64_mask(a, b, _MM_CMPINT_NE);
+        assert_eq!(m, _mm512_cmpneq_epi64_mask(a, b));
+        let m = _mm512_cmp_epi64_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, _mm512_cmplt_epi64_mask(a, b));
+        let m = _mm512_cmp_epi64_mask(a, b, _MM_CMPINT_LE);
+        assert_eq!(m, _mm512_cmple_epi64_mask(a, b));
+        let m = _mm512_cmp_epi64_mask(a, b, _MM_CMPINT_NLE);
+        assert_eq!(m, _mm512_cmpgt_epi64_mask(a, b));
+        let m = _mm512_cmp_epi64_mask(a, b, _MM_CMPINT_NLT);
+        assert_eq!(m, _mm512_cmpge_epi64_mask(a, b));
+        assert_eq!(_mm512_cmp_epi64_mask(a, b, _MM_CMPINT_FALSE), 0);
+        assert_eq!(_mm512_cmp_epi64_mask(a, b, _MM_CMPINT_TRUE), 0b11111111);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpeq_epu64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmpeq_epu64_mask(a, b);
+        assert_eq!(m, 0b10000001);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpneq_epu64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmpneq_epu64_mask(a, b);
+        assert_eq!(m, 0b01111110);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmplt_epu64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmplt_epu64_mask(a, b);
+        assert_eq!(m, 0b00111000);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmple_epu64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmple_epu64_mask(a, b);
+        assert_eq!(m, 0b10111001);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpgt_epu64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmpgt_epu64_mask(a, b);
+        assert_eq!(m, 0b01000110);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpge_epu64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmpge_epu64_mask(a, b);
+        assert_eq!(m, 0b11000111);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmp_epu64_mask() {
+        let a = _mm512_setr_epi64(0, 1, -1, i64::MAX, i64::MIN, 100, -100, 13);
+        let b = _mm512_setr_epi64(0, 0, 0, -1, -1, -1, 1, 13);
+        let m = _mm512_cmp_epu64_mask(a, b, _MM_CMPINT_EQ);
+        assert_eq!(m, _mm512_cmpeq_epu64_mask(a, b));
+        let m = _mm512_cmp_epu64_mask(a, b, _MM_CMPINT_NE);
+        assert_eq!(m, _mm512_cmpneq_epu64_mask(a, b));
+        let m = _mm512_cmp_epu64_mask(a, b, _MM_CMPINT_LT);
+        assert_eq!(m, _mm512_cmplt_epu64_mask(a, b));
+        let m = _mm512_cmp_epu64_mask(a, b, _MM_CMPINT_LE);
+        assert_eq!(m, _mm512_cmple_epu64_mask(a, b));
+        let m = _mm512_cmp_epu64_mask(a, b, _MM_CMPINT_NLE);
+        assert_eq!(m, _mm512_cmpgt_epu64_mask(a, b));
+        let m = _mm512_cmp_epu64_mask(a, b, _MM_CMPINT_NLT);
+        assert_eq!(m, _mm512_cmpge_epu64_mask(a, b));
+        assert_eq!(_mm512_cmp_epu64_mask(a, b, _MM_CMPINT_FALSE), 0);
+        assert_eq!(_mm512_cmp_epu64_mask(a, b, _MM_CMPINT_TRUE), 0b11111111);
+    }
+
+    #[simd_test = "avx512f,avx512vl"]
+    unsafe fn test_mm256_cmp_epi32_mask() {
+        let a = _mm256_setr_epi32(0, 1, -1, 3, 4, 5, 6, 7);
+        let b = _mm256_set1_epi32(1);
+        assert_eq!(_mm256_cmp_epi32_mask(a, b, _MM_CMPINT_LT), 0b0000_0101);
+        assert_eq!(_mm256_cmp_epu32_mask(a, b, _MM_CMPINT_LT), 0b0000_0001);
+        assert_eq!(_mm256_cmp_epi32_mask(a, b, _MM_CMPINT_EQ), 0b0000_0010);
+    }
+
+    #[simd_test = "avx512f,avx512vl"]
+    unsafe fn test_mm256_cmp_epi64_mask() {
+        let a = _mm256_setr_epi64x(0, 1, -1, 3);
+        let b = _mm256_set1_epi64x(1);
+        assert_eq!(_mm256_cmp_epi64_mask(a, b, _MM_CMPINT_LT), 0b0101);
+        assert_eq!(_mm256_cmp_epu64_mask(a, b, _MM_CMPINT_LT), 0b0001);
+        assert_eq!(_mm256_cmp_epi64_mask(a, b, _MM_CMPINT_NLE), 0b1000);
+    }
+
+    #[simd_test = "avx512f,avx512vl"]
+    unsafe fn test_mm_cmp_epi32_mask() {
+        let a = _mm_setr_epi32(0, 1, -1, 3);
+        let b = _mm_set1_epi32(1);
+        assert_eq!(_mm_cmp_epi32_mask(a, b, _MM_CMPINT_LT), 0b0101);
+        assert_eq!(_mm_cmp_epu32_mask(a, b, _MM_CMPINT_LT), 0b0001);
+        assert_eq!(_mm_cmp_epi32_mask(a, b, _MM_CMPINT_TRUE), 0b1111);
+    }
+
+    #[simd_test = "avx512f,avx512vl"]
+    unsafe fn test_mm_cmp_epi64_mask() {
+        let a = _mm_setr_epi64x(-1, 1);
+        let b = _mm_set1_epi64x(1);
+        assert_eq!(_mm_cmp_epi64_mask(a, b, _MM_CMPINT_LT), 0b01);
+        assert_eq!(_mm_cmp_epu64_mask(a, b, _MM_CMPINT_LT), 0b00);
+        assert_eq!(_mm_cmp_epi64_mask(a, b, _MM_CMPINT_TRUE), 0b11);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmp_ps_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., -1., 2., f32::NAN, 0., f32::NAN, -0.,
+            1., 2., 3., 4., 5., 6., 7., 8.
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_ps(
+            0., 2., -2., 2., 0., f32::NAN, f32::NAN, 0.,
+            8., 7., 6., 5., 4., 3., 2., 1.
+        );
+        let m = _mm512_cmp_ps_mask(a, b, _CMP_EQ_OQ);
+        assert_eq!(m, _mm512_cmpeq_ps_mask(a, b));
+        let m = _mm512_cmp_ps_mask(a, b, _CMP_NEQ_UQ);
+        assert_eq!(m, _mm512_cmpneq_ps_mask(a, b));
+        let m = _mm512_cmp_ps_mask(a, b, _CMP_LT_OS);
+        assert_eq!(m, _mm512_cmplt_ps_mask(a, b));
+        let m = _mm512_cmp_ps_mask(a, b, _CMP_LE_OS);
+        assert_eq!(m, _mm512_cmple_ps_mask(a, b));
+        let m = _mm512_cmp_ps_mask(a, b, _CMP_NLT_US);
+        assert_eq!(m, _mm512_cmpnlt_ps_mask(a, b));
+        let m = _mm512_cmp_ps_mask(a, b, _CMP_NLE_US);
+        assert_eq!(m, _mm512_cmpnle_ps_mask(a, b));
+        let m = _mm512_cmp_ps_mask(a, b, _CMP_ORD_Q);
+        assert_eq!(m, _mm512_cmpord_ps_mask(a, b));
+        let m = _mm512_cmp_ps_mask(a, b, _CMP_UNORD_Q);
+        assert_eq!(m, _mm512_cmpunord_ps_mask(a, b));
+        let m = _mm512_cmp_ps_mask(a, b, _CMP_GT_OQ);
+        assert_eq!(m, 0b11110000_00000100);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpeq_ps_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., -1., 2., f32::NAN, 0., f32::NAN, -0.,
+            1., 2., 3., 4., 5., 6., 7., 8.
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_ps(
+            0., 2., -2., 2., 0., f32::NAN, f32::NAN, 0.,
+            8., 7., 6., 5., 4., 3., 2., 1.
+        );
+        let m = _mm512_cmpeq_ps_mask(a, b);
+        assert_eq!(m, 0b00000000_10001001);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpneq_ps_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., -1., 2., f32::NAN, 0., f32::NAN, -0.,
+            1., 2., 3., 4., 5., 6., 7., 8.
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_ps(
+            0., 2., -2., 2., 0., f32::NAN, f32::NAN, 0.,
+            8., 7., 6., 5., 4., 3., 2., 1.
+        );
+        let m = _mm512_cmpneq_ps_mask(a, b);
+        assert_eq!(m, 0b11111111_01110110);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmplt_ps_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., -1., 2., f32::NAN, 0., f32::NAN, -0.,
+            1., 2., 3., 4., 5., 6., 7., 8.
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_ps(
+            0., 2., -2., 2., 0., f32::NAN, f32::NAN, 0.,
+            8., 7., 6., 5., 4., 3., 2., 1.
+        );
+        let m = _mm512_cmplt_ps_mask(a, b);
+        assert_eq!(m, 0b00001111_00000010);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmple_ps_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., -1., 2., f32::NAN, 0., f32::NAN, -0.,
+            1., 2., 3., 4., 5., 6., 7., 8.
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_ps(
+            0., 2., -2., 2., 0., f32::NAN, f32::NAN, 0.,
+            8., 7., 6., 5., 4., 3., 2., 1.
+        );
+        let m = _mm512_cmple_ps_mask(a, b);
+        assert_eq!(m, 0b00001111_10001011);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpnlt_ps_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., -1., 2., f32::NAN, 0., f32::NAN, -0.,
+            1., 2., 3., 4., 5., 6., 7., 8.
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_ps(
+            0., 2., -2., 2., 0., f32::NAN, f32::NAN, 0.,
+            8., 7., 6., 5., 4., 3., 2., 1.
+        );
+        let m = _mm512_cmpnlt_ps_mask(a, b);
+        assert_eq!(m, 0b11110000_11111101);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpnle_ps_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., -1., 2., f32::NAN, 0., f32::NAN, -0.,
+            1., 2., 3., 4., 5., 6., 7., 8.
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_ps(
+            0., 2., -2., 2., 0., f32::NAN, f32::NAN, 0.,
+            8., 7., 6., 5., 4., 3., 2., 1.
+        );
+        let m = _mm512_cmpnle_ps_mask(a, b);
+        assert_eq!(m, 0b11110000_01110100);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpord_ps_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., -1., 2., f32::NAN, 0., f32::NAN, -0.,
+            1., 2., 3., 4., 5., 6., 7., 8.
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_ps(
+            0., 2., -2., 2., 0., f32::NAN, f32::NAN, 0.,
+            8., 7., 6., 5., 4., 3., 2., 1.
+        );
+        let m = _mm512_cmpord_ps_mask(a, b);
+        assert_eq!(m, 0b11111111_10001111);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpunord_ps_mask() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., -1., 2., f32::NAN, 0., f32::NAN, -0.,
+            1., 2., 3., 4., 5., 6., 7., 8.
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm512_setr_ps(
+            0., 2., -2., 2., 0., f32::NAN, f32::NAN, 0.,
+            8., 7., 6., 5., 4., 3., 2., 1.
+        );
+        let m = _mm512_cmpunord_ps_mask(a, b);
+        assert_eq!(m, 0b00000000_01110000);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmp_pd_mask() {
+        let a = _mm512_setr_pd(0., 1., -1., 2., f64::NAN, 0., f64::NAN, -0.);
+        let b = _mm512_setr_pd(0., 2., -2., 2., 0., f64::NAN, f64::NAN, 0.);
+        let m = _mm512_cmp_pd_mask(a, b, _CMP_EQ_OQ);
+        assert_eq!(m, _mm512_cmpeq_pd_mask(a, b));
+        let m = _mm512_cmp_pd_mask(a, b, _CMP_NEQ_UQ);
+        assert_eq!(m, _mm512_cmpneq_pd_mask(a, b));
+        let m = _mm512_cmp_pd_mask(a, b, _CMP_LT_OS);
+        assert_eq!(m, _mm512_cmplt_pd_mask(a, b));
+        let m = _mm512_cmp_pd_mask(a, b, _CMP_LE_OS);
+        assert_eq!(m, _mm512_cmple_pd_mask(a, b));
+        let m = _mm512_cmp_pd_mask(a, b, _CMP_NLT_US);
+        assert_eq!(m, _mm512_cmpnlt_pd_mask(a, b));
+        let m = _mm512_cmp_pd_mask(a, b, _CMP_NLE_US);
+        assert_eq!(m, _mm512_cmpnle_pd_mask(a, b));
+        let m = _mm512_cmp_pd_mask(a, b, _CMP_ORD_Q);
+        assert_eq!(m, _mm512_cmpord_pd_mask(a, b));
+        let m = _mm512_cmp_pd_mask(a, b, _CMP_UNORD_Q);
+        assert_eq!(m, _mm512_cmpunord_pd_mask(a, b));
+        let m = _mm512_cmp_pd_mask(a, b, _CMP_GT_OQ);
+        assert_eq!(m, 0b00000100);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpeq_pd_mask() {
+        let a = _mm512_setr_pd(0., 1., -1., 2., f64::NAN, 0., f64::NAN, -0.);
+        let b = _mm512_setr_pd(0., 2., -2., 2., 0., f64::NAN, f64::NAN, 0.);
+        let m = _mm512_cmpeq_pd_mask(a, b);
+        assert_eq!(m, 0b10001001);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpneq_pd_mask() {
+        let a = _mm512_setr_pd(0., 1., -1., 2., f64::NAN, 0., f64::NAN, -0.);
+        let b = _mm512_setr_pd(0., 2., -2., 2., 0., f64::NAN, f64::NAN, 0.);
+        let m = _mm512_cmpneq_pd_mask(a, b);
+        assert_eq!(m, 0b01110110);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmplt_pd_mask() {
+        let a = _mm512_setr_pd(0., 1., -1., 2., f64::NAN, 0., f64::NAN, -0.);
+        let b = _mm512_setr_pd(0., 2., -2., 2., 0., f64::NAN, f64::NAN, 0.);
+        let m = _mm512_cmplt_pd_mask(a, b);
+        assert_eq!(m, 0b00000010);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmple_pd_mask() {
+        let a = _mm512_setr_pd(0., 1., -1., 2., f64::NAN, 0., f64::NAN, -0.);
+        let b = _mm512_setr_pd(0., 2., -2., 2., 0., f64::NAN, f64::NAN, 0.);
+        let m = _mm512_cmple_pd_mask(a, b);
+        assert_eq!(m, 0b10001011);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpnlt_pd_mask() {
+        let a = _mm512_setr_pd(0., 1., -1., 2., f64::NAN, 0., f64::NAN, -0.);
+        let b = _mm512_setr_pd(0., 2., -2., 2., 0., f64::NAN, f64::NAN, 0.);
+        let m = _mm512_cmpnlt_pd_mask(a, b);
+        assert_eq!(m, 0b11111101);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpnle_pd_mask() {
+        let a = _mm512_setr_pd(0., 1., -1., 2., f64::NAN, 0., f64::NAN, -0.);
+        let b = _mm512_setr_pd(0., 2., -2., 2., 0., f64::NAN, f64::NAN, 0.);
+        let m = _mm512_cmpnle_pd_mask(a, b);
+        assert_eq!(m, 0b01110100);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpord_pd_mask() {
+        let a = _mm512_setr_pd(0., 1., -1., 2., f64::NAN, 0., f64::NAN, -0.);
+        let b = _mm512_setr_pd(0., 2., -2., 2., 0., f64::NAN, f64::NAN, 0.);
+        let m = _mm512_cmpord_pd_mask(a, b);
+        assert_eq!(m, 0b10001111);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_cmpunord_pd_mask() {
+        let a = _mm512_setr_pd(0., 1., -1., 2., f64::NAN, 0., f64::NAN, -0.);
+        let b = _mm512_setr_pd(0., 2., -2., 2., 0., f64::NAN, f64::NAN, 0.);
+        let m = _mm512_cmpunord_pd_mask(a, b);
+        assert_eq!(m, 0b01110000);
+    }
+
+    const MASKS16: [__mmask16; 5] =
+        [0, 0xffff, 0b01010101_01010101, 0b11000011_11110000, 0x8001];
+    const MASKS8: [__mmask8; 5] = [0, 0xff, 0b0101_0101, 0b1111_0000, 0x81];
+
+    /// Reference writemask: lane `i` is taken from `a` if bit `i` of `k` is
+    /// set and from `src` otherwise.
+    #[target_feature(enable = "avx512f")]
+    unsafe fn blend_ps(src: __m512, k: __mmask16, a: __m512) -> __m512 {
+        let src: [f32; 16] = mem::transmute(src);
+        let a: [f32; 16] = mem::transmute(a);
+        let mut e = src;
+        for i in 0..16 {
+            if k & (1 << i) != 0 {
+                e[i] = a[i];
+            }
+        }
+        mem::transmute(e)
+    }
+
+    /// Reference writemask: lane `i` is taken from `a` if bit `i` of `k` is
+    /// set and from `src` otherwise.
+    #[target_feature(enable = "avx512f")]
+    unsafe fn blend_pd(src: __m512d, k: __mmask8, a: __m512d) -> __m512d {
+        let src: [f64; 8] = mem::transmute(src);
+        let a: [f64; 8] = mem::transmute(a);
+        let mut e = src;
+        for i in 0..8 {
+            if k & (1 << i) != 0 {
+                e[i] = a[i];
+            }
+        }
+        mem::transmute(e)
+    }
+
+    /// Reference writemask: lane `i` is taken from `a` if bit `i` of `k` is
+    /// set and from `src` otherwise.
+    #[target_feature(enable = "avx512f")]
+    unsafe fn blend_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i {
+        let src: [i32; 16] = mem::transmute(src);
+        let a: [i32; 16] = mem::transmute(a);
+        let mut e = src;
+        for i in 0..16 {
+            if k & (1 << i) != 0 {
+                e[i] = a[i];
+            }
+        }
+        mem::transmute(e)
+    }
+
+    /// Reference writemask: lane `i` is taken from `a` if bit `i` of `k` is
+    /// set and from `src` otherwise.
+    #[target_feature(enable = "avx512f")]
+    unsafe fn blend_epi64(src: __m512i, k: __mmask8, a: __m512i) -> __m512i {
+        let src: [i64; 8] = mem::transmute(src);
+        let a: [i64; 8] = mem::transmute(a);
+        let mut e = src;
+        for i in 0..8 {
+            if k & (1 << i) != 0 {
+                e[i] = a[i];
+            }
+        }
+        mem::transmute(e)
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_mov_ps() {
+        let src = _mm512_set1_ps(-1.);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_mov_ps(src, k, a);
+            assert_eq_m512(r, blend_ps(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_mov_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_mov_ps(k, a);
+            assert_eq_m512(r, blend_ps(_mm512_setzero_ps(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_mov_pd() {
+        let src = _mm512_set1_pd(-1.);
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_mov_pd(src, k, a);
+            assert_eq_m512d(r, blend_pd(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_mov_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_mov_pd(k, a);
+            assert_eq_m512d(r, blend_pd(_mm512_setzero_pd(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_mov_epi32() {
+        let src = _mm512_set1_epi32(-1);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_mov_epi32(src, k, a);
+            assert_eq_m512i(r, blend_epi32(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_mov_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_mov_epi32(k, a);
+            assert_eq_m512i(r, blend_epi32(_mm512_setzero_si512(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_mov_epi64() {
+        let src = _mm512_set1_epi64(-1);
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_mov_epi64(src, k, a);
+            assert_eq_m512i(r, blend_epi64(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_mov_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_mov_epi64(k, a);
+            assert_eq_m512i(r, blend_epi64(_mm512_setzero_si512(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_add_ps() {
+        let src = _mm512_set1_ps(-1.);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let b = _mm512_set1_ps(4.);
+        let e = _mm512_add_ps(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_add_ps(src, k, a, b);
+            assert_eq_m512(r, blend_ps(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_add_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let b = _mm512_set1_ps(4.);
+        let e = _mm512_add_ps(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_add_ps(k, a, b);
+            assert_eq_m512(r, blend_ps(_mm512_setzero_ps(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_add_pd() {
+        let src = _mm512_set1_pd(-1.);
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm512_set1_pd(4.);
+        let e = _mm512_add_pd(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_add_pd(src, k, a, b);
+            assert_eq_m512d(r, blend_pd(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_add_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm512_set1_pd(4.);
+        let e = _mm512_add_pd(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_add_pd(k, a, b);
+            assert_eq_m512d(r, blend_pd(_mm512_setzero_pd(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_sub_ps() {
+        let src = _mm512_set1_ps(-1.);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let b = _mm512_set1_ps(4.);
+        let e = _mm512_sub_ps(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_sub_ps(src, k, a, b);
+            assert_eq_m512(r, blend_ps(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_sub_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let b = _mm512_set1_ps(4.);
+        let e = _mm512_sub_ps(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_sub_ps(k, a, b);
+            assert_eq_m512(r, blend_ps(_mm512_setzero_ps(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_sub_pd() {
+        let src = _mm512_set1_pd(-1.);
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm512_set1_pd(4.);
+        let e = _mm512_sub_pd(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_sub_pd(src, k, a, b);
+            assert_eq_m512d(r, blend_pd(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_sub_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm512_set1_pd(4.);
+        let e = _mm512_sub_pd(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_sub_pd(k, a, b);
+            assert_eq_m512d(r, blend_pd(_mm512_setzero_pd(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_mul_ps() {
+        let src = _mm512_set1_ps(-1.);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let b = _mm512_set1_ps(4.);
+        let e = _mm512_mul_ps(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_mul_ps(src, k, a, b);
+            assert_eq_m512(r, blend_ps(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_mul_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let b = _mm512_set1_ps(4.);
+        let e = _mm512_mul_ps(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_mul_ps(k, a, b);
+            assert_eq_m512(r, blend_ps(_mm512_setzero_ps(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_mul_pd() {
+        let src = _mm512_set1_pd(-1.);
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm512_set1_pd(4.);
+        let e = _mm512_mul_pd(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_mul_pd(src, k, a, b);
+            assert_eq_m512d(r, blend_pd(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_mul_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm512_set1_pd(4.);
+        let e = _mm512_mul_pd(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_mul_pd(k, a, b);
+            assert_eq_m512d(r, blend_pd(_mm512_setzero_pd(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_div_ps() {
+        let src = _mm512_set1_ps(-1.);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let b = _mm512_set1_ps(4.);
+        let e = _mm512_div_ps(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_div_ps(src, k, a, b);
+            assert_eq_m512(r, blend_ps(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_div_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let b = _mm512_set1_ps(4.);
+        let e = _mm512_div_ps(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_div_ps(k, a, b);
+            assert_eq_m512(r, blend_ps(_mm512_setzero_ps(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_div_pd() {
+        let src = _mm512_set1_pd(-1.);
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm512_set1_pd(4.);
+        let e = _mm512_div_pd(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_div_pd(src, k, a, b);
+            assert_eq_m512d(r, blend_pd(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_div_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let b = _mm512_set1_pd(4.);
+        let e = _mm512_div_pd(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_div_pd(k, a, b);
+            assert_eq_m512d(r, blend_pd(_mm512_setzero_pd(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_add_epi32() {
+        let src = _mm512_set1_epi32(-1);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        let b = _mm512_set1_epi32(3);
+        let e = _mm512_add_epi32(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_add_epi32(src, k, a, b);
+            assert_eq_m512i(r, blend_epi32(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_add_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        let b = _mm512_set1_epi32(3);
+        let e = _mm512_add_epi32(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_add_epi32(k, a, b);
+            assert_eq_m512i(r, blend_epi32(_mm512_setzero_si512(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_add_epi64() {
+        let src = _mm512_set1_epi64(-1);
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm512_set1_epi64(3);
+        let e = _mm512_add_epi64(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_add_epi64(src, k, a, b);
+            assert_eq_m512i(r, blend_epi64(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_add_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm512_set1_epi64(3);
+        let e = _mm512_add_epi64(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_add_epi64(k, a, b);
+            assert_eq_m512i(r, blend_epi64(_mm512_setzero_si512(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_sub_epi32() {
+        let src = _mm512_set1_epi32(-1);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        let b = _mm512_set1_epi32(3);
+        let e = _mm512_sub_epi32(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_sub_epi32(src, k, a, b);
+            assert_eq_m512i(r, blend_epi32(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_sub_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        let b = _mm512_set1_epi32(3);
+        let e = _mm512_sub_epi32(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_sub_epi32(k, a, b);
+            assert_eq_m512i(r, blend_epi32(_mm512_setzero_si512(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_sub_epi64() {
+        let src = _mm512_set1_epi64(-1);
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm512_set1_epi64(3);
+        let e = _mm512_sub_epi64(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_sub_epi64(src, k, a, b);
+            assert_eq_m512i(r, blend_epi64(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_sub_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let b = _mm512_set1_epi64(3);
+        let e = _mm512_sub_epi64(a, b);
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_sub_epi64(k, a, b);
+            assert_eq_m512i(r, blend_epi64(_mm512_setzero_si512(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_mullo_epi32() {
+        let src = _mm512_set1_epi32(-1);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        let b = _mm512_set1_epi32(3);
+        let e = _mm512_mullo_epi32(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_mullo_epi32(src, k, a, b);
+            assert_eq_m512i(r, blend_epi32(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_mullo_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        let b = _mm512_set1_epi32(3);
+        let e = _mm512_mullo_epi32(a, b);
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_mullo_epi32(k, a, b);
+            assert_eq_m512i(r, blend_epi32(_mm512_setzero_si512(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_sqrt_ps() {
+        let src = _mm512_set1_ps(-1.);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let e = _mm512_sqrt_ps(a);
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_sqrt_ps(src, k, a);
+            assert_eq_m512(r, blend_ps(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_sqrt_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let e = _mm512_sqrt_ps(a);
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_sqrt_ps(k, a);
+            assert_eq_m512(r, blend_ps(_mm512_setzero_ps(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_sqrt_pd() {
+        let src = _mm512_set1_pd(-1.);
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let e = _mm512_sqrt_pd(a);
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_sqrt_pd(src, k, a);
+            assert_eq_m512d(r, blend_pd(src, k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_sqrt_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let e = _mm512_sqrt_pd(a);
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_sqrt_pd(k, a);
+            assert_eq_m512d(r, blend_pd(_mm512_setzero_pd(), k, e));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_loadu_ps() {
+        let src = _mm512_set1_ps(-1.);
+        let mut mem = [0 as f32; 17];
+        for i in 0..17 {
+            mem[i] = i as f32;
+        }
+        let p = mem[1..].as_ptr();
+        let a: __m512 = mem::transmute(*(p as *const [f32; 16]));
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_loadu_ps(src, k, p);
+            assert_eq_m512(r, blend_ps(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_loadu_ps() {
+        let mut mem = [0 as f32; 17];
+        for i in 0..17 {
+            mem[i] = i as f32;
+        }
+        let p = mem[1..].as_ptr();
+        let a: __m512 = mem::transmute(*(p as *const [f32; 16]));
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_loadu_ps(k, p);
+            assert_eq_m512(r, blend_ps(_mm512_setzero_ps(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_load_ps() {
+        let src = _mm512_set1_ps(-1.);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let p = &a as *const _ as *const f32;
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_load_ps(src, k, p);
+            assert_eq_m512(r, blend_ps(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_load_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        let p = &a as *const _ as *const f32;
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_load_ps(k, p);
+            assert_eq_m512(r, blend_ps(_mm512_setzero_ps(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_storeu_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        for &k in MASKS16.iter() {
+            let mut mem = [-1. as f32; 17];
+            _mm512_mask_storeu_ps(mem[1..].as_mut_ptr(), k, a);
+            assert_eq!(mem[0], -1.);
+            let r: __m512 =
+                mem::transmute(*(mem[1..].as_ptr() as *const [f32; 16]));
+            assert_eq_m512(r, blend_ps(_mm512_set1_ps(-1.), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_store_ps() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_ps(
+            0., 1., 2., 3., 4., 5., 6., 7.,
+            8., 9., 10., 11., 12., 13., 14., 15.,
+        );
+        for &k in MASKS16.iter() {
+            let mut r = _mm512_set1_ps(-1.);
+            _mm512_mask_store_ps(&mut r as *mut _ as *mut f32, k, a);
+            assert_eq_m512(r, blend_ps(_mm512_set1_ps(-1.), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_loadu_pd() {
+        let src = _mm512_set1_pd(-1.);
+        let mut mem = [0 as f64; 9];
+        for i in 0..9 {
+            mem[i] = i as f64;
+        }
+        let p = mem[1..].as_ptr();
+        let a: __m512d = mem::transmute(*(p as *const [f64; 8]));
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_loadu_pd(src, k, p);
+            assert_eq_m512d(r, blend_pd(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_loadu_pd() {
+        let mut mem = [0 as f64; 9];
+        for i in 0..9 {
+            mem[i] = i as f64;
+        }
+        let p = mem[1..].as_ptr();
+        let a: __m512d = mem::transmute(*(p as *const [f64; 8]));
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_loadu_pd(k, p);
+            assert_eq_m512d(r, blend_pd(_mm512_setzero_pd(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_load_pd() {
+        let src = _mm512_set1_pd(-1.);
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let p = &a as *const _ as *const f64;
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_load_pd(src, k, p);
+            assert_eq_m512d(r, blend_pd(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_load_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        let p = &a as *const _ as *const f64;
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_load_pd(k, p);
+            assert_eq_m512d(r, blend_pd(_mm512_setzero_pd(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_storeu_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        for &k in MASKS8.iter() {
+            let mut mem = [-1. as f64; 9];
+            _mm512_mask_storeu_pd(mem[1..].as_mut_ptr(), k, a);
+            assert_eq!(mem[0], -1.);
+            let r: __m512d =
+                mem::transmute(*(mem[1..].as_ptr() as *const [f64; 8]));
+            assert_eq_m512d(r, blend_pd(_mm512_set1_pd(-1.), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_store_pd() {
+        let a = _mm512_setr_pd(0., 1., 2., 3., 4., 5., 6., 7.);
+        for &k in MASKS8.iter() {
+            let mut r = _mm512_set1_pd(-1.);
+            _mm512_mask_store_pd(&mut r as *mut _ as *mut f64, k, a);
+            assert_eq_m512d(r, blend_pd(_mm512_set1_pd(-1.), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_loadu_epi32() {
+        let src = _mm512_set1_epi32(-1);
+        let mut mem = [0 as i32; 17];
+        for i in 0..17 {
+            mem[i] = i as i32;
+        }
+        let p = mem[1..].as_ptr();
+        let a: __m512i = mem::transmute(*(p as *const [i32; 16]));
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_loadu_epi32(src, k, p);
+            assert_eq_m512i(r, blend_epi32(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_loadu_epi32() {
+        let mut mem = [0 as i32; 17];
+        for i in 0..17 {
+            mem[i] = i as i32;
+        }
+        let p = mem[1..].as_ptr();
+        let a: __m512i = mem::transmute(*(p as *const [i32; 16]));
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_loadu_epi32(k, p);
+            assert_eq_m512i(r, blend_epi32(_mm512_setzero_si512(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_load_epi32() {
+        let src = _mm512_set1_epi32(-1);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        let p = &a as *const _ as *const i32;
+        for &k in MASKS16.iter() {
+            let r = _mm512_mask_load_epi32(src, k, p);
+            assert_eq_m512i(r, blend_epi32(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_load_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        let p = &a as *const _ as *const i32;
+        for &k in MASKS16.iter() {
+            let r = _mm512_maskz_load_epi32(k, p);
+            assert_eq_m512i(r, blend_epi32(_mm512_setzero_si512(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_storeu_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        for &k in MASKS16.iter() {
+            let mut mem = [-1 as i32; 17];
+            _mm512_mask_storeu_epi32(mem[1..].as_mut_ptr(), k, a);
+            assert_eq!(mem[0], -1);
+            let r: __m512i =
+                mem::transmute(*(mem[1..].as_ptr() as *const [i32; 16]));
+            assert_eq_m512i(r, blend_epi32(_mm512_set1_epi32(-1), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_store_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        for &k in MASKS16.iter() {
+            let mut r = _mm512_set1_epi32(-1);
+            _mm512_mask_store_epi32(&mut r as *mut _ as *mut i32, k, a);
+            assert_eq_m512i(r, blend_epi32(_mm512_set1_epi32(-1), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_loadu_epi64() {
+        let src = _mm512_set1_epi64(-1);
+        let mut mem = [0 as i64; 9];
+        for i in 0..9 {
+            mem[i] = i as i64;
+        }
+        let p = mem[1..].as_ptr();
+        let a: __m512i = mem::transmute(*(p as *const [i64; 8]));
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_loadu_epi64(src, k, p);
+            assert_eq_m512i(r, blend_epi64(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_loadu_epi64() {
+        let mut mem = [0 as i64; 9];
+        for i in 0..9 {
+            mem[i] = i as i64;
+        }
+        let p = mem[1..].as_ptr();
+        let a: __m512i = mem::transmute(*(p as *const [i64; 8]));
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_loadu_epi64(k, p);
+            assert_eq_m512i(r, blend_epi64(_mm512_setzero_si512(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_load_epi64() {
+        let src = _mm512_set1_epi64(-1);
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let p = &a as *const _ as *const i64;
+        for &k in MASKS8.iter() {
+            let r = _mm512_mask_load_epi64(src, k, p);
+            assert_eq_m512i(r, blend_epi64(src, k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_maskz_load_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        let p = &a as *const _ as *const i64;
+        for &k in MASKS8.iter() {
+            let r = _mm512_maskz_load_epi64(k, p);
+            assert_eq_m512i(r, blend_epi64(_mm512_setzero_si512(), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_storeu_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        for &k in MASKS8.iter() {
+            let mut mem = [-1 as i64; 9];
+            _mm512_mask_storeu_epi64(mem[1..].as_mut_ptr(), k, a);
+            assert_eq!(mem[0], -1);
+            let r: __m512i =
+                mem::transmute(*(mem[1..].as_ptr() as *const [i64; 8]));
+            assert_eq_m512i(r, blend_epi64(_mm512_set1_epi64(-1), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_mask_store_epi64() {
+        let a = _mm512_setr_epi64(0, 1, 2, 3, 4, 5, 6, 7);
+        for &k in MASKS8.iter() {
+            let mut r = _mm512_set1_epi64(-1);
+            _mm512_mask_store_epi64(&mut r as *mut _ as *mut i64, k, a);
+            assert_eq_m512i(r, blend_epi64(_mm512_set1_epi64(-1), k, a));
+        }
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_kand() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _mm512_kand(a, b);
+        assert_eq!(r, 0b11000000_00000011);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_kand_mask16() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _kand_mask16(a, b);
+        assert_eq!(r, _mm512_kand(a, b));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_kandn() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _mm512_kandn(a, b);
+        assert_eq!(r, 0b00001100_00110000);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_kandn_mask16() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _kandn_mask16(a, b);
+        assert_eq!(r, _mm512_kandn(a, b));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_kor() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _mm512_kor(a, b);
+        assert_eq!(r, 0b11111100_00111111);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_kor_mask16() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _kor_mask16(a, b);
+        assert_eq!(r, _mm512_kor(a, b));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_kxor() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _mm512_kxor(a, b);
+        assert_eq!(r, 0b00111100_00111100);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_kxor_mask16() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _kxor_mask16(a, b);
+        assert_eq!(r, _mm512_kxor(a, b));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_kxnor() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _mm512_kxnor(a, b);
+        assert_eq!(r, 0b11000011_11000011);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_kxnor_mask16() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let b: __mmask16 = 0b11001100_00110011;
+        let r = _kxnor_mask16(a, b);
+        assert_eq!(r, _mm512_kxnor(a, b));
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_knot() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let r = _mm512_knot(a);
+        assert_eq!(r, 0b00001111_11110000);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_knot_mask16() {
+        let a: __mmask16 = 0b11110000_00001111;
+        let r = _knot_mask16(a);
+        assert_eq!(r, 0b00001111_11110000);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_kortestz() {
+        assert_eq!(_mm512_kortestz(0, 0), 1);
+        assert_eq!(_mm512_kortestz(0, 0x8000), 0);
+        assert_eq!(_mm512_kortestz(0x00ff, 0xff00), 0);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_mm512_kortestc() {
+        assert_eq!(_mm512_kortestc(0, 0), 0);
+        assert_eq!(_mm512_kortestc(0x7fff, 0), 0);
+        assert_eq!(_mm512_kortestc(0x00ff, 0xff00), 1);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_kortestz_mask16_u8() {
+        assert_eq!(_kortestz_mask16_u8(0, 0), 1);
+        assert_eq!(_kortestz_mask16_u8(1, 0), 0);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_kortestc_mask16_u8() {
+        assert_eq!(_kortestc_mask16_u8(0xf0f0, 0x0f0f), 1);
+        assert_eq!(_kortestc_mask16_u8(0xf0f0, 0x0f0e), 0);
+    }
+
+    #[simd_test = "avx512f"]
+    unsafe fn test_kortest_mask16_u8() {
+        let mut all_ones = 2;
+        let r = _kortest_mask16_u8(0, 0, &mut all_ones);
+        assert_eq!((r, all_ones), (1, 0));
+        let r = _kortest_mask16_u8(0xff00, 0x00ff, &mut all_ones);
+        assert_eq!((r, all_ones), (0, 1));
+        let r = _kortest_mask16_u8(0xff00, 0x00f0, &mut all_ones);
+        assert_eq!((r, all_ones), (0, 0));
+    }
+}
@@ -0,0 +1,248 @@
+//! AVX-512 Integer Fused Multiply-Add (AVX512_IFMA52) intrinsics.
+//!
+//! These multiply 52-bit unsigned integers and accumulate either the low or
+//! the high half of the 104-bit product, which is the building block of
+//! vectorized big-integer arithmetic with 52-bit limbs.
+//!
+//! The 128-bit and 256-bit forms additionally require AVX-512VL.
+//!
+//! [Intel Intrinsics Guide][intel_guide].
+//!
+//! [intel_guide]: https://software.intel.com/sites/landingpage/IntrinsicsGuide/#techs=AVX_512&avx512techs=AVX512IFMA52
+
+use core::mem;
+
+use v128::*;
+use v256::*;
+use v512::*;
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Multiply packed unsigned 52-bit integers in each 64-bit element of `b`
+/// and `c` to form a 104-bit intermediate result. Add the high 52-bit
+/// unsigned integer from the intermediate result to the corresponding
+/// unsigned 64-bit integer in `a`, and return the results.
+#[inline]
+#[target_feature(enable = "avx512ifma")]
+#[cfg_attr(test, assert_instr(vpmadd52huq))]
+pub unsafe fn _mm512_madd52hi_epu64(
+    a: __m512i, b: __m512i, c: __m512i
+) -> __m512i {
+    let (a, b, c) = (a.as_i64x8(), b.as_i64x8(), c.as_i64x8());
+    mem::transmute(vpmadd52huq512(a, b, c, -1))
+}
+
+/// Multiply packed unsigned 52-bit integers in each 64-bit element of `b`
+/// and `c` to form a 104-bit intermediate result. Add the low 52-bit
+/// unsigned integer from the intermediate result to the corresponding
+/// unsigned 64-bit integer in `a`, and return the results.
+#[inline]
+#[target_feature(enable = "avx512ifma")]
+#[cfg_attr(test, assert_instr(vpmadd52luq))]
+pub unsafe fn _mm512_madd52lo_epu64(
+    a: __m512i, b: __m512i, c: __m512i
+) -> __m512i {
+    let (a, b, c) = (a.as_i64x8(), b.as_i64x8(), c.as_i64x8());
+    mem::transmute(vpmadd52luq512(a, b, c, -1))
+}
+
+/// Multiply packed unsigned 52-bit integers in each 64-bit element of `b`
+/// and `c` to form a 104-bit intermediate result. Add the high 52-bit
+/// unsigned integer from the intermediate result to the corresponding
+/// unsigned 64-bit integer in `a`, and return the results.
+#[inline]
+#[target_feature(enable = "avx512ifma,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmadd52huq))]
+pub unsafe fn _mm256_madd52hi_epu64(
+    a: __m256i, b: __m256i, c: __m256i
+) -> __m256i {
+    let (a, b, c) = (a.as_i64x4(), b.as_i64x4(), c.as_i64x4());
+    mem::transmute(vpmadd52huq256(a, b, c, -1))
+}
+
+/// Multiply packed unsigned 52-bit integers in each 64-bit element of `b`
+/// and `c` to form a 104-bit intermediate result. Add the low 52-bit
+/// unsigned integer from the intermediate result to the corresponding
+/// unsigned 64-bit integer in `a`, and return the results.
+#[inline]
+#[target_feature(enable = "avx512ifma,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmadd52luq))]
+pub unsafe fn _mm256_madd52lo_epu64(
+    a: __m256i, b: __m256i, c: __m256i
+) -> __m256i {
+    let (a, b, c) = (a.as_i64x4(), b.as_i64x4(), c.as_i64x4());
+    mem::transmute(vpmadd52luq256(a, b, c, -1))
+}
+
+/// Multiply packed unsigned 52-bit integers in each 64-bit element of `b`
+/// and `c` to form a 104-bit intermediate result. Add the high 52-bit
+/// unsigned integer from the intermediate result to the corresponding
+/// unsigned 64-bit integer in `a`, and return the results.
+#[inline]
+#[target_feature(enable = "avx512ifma,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmadd52huq))]
+pub unsafe fn _mm_madd52hi_epu64(
+    a: __m128i, b: __m128i, c: __m128i
+) -> __m128i {
+    let (a, b, c) = (a.as_i64x2(), b.as_i64x2(), c.as_i64x2());
+    mem::transmute(vpmadd52huq128(a, b, c, -1))
+}
+
+/// Multiply packed unsigned 52-bit integers in each 64-bit element of `b`
+/// and `c` to form a 104-bit intermediate result. Add the low 52-bit
+/// unsigned integer from the intermediate result to the corresponding
+/// unsigned 64-bit integer in `a`, and return the results.
+#[inline]
+#[target_feature(enable = "avx512ifma,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmadd52luq))]
+pub unsafe fn _mm_madd52lo_epu64(
+    a: __m128i, b: __m128i, c: __m128i
+) -> __m128i {
+    let (a, b, c) = (a.as_i64x2(), b.as_i64x2(), c.as_i64x2());
+    mem::transmute(vpmadd52luq128(a, b, c, -1))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.mask.vpmadd52h.uq.512"]
+    fn vpmadd52huq512(a: i64x8, b: i64x8, c: i64x8, k: i8) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.vpmadd52l.uq.512"]
+    fn vpmadd52luq512(a: i64x8, b: i64x8, c: i64x8, k: i8) -> i64x8;
+    #[link_name = "llvm.x86.avx512.mask.vpmadd52h.uq.256"]
+    fn vpmadd52huq256(a: i64x4, b: i64x4, c: i64x4, k: i8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.vpmadd52l.uq.256"]
+    fn vpmadd52luq256(a: i64x4, b: i64x4, c: i64x4, k: i8) -> i64x4;
+    #[link_name = "llvm.x86.avx512.mask.vpmadd52h.uq.128"]
+    fn vpmadd52huq128(a: i64x2, b: i64x2, c: i64x2, k: i8) -> i64x2;
+    #[link_name = "llvm.x86.avx512.mask.vpmadd52l.uq.128"]
+    fn vpmadd52luq128(a: i64x2, b: i64x2, c: i64x2, k: i8) -> i64x2;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    const MASK52: u64 = (1 << 52) - 1;
+
+    /// Reference implementation of `vpmadd52luq` and `vpmadd52huq` on a
+    /// single 64-bit element, returning `(lo, hi)`.
+    fn madd52(a: u64, b: u64, c: u64) -> (u64, u64) {
+        let p = (b & MASK52) as u128 * (c & MASK52) as u128;
+        let lo = a.wrapping_add(p as u64 & MASK52);
+        let hi = a.wrapping_add((p >> 52) as u64);
+        (lo, hi)
+    }
+
+    const A: [u64; 8] = [
+        0,
+        1,
+        0xffff_ffff_ffff_ffff,
+        0x0123_4567_89ab_cdef,
+        10,
+        0,
+        1 << 63,
+        0x000f_ffff_ffff_ffff,
+    ];
+    // the upper 12 bits of `b` and `c` must be ignored
+    const B: [u64; 8] = [
+        MASK52,
+        2,
+        3,
+        0xfff0_0000_0000_0007,
+        0x000a_bcde_f012_3456,
+        1 << 51,
+        0xffff_ffff_ffff_ffff,
+        MASK52,
+    ];
+    const C: [u64; 8] = [
+        MASK52,
+        3,
+        0xffff_ffff_ffff_ffff,
+        11,
+        0x0008_7654_3210_fedc,
+        1 << 51,
+        0xffff_ffff_ffff_ffff,
+        1,
+    ];
+
+    #[simd_test = "avx512ifma"]
+    unsafe fn test_mm512_madd52lo_epu64() {
+        let r = _mm512_madd52lo_epu64(
+            mem::transmute(A),
+            mem::transmute(B),
+            mem::transmute(C),
+        );
+        let r: [u64; 8] = mem::transmute(r);
+        for i in 0..8 {
+            assert_eq!(r[i], madd52(A[i], B[i], C[i]).0);
+        }
+    }
+
+    #[simd_test = "avx512ifma"]
+    unsafe fn test_mm512_madd52hi_epu64() {
+        let r = _mm512_madd52hi_epu64(
+            mem::transmute(A),
+            mem::transmute(B),
+            mem::transmute(C),
+        );
+        let r: [u64; 8] = mem::transmute(r);
+        for i in 0..8 {
+            assert_eq!(r[i], madd52(A[i], B[i], C[i]).1);
+        }
+        // (2^52 - 1)^2 = 2^104 - 2^53 + 1, whose high half is 2^52 - 2
+        assert_eq!(r[0], MASK52 - 1);
+    }
+
+    #[simd_test = "avx512ifma,avx512vl"]
+    unsafe fn test_mm256_madd52lo_epu64() {
+        let (a, b, c) = (&A[4..], &B[4..], &C[4..]);
+        let r = _mm256_madd52lo_epu64(
+            _mm256_loadu_si256(a.as_ptr() as *const _),
+            _mm256_loadu_si256(b.as_ptr() as *const _),
+            _mm256_loadu_si256(c.as_ptr() as *const _),
+        );
+        let r: [u64; 4] = mem::transmute(r);
+        for i in 0..4 {
+            assert_eq!(r[i], madd52(a[i], b[i], c[i]).0);
+        }
+    }
+
+    #[simd_test = "avx512ifma,avx512vl"]
+    unsafe fn test_mm256_madd52hi_epu64() {
+        let (a, b, c) = (&A[4..], &B[4..], &C[4..]);
+        let r = _mm256_madd52hi_epu64(
+            _mm256_loadu_si256(a.as_ptr() as *const _),
+            _mm256_loadu_si256(b.as_ptr() as *const _),
+            _mm256_loadu_si256(c.as_ptr() as *const _),
+        );
+        let r: [u64; 4] = mem::transmute(r);
+        for i in 0..4 {
+            assert_eq!(r[i], madd52(a[i], b[i], c[i]).1);
+        }
+    }
+
+    #[simd_test = "avx512ifma,avx512vl"]
+    unsafe fn test_mm_madd52lo_epu64() {
+        let a = _mm_setr_epi64x(5, -1);
+        let b = _mm_setr_epi64x(7, 1);
+        let c = _mm_setr_epi64x(6, 1);
+        let r = _mm_madd52lo_epu64(a, b, c);
+        assert_eq_m128i(r, _mm_setr_epi64x(47, 0));
+    }
+
+    #[simd_test = "avx512ifma,avx512vl"]
+    unsafe fn test_mm_madd52hi_epu64() {
+        let a = _mm_setr_epi64x(5, 0);
+        let b = _mm_setr_epi64x(1 << 51, MASK52 as i64);
+        let c = _mm_setr_epi64x(1 << 2, MASK52 as i64);
+        let r = _mm_madd52hi_epu64(a, b, c);
+        let e = _mm_setr_epi64x(5 + 2, (MASK52 - 1) as i64);
+        assert_eq_m128i(r, e);
+    }
+}
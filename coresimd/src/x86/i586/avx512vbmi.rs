@@ -0,0 +1,347 @@
+//! AVX-512 Vector Byte Manipulation Instructions (AVX512_VBMI) intrinsics.
+//!
+//! The 128-bit and 256-bit forms additionally require AVX-512VL.
+//!
+//! [Intel Intrinsics Guide][intel_guide].
+//!
+//! [intel_guide]: https://software.intel.com/sites/landingpage/IntrinsicsGuide/#techs=AVX_512&avx512techs=AVX512_VBMI
+
+use core::mem;
+
+use v128::*;
+use v256::*;
+use v512::*;
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Shuffle 8-bit integers in `a` across lanes using the corresponding index
+/// in `idx`. Only the low 6 bits of each index are used.
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm512_permutexvar_epi8(idx: __m512i, a: __m512i) -> __m512i {
+    let src = _mm512_setzero_si512().as_i8x64();
+    mem::transmute(vpermb512(a.as_i8x64(), idx.as_i8x64(), src, -1))
+}
+
+/// Shuffle 8-bit integers in `a` across lanes using the corresponding index
+/// in `idx`. Only the low 5 bits of each index are used.
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm256_permutexvar_epi8(idx: __m256i, a: __m256i) -> __m256i {
+    let src = _mm256_setzero_si256().as_i8x32();
+    mem::transmute(vpermb256(a.as_i8x32(), idx.as_i8x32(), src, -1))
+}
+
+/// Shuffle 8-bit integers in `a` using the corresponding index in `idx`.
+/// Only the low 4 bits of each index are used.
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpermb))]
+pub unsafe fn _mm_permutexvar_epi8(idx: __m128i, a: __m128i) -> __m128i {
+    let src = _mm_setzero_si128().as_i8x16();
+    mem::transmute(vpermb128(a.as_i8x16(), idx.as_i8x16(), src, -1))
+}
+
+/// Shuffle 8-bit integers in `a` and `b` across lanes using the
+/// corresponding selector and index in `idx`. Bit 6 of each index selects
+/// between `a` (clear) and `b` (set), and the low 6 bits select the element.
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+// LLVM picks `vpermi2b` or `vpermt2b` depending on which input it can
+// overwrite.
+#[cfg_attr(test, assert_instr(vperm))]
+pub unsafe fn _mm512_permutex2var_epi8(
+    a: __m512i, idx: __m512i, b: __m512i
+) -> __m512i {
+    let (a, idx, b) = (a.as_i8x64(), idx.as_i8x64(), b.as_i8x64());
+    mem::transmute(vpermi2b512(a, idx, b, -1))
+}
+
+/// Shuffle 8-bit integers in `a` and `b` across lanes using the
+/// corresponding selector and index in `idx`. Bit 5 of each index selects
+/// between `a` (clear) and `b` (set), and the low 5 bits select the element.
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+// LLVM picks `vpermi2b` or `vpermt2b` depending on which input it can
+// overwrite.
+#[cfg_attr(test, assert_instr(vperm))]
+pub unsafe fn _mm256_permutex2var_epi8(
+    a: __m256i, idx: __m256i, b: __m256i
+) -> __m256i {
+    let (a, idx, b) = (a.as_i8x32(), idx.as_i8x32(), b.as_i8x32());
+    mem::transmute(vpermi2b256(a, idx, b, -1))
+}
+
+/// Shuffle 8-bit integers in `a` and `b` using the corresponding selector
+/// and index in `idx`. Bit 4 of each index selects between `a` (clear) and
+/// `b` (set), and the low 4 bits select the element.
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+// LLVM picks `vpermi2b` or `vpermt2b` depending on which input it can
+// overwrite.
+#[cfg_attr(test, assert_instr(vperm))]
+pub unsafe fn _mm_permutex2var_epi8(
+    a: __m128i, idx: __m128i, b: __m128i
+) -> __m128i {
+    let (a, idx, b) = (a.as_i8x16(), idx.as_i8x16(), b.as_i8x16());
+    mem::transmute(vpermi2b128(a, idx, b, -1))
+}
+
+/// For each 64-bit element in `b`, select 8 unaligned bytes using the
+/// byte-granular shift controls in the corresponding 64-bit element of `a`.
+/// Byte `j` of each result element holds the 8 bits of the `b` element
+/// starting at bit `a[j] & 63`, wrapping around at bit 63.
+#[inline]
+#[target_feature(enable = "avx512vbmi")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm512_multishift_epi64_epi8(a: __m512i, b: __m512i) -> __m512i {
+    let src = _mm512_setzero_si512().as_i8x64();
+    mem::transmute(vpmultishiftqb512(a.as_i8x64(), b.as_i8x64(), src, -1))
+}
+
+/// For each 64-bit element in `b`, select 8 unaligned bytes using the
+/// byte-granular shift controls in the corresponding 64-bit element of `a`.
+/// Byte `j` of each result element holds the 8 bits of the `b` element
+/// starting at bit `a[j] & 63`, wrapping around at bit 63.
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm256_multishift_epi64_epi8(a: __m256i, b: __m256i) -> __m256i {
+    let src = _mm256_setzero_si256().as_i8x32();
+    mem::transmute(vpmultishiftqb256(a.as_i8x32(), b.as_i8x32(), src, -1))
+}
+
+/// For each 64-bit element in `b`, select 8 unaligned bytes using the
+/// byte-granular shift controls in the corresponding 64-bit element of `a`.
+/// Byte `j` of each result element holds the 8 bits of the `b` element
+/// starting at bit `a[j] & 63`, wrapping around at bit 63.
+#[inline]
+#[target_feature(enable = "avx512vbmi,avx512vl")]
+#[cfg_attr(test, assert_instr(vpmultishiftqb))]
+pub unsafe fn _mm_multishift_epi64_epi8(a: __m128i, b: __m128i) -> __m128i {
+    let src = _mm_setzero_si128().as_i8x16();
+    mem::transmute(vpmultishiftqb128(a.as_i8x16(), b.as_i8x16(), src, -1))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.avx512.mask.permvar.qi.512"]
+    fn vpermb512(a: i8x64, idx: i8x64, src: i8x64, k: i64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.mask.permvar.qi.256"]
+    fn vpermb256(a: i8x32, idx: i8x32, src: i8x32, k: i32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.mask.permvar.qi.128"]
+    fn vpermb128(a: i8x16, idx: i8x16, src: i8x16, k: i16) -> i8x16;
+
+    #[link_name = "llvm.x86.avx512.mask.vpermi2var.qi.512"]
+    fn vpermi2b512(a: i8x64, idx: i8x64, b: i8x64, k: i64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.mask.vpermi2var.qi.256"]
+    fn vpermi2b256(a: i8x32, idx: i8x32, b: i8x32, k: i32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.mask.vpermi2var.qi.128"]
+    fn vpermi2b128(a: i8x16, idx: i8x16, b: i8x16, k: i16) -> i8x16;
+
+    #[link_name = "llvm.x86.avx512.mask.pmultishift.qb.512"]
+    fn vpmultishiftqb512(a: i8x64, b: i8x64, src: i8x64, k: i64) -> i8x64;
+    #[link_name = "llvm.x86.avx512.mask.pmultishift.qb.256"]
+    fn vpmultishiftqb256(a: i8x32, b: i8x32, src: i8x32, k: i32) -> i8x32;
+    #[link_name = "llvm.x86.avx512.mask.pmultishift.qb.128"]
+    fn vpmultishiftqb128(a: i8x16, b: i8x16, src: i8x16, k: i16) -> i8x16;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "avx512vbmi"]
+    unsafe fn test_mm512_permutexvar_epi8() {
+        let mut a = [0u8; 64];
+        let mut idx = [0u8; 64];
+        for i in 0..64 {
+            a[i] = i as u8 * 3;
+            // reverse, with garbage in the ignored upper bits
+            idx[i] = (63 - i as u8) | 0b1100_0000;
+        }
+        let r = _mm512_permutexvar_epi8(
+            mem::transmute(idx),
+            mem::transmute(a),
+        );
+        let r: [u8; 64] = mem::transmute(r);
+        for i in 0..64 {
+            assert_eq!(r[i], a[63 - i]);
+        }
+    }
+
+    #[simd_test = "avx512vbmi,avx512vl"]
+    unsafe fn test_mm256_permutexvar_epi8() {
+        let mut a = [0u8; 32];
+        let mut idx = [0u8; 32];
+        for i in 0..32 {
+            a[i] = i as u8 + 100;
+            idx[i] = (i as u8 * 5) & 31;
+        }
+        let r = _mm256_permutexvar_epi8(
+            mem::transmute(idx),
+            mem::transmute(a),
+        );
+        let r: [u8; 32] = mem::transmute(r);
+        for i in 0..32 {
+            assert_eq!(r[i], a[idx[i] as usize]);
+        }
+    }
+
+    #[simd_test = "avx512vbmi,avx512vl"]
+    unsafe fn test_mm_permutexvar_epi8() {
+        let a = _mm_setr_epi8(
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        let idx = _mm_setr_epi8(
+            15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0,
+        );
+        let r = _mm_permutexvar_epi8(idx, a);
+        assert_eq_m128i(r, idx);
+    }
+
+    #[simd_test = "avx512vbmi"]
+    unsafe fn test_mm512_permutex2var_epi8() {
+        let mut a = [0u8; 64];
+        let mut b = [0u8; 64];
+        let mut idx = [0u8; 64];
+        for i in 0..64 {
+            a[i] = i as u8;
+            b[i] = i as u8 + 128;
+            idx[i] = (i as u8).wrapping_mul(37);
+        }
+        let r = _mm512_permutex2var_epi8(
+            mem::transmute(a),
+            mem::transmute(idx),
+            mem::transmute(b),
+        );
+        let r: [u8; 64] = mem::transmute(r);
+        for i in 0..64 {
+            let j = (idx[i] & 63) as usize;
+            let e = if idx[i] & 64 == 0 { a[j] } else { b[j] };
+            assert_eq!(r[i], e);
+        }
+    }
+
+    #[simd_test = "avx512vbmi,avx512vl"]
+    unsafe fn test_mm256_permutex2var_epi8() {
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        let mut idx = [0u8; 32];
+        for i in 0..32 {
+            a[i] = i as u8;
+            b[i] = i as u8 + 128;
+            idx[i] = (i as u8).wrapping_mul(37);
+        }
+        let r = _mm256_permutex2var_epi8(
+            mem::transmute(a),
+            mem::transmute(idx),
+            mem::transmute(b),
+        );
+        let r: [u8; 32] = mem::transmute(r);
+        for i in 0..32 {
+            let j = (idx[i] & 31) as usize;
+            let e = if idx[i] & 32 == 0 { a[j] } else { b[j] };
+            assert_eq!(r[i], e);
+        }
+    }
+
+    #[simd_test = "avx512vbmi,avx512vl"]
+    unsafe fn test_mm_permutex2var_epi8() {
+        let a = _mm_set1_epi8(1);
+        let b = _mm_set1_epi8(2);
+        let idx = _mm_setr_epi8(
+            0, 16, 1, 17, 2, 18, 3, 19, 4, 20, 5, 21, 6, 22, 7, 23,
+        );
+        let r = _mm_permutex2var_epi8(a, idx, b);
+        let e = _mm_setr_epi8(1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    /// Reference implementation of `vpmultishiftqb` on a single 64-bit
+    /// element.
+    fn multishift(ctrl: u64, b: u64) -> u64 {
+        let mut r = 0;
+        for j in 0..8 {
+            let shift = (ctrl >> (j * 8)) & 63;
+            let byte = b.rotate_right(shift as u32) & 0xff;
+            r |= byte << (j * 8);
+        }
+        r
+    }
+
+    #[simd_test = "avx512vbmi"]
+    unsafe fn test_mm512_multishift_epi64_epi8() {
+        let ctrl: [u64; 8] = [
+            0x38_30_28_20_18_10_08_00,
+            0x3c_34_2c_24_1c_14_0c_04,
+            0x3f_3e_3d_3c_03_02_01_00,
+            0xff_c0_7f_40_bf_80_3f_00,
+            0,
+            0x0101_0101_0101_0101,
+            0x0706_0504_0302_0100,
+            0x2d3c_4b5a_6978_8796,
+        ];
+        let b: [u64; 8] = [
+            0x0123_4567_89ab_cdef,
+            0x0123_4567_89ab_cdef,
+            0x8000_0000_0000_0001,
+            0xdead_beef_cafe_f00d,
+            0xffff_ffff_ffff_ff7f,
+            0x0f0f_0f0f_0f0f_0f0f,
+            0x1111_2222_3333_4444,
+            0x5a5a_a5a5_5a5a_a5a5,
+        ];
+        let r = _mm512_multishift_epi64_epi8(
+            mem::transmute(ctrl),
+            mem::transmute(b),
+        );
+        let r: [u64; 8] = mem::transmute(r);
+        for i in 0..8 {
+            assert_eq!(r[i], multishift(ctrl[i], b[i]));
+        }
+        // controls of 0, 8, .., 56 reproduce the source
+        assert_eq!(r[0], b[0]);
+    }
+
+    #[simd_test = "avx512vbmi,avx512vl"]
+    unsafe fn test_mm256_multishift_epi64_epi8() {
+        let ctrl: [u64; 4] = [
+            0x38_30_28_20_18_10_08_00,
+            0x3c_34_2c_24_1c_14_0c_04,
+            0x3f_3e_3d_3c_03_02_01_00,
+            0x2d3c_4b5a_6978_8796,
+        ];
+        let b: [u64; 4] = [
+            0xdead_beef_cafe_f00d,
+            0x0123_4567_89ab_cdef,
+            0x8000_0000_0000_0001,
+            0x5a5a_a5a5_5a5a_a5a5,
+        ];
+        let r = _mm256_multishift_epi64_epi8(
+            mem::transmute(ctrl),
+            mem::transmute(b),
+        );
+        let r: [u64; 4] = mem::transmute(r);
+        for i in 0..4 {
+            assert_eq!(r[i], multishift(ctrl[i], b[i]));
+        }
+    }
+
+    #[simd_test = "avx512vbmi,avx512vl"]
+    unsafe fn test_mm_multishift_epi64_epi8() {
+        let ctrl = _mm_set1_epi64x(0x3c_34_2c_24_1c_14_0c_04);
+        let b = _mm_set1_epi64x(0x0123_4567_89ab_cdef);
+        let r = _mm_multishift_epi64_epi8(ctrl, b);
+        let e = _mm_set1_epi64x(0xf012_3456_789a_bcdeu64 as i64);
+        assert_eq_m128i(r, e);
+    }
+}
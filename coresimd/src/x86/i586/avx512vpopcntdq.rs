@@ -0,0 +1,148 @@
+//! AVX-512 Vector Population Count (AVX512_VPOPCNTDQ) intrinsics.
+//!
+//! The 128-bit and 256-bit forms additionally require AVX-512VL.
+//!
+//! [Intel Intrinsics Guide][intel_guide].
+//!
+//! [intel_guide]: https://software.intel.com/sites/landingpage/IntrinsicsGuide/#techs=AVX_512&avx512techs=AVX512_VPOPCNTDQ
+
+use core::mem;
+
+use v128::*;
+use v256::*;
+use v512::*;
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Count the number of set bits in each packed 32-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm512_popcnt_epi32(a: __m512i) -> __m512i {
+    mem::transmute(vpopcntd512(a.as_i32x16()))
+}
+
+/// Count the number of set bits in each packed 32-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm256_popcnt_epi32(a: __m256i) -> __m256i {
+    mem::transmute(vpopcntd256(a.as_i32x8()))
+}
+
+/// Count the number of set bits in each packed 32-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntd))]
+pub unsafe fn _mm_popcnt_epi32(a: __m128i) -> __m128i {
+    mem::transmute(vpopcntd128(a.as_i32x4()))
+}
+
+/// Count the number of set bits in each packed 64-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm512_popcnt_epi64(a: __m512i) -> __m512i {
+    mem::transmute(vpopcntq512(a.as_i64x8()))
+}
+
+/// Count the number of set bits in each packed 64-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm256_popcnt_epi64(a: __m256i) -> __m256i {
+    mem::transmute(vpopcntq256(a.as_i64x4()))
+}
+
+/// Count the number of set bits in each packed 64-bit integer in `a`.
+#[inline]
+#[target_feature(enable = "avx512vpopcntdq,avx512vl")]
+#[cfg_attr(test, assert_instr(vpopcntq))]
+pub unsafe fn _mm_popcnt_epi64(a: __m128i) -> __m128i {
+    mem::transmute(vpopcntq128(a.as_i64x2()))
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.ctpop.v16i32"]
+    fn vpopcntd512(a: i32x16) -> i32x16;
+    #[link_name = "llvm.ctpop.v8i32"]
+    fn vpopcntd256(a: i32x8) -> i32x8;
+    #[link_name = "llvm.ctpop.v4i32"]
+    fn vpopcntd128(a: i32x4) -> i32x4;
+    #[link_name = "llvm.ctpop.v8i64"]
+    fn vpopcntq512(a: i64x8) -> i64x8;
+    #[link_name = "llvm.ctpop.v4i64"]
+    fn vpopcntq256(a: i64x4) -> i64x4;
+    #[link_name = "llvm.ctpop.v2i64"]
+    fn vpopcntq128(a: i64x2) -> i64x2;
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "avx512vpopcntdq"]
+    unsafe fn test_mm512_popcnt_epi32() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi32(
+            0, 1, 2, 3, -1, 0x7fff_ffff, 0x5555_5555, 0x0f0f_0f0f,
+            0x100, 0xff, 7, 8, 15, 16, 31, 32,
+        );
+        let r = _mm512_popcnt_epi32(a);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm512_setr_epi32(
+            0, 1, 1, 2, 32, 31, 16, 16,
+            1, 8, 3, 1, 4, 1, 5, 1,
+        );
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512vpopcntdq,avx512vl"]
+    unsafe fn test_mm256_popcnt_epi32() {
+        let a = _mm256_setr_epi32(0, 1, 2, 3, -1, 0x7fff_ffff, 0xff, 7);
+        let r = _mm256_popcnt_epi32(a);
+        let e = _mm256_setr_epi32(0, 1, 1, 2, 32, 31, 8, 3);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "avx512vpopcntdq,avx512vl"]
+    unsafe fn test_mm_popcnt_epi32() {
+        let a = _mm_setr_epi32(0, -1, 0x5555_5555, 0x100);
+        let r = _mm_popcnt_epi32(a);
+        let e = _mm_setr_epi32(0, 32, 16, 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "avx512vpopcntdq"]
+    unsafe fn test_mm512_popcnt_epi64() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm512_setr_epi64(
+            0, 1, -1, i64::max_value(),
+            0x5555_5555_5555_5555, 1 << 40, 0xff, 7,
+        );
+        let r = _mm512_popcnt_epi64(a);
+        let e = _mm512_setr_epi64(0, 1, 64, 63, 32, 1, 8, 3);
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "avx512vpopcntdq,avx512vl"]
+    unsafe fn test_mm256_popcnt_epi64() {
+        let a = _mm256_setr_epi64x(0, 1, -1, 0x5555_5555_5555_5555);
+        let r = _mm256_popcnt_epi64(a);
+        let e = _mm256_setr_epi64x(0, 1, 64, 32);
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "avx512vpopcntdq,avx512vl"]
+    unsafe fn test_mm_popcnt_epi64() {
+        let a = _mm_setr_epi64x(-1, 1 << 63);
+        let r = _mm_popcnt_epi64(a);
+        let e = _mm_setr_epi64x(64, 1);
+        assert_eq_m128i(r, e);
+    }
+}
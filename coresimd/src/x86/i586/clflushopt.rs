@@ -0,0 +1,42 @@
+//! Optimized cache line flush (CLFLUSHOPT)
+//!
+//! `clflushopt` behaves like `clflush`, except that flushes of different
+//! cache lines are not ordered with respect to each other, which makes
+//! flushing a large buffer considerably faster. Use a fence such as
+//! `_mm_sfence` to order the flushes with respect to later stores.
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+extern "C" {
+    #[link_name = "llvm.x86.clflushopt"]
+    fn clflushopt(p: *mut u8);
+}
+
+/// Invalidate and flush the cache line that contains `p` from all levels of
+/// the cache hierarchy, without ordering the flush with respect to flushes
+/// of other cache lines.
+#[inline]
+#[target_feature(enable = "clflushopt")]
+#[cfg_attr(test, assert_instr(clflushopt))]
+pub unsafe fn _mm_clflushopt(p: *mut u8) {
+    clflushopt(p)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "clflushopt"]
+    unsafe fn test_mm_clflushopt() {
+        let mut x = [0u8; 256];
+        for i in 0..4 {
+            _mm_clflushopt(x.as_mut_ptr().offset(i * 64));
+        }
+        _mm_sfence();
+        // flushing does not change the contents of memory
+        assert!(x.iter().all(|&b| b == 0));
+    }
+}
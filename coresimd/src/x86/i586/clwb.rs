@@ -0,0 +1,42 @@
+//! Cache line write back (CLWB)
+//!
+//! `clwb` writes a modified cache line back to memory like `clflushopt`,
+//! but may keep the line in the cache, so that subsequent accesses to it
+//! still hit. This is the preferred way of making stores to persistent
+//! memory durable.
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+extern "C" {
+    #[link_name = "llvm.x86.clwb"]
+    fn clwb(p: *mut u8);
+}
+
+/// Write back the cache line that contains `p` to memory if it is
+/// modified, possibly retaining it in the cache hierarchy.
+///
+/// Like `_mm_clflushopt`, the write back is only ordered with respect to
+/// other write backs and flushes by a fence such as `_mm_sfence`.
+#[inline]
+#[target_feature(enable = "clwb")]
+#[cfg_attr(test, assert_instr(clwb))]
+pub unsafe fn _mm_clwb(p: *mut u8) {
+    clwb(p)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "clwb"]
+    unsafe fn test_mm_clwb() {
+        let mut x = 0u64;
+        x += 1;
+        _mm_clwb(&mut x as *mut _ as *mut u8);
+        _mm_sfence();
+        assert_eq!(x, 1);
+    }
+}
@@ -0,0 +1,166 @@
+//! [F16C intrinsics].
+//!
+//! [F16C intrinsics]: https://software.intel.com/sites/landingpage/IntrinsicsGuide/#expand=1765,1769&techs=F16C
+
+use core::mem;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+use v128::*;
+use v256::*;
+use x86::*;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.vcvtph2ps.128"]
+    fn vcvtph2ps128(a: i16x8) -> f32x4;
+    #[link_name = "llvm.x86.vcvtph2ps.256"]
+    fn vcvtph2ps256(a: i16x8) -> f32x8;
+    #[link_name = "llvm.x86.vcvtps2ph.128"]
+    fn vcvtps2ph128(a: f32x4, rounding: i32) -> i16x8;
+    #[link_name = "llvm.x86.vcvtps2ph.256"]
+    fn vcvtps2ph256(a: f32x8, rounding: i32) -> i16x8;
+}
+
+/// Convert the 4 x 16-bit half-precision float values in the lowest 64-bit of
+/// the 128-bit vector `a` into 4 x 32-bit float values stored in a 128-bit
+/// wide vector.
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtph2ps))]
+pub unsafe fn _mm_cvtph_ps(a: __m128i) -> __m128 {
+    mem::transmute(vcvtph2ps128(a.as_i16x8()))
+}
+
+/// Convert the 8 x 16-bit half-precision float values in the 128-bit vector
+/// `a` into 8 x 32-bit float values stored in a 256-bit wide vector.
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtph2ps))]
+pub unsafe fn _mm256_cvtph_ps(a: __m128i) -> __m256 {
+    mem::transmute(vcvtph2ps256(a.as_i16x8()))
+}
+
+/// Convert the 4 x 32-bit float values in the 128-bit vector `a` into 4 x
+/// 16-bit half-precision float values stored in the lowest 64-bit of a 128-bit
+/// vector. The upper 64 bits of the result are zeroed.
+///
+/// Rounding is done according to the `imm_rounding` parameter, which can be
+/// one of:
+///
+/// * `_MM_FROUND_TO_NEAREST_INT`: round to nearest
+/// * `_MM_FROUND_TO_NEG_INF`: round down
+/// * `_MM_FROUND_TO_POS_INF`: round up
+/// * `_MM_FROUND_TO_ZERO`: truncate
+/// * `_MM_FROUND_CUR_DIRECTION`: use `MXCSR.RC` - see `_MM_SET_ROUNDING_MODE`
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtps2ph, imm_rounding = 0))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm_cvtps_ph(a: __m128, imm_rounding: i32) -> __m128i {
+    let a = a.as_f32x4();
+    macro_rules! call {
+        ($rounding:expr) => {
+            vcvtps2ph128(a, $rounding)
+        };
+    }
+    mem::transmute(constify_imm3!(imm_rounding, call))
+}
+
+/// Convert the 8 x 32-bit float values in the 256-bit vector `a` into 8 x
+/// 16-bit half-precision float values stored in a 128-bit wide vector.
+///
+/// Rounding is done according to the `imm_rounding` parameter, which can be
+/// one of:
+///
+/// * `_MM_FROUND_TO_NEAREST_INT`: round to nearest
+/// * `_MM_FROUND_TO_NEG_INF`: round down
+/// * `_MM_FROUND_TO_POS_INF`: round up
+/// * `_MM_FROUND_TO_ZERO`: truncate
+/// * `_MM_FROUND_CUR_DIRECTION`: use `MXCSR.RC` - see `_MM_SET_ROUNDING_MODE`
+#[inline]
+#[target_feature(enable = "f16c")]
+#[cfg_attr(test, assert_instr(vcvtps2ph, imm_rounding = 0))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm256_cvtps_ph(a: __m256, imm_rounding: i32) -> __m128i {
+    let a = a.as_f32x8();
+    macro_rules! call {
+        ($rounding:expr) => {
+            vcvtps2ph256(a, $rounding)
+        };
+    }
+    mem::transmute(constify_imm3!(imm_rounding, call))
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "f16c"]
+    unsafe fn test_mm_cvtph_ps() {
+        let a = _mm_setr_epi16(0x3c00, 0x4000, -0x3e00, 0x7c00, 0, 0, 0, 0);
+        let r = _mm_cvtph_ps(a);
+        let e = _mm_setr_ps(1., 2., -3., ::core::f32::INFINITY);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "f16c"]
+    unsafe fn test_mm256_cvtph_ps() {
+        let a = _mm_setr_epi16(
+            0x3c00, 0x4000, -0x3e00, 0x7c00, 0x3800, 0x0001, 0x7bff, -0x8000,
+        );
+        let r = _mm256_cvtph_ps(a);
+        let inf = ::core::f32::INFINITY;
+        let e =
+            _mm256_setr_ps(1., 2., -3., inf, 0.5, 5.9604645e-8, 65504., -0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "f16c"]
+    unsafe fn test_mm_cvtps_ph() {
+        // 1 + 3 * 2^-12 lies between the `f16` values 1 and 1 + 2^-10, but
+        // closer to the latter.
+        let x = 1.000732421875;
+        let a = _mm_setr_ps(x, -x, 1., 65520.);
+
+        let r = _mm_cvtps_ph(a, _MM_FROUND_TO_NEAREST_INT);
+        let e = _mm_setr_epi16(0x3c01, -0x43ff, 0x3c00, 0x7c00, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+
+        let r = _mm_cvtps_ph(a, _MM_FROUND_TO_NEG_INF);
+        let e = _mm_setr_epi16(0x3c00, -0x43ff, 0x3c00, 0x7bff, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+
+        let r = _mm_cvtps_ph(a, _MM_FROUND_TO_POS_INF);
+        let e = _mm_setr_epi16(0x3c01, -0x4400, 0x3c00, 0x7c00, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+
+        let r = _mm_cvtps_ph(a, _MM_FROUND_TO_ZERO);
+        let e = _mm_setr_epi16(0x3c00, -0x4400, 0x3c00, 0x7bff, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "f16c"]
+    unsafe fn test_mm256_cvtps_ph() {
+        let a = _mm256_setr_ps(1., 2., -3., 0.5, 1e-8, 65504., -0., 1e6);
+        let r = _mm256_cvtps_ph(a, _MM_FROUND_TO_NEAREST_INT);
+        let e = _mm_setr_epi16(
+            0x3c00, 0x4000, -0x3e00, 0x3800, 0, 0x7bff, -0x8000, 0x7c00,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "f16c"]
+    unsafe fn test_mm_cvtps_ph_round_trip() {
+        let a = _mm_setr_epi16(
+            0x0001, 0x03ff, 0x0400, 0x3555, -0x3e00, 0x7bff, 0x7c00, -0x8000,
+        );
+        let lo = _mm_cvtps_ph(_mm_cvtph_ps(a), _MM_FROUND_CUR_DIRECTION);
+        let r = _mm256_cvtps_ph(_mm256_cvtph_ps(a), _MM_FROUND_TO_ZERO);
+        assert_eq_m128i(r, a);
+        assert_eq_m128i(lo, _mm_unpacklo_epi64(a, _mm_setzero_si128()));
+    }
+}
@@ -0,0 +1,783 @@
+//! Fused Multiply-Add instruction set (FMA)
+//!
+//! The FMA instruction set is an extension to the 128 and 256-bit SSE
+//! instructions in the x86 microprocessor instruction set to perform fused
+//! multiply–add (FMA) operations.
+//!
+//! The references are:
+//!
+//! - [Intel 64 and IA-32 Architectures Software Developer's Manual Volume 2:
+//!   Instruction Set Reference, A-Z][intel64_ref].
+//! - [AMD64 Architecture Programmer's Manual, Volume 3: General-Purpose and
+//!   System Instructions][amd64_ref].
+//!
+//! Wikipedia's [FMA][wiki_fma] page provides a quick overview of the
+//! instructions available.
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+//! [amd64_ref]: http://support.amd.com/TechDocs/24594.pdf
+//! [wiki_fma]: https://en.wikipedia.org/wiki/Fused_multiply-accumulate
+
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmadd213pd))]
+pub unsafe fn _mm_fmadd_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmaddpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmadd213ps))]
+pub unsafe fn _mm_fmadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmaddps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmadd213pd))]
+pub unsafe fn _mm256_fmadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    vfmaddpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmadd213ps))]
+pub unsafe fn _mm256_fmadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfmaddps256(a, b, c)
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to the lower element in `c`.
+/// Store the result in the lower element of the returned value, and copy the
+/// upper elements from `a` to the upper elements of the result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmadd213sd))]
+pub unsafe fn _mm_fmadd_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmaddsd(a, b, c)
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to the lower element in `c`.
+/// Store the result in the lower element of the returned value, and copy the
+/// upper elements from `a` to the upper elements of the result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmadd213ss))]
+pub unsafe fn _mm_fmadd_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmaddss(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmsub213pd))]
+pub unsafe fn _mm_fmsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmsubpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmsub213ps))]
+pub unsafe fn _mm_fmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmsubps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmsub213pd))]
+pub unsafe fn _mm256_fmsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    vfmsubpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmsub213ps))]
+pub unsafe fn _mm256_fmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfmsubps256(a, b, c)
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the intermediate
+/// result.
+/// Store the result in the lower element of the returned value, and copy the
+/// upper elements from `a` to the upper elements of the result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmsub213sd))]
+pub unsafe fn _mm_fmsub_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmsubsd(a, b, c)
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the intermediate
+/// result.
+/// Store the result in the lower element of the returned value, and copy the
+/// upper elements from `a` to the upper elements of the result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmsub213ss))]
+pub unsafe fn _mm_fmsub_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmsubss(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmadd213pd))]
+pub unsafe fn _mm_fnmadd_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfnmaddpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmadd213ps))]
+pub unsafe fn _mm_fnmadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfnmaddps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmadd213pd))]
+pub unsafe fn _mm256_fnmadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    vfnmaddpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmadd213ps))]
+pub unsafe fn _mm256_fnmadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfnmaddps256(a, b, c)
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to the lower element in
+/// `c`.
+/// Store the result in the lower element of the returned value, and copy the
+/// upper elements from `a` to the upper elements of the result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmadd213sd))]
+pub unsafe fn _mm_fnmadd_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfnmaddsd(a, b, c)
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to the lower element in
+/// `c`.
+/// Store the result in the lower element of the returned value, and copy the
+/// upper elements from `a` to the upper elements of the result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmadd213ss))]
+pub unsafe fn _mm_fnmadd_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfnmaddss(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmsub213pd))]
+pub unsafe fn _mm_fnmsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfnmsubpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmsub213ps))]
+pub unsafe fn _mm_fnmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfnmsubps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmsub213pd))]
+pub unsafe fn _mm256_fnmsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    vfnmsubpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmsub213ps))]
+pub unsafe fn _mm256_fnmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfnmsubps256(a, b, c)
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the negated
+/// intermediate result.
+/// Store the result in the lower element of the returned value, and copy the
+/// upper elements from `a` to the upper elements of the result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmsub213sd))]
+pub unsafe fn _mm_fnmsub_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfnmsubsd(a, b, c)
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the negated
+/// intermediate result.
+/// Store the result in the lower element of the returned value, and copy the
+/// upper elements from `a` to the upper elements of the result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfnmsub213ss))]
+pub unsafe fn _mm_fnmsub_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfnmsubss(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c` to/from
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmaddsub213pd))]
+pub unsafe fn _mm_fmaddsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmaddsubpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c` to/from
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmaddsub213ps))]
+pub unsafe fn _mm_fmaddsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmaddsubps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c` to/from
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmaddsub213pd))]
+pub unsafe fn _mm256_fmaddsub_pd(
+    a: __m256d, b: __m256d, c: __m256d
+) -> __m256d {
+    vfmaddsubpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c` to/from
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmaddsub213ps))]
+pub unsafe fn _mm256_fmaddsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfmaddsubps256(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c` from/to
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmsubadd213pd))]
+pub unsafe fn _mm_fmsubadd_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmsubaddpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c` from/to
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmsubadd213ps))]
+pub unsafe fn _mm_fmsubadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmsubaddps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c` from/to
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmsubadd213pd))]
+pub unsafe fn _mm256_fmsubadd_pd(
+    a: __m256d, b: __m256d, c: __m256d
+) -> __m256d {
+    vfmsubaddpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c` from/to
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma")]
+#[cfg_attr(test, assert_instr(vfmsubadd213ps))]
+pub unsafe fn _mm256_fmsubadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfmsubaddps256(a, b, c)
+}
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.fma.vfmadd.pd"]
+    fn vfmaddpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfmadd.ps"]
+    fn vfmaddps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmadd.pd.256"]
+    fn vfmaddpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmadd.ps.256"]
+    fn vfmaddps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfmadd.sd"]
+    fn vfmaddsd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfmadd.ss"]
+    fn vfmaddss(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmsub.pd"]
+    fn vfmsubpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfmsub.ps"]
+    fn vfmsubps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmsub.pd.256"]
+    fn vfmsubpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmsub.ps.256"]
+    fn vfmsubps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfmsub.sd"]
+    fn vfmsubsd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfmsub.ss"]
+    fn vfmsubss(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfnmadd.pd"]
+    fn vfnmaddpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfnmadd.ps"]
+    fn vfnmaddps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfnmadd.pd.256"]
+    fn vfnmaddpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfnmadd.ps.256"]
+    fn vfnmaddps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfnmadd.sd"]
+    fn vfnmaddsd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfnmadd.ss"]
+    fn vfnmaddss(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfnmsub.pd"]
+    fn vfnmsubpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfnmsub.ps"]
+    fn vfnmsubps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfnmsub.pd.256"]
+    fn vfnmsubpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfnmsub.ps.256"]
+    fn vfnmsubps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfnmsub.sd"]
+    fn vfnmsubsd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfnmsub.ss"]
+    fn vfnmsubss(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmaddsub.pd"]
+    fn vfmaddsubpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfmaddsub.ps"]
+    fn vfmaddsubps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmaddsub.pd.256"]
+    fn vfmaddsubpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmaddsub.ps.256"]
+    fn vfmaddsubps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfmsubadd.pd"]
+    fn vfmsubaddpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfmsubadd.ps"]
+    fn vfmsubaddps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmsubadd.pd.256"]
+    fn vfmsubaddpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmsubadd.ps.256"]
+    fn vfmsubaddps256(a: __m256, b: __m256, c: __m256) -> __m256;
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmadd_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_fmadd_pd(a, b, c);
+        let e = _mm_setr_pd(9., 15.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmadd_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_fmadd_ps(a, b, c);
+        let e = _mm_setr_ps(9., 15., 22., 15.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fmadd_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_fmadd_pd(a, b, c);
+        let e = _mm256_setr_pd(9., 15., 22., 15.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fmadd_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 1., 4., 6., 9.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 3., 2., 8., 5.);
+        let r = _mm256_fmadd_ps(a, b, c);
+        let e = _mm256_setr_ps(9., 15., 22., 15., 8., 26., 50., 77.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmadd_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_fmadd_sd(a, b, c);
+        let e = _mm_setr_pd(9., 2.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmadd_ss() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_fmadd_ss(a, b, c);
+        let e = _mm_setr_ps(9., 2., 3., 4.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmsub_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_fmsub_pd(a, b, c);
+        let e = _mm_setr_pd(1., -3.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmsub_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_fmsub_ps(a, b, c);
+        let e = _mm_setr_ps(1., -3., 20., 1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fmsub_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_fmsub_pd(a, b, c);
+        let e = _mm256_setr_pd(1., -3., 20., 1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fmsub_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 1., 4., 6., 9.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 3., 2., 8., 5.);
+        let r = _mm256_fmsub_ps(a, b, c);
+        let e = _mm256_setr_ps(1., -3., 20., 1., 2., 22., 34., 67.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmsub_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_fmsub_sd(a, b, c);
+        let e = _mm_setr_pd(1., 2.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmsub_ss() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_fmsub_ss(a, b, c);
+        let e = _mm_setr_ps(1., 2., 3., 4.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fnmadd_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_fnmadd_pd(a, b, c);
+        let e = _mm_setr_pd(-1., 3.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fnmadd_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_fnmadd_ps(a, b, c);
+        let e = _mm_setr_ps(-1., 3., -20., -1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fnmadd_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_fnmadd_pd(a, b, c);
+        let e = _mm256_setr_pd(-1., 3., -20., -1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fnmadd_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 1., 4., 6., 9.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 3., 2., 8., 5.);
+        let r = _mm256_fnmadd_ps(a, b, c);
+        let e = _mm256_setr_ps(-1., 3., -20., -1., -2., -22., -34., -67.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fnmadd_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_fnmadd_sd(a, b, c);
+        let e = _mm_setr_pd(-1., 2.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fnmadd_ss() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_fnmadd_ss(a, b, c);
+        let e = _mm_setr_ps(-1., 2., 3., 4.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fnmsub_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_fnmsub_pd(a, b, c);
+        let e = _mm_setr_pd(-9., -15.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fnmsub_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_fnmsub_ps(a, b, c);
+        let e = _mm_setr_ps(-9., -15., -22., -15.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fnmsub_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_fnmsub_pd(a, b, c);
+        let e = _mm256_setr_pd(-9., -15., -22., -15.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fnmsub_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 1., 4., 6., 9.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 3., 2., 8., 5.);
+        let r = _mm256_fnmsub_ps(a, b, c);
+        let e = _mm256_setr_ps(-9., -15., -22., -15., -8., -26., -50., -77.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fnmsub_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_fnmsub_sd(a, b, c);
+        let e = _mm_setr_pd(-9., 2.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fnmsub_ss() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_fnmsub_ss(a, b, c);
+        let e = _mm_setr_ps(-9., 2., 3., 4.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmaddsub_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_fmaddsub_pd(a, b, c);
+        let e = _mm_setr_pd(1., 15.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmaddsub_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_fmaddsub_ps(a, b, c);
+        let e = _mm_setr_ps(1., 15., 20., 15.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fmaddsub_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_fmaddsub_pd(a, b, c);
+        let e = _mm256_setr_pd(1., 15., 20., 15.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fmaddsub_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 1., 4., 6., 9.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 3., 2., 8., 5.);
+        let r = _mm256_fmaddsub_ps(a, b, c);
+        let e = _mm256_setr_ps(1., 15., 20., 15., 2., 26., 34., 77.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmsubadd_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_fmsubadd_pd(a, b, c);
+        let e = _mm_setr_pd(9., -3.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmsubadd_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_fmsubadd_ps(a, b, c);
+        let e = _mm_setr_ps(9., -3., 22., 1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fmsubadd_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_fmsubadd_pd(a, b, c);
+        let e = _mm256_setr_pd(9., -3., 22., 1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm256_fmsubadd_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 1., 4., 6., 9.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 3., 2., 8., 5.);
+        let r = _mm256_fmsubadd_ps(a, b, c);
+        let e = _mm256_setr_ps(9., -3., 22., 1., 8., 22., 50., 67.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmadd_ps_single_rounding() {
+        // Rounding `a * b` to `f32` before adding `c` changes the result of
+        // every lane. The products and sums are exact in `f64`, so rounding
+        // the `f64` result once is the correctly rounded fused result.
+        let x = 1. + 1. / 4096.;
+        let a: [f32; 4] = [x, x, -x, 3.];
+        let b: [f32; 4] = [x, -x, x, 1. / 3.];
+        let c: [f32; 4] = [-1., 1., 1., -1.];
+        let r = _mm_fmadd_ps(
+            _mm_loadu_ps(a.as_ptr()),
+            _mm_loadu_ps(b.as_ptr()),
+            _mm_loadu_ps(c.as_ptr()),
+        );
+        let mut e = [0_f32; 4];
+        for i in 0..4 {
+            e[i] = (a[i] as f64 * b[i] as f64 + c[i] as f64) as f32;
+            assert!(e[i] != a[i] * b[i] + c[i]);
+        }
+        assert_eq_m128(r, _mm_loadu_ps(e.as_ptr()));
+    }
+
+    #[simd_test = "fma"]
+    unsafe fn test_mm_fmadd_pd_single_rounding() {
+        // (1 + 2^-27)^2 - 1 = 2^-26 + 2^-54, but the product rounds to
+        // 1 + 2^-26. 3 * fl(1 / 3) - 1 = -2^-54, but the product rounds to 1.
+        let x = 1. + 1. / 134217728.;
+        let a = _mm_setr_pd(x, 3.);
+        let b = _mm_setr_pd(x, 1. / 3.);
+        let c = _mm_setr_pd(-1., -1.);
+        let r = _mm_fmadd_pd(a, b, c);
+        let e = _mm_setr_pd(
+            1. / 67108864. + 1. / 18014398509481984.,
+            -1. / 18014398509481984.,
+        );
+        assert_eq_m128d(r, e);
+    }
+}
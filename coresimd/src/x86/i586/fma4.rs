@@ -0,0 +1,722 @@
+//! Four-operand Fused Multiply-Add (FMA4)
+//!
+//! FMA4 is AMD's variant of fused multiply-add, available on the Bulldozer
+//! family of processors. It computes the same results as the `fma`
+//! intrinsics, but encodes a separate destination register, and its scalar
+//! forms zero the upper elements instead of copying them from `a`.
+//!
+//! The reference is [AMD64 Architecture Programmer's Manual, Volume 6:
+//! 128-Bit and 256-Bit XOP and FMA4 Instructions][amd64_ref].
+//!
+//! [amd64_ref]: http://support.amd.com/TechDocs/43479.pdf
+
+use simd_llvm::simd_sub;
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.fma.vfmadd.pd"]
+    fn vfmaddpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfmadd.ps"]
+    fn vfmaddps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmadd.pd.256"]
+    fn vfmaddpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmadd.ps.256"]
+    fn vfmaddps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfmsub.pd"]
+    fn vfmsubpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfmsub.ps"]
+    fn vfmsubps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmsub.pd.256"]
+    fn vfmsubpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmsub.ps.256"]
+    fn vfmsubps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfnmadd.pd"]
+    fn vfnmaddpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfnmadd.ps"]
+    fn vfnmaddps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfnmadd.pd.256"]
+    fn vfnmaddpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfnmadd.ps.256"]
+    fn vfnmaddps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfnmsub.pd"]
+    fn vfnmsubpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfnmsub.ps"]
+    fn vfnmsubps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfnmsub.pd.256"]
+    fn vfnmsubpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfnmsub.ps.256"]
+    fn vfnmsubps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfmaddsub.pd"]
+    fn vfmaddsubpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfmaddsub.ps"]
+    fn vfmaddsubps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmaddsub.pd.256"]
+    fn vfmaddsubpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmaddsub.ps.256"]
+    fn vfmaddsubps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma.vfmsubadd.pd"]
+    fn vfmsubaddpd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.fma.vfmsubadd.ps"]
+    fn vfmsubaddps(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma.vfmsubadd.pd.256"]
+    fn vfmsubaddpd256(a: __m256d, b: __m256d, c: __m256d) -> __m256d;
+    #[link_name = "llvm.x86.fma.vfmsubadd.ps.256"]
+    fn vfmsubaddps256(a: __m256, b: __m256, c: __m256) -> __m256;
+    #[link_name = "llvm.x86.fma4.vfmadd.ss"]
+    fn vfmaddss(a: __m128, b: __m128, c: __m128) -> __m128;
+    #[link_name = "llvm.x86.fma4.vfmadd.sd"]
+    fn vfmaddsd(a: __m128d, b: __m128d, c: __m128d) -> __m128d;
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddpd))]
+pub unsafe fn _mm_macc_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmaddpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddps))]
+pub unsafe fn _mm_macc_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmaddps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddpd))]
+pub unsafe fn _mm256_macc_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    vfmaddpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddps))]
+pub unsafe fn _mm256_macc_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfmaddps256(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubpd))]
+pub unsafe fn _mm_msub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmsubpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubps))]
+pub unsafe fn _mm_msub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmsubps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubpd))]
+pub unsafe fn _mm256_msub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    vfmsubpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubps))]
+pub unsafe fn _mm256_msub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfmsubps256(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddpd))]
+pub unsafe fn _mm_nmacc_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfnmaddpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddps))]
+pub unsafe fn _mm_nmacc_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfnmaddps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddpd))]
+pub unsafe fn _mm256_nmacc_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    vfnmaddpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to packed elements in `c`.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddps))]
+pub unsafe fn _mm256_nmacc_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfnmaddps256(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubpd))]
+pub unsafe fn _mm_nmsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfnmsubpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubps))]
+pub unsafe fn _mm_nmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfnmsubps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubpd))]
+pub unsafe fn _mm256_nmsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    vfnmsubpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract packed elements in `c` from the negated intermediate
+/// result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubps))]
+pub unsafe fn _mm256_nmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfnmsubps256(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c` to/from
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddsubpd))]
+pub unsafe fn _mm_maddsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmaddsubpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c` to/from
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddsubps))]
+pub unsafe fn _mm_maddsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmaddsubps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c` to/from
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddsubpd))]
+pub unsafe fn _mm256_maddsub_pd(
+    a: __m256d, b: __m256d, c: __m256d
+) -> __m256d {
+    vfmaddsubpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively add and subtract packed elements in `c` to/from
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddsubps))]
+pub unsafe fn _mm256_maddsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfmaddsubps256(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c` from/to
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubaddpd))]
+pub unsafe fn _mm_msubadd_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmsubaddpd(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c` from/to
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubaddps))]
+pub unsafe fn _mm_msubadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmsubaddps(a, b, c)
+}
+
+/// Multiply packed double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c` from/to
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubaddpd))]
+pub unsafe fn _mm256_msubadd_pd(
+    a: __m256d, b: __m256d, c: __m256d
+) -> __m256d {
+    vfmsubaddpd256(a, b, c)
+}
+
+/// Multiply packed single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and alternatively subtract and add packed elements in `c` from/to
+/// the intermediate result.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubaddps))]
+pub unsafe fn _mm256_msubadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    vfmsubaddps256(a, b, c)
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to the lower element in `c`. Store
+/// the result in the lower element of the returned value, and zero the upper
+/// elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddsd))]
+pub unsafe fn _mm_macc_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmaddsd(a, b, c)
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the intermediate result to the lower element in `c`. Store
+/// the result in the lower element of the returned value, and zero the upper
+/// elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmaddss))]
+pub unsafe fn _mm_macc_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmaddss(a, b, c)
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the intermediate
+/// result. Store the result in the lower element of the returned value, and
+/// zero the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubsd))]
+pub unsafe fn _mm_msub_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmaddsd(a, b, simd_sub(_mm_set1_pd(-0.0), c))
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the intermediate
+/// result. Store the result in the lower element of the returned value, and
+/// zero the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfmsubss))]
+pub unsafe fn _mm_msub_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmaddss(a, b, simd_sub(_mm_set1_ps(-0.0), c))
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to the lower element in
+/// `c`. Store the result in the lower element of the returned value, and zero
+/// the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddsd))]
+pub unsafe fn _mm_nmacc_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    vfmaddsd(simd_sub(_mm_set1_pd(-0.0), a), b, c)
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and add the negated intermediate result to the lower element in
+/// `c`. Store the result in the lower element of the returned value, and zero
+/// the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmaddss))]
+pub unsafe fn _mm_nmacc_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    vfmaddss(simd_sub(_mm_set1_ps(-0.0), a), b, c)
+}
+
+/// Multiply the lower double-precision (64-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the negated
+/// intermediate result. Store the result in the lower element of the returned
+/// value, and zero the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubsd))]
+pub unsafe fn _mm_nmsub_sd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    let a = simd_sub(_mm_set1_pd(-0.0), a);
+    vfmaddsd(a, b, simd_sub(_mm_set1_pd(-0.0), c))
+}
+
+/// Multiply the lower single-precision (32-bit) floating-point elements in `a`
+/// and `b`, and subtract the lower element in `c` from the negated
+/// intermediate result. Store the result in the lower element of the returned
+/// value, and zero the upper elements.
+#[inline]
+#[target_feature(enable = "fma4")]
+#[cfg_attr(test, assert_instr(vfnmsubss))]
+pub unsafe fn _mm_nmsub_ss(a: __m128, b: __m128, c: __m128) -> __m128 {
+    let a = simd_sub(_mm_set1_ps(-0.0), a);
+    vfmaddss(a, b, simd_sub(_mm_set1_ps(-0.0), c))
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_macc_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_macc_pd(a, b, c);
+        let e = _mm_setr_pd(9., 15.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_macc_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_macc_ps(a, b, c);
+        let e = _mm_setr_ps(9., 15., 22., 15.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_macc_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_macc_pd(a, b, c);
+        let e = _mm256_setr_pd(9., 15., 22., 15.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_macc_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 1., 2., 3., 4.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 5., 3., 7., 2.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 4., 9., 1., 7.);
+        let r = _mm256_macc_ps(a, b, c);
+        let e = _mm256_setr_ps(9., 15., 22., 15., 9., 15., 22., 15.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_msub_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_msub_pd(a, b, c);
+        let e = _mm_setr_pd(1., -3.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_msub_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_msub_ps(a, b, c);
+        let e = _mm_setr_ps(1., -3., 20., 1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_msub_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_msub_pd(a, b, c);
+        let e = _mm256_setr_pd(1., -3., 20., 1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_msub_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 1., 2., 3., 4.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 5., 3., 7., 2.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 4., 9., 1., 7.);
+        let r = _mm256_msub_ps(a, b, c);
+        let e = _mm256_setr_ps(1., -3., 20., 1., 1., -3., 20., 1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_nmacc_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_nmacc_pd(a, b, c);
+        let e = _mm_setr_pd(-1., 3.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_nmacc_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_nmacc_ps(a, b, c);
+        let e = _mm_setr_ps(-1., 3., -20., -1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_nmacc_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_nmacc_pd(a, b, c);
+        let e = _mm256_setr_pd(-1., 3., -20., -1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_nmacc_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 1., 2., 3., 4.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 5., 3., 7., 2.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 4., 9., 1., 7.);
+        let r = _mm256_nmacc_ps(a, b, c);
+        let e = _mm256_setr_ps(-1., 3., -20., -1., -1., 3., -20., -1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_nmsub_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_nmsub_pd(a, b, c);
+        let e = _mm_setr_pd(-9., -15.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_nmsub_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_nmsub_ps(a, b, c);
+        let e = _mm_setr_ps(-9., -15., -22., -15.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_nmsub_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_nmsub_pd(a, b, c);
+        let e = _mm256_setr_pd(-9., -15., -22., -15.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_nmsub_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 1., 2., 3., 4.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 5., 3., 7., 2.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 4., 9., 1., 7.);
+        let r = _mm256_nmsub_ps(a, b, c);
+        let e = _mm256_setr_ps(-9., -15., -22., -15., -9., -15., -22., -15.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_maddsub_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_maddsub_pd(a, b, c);
+        let e = _mm_setr_pd(1., 15.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_maddsub_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_maddsub_ps(a, b, c);
+        let e = _mm_setr_ps(1., 15., 20., 15.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_maddsub_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_maddsub_pd(a, b, c);
+        let e = _mm256_setr_pd(1., 15., 20., 15.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_maddsub_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 1., 2., 3., 4.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 5., 3., 7., 2.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 4., 9., 1., 7.);
+        let r = _mm256_maddsub_ps(a, b, c);
+        let e = _mm256_setr_ps(1., 15., 20., 15., 1., 15., 20., 15.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_msubadd_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_msubadd_pd(a, b, c);
+        let e = _mm_setr_pd(9., -3.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_msubadd_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_msubadd_ps(a, b, c);
+        let e = _mm_setr_ps(9., -3., 22., 1.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_msubadd_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 3., 7., 2.);
+        let c = _mm256_setr_pd(4., 9., 1., 7.);
+        let r = _mm256_msubadd_pd(a, b, c);
+        let e = _mm256_setr_pd(9., -3., 22., 1.);
+        assert_eq_m256d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm256_msubadd_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 1., 2., 3., 4.);
+        let b = _mm256_setr_ps(5., 3., 7., 2., 5., 3., 7., 2.);
+        let c = _mm256_setr_ps(4., 9., 1., 7., 4., 9., 1., 7.);
+        let r = _mm256_msubadd_ps(a, b, c);
+        let e = _mm256_setr_ps(9., -3., 22., 1., 9., -3., 22., 1.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_macc_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_macc_sd(a, b, c);
+        let e = _mm_setr_pd(9., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_macc_ss() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_macc_ss(a, b, c);
+        let e = _mm_setr_ps(9., 0., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_msub_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_msub_sd(a, b, c);
+        let e = _mm_setr_pd(1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_msub_ss() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_msub_ss(a, b, c);
+        let e = _mm_setr_ps(1., 0., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_nmacc_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_nmacc_sd(a, b, c);
+        let e = _mm_setr_pd(-1., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_nmacc_ss() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_nmacc_ss(a, b, c);
+        let e = _mm_setr_ps(-1., 0., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_nmsub_sd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(5., 3.);
+        let c = _mm_setr_pd(4., 9.);
+        let r = _mm_nmsub_sd(a, b, c);
+        let e = _mm_setr_pd(-9., 0.);
+        assert_eq_m128d(r, e);
+    }
+
+    #[simd_test = "fma4"]
+    unsafe fn test_mm_nmsub_ss() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 3., 7., 2.);
+        let c = _mm_setr_ps(4., 9., 1., 7.);
+        let r = _mm_nmsub_ss(a, b, c);
+        let e = _mm_setr_ps(-9., 0., 0., 0.);
+        assert_eq_m128(r, e);
+    }
+}
@@ -0,0 +1,406 @@
+//! Galois Field New Instructions (GFNI)
+//!
+//! These operate on bytes as elements of the finite field GF(2^8), using
+//! the reduction polynomial x^8 + x^4 + x^3 + x + 1 of AES, and on 8x8 bit
+//! matrices for affine transformations of those bytes.
+//!
+//! The 256-bit forms additionally require AVX, and the 512-bit forms
+//! AVX-512F.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use core::mem;
+
+use v128::*;
+use v256::*;
+use v512::*;
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.vgf2p8mulb.128"]
+    fn vgf2p8mulb_128(a: i8x16, b: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.vgf2p8mulb.256"]
+    fn vgf2p8mulb_256(a: i8x32, b: i8x32) -> i8x32;
+    #[link_name = "llvm.x86.vgf2p8mulb.512"]
+    fn vgf2p8mulb_512(a: i8x64, b: i8x64) -> i8x64;
+    #[link_name = "llvm.x86.vgf2p8affineqb.128"]
+    fn vgf2p8affineqb_128(x: i8x16, a: i8x16, b: u8) -> i8x16;
+    #[link_name = "llvm.x86.vgf2p8affineqb.256"]
+    fn vgf2p8affineqb_256(x: i8x32, a: i8x32, b: u8) -> i8x32;
+    #[link_name = "llvm.x86.vgf2p8affineqb.512"]
+    fn vgf2p8affineqb_512(x: i8x64, a: i8x64, b: u8) -> i8x64;
+    #[link_name = "llvm.x86.vgf2p8affineinvqb.128"]
+    fn vgf2p8affineinvqb_128(x: i8x16, a: i8x16, b: u8) -> i8x16;
+    #[link_name = "llvm.x86.vgf2p8affineinvqb.256"]
+    fn vgf2p8affineinvqb_256(x: i8x32, a: i8x32, b: u8) -> i8x32;
+    #[link_name = "llvm.x86.vgf2p8affineinvqb.512"]
+    fn vgf2p8affineinvqb_512(x: i8x64, a: i8x64, b: u8) -> i8x64;
+}
+
+/// Multiply the packed 8-bit integers in `a` and `b` as elements of
+/// GF(2^8), reducing the products by the polynomial
+/// x^8 + x^4 + x^3 + x + 1.
+#[inline]
+#[target_feature(enable = "gfni")]
+#[cfg_attr(test, assert_instr(gf2p8mulb))]
+pub unsafe fn _mm_gf2p8mul_epi8(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vgf2p8mulb_128(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Multiply the packed 8-bit integers in `a` and `b` as elements of
+/// GF(2^8), reducing the products by the polynomial
+/// x^8 + x^4 + x^3 + x + 1.
+#[inline]
+#[target_feature(enable = "gfni,avx")]
+#[cfg_attr(test, assert_instr(vgf2p8mulb))]
+pub unsafe fn _mm256_gf2p8mul_epi8(a: __m256i, b: __m256i) -> __m256i {
+    mem::transmute(vgf2p8mulb_256(a.as_i8x32(), b.as_i8x32()))
+}
+
+/// Multiply the packed 8-bit integers in `a` and `b` as elements of
+/// GF(2^8), reducing the products by the polynomial
+/// x^8 + x^4 + x^3 + x + 1.
+#[inline]
+#[target_feature(enable = "gfni,avx512f")]
+#[cfg_attr(test, assert_instr(vgf2p8mulb))]
+pub unsafe fn _mm512_gf2p8mul_epi8(a: __m512i, b: __m512i) -> __m512i {
+    mem::transmute(vgf2p8mulb_512(a.as_i8x64(), b.as_i8x64()))
+}
+
+/// Perform an affine transformation of each packed 8-bit integer in `x`.
+///
+/// Each byte of `x` is multiplied, as a bit vector, by the 8x8 bit matrix
+/// held in the corresponding 64-bit element of `a`, and the result is
+/// XOR-ed with the constant `b`.
+#[inline]
+#[target_feature(enable = "gfni")]
+#[cfg_attr(test, assert_instr(gf2p8affineqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_gf2p8affine_epi64_epi8(
+    x: __m128i, a: __m128i, b: i32
+) -> __m128i {
+    let x = x.as_i8x16();
+    let a = a.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_128(x, a, $imm8)
+        };
+    }
+    mem::transmute(constify_imm8!(b, call))
+}
+
+/// Perform an affine transformation of each packed 8-bit integer in `x`.
+///
+/// Each byte of `x` is multiplied, as a bit vector, by the 8x8 bit matrix
+/// held in the corresponding 64-bit element of `a`, and the result is
+/// XOR-ed with the constant `b`.
+#[inline]
+#[target_feature(enable = "gfni,avx")]
+#[cfg_attr(test, assert_instr(vgf2p8affineqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_gf2p8affine_epi64_epi8(
+    x: __m256i, a: __m256i, b: i32
+) -> __m256i {
+    let x = x.as_i8x32();
+    let a = a.as_i8x32();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_256(x, a, $imm8)
+        };
+    }
+    mem::transmute(constify_imm8!(b, call))
+}
+
+/// Perform an affine transformation of each packed 8-bit integer in `x`.
+///
+/// Each byte of `x` is multiplied, as a bit vector, by the 8x8 bit matrix
+/// held in the corresponding 64-bit element of `a`, and the result is
+/// XOR-ed with the constant `b`.
+#[inline]
+#[target_feature(enable = "gfni,avx512f")]
+#[cfg_attr(test, assert_instr(vgf2p8affineqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_gf2p8affine_epi64_epi8(
+    x: __m512i, a: __m512i, b: i32
+) -> __m512i {
+    let x = x.as_i8x64();
+    let a = a.as_i8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineqb_512(x, a, $imm8)
+        };
+    }
+    mem::transmute(constify_imm8!(b, call))
+}
+
+/// Perform an affine transformation of the multiplicative inverse in
+/// GF(2^8) of each packed 8-bit integer in `x`.
+///
+/// The inverse of each byte of `x` (with zero mapped to zero) is
+/// multiplied, as a bit vector, by the 8x8 bit matrix held in the
+/// corresponding 64-bit element of `a`, and the result is XOR-ed with the
+/// constant `b`.
+#[inline]
+#[target_feature(enable = "gfni")]
+#[cfg_attr(test, assert_instr(gf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_gf2p8affineinv_epi64_epi8(
+    x: __m128i, a: __m128i, b: i32
+) -> __m128i {
+    let x = x.as_i8x16();
+    let a = a.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_128(x, a, $imm8)
+        };
+    }
+    mem::transmute(constify_imm8!(b, call))
+}
+
+/// Perform an affine transformation of the multiplicative inverse in
+/// GF(2^8) of each packed 8-bit integer in `x`.
+///
+/// The inverse of each byte of `x` (with zero mapped to zero) is
+/// multiplied, as a bit vector, by the 8x8 bit matrix held in the
+/// corresponding 64-bit element of `a`, and the result is XOR-ed with the
+/// constant `b`.
+#[inline]
+#[target_feature(enable = "gfni,avx")]
+#[cfg_attr(test, assert_instr(vgf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_gf2p8affineinv_epi64_epi8(
+    x: __m256i, a: __m256i, b: i32
+) -> __m256i {
+    let x = x.as_i8x32();
+    let a = a.as_i8x32();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_256(x, a, $imm8)
+        };
+    }
+    mem::transmute(constify_imm8!(b, call))
+}
+
+/// Perform an affine transformation of the multiplicative inverse in
+/// GF(2^8) of each packed 8-bit integer in `x`.
+///
+/// The inverse of each byte of `x` (with zero mapped to zero) is
+/// multiplied, as a bit vector, by the 8x8 bit matrix held in the
+/// corresponding 64-bit element of `a`, and the result is XOR-ed with the
+/// constant `b`.
+#[inline]
+#[target_feature(enable = "gfni,avx512f")]
+#[cfg_attr(test, assert_instr(vgf2p8affineinvqb, b = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_gf2p8affineinv_epi64_epi8(
+    x: __m512i, a: __m512i, b: i32
+) -> __m512i {
+    let x = x.as_i8x64();
+    let a = a.as_i8x64();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vgf2p8affineinvqb_512(x, a, $imm8)
+        };
+    }
+    mem::transmute(constify_imm8!(b, call))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    /// Software reference: product of `a` and `b` in GF(2^8) modulo
+    /// x^8 + x^4 + x^3 + x + 1.
+    fn mul(mut a: u8, mut b: u8) -> u8 {
+        let mut r = 0;
+        while b != 0 {
+            if b & 1 != 0 {
+                r ^= a;
+            }
+            let carry = a & 0x80 != 0;
+            a <<= 1;
+            if carry {
+                a ^= 0x1b;
+            }
+            b >>= 1;
+        }
+        r
+    }
+
+    /// Software reference: multiplicative inverse in GF(2^8), computed as
+    /// `x^254`, which maps zero to zero.
+    fn inv(x: u8) -> u8 {
+        let mut r = 1;
+        for _ in 0..254 {
+            r = mul(r, x);
+        }
+        r
+    }
+
+    /// Software reference: affine transformation of `x` by the bit matrix
+    /// `a` and the constant `b`.
+    fn affine(x: u8, a: u64, b: u8) -> u8 {
+        let mut r = 0;
+        for i in 0..8 {
+            let row = (a >> (8 * (7 - i))) as u8;
+            let bit = (row & x).count_ones() as u8 & 1;
+            r |= bit << i;
+        }
+        r ^ b
+    }
+
+    /// The identity matrix of the affine transformation.
+    const IDENTITY: u64 = 0x0102_0408_1020_4080;
+    /// The matrix of the AES S-box affine transformation, which is applied
+    /// with the constant `0x63`.
+    const AES: u64 = 0xf1e3_c78f_1f3e_7cf8;
+
+    /// All 256 byte values, in order.
+    fn bytes() -> [u8; 256] {
+        let mut r = [0; 256];
+        for i in 0..256 {
+            r[i] = i as u8;
+        }
+        r
+    }
+
+    #[simd_test = "gfni"]
+    unsafe fn test_mm_gf2p8mul_epi8() {
+        let all = bytes();
+        for chunk in all.chunks(16) {
+            let a = _mm_loadu_si128(chunk.as_ptr() as *const _);
+            for &b in &[0, 1, 2, 3, 0x1b, 0x53, 0x80, 0xca, 0xff] {
+                let r = _mm_gf2p8mul_epi8(a, _mm_set1_epi8(b as i8));
+                let r: [u8; 16] = mem::transmute(r);
+                for i in 0..16 {
+                    assert_eq!(r[i], mul(chunk[i], b));
+                }
+            }
+        }
+        // the example of FIPS-197, section 4.2
+        let r = _mm_gf2p8mul_epi8(_mm_set1_epi8(0x57), _mm_set1_epi8(0x13));
+        assert_eq_m128i(r, _mm_set1_epi8(0xfeu8 as i8));
+    }
+
+    #[simd_test = "gfni,avx"]
+    unsafe fn test_mm256_gf2p8mul_epi8() {
+        let all = bytes();
+        for chunk in all.chunks(32) {
+            let a = _mm256_loadu_si256(chunk.as_ptr() as *const _);
+            let b = _mm256_loadu_si256(all[256 - 32..].as_ptr() as *const _);
+            let r: [u8; 32] = mem::transmute(_mm256_gf2p8mul_epi8(a, b));
+            for i in 0..32 {
+                assert_eq!(r[i], mul(chunk[i], all[256 - 32 + i]));
+            }
+        }
+    }
+
+    #[simd_test = "gfni,avx512f"]
+    unsafe fn test_mm512_gf2p8mul_epi8() {
+        let all = bytes();
+        for chunk in all.chunks(64) {
+            let a = _mm512_loadu_si512(chunk.as_ptr() as *const _);
+            let b = _mm512_loadu_si512(all[128..].as_ptr() as *const _);
+            let r: [u8; 64] = mem::transmute(_mm512_gf2p8mul_epi8(a, b));
+            for i in 0..64 {
+                assert_eq!(r[i], mul(chunk[i], all[128 + i]));
+            }
+        }
+    }
+
+    #[simd_test = "gfni"]
+    unsafe fn test_mm_gf2p8affine_epi64_epi8() {
+        let all = bytes();
+        let m = _mm_set_epi64x(AES as i64, IDENTITY as i64);
+        for chunk in all.chunks(16) {
+            let x = _mm_loadu_si128(chunk.as_ptr() as *const _);
+            let r = _mm_gf2p8affine_epi64_epi8(x, m, 0);
+            let r: [u8; 16] = mem::transmute(r);
+            for i in 0..8 {
+                assert_eq!(r[i], chunk[i]);
+                assert_eq!(r[i + 8], affine(chunk[i + 8], AES, 0));
+            }
+            let r = _mm_gf2p8affine_epi64_epi8(x, m, 0x63);
+            let r: [u8; 16] = mem::transmute(r);
+            for i in 0..8 {
+                assert_eq!(r[i], chunk[i] ^ 0x63);
+                assert_eq!(r[i + 8], affine(chunk[i + 8], AES, 0x63));
+            }
+        }
+    }
+
+    #[simd_test = "gfni"]
+    unsafe fn test_mm_gf2p8affineinv_epi64_epi8() {
+        let all = bytes();
+        let m = _mm_set1_epi64x(AES as i64);
+        for chunk in all.chunks(16) {
+            let x = _mm_loadu_si128(chunk.as_ptr() as *const _);
+            // the AES S-box
+            let r = _mm_gf2p8affineinv_epi64_epi8(x, m, 0x63);
+            let r: [u8; 16] = mem::transmute(r);
+            for i in 0..16 {
+                assert_eq!(r[i], affine(inv(chunk[i]), AES, 0x63));
+            }
+            let id = _mm_set1_epi64x(IDENTITY as i64);
+            let r = _mm_gf2p8affineinv_epi64_epi8(x, id, 0);
+            let r: [u8; 16] = mem::transmute(r);
+            for i in 0..16 {
+                assert_eq!(r[i], inv(chunk[i]));
+            }
+        }
+        // the first entries of the S-box of FIPS-197, figure 7
+        let x = _mm_setr_epi8(0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        let r = _mm_gf2p8affineinv_epi64_epi8(x, m, 0x63);
+        let r: [u8; 16] = mem::transmute(r);
+        assert_eq!(&r[..4], &[0x63, 0x7c, 0x77, 0x7b]);
+    }
+
+    #[simd_test = "gfni,avx"]
+    unsafe fn test_mm256_gf2p8affine_epi64_epi8() {
+        let all = bytes();
+        let m = _mm256_set1_epi64x(AES as i64);
+        for chunk in all.chunks(32) {
+            let x = _mm256_loadu_si256(chunk.as_ptr() as *const _);
+            let r = _mm256_gf2p8affine_epi64_epi8(x, m, 0x63);
+            let r: [u8; 32] = mem::transmute(r);
+            for i in 0..32 {
+                assert_eq!(r[i], affine(chunk[i], AES, 0x63));
+            }
+            let r = _mm256_gf2p8affineinv_epi64_epi8(x, m, 0x63);
+            let r: [u8; 32] = mem::transmute(r);
+            for i in 0..32 {
+                assert_eq!(r[i], affine(inv(chunk[i]), AES, 0x63));
+            }
+        }
+    }
+
+    #[simd_test = "gfni,avx512f"]
+    unsafe fn test_mm512_gf2p8affine_epi64_epi8() {
+        let all = bytes();
+        let m = _mm512_set1_epi64(AES as i64);
+        for chunk in all.chunks(64) {
+            let x = _mm512_loadu_si512(chunk.as_ptr() as *const _);
+            let r = _mm512_gf2p8affine_epi64_epi8(x, m, 0x63);
+            let r: [u8; 64] = mem::transmute(r);
+            for i in 0..64 {
+                assert_eq!(r[i], affine(chunk[i], AES, 0x63));
+            }
+            let r = _mm512_gf2p8affineinv_epi64_epi8(x, m, 0x63);
+            let r: [u8; 64] = mem::transmute(r);
+            for i in 0..64 {
+                assert_eq!(r[i], affine(inv(chunk[i]), AES, 0x63));
+            }
+        }
+    }
+}
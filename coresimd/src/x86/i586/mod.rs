@@ -6,8 +6,16 @@ pub use self::xsave::*;
 pub use self::bswap::*;
 
 pub use self::rdtsc::*;
+pub use self::rdrand::*;
+
+pub use self::rtm::*;
+
+pub use self::clflushopt::*;
+pub use self::clwb::*;
+pub use self::prfchw::*;
 
 pub use self::sse::*;
+pub use self::mxcsr::*;
 pub use self::sse2::*;
 pub use self::sse3::*;
 pub use self::ssse3::*;
@@ -15,13 +23,34 @@ pub use self::sse41::*;
 pub use self::sse42::*;
 pub use self::avx::*;
 pub use self::avx2::*;
+pub use self::avx512f::*;
+pub use self::avx512bw::*;
+pub use self::avx512cd::*;
+pub use self::avx512dq::*;
+pub use self::avx512ifma::*;
+pub use self::avx512vbmi::*;
+pub use self::avx512vpopcntdq::*;
+pub use self::fma::*;
+pub use self::f16c::*;
 
 pub use self::abm::*;
 pub use self::bmi::*;
 pub use self::bmi2::*;
+pub use self::adx::*;
+
+pub use self::aes::*;
+pub use self::pclmulqdq::*;
+pub use self::sha::*;
+pub use self::gfni::*;
+pub use self::vaes::*;
+pub use self::vpclmulqdq::*;
 
 #[cfg(not(feature = "intel_sde"))]
 pub use self::tbm::*;
+#[cfg(not(feature = "intel_sde"))]
+pub use self::xop::*;
+#[cfg(not(feature = "intel_sde"))]
+pub use self::fma4::*;
 
 mod cpuid;
 mod xsave;
@@ -29,8 +58,16 @@ mod xsave;
 mod bswap;
 
 mod rdtsc;
+mod rdrand;
+
+mod rtm;
+
+mod clflushopt;
+mod clwb;
+mod prfchw;
 
 mod sse;
+mod mxcsr;
 mod sse2;
 mod sse3;
 mod ssse3;
@@ -38,10 +75,31 @@ mod sse41;
 mod sse42;
 mod avx;
 mod avx2;
+mod avx512f;
+mod avx512bw;
+mod avx512cd;
+mod avx512dq;
+mod avx512ifma;
+mod avx512vbmi;
+mod avx512vpopcntdq;
+mod fma;
+mod f16c;
 
 mod abm;
 mod bmi;
 mod bmi2;
+mod adx;
+
+mod aes;
+mod pclmulqdq;
+mod sha;
+mod gfni;
+mod vaes;
+mod vpclmulqdq;
 
 #[cfg(not(feature = "intel_sde"))]
 mod tbm;
+#[cfg(not(feature = "intel_sde"))]
+mod xop;
+#[cfg(not(feature = "intel_sde"))]
+mod fma4;
@@ -0,0 +1,516 @@
+//! Scoped control of the MXCSR register.
+//!
+//! The `_MM_SET_*` functions of `sse.rs` modify the MXCSR register for the
+//! rest of the thread, so callers must remember to restore it. A
+//! [`MxcsrGuard`](struct.MxcsrGuard.html) loads a [`Mxcsr`](struct.Mxcsr.html)
+//! value and restores the previous one when it goes out of scope, including
+//! when unwinding from a panic. Creating a guard is `unsafe` because LLVM
+//! assumes that floating-point code runs with the default value of the
+//! register, see [`MxcsrGuard::new`](struct.MxcsrGuard.html#method.new):
+//!
+//! ```rust
+//! # #![feature(cfg_target_feature)]
+//! # #![feature(target_feature)]
+//! #
+//! # #[macro_use] extern crate stdsimd;
+//! #
+//! # fn main() {
+//! #   use stdsimd::vendor::*;
+//! #
+//! if let Some(sse) = Sse::detect() {
+//!     let saved = Mxcsr::current(sse);
+//!     let mxcsr = saved
+//!         .with_rounding_mode(RoundingMode::TowardZero)
+//!         .with_flush_to_zero(FlushToZero::On);
+//!     {
+//!         let _guard = unsafe { MxcsrGuard::new(sse, mxcsr) };
+//!         assert_eq!(Mxcsr::current(sse), mxcsr);
+//!     }
+//!     assert_eq!(Mxcsr::current(sse), saved);
+//! }
+//! # }
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::{BitAnd, BitOr, Not};
+
+use x86::*;
+
+/// The denormals-are-zero flag, which `sse.rs` does not define.
+const DENORMALS_ZERO: u32 = 0x0040;
+
+/// Rounding mode of the SSE floating-point operations.
+///
+/// See [`_MM_GET_ROUNDING_MODE`](fn._MM_GET_ROUNDING_MODE.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even (default).
+    Nearest,
+    /// Round toward negative infinity.
+    Down,
+    /// Round toward positive infinity.
+    Up,
+    /// Round toward zero.
+    TowardZero,
+}
+
+/// Whether denormal results are flushed to zero.
+///
+/// See [`_MM_GET_FLUSH_ZERO_MODE`](fn._MM_GET_FLUSH_ZERO_MODE.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FlushToZero {
+    /// Denormal results are produced (default).
+    Off,
+    /// Denormal results are replaced by a zero of the same sign.
+    On,
+}
+
+/// Whether denormal inputs are treated as zero.
+///
+/// Not every CPU supports this mode, not even every SSE2 CPU.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DenormalsAreZero {
+    /// Denormal inputs are used as they are (default).
+    Off,
+    /// Denormal inputs are replaced by a zero of the same sign.
+    On,
+}
+
+/// A set of SSE floating-point exceptions.
+///
+/// See [`_mm_setcsr`](fn._mm_setcsr.html) for the meaning of each exception.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Exceptions(u32);
+
+impl Exceptions {
+    /// No exception.
+    pub const NONE: Exceptions = Exceptions(0);
+    /// Invalid operation, e.g. `0.0 / 0.0`.
+    pub const INVALID: Exceptions = Exceptions(_MM_EXCEPT_INVALID);
+    /// An operand was denormal.
+    pub const DENORMAL: Exceptions = Exceptions(_MM_EXCEPT_DENORM);
+    /// Division by zero.
+    pub const DIV_ZERO: Exceptions = Exceptions(_MM_EXCEPT_DIV_ZERO);
+    /// The result was too large to be represented.
+    pub const OVERFLOW: Exceptions = Exceptions(_MM_EXCEPT_OVERFLOW);
+    /// The result was too small to be represented.
+    pub const UNDERFLOW: Exceptions = Exceptions(_MM_EXCEPT_UNDERFLOW);
+    /// The result was rounded.
+    pub const INEXACT: Exceptions = Exceptions(_MM_EXCEPT_INEXACT);
+    /// All exceptions.
+    pub const ALL: Exceptions = Exceptions(_MM_EXCEPT_MASK);
+
+    /// Returns the exceptions as `_MM_EXCEPT_*` flags.
+    #[inline]
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether the set is empty.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns whether all exceptions of `other` are in the set.
+    #[inline]
+    pub fn contains(self, other: Exceptions) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns the exceptions raised by the current thread since their flags
+    /// were last cleared.
+    ///
+    /// The flags are sticky: the SSE floating-point operations set them but
+    /// never clear them.
+    #[inline]
+    pub fn raised(sse: Sse) -> Exceptions {
+        Mxcsr::current(sse).exceptions()
+    }
+
+    /// Clears the exception flags of the current thread.
+    ///
+    /// # Safety
+    ///
+    /// LLVM assumes that the MXCSR register is not accessed, so it may move
+    /// floating-point operations across this call. The caller must not rely
+    /// on which exceptions of the surrounding operations are cleared.
+    #[inline]
+    pub unsafe fn clear(sse: Sse) {
+        Mxcsr::current(sse)
+            .with_exceptions(Exceptions::NONE)
+            .load(sse)
+    }
+}
+
+impl BitOr for Exceptions {
+    type Output = Exceptions;
+    #[inline]
+    fn bitor(self, other: Exceptions) -> Exceptions {
+        Exceptions(self.0 | other.0)
+    }
+}
+
+impl BitAnd for Exceptions {
+    type Output = Exceptions;
+    #[inline]
+    fn bitand(self, other: Exceptions) -> Exceptions {
+        Exceptions(self.0 & other.0)
+    }
+}
+
+impl Not for Exceptions {
+    type Output = Exceptions;
+    #[inline]
+    fn not(self) -> Exceptions {
+        Exceptions(!self.0 & _MM_EXCEPT_MASK)
+    }
+}
+
+/// A value of the MXCSR control and status register.
+///
+/// The `with_*` methods build a new value, which takes effect once it is
+/// loaded with a [`MxcsrGuard`](struct.MxcsrGuard.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mxcsr(u32);
+
+impl Default for Mxcsr {
+    /// Returns the value of the register at power-up: all exceptions
+    /// masked, no exception raised, round to nearest, and neither
+    /// flush-to-zero nor denormals-are-zero.
+    #[inline]
+    fn default() -> Mxcsr {
+        Mxcsr(_MM_MASK_MASK)
+    }
+}
+
+impl Mxcsr {
+    /// Returns the value of the register in the current thread.
+    #[inline]
+    pub fn current(_: Sse) -> Mxcsr {
+        Mxcsr(unsafe { _mm_getcsr() })
+    }
+
+    /// Returns the raw value of the register.
+    #[inline]
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the rounding mode.
+    #[inline]
+    pub fn rounding_mode(self) -> RoundingMode {
+        match self.0 & _MM_ROUND_MASK {
+            _MM_ROUND_NEAREST => RoundingMode::Nearest,
+            _MM_ROUND_DOWN => RoundingMode::Down,
+            _MM_ROUND_UP => RoundingMode::Up,
+            _ => RoundingMode::TowardZero,
+        }
+    }
+
+    /// Returns the value with the rounding mode set to `mode`.
+    #[inline]
+    pub fn with_rounding_mode(self, mode: RoundingMode) -> Mxcsr {
+        let bits = match mode {
+            RoundingMode::Nearest => _MM_ROUND_NEAREST,
+            RoundingMode::Down => _MM_ROUND_DOWN,
+            RoundingMode::Up => _MM_ROUND_UP,
+            RoundingMode::TowardZero => _MM_ROUND_TOWARD_ZERO,
+        };
+        Mxcsr((self.0 & !_MM_ROUND_MASK) | bits)
+    }
+
+    /// Returns the flush-to-zero mode.
+    #[inline]
+    pub fn flush_to_zero(self) -> FlushToZero {
+        if self.0 & _MM_FLUSH_ZERO_MASK == _MM_FLUSH_ZERO_ON {
+            FlushToZero::On
+        } else {
+            FlushToZero::Off
+        }
+    }
+
+    /// Returns the value with the flush-to-zero mode set to `mode`.
+    #[inline]
+    pub fn with_flush_to_zero(self, mode: FlushToZero) -> Mxcsr {
+        let bits = match mode {
+            FlushToZero::Off => _MM_FLUSH_ZERO_OFF,
+            FlushToZero::On => _MM_FLUSH_ZERO_ON,
+        };
+        Mxcsr((self.0 & !_MM_FLUSH_ZERO_MASK) | bits)
+    }
+
+    /// Returns the denormals-are-zero mode.
+    #[inline]
+    pub fn denormals_are_zero(self) -> DenormalsAreZero {
+        if self.0 & DENORMALS_ZERO != 0 {
+            DenormalsAreZero::On
+        } else {
+            DenormalsAreZero::Off
+        }
+    }
+
+    /// Returns the value with the denormals-are-zero mode set to `mode`.
+    #[inline]
+    pub fn with_denormals_are_zero(self, mode: DenormalsAreZero) -> Mxcsr {
+        match mode {
+            DenormalsAreZero::Off => Mxcsr(self.0 & !DENORMALS_ZERO),
+            DenormalsAreZero::On => Mxcsr(self.0 | DENORMALS_ZERO),
+        }
+    }
+
+    /// Returns the masked exceptions, which do not trap when raised.
+    #[inline]
+    pub fn masked_exceptions(self) -> Exceptions {
+        Exceptions((self.0 & _MM_MASK_MASK) >> 7)
+    }
+
+    /// Returns the value with the masked exceptions set to `masked`.
+    ///
+    /// Unmasking an exception makes the operations that raise it trap,
+    /// which terminates the process with `SIGFPE` on most platforms.
+    #[inline]
+    pub fn with_masked_exceptions(self, masked: Exceptions) -> Mxcsr {
+        Mxcsr((self.0 & !_MM_MASK_MASK) | (masked.0 << 7))
+    }
+
+    /// Returns the raised exceptions, see
+    /// [`Exceptions::raised`](struct.Exceptions.html#method.raised).
+    #[inline]
+    pub fn exceptions(self) -> Exceptions {
+        Exceptions(self.0 & _MM_EXCEPT_MASK)
+    }
+
+    /// Returns the value with the raised exceptions set to `raised`.
+    #[inline]
+    pub fn with_exceptions(self, raised: Exceptions) -> Mxcsr {
+        Mxcsr((self.0 & !_MM_EXCEPT_MASK) | raised.0)
+    }
+
+    /// Loads the value into the register of the current thread.
+    #[inline]
+    fn load(self, _: Sse) {
+        if self.denormals_are_zero() == DenormalsAreZero::On {
+            assert!(
+                supports_denormals_are_zero(),
+                "denormals-are-zero mode is not supported by the host CPU"
+            );
+        }
+        unsafe { _mm_setcsr(self.0) }
+    }
+}
+
+/// Returns whether the host CPU supports the denormals-are-zero mode.
+///
+/// Setting an unsupported MXCSR bit raises a general-protection exception.
+/// The bits that can be set are given by the MXCSR_MASK field stored by
+/// `FXSAVE`, where zero stands for the default mask `0xffbf`, which does not
+/// include the denormals-are-zero bit.
+fn supports_denormals_are_zero() -> bool {
+    #[repr(align(16))]
+    struct FxsaveArea([u8; 512]);
+
+    if !cfg_feature_enabled!("fxsr") {
+        return false;
+    }
+    let mut area = FxsaveArea([0; 512]);
+    unsafe { _fxsave(area.0.as_mut_ptr()) };
+    // MXCSR_MASK is stored at offset 28 of the area.
+    let mask = area.0[28..32]
+        .iter()
+        .rev()
+        .fold(0, |mask, &b| mask << 8 | u32::from(b));
+    mask & DENORMALS_ZERO != 0
+}
+
+/// Loads a value into the MXCSR register for the lifetime of the guard.
+///
+/// Dropping the guard restores the whole previous value of the register,
+/// including the exception flags. Query
+/// [`Exceptions::raised`](struct.Exceptions.html#method.raised) before the
+/// guard goes out of scope to check the exceptions raised while it was
+/// active.
+///
+/// The register is per thread, so a guard is neither `Send` nor `Sync`.
+/// Nested guards must be dropped in the reverse order of their creation:
+/// dropping an outer guard first restores its value, and the inner guard
+/// then loads the value that was current when it was created.
+#[must_use]
+#[derive(Debug)]
+pub struct MxcsrGuard {
+    sse: Sse,
+    saved: Mxcsr,
+    // The register belongs to the thread that created the guard.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl MxcsrGuard {
+    /// Loads `mxcsr` into the register of the current thread.
+    ///
+    /// # Safety
+    ///
+    /// LLVM assumes that floating-point code runs with the default value of
+    /// the register: all exceptions masked, round to nearest, and neither
+    /// flush-to-zero nor denormals-are-zero. While the guard is alive, an
+    /// unmasked exception makes the operations that raise it trap, which
+    /// terminates the process with `SIGFPE` on most platforms, and the other
+    /// modes make computed results differ from the ones LLVM folds at
+    /// compile-time. The caller must ensure that the code running while the
+    /// guard is alive is correct with `mxcsr`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mxcsr` enables the denormals-are-zero mode and the host
+    /// CPU does not support it.
+    #[inline]
+    pub unsafe fn new(sse: Sse, mxcsr: Mxcsr) -> MxcsrGuard {
+        let saved = Mxcsr::current(sse);
+        mxcsr.load(sse);
+        MxcsrGuard {
+            sse,
+            saved,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Returns the value that is restored when the guard is dropped.
+    #[inline]
+    pub fn saved(&self) -> Mxcsr {
+        self.saved
+    }
+}
+
+impl Drop for MxcsrGuard {
+    #[inline]
+    fn drop(&mut self) {
+        self.saved.load(self.sse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32;
+    use std::panic;
+
+    use stdsimd_test::simd_test;
+    use test::black_box; // Used to inhibit constant-folding.
+
+    use x86::*;
+
+    #[simd_test = "sse2"]
+    unsafe fn rounding_mode_cvtps_epi32() {
+        let sse = Sse::detect().unwrap();
+        let modes = [
+            (RoundingMode::Nearest, [-2, -2, 2, 2]),
+            (RoundingMode::Down, [-3, -2, 1, 2]),
+            (RoundingMode::Up, [-2, -1, 2, 3]),
+            (RoundingMode::TowardZero, [-2, -1, 1, 2]),
+        ];
+        let saved = Mxcsr::current(sse);
+        for &(mode, e) in modes.iter() {
+            let r = {
+                let mxcsr = saved.with_rounding_mode(mode);
+                let _guard = MxcsrGuard::new(sse, mxcsr);
+                assert_eq!(Mxcsr::current(sse).rounding_mode(), mode);
+                let a = _mm_setr_ps(-2.5, -1.5, 1.5, 2.5);
+                _mm_cvtps_epi32(black_box(a))
+            };
+            assert_eq_m128i(r, _mm_setr_epi32(e[0], e[1], e[2], e[3]));
+            assert_eq!(Mxcsr::current(sse), saved);
+        }
+    }
+
+    #[simd_test = "sse"]
+    unsafe fn restore_on_panic() {
+        let sse = Sse::detect().unwrap();
+        let saved = Mxcsr::current(sse);
+        let r = panic::catch_unwind(|| {
+            let mxcsr = saved
+                .with_rounding_mode(RoundingMode::Up)
+                .with_flush_to_zero(FlushToZero::On);
+            let _guard = MxcsrGuard::new(sse, mxcsr);
+            panic!("unwinding through the guard");
+        });
+        assert!(r.is_err());
+        assert_eq!(Mxcsr::current(sse), saved);
+    }
+
+    #[simd_test = "sse"]
+    unsafe fn flush_to_zero() {
+        let sse = Sse::detect().unwrap();
+        let min = _mm_set_ss(f32::MIN_POSITIVE);
+        let half = _mm_set_ss(0.5);
+        let r = {
+            let mxcsr =
+                Mxcsr::current(sse).with_flush_to_zero(FlushToZero::On);
+            let _guard = MxcsrGuard::new(sse, mxcsr);
+            _mm_cvtss_f32(_mm_mul_ss(black_box(min), black_box(half)))
+        };
+        assert_eq!(r, 0.0);
+        let r = _mm_cvtss_f32(_mm_mul_ss(black_box(min), half));
+        assert_eq!(r, f32::MIN_POSITIVE / 2.0);
+    }
+
+    #[simd_test = "sse2"]
+    unsafe fn denormals_are_zero() {
+        if !super::supports_denormals_are_zero() {
+            return;
+        }
+        let sse = Sse::detect().unwrap();
+        let denormal = _mm_set_ss(f32::MIN_POSITIVE / 2.0);
+        let one = _mm_set_ss(1.0);
+        let r = {
+            let mxcsr = Mxcsr::current(sse)
+                .with_denormals_are_zero(DenormalsAreZero::On);
+            let _guard = MxcsrGuard::new(sse, mxcsr);
+            _mm_cvtss_f32(_mm_mul_ss(black_box(denormal), black_box(one)))
+        };
+        assert_eq!(r, 0.0);
+        let r = _mm_cvtss_f32(_mm_mul_ss(black_box(denormal), one));
+        assert_eq!(r, f32::MIN_POSITIVE / 2.0);
+    }
+
+    #[simd_test = "sse"]
+    unsafe fn sticky_exceptions() {
+        let sse = Sse::detect().unwrap();
+        let mxcsr = Mxcsr::current(sse).with_exceptions(Exceptions::NONE);
+        let _guard = MxcsrGuard::new(sse, mxcsr);
+        assert!(Exceptions::raised(sse).is_empty());
+
+        let zero = _mm_set_ss(0.0);
+        black_box(_mm_div_ss(black_box(_mm_set_ss(1.0)), black_box(zero)));
+        black_box(_mm_add_ss(black_box(_mm_set_ss(1.0)), black_box(zero)));
+        let raised = Exceptions::raised(sse);
+        assert!(raised.contains(Exceptions::DIV_ZERO));
+        assert!(!raised.contains(Exceptions::INVALID));
+
+        Exceptions::clear(sse);
+        assert_eq!(Exceptions::raised(sse), Exceptions::NONE);
+    }
+
+    #[test]
+    fn builder() {
+        let mxcsr = Mxcsr::default()
+            .with_rounding_mode(RoundingMode::Down)
+            .with_flush_to_zero(FlushToZero::On)
+            .with_denormals_are_zero(DenormalsAreZero::On)
+            .with_masked_exceptions(Exceptions::ALL & !Exceptions::OVERFLOW)
+            .with_exceptions(Exceptions::INEXACT);
+        assert_eq!(mxcsr.rounding_mode(), RoundingMode::Down);
+        assert_eq!(mxcsr.flush_to_zero(), FlushToZero::On);
+        assert_eq!(mxcsr.denormals_are_zero(), DenormalsAreZero::On);
+        assert_eq!(
+            mxcsr.masked_exceptions(),
+            Exceptions::ALL & !Exceptions::OVERFLOW
+        );
+        assert_eq!(mxcsr.exceptions(), Exceptions::INEXACT);
+        assert_eq!(
+            mxcsr.bits(),
+            _MM_ROUND_DOWN
+                | _MM_FLUSH_ZERO_ON
+                | 0x0040
+                | (_MM_MASK_MASK & !_MM_MASK_OVERFLOW)
+                | _MM_EXCEPT_INEXACT
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//! Carry-less Multiplication (CLMUL)
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref] (p. 4-241).
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use x86::__m128i;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.pclmulqdq"]
+    fn pclmulqdq(a: __m128i, b: __m128i, imm8: u8) -> __m128i;
+}
+
+/// Perform a carry-less multiplication of two 64-bit polynomials over the
+/// finite field GF(2^k).
+///
+/// The immediate byte is used for determining which halves of `a` and `b`
+/// should be used. Immediate bits other than 0 and 4 are ignored.
+#[inline]
+#[target_feature(enable = "pclmulqdq")]
+#[cfg_attr(all(test, not(target_os = "linux")),
+           assert_instr(pclmulqdq, imm8 = 0))]
+#[cfg_attr(all(test, target_os = "linux"),
+           assert_instr(pclmullqlqdq, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_clmulepi64_si128(
+    a: __m128i, b: __m128i, imm8: i32
+) -> __m128i {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pclmulqdq(a, b, $imm8)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    /// Software reference: carry-less product of `a` and `b` as `(hi, lo)`.
+    fn clmul(a: u64, b: u64) -> (u64, u64) {
+        let (mut hi, mut lo) = (0_u64, 0_u64);
+        for i in 0..64 {
+            if b & (1 << i) != 0 {
+                lo ^= a << i;
+                if i != 0 {
+                    hi ^= a >> (64 - i);
+                }
+            }
+        }
+        (hi, lo)
+    }
+
+    #[simd_test = "pclmulqdq"]
+    unsafe fn test_mm_clmulepi64_si128() {
+        // Constants taken from https://software.intel.com/sites/default/files/managed/72/cc/clmul-wp-rev-2.02-2014-04-20.pdf
+        let a = _mm_set_epi64x(0x7b5b546573745665, 0x63746f725d53475d);
+        let b = _mm_set_epi64x(0x4869285368617929, 0x5b477565726f6e5d);
+        let r00 =
+            _mm_set_epi64x(0x1d4d84c85c3440c0, 0x929633d5d36f0451u64 as i64);
+        let r01 =
+            _mm_set_epi64x(0x1a2bf6db3a30862f, 0xbabf262df4b7d5c9u64 as i64);
+        let r10 = _mm_set_epi64x(0x1bd17c8d556ab5a1, 0x7fa540ac2a281315);
+        let r11 =
+            _mm_set_epi64x(0x1d1e1f2c592e7c45, 0xd66ee03e410fd4edu64 as i64);
+
+        assert_eq_m128i(_mm_clmulepi64_si128(a, b, 0x00), r00);
+        assert_eq_m128i(_mm_clmulepi64_si128(a, b, 0x01), r01);
+        assert_eq_m128i(_mm_clmulepi64_si128(a, b, 0x10), r10);
+        assert_eq_m128i(_mm_clmulepi64_si128(a, b, 0x11), r11);
+
+        // Only bits 0 and 4 of the immediate are used:
+        assert_eq_m128i(_mm_clmulepi64_si128(a, b, 0xee), r00);
+        assert_eq_m128i(_mm_clmulepi64_si128(a, b, 0xff), r11);
+    }
+
+    #[simd_test = "pclmulqdq"]
+    unsafe fn test_mm_clmulepi64_si128_reference() {
+        let mut seed = 0x9e37_79b9_7f4a_7c15_u64;
+        let mut next = || {
+            // xorshift64
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        for _ in 0..64 {
+            let (a_hi, a_lo, b_hi, b_lo) = (next(), next(), next(), next());
+            let a = _mm_set_epi64x(a_hi as i64, a_lo as i64);
+            let b = _mm_set_epi64x(b_hi as i64, b_lo as i64);
+            for &(imm8, x, y) in &[
+                (0x00, a_lo, b_lo),
+                (0x01, a_hi, b_lo),
+                (0x10, a_lo, b_hi),
+                (0x11, a_hi, b_hi),
+            ] {
+                let (hi, lo) = clmul(x, y);
+                let e = _mm_set_epi64x(hi as i64, lo as i64);
+                let r = match imm8 {
+                    0x00 => _mm_clmulepi64_si128(a, b, 0x00),
+                    0x01 => _mm_clmulepi64_si128(a, b, 0x01),
+                    0x10 => _mm_clmulepi64_si128(a, b, 0x10),
+                    _ => _mm_clmulepi64_si128(a, b, 0x11),
+                };
+                assert_eq_m128i(r, e);
+            }
+        }
+    }
+}
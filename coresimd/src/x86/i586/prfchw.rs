@@ -0,0 +1,42 @@
+//! Prefetch for write (PRFCHW)
+//!
+//! `prefetchw` fetches a cache line in anticipation of a write to it,
+//! acquiring the line in an exclusive state so that the store does not
+//! have to invalidate other copies first.
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+extern "C" {
+    #[link_name = "llvm.prefetch"]
+    fn prefetch(p: *const i8, rw: i32, loc: i32, ty: i32);
+}
+
+/// Fetch the cache line that contains `p` into the cache hierarchy in
+/// anticipation of a write.
+///
+/// Like `_mm_prefetch` this is only a hint, which never faults and which
+/// the processor is free to ignore.
+#[inline]
+#[target_feature(enable = "prfchw")]
+#[cfg_attr(test, assert_instr(prefetchw))]
+pub unsafe fn _m_prefetchw(p: *const u8) {
+    // `rw` = 1 (write), `locality` = 3 (keep in all cache levels) and
+    // `cache type` = 1 (data cache).
+    prefetch(p as *const i8, 1, 3, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "prfchw"]
+    unsafe fn test_m_prefetchw() {
+        let mut x = [0u8; 128];
+        _m_prefetchw(x.as_ptr());
+        x[0] = 1;
+        assert_eq!(x[0], 1);
+    }
+}
@@ -0,0 +1,129 @@
+//! RDRAND and RDSEED instructions for returning random numbers from an Intel
+//! on-chip hardware random number generator which has been seeded by an
+//! on-chip entropy source.
+//!
+//! Both instructions report whether a random value was available through the
+//! carry flag, which the `_step` intrinsics return: `1` means that the value
+//! written through `val` is random, `0` means that no random value was
+//! available and that `0` was written instead. `RDSEED` can run out of
+//! entropy much more easily than `RDRAND`, so callers should retry (Intel
+//! recommends a short loop with `_mm_pause`) instead of treating a `0` as an
+//! error.
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+extern "unadjusted" {
+    #[link_name = "llvm.x86.rdrand.16"]
+    fn x86_rdrand16_step() -> (u16, i32);
+    #[link_name = "llvm.x86.rdrand.32"]
+    fn x86_rdrand32_step() -> (u32, i32);
+    #[link_name = "llvm.x86.rdseed.16"]
+    fn x86_rdseed16_step() -> (u16, i32);
+    #[link_name = "llvm.x86.rdseed.32"]
+    fn x86_rdseed32_step() -> (u32, i32);
+}
+
+/// Read a hardware generated 16-bit random value and store the result in val.
+/// Return 1 if a random value was generated, and 0 otherwise.
+#[inline]
+#[target_feature(enable = "rdrand")]
+#[cfg_attr(test, assert_instr(rdrand))]
+pub unsafe fn _rdrand16_step(val: &mut u16) -> i32 {
+    let (v, flag) = x86_rdrand16_step();
+    *val = v;
+    flag
+}
+
+/// Read a hardware generated 32-bit random value and store the result in val.
+/// Return 1 if a random value was generated, and 0 otherwise.
+#[inline]
+#[target_feature(enable = "rdrand")]
+#[cfg_attr(test, assert_instr(rdrand))]
+pub unsafe fn _rdrand32_step(val: &mut u32) -> i32 {
+    let (v, flag) = x86_rdrand32_step();
+    *val = v;
+    flag
+}
+
+/// Read a 16-bit NIST SP800-90B and SP800-90C compliant random value and store
+/// in val. Return 1 if a random value was generated, and 0 otherwise.
+#[inline]
+#[target_feature(enable = "rdseed")]
+#[cfg_attr(test, assert_instr(rdseed))]
+pub unsafe fn _rdseed16_step(val: &mut u16) -> i32 {
+    let (v, flag) = x86_rdseed16_step();
+    *val = v;
+    flag
+}
+
+/// Read a 32-bit NIST SP800-90B and SP800-90C compliant random value and store
+/// in val. Return 1 if a random value was generated, and 0 otherwise.
+#[inline]
+#[target_feature(enable = "rdseed")]
+#[cfg_attr(test, assert_instr(rdseed))]
+pub unsafe fn _rdseed32_step(val: &mut u32) -> i32 {
+    let (v, flag) = x86_rdseed32_step();
+    *val = v;
+    flag
+}
+
+/// Calls `step` until it reports success, checking the carry-flag
+/// protocol of every attempt, and returns the random value.
+///
+/// Each attempt starts from `sentinel`, which must not be zero, so that
+/// a failed attempt is seen to clear the output.
+///
+/// Panics if no random value is produced after many retries, which would
+/// indicate a broken generator rather than a transient lack of entropy.
+#[cfg(test)]
+pub(crate) unsafe fn retry<T, F>(sentinel: T, mut step: F) -> T
+where
+    T: Copy + Default + PartialEq + ::core::fmt::Debug,
+    F: FnMut(&mut T) -> i32,
+{
+    for _ in 0..1_000 {
+        let mut val = sentinel;
+        match step(&mut val) {
+            1 => return val,
+            0 => assert_eq!(val, T::default()),
+            flag => panic!("unexpected carry flag {}", flag),
+        }
+        ::x86::_mm_pause();
+    }
+    panic!("no random value was available after 1000 attempts")
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use super::retry;
+    use x86::*;
+
+    #[simd_test = "rdrand"]
+    unsafe fn test_rdrand16_step() {
+        retry(!0, |v| _rdrand16_step(v));
+    }
+
+    #[simd_test = "rdrand"]
+    unsafe fn test_rdrand32_step() {
+        let a = retry(!0, |v| _rdrand32_step(v));
+        let b = retry(!0, |v| _rdrand32_step(v));
+        // This fails with a probability of 2^-32 for a working generator.
+        assert_ne!(a, b);
+    }
+
+    #[simd_test = "rdseed"]
+    unsafe fn test_rdseed16_step() {
+        retry(!0, |v| _rdseed16_step(v));
+    }
+
+    #[simd_test = "rdseed"]
+    unsafe fn test_rdseed32_step() {
+        let a = retry(!0, |v| _rdseed32_step(v));
+        let b = retry(!0, |v| _rdseed32_step(v));
+        // This fails with a probability of 2^-32 for a working generator.
+        assert_ne!(a, b);
+    }
+}
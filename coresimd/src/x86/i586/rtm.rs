@@ -0,0 +1,184 @@
+//! Restricted Transactional Memory (RTM)
+//!
+//! RTM is one of the two interfaces of Intel's Transactional
+//! Synchronization Extensions (TSX). `_xbegin` starts a transaction, whose
+//! memory operations become visible atomically once `_xend` commits it.
+//! If the transaction aborts instead, all of its effects are discarded and
+//! execution resumes at `_xbegin`, which then returns a status other than
+//! `_XBEGIN_STARTED` describing why.
+//!
+//! A transaction may abort at any time for reasons outside of the program's
+//! control, so every transactional code path needs a non-transactional
+//! fallback.
+//!
+//! The other TSX interface, Hardware Lock Elision (HLE), consists of
+//! instruction prefixes only and has no intrinsics.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+extern "C" {
+    #[link_name = "llvm.x86.xbegin"]
+    fn x86_xbegin() -> i32;
+    #[link_name = "llvm.x86.xend"]
+    fn x86_xend();
+    #[link_name = "llvm.x86.xabort"]
+    fn x86_xabort(imm8: u8);
+    #[link_name = "llvm.x86.xtest"]
+    fn x86_xtest() -> i32;
+}
+
+/// Transaction successfully started.
+pub const _XBEGIN_STARTED: u32 = !0;
+
+/// Transaction explicitly aborted with `_xabort`. The parameter passed to
+/// `_xabort` is available with `_xabort_code(status)`.
+pub const _XABORT_EXPLICIT: u32 = 1 << 0;
+
+/// Transaction retry is possible.
+pub const _XABORT_RETRY: u32 = 1 << 1;
+
+/// Transaction abort due to a memory conflict with another thread.
+pub const _XABORT_CONFLICT: u32 = 1 << 2;
+
+/// Transaction abort due to the transaction using too much memory.
+pub const _XABORT_CAPACITY: u32 = 1 << 3;
+
+/// Transaction abort due to a debug trap.
+pub const _XABORT_DEBUG: u32 = 1 << 4;
+
+/// Transaction abort in an inner nested transaction.
+pub const _XABORT_NESTED: u32 = 1 << 5;
+
+/// Specify the start of an RTM code region.
+///
+/// Returns `_XBEGIN_STARTED` when the transaction has started. When the
+/// transaction aborts, execution continues from here with all of its
+/// effects rolled back, and the returned status is a combination of the
+/// `_XABORT_*` flags instead.
+#[inline]
+#[target_feature(enable = "rtm")]
+#[cfg_attr(test, assert_instr(xbegin))]
+pub unsafe fn _xbegin() -> u32 {
+    x86_xbegin() as u32
+}
+
+/// Specify the end of an RTM code region, committing the transaction.
+///
+/// Calling this outside of a transaction raises a general protection
+/// exception.
+#[inline]
+#[target_feature(enable = "rtm")]
+#[cfg_attr(test, assert_instr(xend))]
+pub unsafe fn _xend() {
+    x86_xend()
+}
+
+/// Force an RTM abort of the current transaction.
+///
+/// The 8-bit constant `imm8` is returned in bits 31:24 of the status of
+/// `_xbegin`, see `_xabort_code`. Outside of a transaction this does
+/// nothing.
+#[inline]
+#[target_feature(enable = "rtm")]
+#[cfg_attr(test, assert_instr(xabort, imm8 = 0x0))]
+#[rustc_args_required_const(0)]
+pub unsafe fn _xabort(imm8: u32) {
+    macro_rules! call {
+        ($imm8:expr) => {
+            x86_xabort($imm8)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Query whether the processor is executing in a transactional region
+/// identified by RTM or HLE.
+///
+/// Returns `1` inside of a transaction and `0` otherwise.
+#[inline]
+#[target_feature(enable = "rtm")]
+#[cfg_attr(test, assert_instr(xtest))]
+pub unsafe fn _xtest() -> u8 {
+    x86_xtest() as u8
+}
+
+/// Retrieve the parameter passed to `_xabort` from the status returned by
+/// `_xbegin` when the transaction was aborted explicitly.
+#[inline]
+pub fn _xabort_code(status: u32) -> u32 {
+    (status >> 24) & 0xff
+}
+
+#[cfg(test)]
+mod tests {
+    // Transactions may abort spuriously (interrupts, page faults, the
+    // hypervisor...), so the tests below retry a few times and only check
+    // the results of the attempts that did run transactionally.
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    const ATTEMPTS: usize = 16;
+
+    #[simd_test = "rtm"]
+    unsafe fn test_xbegin_xend() {
+        let mut x = 0;
+        for _ in 0..ATTEMPTS {
+            let status = _xbegin();
+            if status == _XBEGIN_STARTED {
+                x += 1;
+                _xend();
+                break;
+            }
+            // the effects of an aborted transaction are rolled back
+            assert_eq!(x, 0);
+        }
+        assert!(x <= 1);
+    }
+
+    #[simd_test = "rtm"]
+    unsafe fn test_xabort() {
+        // outside of a transaction this does nothing
+        _xabort(42);
+        for _ in 0..ATTEMPTS {
+            let mut x = 0;
+            let status = _xbegin();
+            if status == _XBEGIN_STARTED {
+                x += 1;
+                _xabort(42);
+            } else if status & _XABORT_EXPLICIT != 0 {
+                assert_eq!(_xabort_code(status), 42);
+            }
+            assert_eq!(x, 0);
+        }
+    }
+
+    #[simd_test = "rtm"]
+    unsafe fn test_xtest() {
+        assert_eq!(_xtest(), 0);
+        for _ in 0..ATTEMPTS {
+            if _xbegin() == _XBEGIN_STARTED {
+                let r = _xtest();
+                _xend();
+                // a failing assert inside of the transaction would only
+                // abort it, so check the result after committing
+                assert_eq!(r, 1);
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn test_xabort_code() {
+        assert_eq!(_xabort_code(0xff00_0001), 0xff);
+        assert_eq!(_xabort_code(0x2a00_0001), 42);
+        assert_eq!(_xabort_code(_XABORT_RETRY), 0);
+    }
+}
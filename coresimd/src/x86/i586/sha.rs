@@ -0,0 +1,429 @@
+//! Intel SHA Extensions
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use core::mem;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+use v128::*;
+use x86::*;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.sha1msg1"]
+    fn sha1msg1(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.sha1msg2"]
+    fn sha1msg2(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.sha1nexte"]
+    fn sha1nexte(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.sha1rnds4"]
+    fn sha1rnds4(a: i32x4, b: i32x4, c: i8) -> i32x4;
+    #[link_name = "llvm.x86.sha256msg1"]
+    fn sha256msg1(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.sha256msg2"]
+    fn sha256msg2(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.sha256rnds2"]
+    fn sha256rnds2(a: i32x4, b: i32x4, k: i32x4) -> i32x4;
+}
+
+/// Perform an intermediate calculation for the next four SHA1 message values
+/// (unsigned 32-bit integers) using previous message values from `a` and `b`,
+/// and returning the result.
+#[inline]
+#[target_feature(enable = "sha")]
+#[cfg_attr(test, assert_instr(sha1msg1))]
+pub unsafe fn _mm_sha1msg1_epu32(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(sha1msg1(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Perform the final calculation for the next four SHA1 message values
+/// (unsigned 32-bit integers) using the intermediate result in `a` and the
+/// previous message values in `b`, and returns the result.
+#[inline]
+#[target_feature(enable = "sha")]
+#[cfg_attr(test, assert_instr(sha1msg2))]
+pub unsafe fn _mm_sha1msg2_epu32(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(sha1msg2(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Calculate SHA1 state variable E after four rounds of operation from the
+/// current SHA1 state variable `a`, add that value to the scheduled values
+/// (unsigned 32-bit integers) in `b`, and returns the result.
+#[inline]
+#[target_feature(enable = "sha")]
+#[cfg_attr(test, assert_instr(sha1nexte))]
+pub unsafe fn _mm_sha1nexte_epu32(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(sha1nexte(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Perform four rounds of SHA1 operation using an initial SHA1 state (A,B,C,D)
+/// from `a` and some pre-computed sum of the next 4 round message values
+/// (unsigned 32-bit integers), and state variable E from `b`, and return the
+/// updated SHA1 state (A,B,C,D). `func` contains the logic functions and round
+/// constants.
+#[inline]
+#[target_feature(enable = "sha")]
+#[cfg_attr(test, assert_instr(sha1rnds4, func = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_sha1rnds4_epu32(
+    a: __m128i, b: __m128i, func: i32
+) -> __m128i {
+    let a = a.as_i32x4();
+    let b = b.as_i32x4();
+    macro_rules! call {
+        ($imm2:expr) => {
+            sha1rnds4(a, b, $imm2)
+        };
+    }
+    mem::transmute(constify_imm2!(func, call))
+}
+
+/// Perform an intermediate calculation for the next four SHA256 message values
+/// (unsigned 32-bit integers) using previous message values from `a` and `b`,
+/// and return the result.
+#[inline]
+#[target_feature(enable = "sha")]
+#[cfg_attr(test, assert_instr(sha256msg1))]
+pub unsafe fn _mm_sha256msg1_epu32(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(sha256msg1(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Perform the final calculation for the next four SHA256 message values
+/// (unsigned 32-bit integers) using previous message values from `a` and `b`,
+/// and return the result.
+#[inline]
+#[target_feature(enable = "sha")]
+#[cfg_attr(test, assert_instr(sha256msg2))]
+pub unsafe fn _mm_sha256msg2_epu32(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(sha256msg2(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Perform 2 rounds of SHA256 operation using an initial SHA256 state
+/// (C,D,G,H) from `a`, an initial SHA256 state (A,B,E,F) from `b`, and a
+/// pre-computed sum of the next 2 round message values (unsigned 32-bit
+/// integers) and the corresponding round constants from `k`, and store the
+/// updated SHA256 state (A,B,E,F) in dst.
+#[inline]
+#[target_feature(enable = "sha")]
+#[cfg_attr(test, assert_instr(sha256rnds2))]
+pub unsafe fn _mm_sha256rnds2_epu32(
+    a: __m128i, b: __m128i, k: __m128i
+) -> __m128i {
+    mem::transmute(sha256rnds2(a.as_i32x4(), b.as_i32x4(), k.as_i32x4()))
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "sha"]
+    unsafe fn test_mm_sha1msg1_epu32() {
+        let a =
+            _mm_set_epi64x(0xe9b5dba5b5c0fbcfu64 as i64, 0x71374491428a2f98);
+        let b =
+            _mm_set_epi64x(0xab1c5ed5923f82a4u64 as i64, 0x59f111f13956c25b);
+        let e = _mm_set_epi64x(
+            0x98829f34f74ad457u64 as i64,
+            0xda2b1a44d0b5ad3cu64 as i64,
+        );
+        assert_eq_m128i(_mm_sha1msg1_epu32(a, b), e);
+    }
+
+    #[simd_test = "sha"]
+    unsafe fn test_mm_sha1msg2_epu32() {
+        let a =
+            _mm_set_epi64x(0xe9b5dba5b5c0fbcfu64 as i64, 0x71374491428a2f98);
+        let b =
+            _mm_set_epi64x(0xab1c5ed5923f82a4u64 as i64, 0x59f111f13956c25b);
+        let e = _mm_set_epi64x(
+            0xf714b202d863d47du64 as i64,
+            0x90c30d946b3d3b35u64 as i64,
+        );
+        assert_eq_m128i(_mm_sha1msg2_epu32(a, b), e);
+    }
+
+    #[simd_test = "sha"]
+    unsafe fn test_mm_sha1nexte_epu32() {
+        let a =
+            _mm_set_epi64x(0xe9b5dba5b5c0fbcfu64 as i64, 0x71374491428a2f98);
+        let b =
+            _mm_set_epi64x(0xab1c5ed5923f82a4u64 as i64, 0x59f111f13956c25b);
+        let e = _mm_set_epi64x(0x2589d5be923f82a4, 0x59f111f13956c25b);
+        assert_eq_m128i(_mm_sha1nexte_epu32(a, b), e);
+    }
+
+    #[simd_test = "sha"]
+    unsafe fn test_mm_sha1rnds4_epu32() {
+        let a =
+            _mm_set_epi64x(0xe9b5dba5b5c0fbcfu64 as i64, 0x71374491428a2f98);
+        let b =
+            _mm_set_epi64x(0xab1c5ed5923f82a4u64 as i64, 0x59f111f13956c25b);
+
+        let e =
+            _mm_set_epi64x(0x32b13cd8322f5268, 0xc54420862bd9246fu64 as i64);
+        assert_eq_m128i(_mm_sha1rnds4_epu32(a, b, 0), e);
+
+        let e =
+            _mm_set_epi64x(0x6d4c43e56a3c25d9, 0xa7e00fb775cbd3feu64 as i64);
+        assert_eq_m128i(_mm_sha1rnds4_epu32(a, b, 1), e);
+
+        let e =
+            _mm_set_epi64x(0xb304e383c01222f4u64 as i64, 0x66f6b3b1f89d8001);
+        assert_eq_m128i(_mm_sha1rnds4_epu32(a, b, 2), e);
+
+        let e = _mm_set_epi64x(
+            0x8189b758bfabfa79u64 as i64,
+            0xdb08f6e78cae098bu64 as i64,
+        );
+        assert_eq_m128i(_mm_sha1rnds4_epu32(a, b, 3), e);
+    }
+
+    #[simd_test = "sha"]
+    unsafe fn test_mm_sha256msg1_epu32() {
+        let a =
+            _mm_set_epi64x(0xe9b5dba5b5c0fbcfu64 as i64, 0x71374491428a2f98);
+        let b =
+            _mm_set_epi64x(0xab1c5ed5923f82a4u64 as i64, 0x59f111f13956c25b);
+        let e =
+            _mm_set_epi64x(0xeb84973fd5cda67du64 as i64, 0x2857b88f406b09ee);
+        assert_eq_m128i(_mm_sha256msg1_epu32(a, b), e);
+    }
+
+    #[simd_test = "sha"]
+    unsafe fn test_mm_sha256msg2_epu32() {
+        let a =
+            _mm_set_epi64x(0xe9b5dba5b5c0fbcfu64 as i64, 0x71374491428a2f98);
+        let b =
+            _mm_set_epi64x(0xab1c5ed5923f82a4u64 as i64, 0x59f111f13956c25b);
+        let e =
+            _mm_set_epi64x(0xb58777ce887fd851u64 as i64, 0x15d1ec8b73ac8450);
+        assert_eq_m128i(_mm_sha256msg2_epu32(a, b), e);
+    }
+
+    #[simd_test = "sha"]
+    unsafe fn test_mm_sha256rnds2_epu32() {
+        let a =
+            _mm_set_epi64x(0xe9b5dba5b5c0fbcfu64 as i64, 0x71374491428a2f98);
+        let b =
+            _mm_set_epi64x(0xab1c5ed5923f82a4u64 as i64, 0x59f111f13956c25b);
+        let k =
+            _mm_set_epi64x(0xc19bf174550c7dc3u64 as i64, 0x72be5d7412835b01);
+        let e =
+            _mm_set_epi64x(0x7879ba4a2a76c653, 0x94d3208f47e8cd82u64 as i64);
+        assert_eq_m128i(_mm_sha256rnds2_epu32(a, b, k), e);
+    }
+
+    // FIPS 180-4 example messages and their digests.
+    const ABC: &'static [u8] = b"abc";
+    const ABCDBCDE: &'static [u8] =
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+    /// Pads `msg` as described in section 5.1.1 of FIPS 180-4 and calls `f`
+    /// with each of the resulting 512-bit blocks, as 16 big-endian words.
+    fn for_each_block<F: FnMut(&[u32; 16])>(msg: &[u8], mut f: F) {
+        let mut padded = [0_u8; 128];
+        let len = if msg.len() % 64 < 56 {
+            msg.len() + 64 - msg.len() % 64
+        } else {
+            msg.len() + 128 - msg.len() % 64
+        };
+        assert!(len <= padded.len());
+        padded[..msg.len()].copy_from_slice(msg);
+        padded[msg.len()] = 0x80;
+        let bits = (msg.len() as u64) * 8;
+        for i in 0..8 {
+            padded[len - 1 - i] = (bits >> (8 * i)) as u8;
+        }
+        for block in padded[..len].chunks(64) {
+            let mut w = [0_u32; 16];
+            for (w, b) in w.iter_mut().zip(block.chunks(4)) {
+                *w = (b[0] as u32) << 24
+                    | (b[1] as u32) << 16
+                    | (b[2] as u32) << 8
+                    | b[3] as u32;
+            }
+            f(&w);
+        }
+    }
+
+    /// `_mm_alignr_epi8(hi, lo, 4)` using only SSE2.
+    #[target_feature(enable = "sse2")]
+    unsafe fn alignr4(hi: __m128i, lo: __m128i) -> __m128i {
+        _mm_or_si128(_mm_srli_si128(lo, 4), _mm_slli_si128(hi, 12))
+    }
+
+    /// Returns the lanes of `a`, lowest lane first.
+    #[target_feature(enable = "sse2")]
+    unsafe fn lanes(a: __m128i) -> [u32; 4] {
+        let mut r = [0_u32; 4];
+        _mm_storeu_si128(r.as_mut_ptr() as *mut _, a);
+        r
+    }
+
+    #[target_feature(enable = "sha")]
+    unsafe fn sha1(msg: &[u8]) -> [u32; 5] {
+        let mut h: [u32; 5] =
+            [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+        for_each_block(msg, |w| {
+            let mut msgs = [_mm_setzero_si128(); 4];
+            for (i, m) in msgs.iter_mut().enumerate() {
+                let w = &w[4 * i..];
+                *m = _mm_set_epi32(
+                    w[0] as i32,
+                    w[1] as i32,
+                    w[2] as i32,
+                    w[3] as i32,
+                );
+            }
+            let abcd_save = _mm_set_epi32(
+                h[0] as i32,
+                h[1] as i32,
+                h[2] as i32,
+                h[3] as i32,
+            );
+            let e_save = _mm_set_epi32(h[4] as i32, 0, 0, 0);
+
+            let mut abcd = abcd_save;
+            let mut e = e_save;
+            for i in 0..20 {
+                let m = msgs[i % 4];
+                e = if i == 0 {
+                    _mm_add_epi32(e, m)
+                } else {
+                    _mm_sha1nexte_epu32(e, m)
+                };
+                let prev = abcd;
+                if i >= 3 && i <= 18 {
+                    let n = &mut msgs[(i + 1) % 4];
+                    *n = _mm_sha1msg2_epu32(*n, m);
+                }
+                abcd = match i / 5 {
+                    0 => _mm_sha1rnds4_epu32(abcd, e, 0),
+                    1 => _mm_sha1rnds4_epu32(abcd, e, 1),
+                    2 => _mm_sha1rnds4_epu32(abcd, e, 2),
+                    _ => _mm_sha1rnds4_epu32(abcd, e, 3),
+                };
+                if i >= 1 && i <= 16 {
+                    let n = &mut msgs[(i + 3) % 4];
+                    *n = _mm_sha1msg1_epu32(*n, m);
+                }
+                if i >= 2 && i <= 17 {
+                    let n = &mut msgs[(i + 2) % 4];
+                    *n = _mm_xor_si128(*n, m);
+                }
+                e = prev;
+            }
+            let e = lanes(_mm_sha1nexte_epu32(e, e_save));
+            let abcd = lanes(_mm_add_epi32(abcd, abcd_save));
+            h = [abcd[3], abcd[2], abcd[1], abcd[0], e[3]];
+        });
+        h
+    }
+
+    #[simd_test = "sha"]
+    unsafe fn test_sha1_fips180_4() {
+        assert_eq!(
+            sha1(ABC),
+            [0xa9993e36, 0x4706816a, 0xba3e2571, 0x7850c26c, 0x9cd0d89d]
+        );
+        assert_eq!(
+            sha1(ABCDBCDE),
+            [0x84983e44, 0x1c3bd26e, 0xbaae4aa1, 0xf95129e5, 0xe54670f1]
+        );
+    }
+
+    const K256: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b,
+        0x59f111f1, 0x923f82a4, 0xab1c5ed5, 0xd807aa98, 0x12835b01,
+        0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7,
+        0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc,
+        0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152,
+        0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147,
+        0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+        0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819,
+        0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116, 0x1e376c08,
+        0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f,
+        0x682e6ff3, 0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208,
+        0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+    ];
+
+    #[target_feature(enable = "sha")]
+    unsafe fn sha256(msg: &[u8]) -> [u32; 8] {
+        let mut h: [u32; 8] = [
+            0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f,
+            0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+        ];
+        for_each_block(msg, |w| {
+            let mut msgs = [_mm_setzero_si128(); 4];
+            for (i, m) in msgs.iter_mut().enumerate() {
+                *m = _mm_loadu_si128(w[4 * i..].as_ptr() as *const _);
+            }
+            // The state is kept as (A,B,E,F) and (C,D,G,H):
+            let abef_save = _mm_set_epi32(
+                h[0] as i32,
+                h[1] as i32,
+                h[4] as i32,
+                h[5] as i32,
+            );
+            let cdgh_save = _mm_set_epi32(
+                h[2] as i32,
+                h[3] as i32,
+                h[6] as i32,
+                h[7] as i32,
+            );
+
+            let mut abef = abef_save;
+            let mut cdgh = cdgh_save;
+            for i in 0..16 {
+                let m = msgs[i % 4];
+                let k = _mm_loadu_si128(K256[4 * i..].as_ptr() as *const _);
+                let wk = _mm_add_epi32(m, k);
+                cdgh = _mm_sha256rnds2_epu32(cdgh, abef, wk);
+                if i >= 3 && i <= 14 {
+                    let t = alignr4(m, msgs[(i + 3) % 4]);
+                    let n = &mut msgs[(i + 1) % 4];
+                    *n = _mm_sha256msg2_epu32(_mm_add_epi32(*n, t), m);
+                }
+                let wk = _mm_shuffle_epi32(wk, 0x0e);
+                abef = _mm_sha256rnds2_epu32(abef, cdgh, wk);
+                if i >= 1 && i <= 12 {
+                    let n = &mut msgs[(i + 3) % 4];
+                    *n = _mm_sha256msg1_epu32(*n, m);
+                }
+            }
+            let abef = lanes(_mm_add_epi32(abef, abef_save));
+            let cdgh = lanes(_mm_add_epi32(cdgh, cdgh_save));
+            h = [
+                abef[3], abef[2], cdgh[3], cdgh[2], abef[1], abef[0], cdgh[1],
+                cdgh[0],
+            ];
+        });
+        h
+    }
+
+    #[simd_test = "sha"]
+    unsafe fn test_sha256_fips180_4() {
+        assert_eq!(
+            sha256(ABC),
+            [
+                0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3,
+                0x96177a9c, 0xb410ff61, 0xf20015ad,
+            ]
+        );
+        assert_eq!(
+            sha256(ABCDBCDE),
+            [
+                0x248d6a61, 0xd20638b8, 0xe5c02693, 0x0c3e6039, 0xa33ce459,
+                0x64ff2167, 0xf6ecedd4, 0x19db06c1,
+            ]
+        );
+    }
+}
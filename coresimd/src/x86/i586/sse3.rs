@@ -107,6 +107,46 @@ pub unsafe fn _mm_moveldup_ps(a: __m128) -> __m128 {
     simd_shuffle4(a, a, [0, 0, 2, 2])
 }
 
+/// Arm address monitoring hardware using the address specified in `p`.
+///
+/// A store to the address range starting at `p` then wakes up a processor
+/// waiting in `_mm_mwait`. `extensions` and `hints` are passed in `ecx` and
+/// `edx` respectively; no extensions or hints are currently defined, so
+/// both should be `0`.
+///
+/// Depending on the operating system, `monitor` may only be executable at
+/// privilege level 0, in which case it raises an invalid-opcode exception
+/// in user mode.
+///
+/// MONITOR has its own CPUID bit, which `sse3` does not imply: callers must
+/// check `cfg_feature_enabled!("monitor")` first.
+#[inline]
+#[target_feature(enable = "sse3")]
+#[cfg_attr(test, assert_instr(monitor))]
+pub unsafe fn _mm_monitor(p: *const u8, extensions: u32, hints: u32) {
+    monitor(p, extensions, hints)
+}
+
+/// Hint to the processor that it can enter an implementation-dependent
+/// optimized state while waiting for a store to the address range armed by
+/// `_mm_monitor`.
+///
+/// `extensions` and `hints` are passed in `ecx` and `eax` respectively,
+/// which can for example select a C-state on processors supporting it.
+///
+/// Depending on the operating system, `mwait` may only be executable at
+/// privilege level 0, in which case it raises an invalid-opcode exception
+/// in user mode.
+///
+/// MWAIT has its own CPUID bit, which `sse3` does not imply: callers must
+/// check `cfg_feature_enabled!("monitor")` first.
+#[inline]
+#[target_feature(enable = "sse3")]
+#[cfg_attr(test, assert_instr(mwait))]
+pub unsafe fn _mm_mwait(extensions: u32, hints: u32) {
+    mwait(extensions, hints)
+}
+
 #[allow(improper_ctypes)]
 extern "C" {
     #[link_name = "llvm.x86.sse3.addsub.ps"]
@@ -123,6 +163,10 @@ extern "C" {
     fn hsubps(a: __m128, b: __m128) -> __m128;
     #[link_name = "llvm.x86.sse3.ldu.dq"]
     fn lddqu(mem_addr: *const i8) -> i8x16;
+    #[link_name = "llvm.x86.sse3.monitor"]
+    fn monitor(p: *const u8, extensions: u32, hints: u32);
+    #[link_name = "llvm.x86.sse3.mwait"]
+    fn mwait(extensions: u32, hints: u32);
 }
 
 #[cfg(test)]
@@ -219,4 +263,21 @@ mod tests {
         let r = _mm_loaddup_pd(&d);
         assert_eq_m128d(r, _mm_setr_pd(d, d));
     }
+
+    // Most operating systems only allow `monitor` and `mwait` at privilege
+    // level 0, so this test has to be requested with `--ignored`.
+    #[test]
+    #[ignore]
+    fn test_mm_monitor_mwait() {
+        #[target_feature(enable = "sse3")]
+        unsafe fn monitor_mwait() {
+            let x = 0_u8;
+            _mm_monitor(&x, 0, 0);
+            // Returns on the next interrupt at the latest.
+            _mm_mwait(0, 0);
+        }
+        if cfg_feature_enabled!("sse3") && cfg_feature_enabled!("monitor") {
+            unsafe { monitor_mwait() }
+        }
+    }
 }
@@ -0,0 +1,226 @@
+//! Vector AES Instructions (VAES)
+//!
+//! These extend the AES-NI round instructions to 256-bit and 512-bit
+//! registers, performing one independent AES round in each 128-bit lane.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.aesni.aesenc.256"]
+    fn aesenc_256(a: __m256i, round_key: __m256i) -> __m256i;
+    #[link_name = "llvm.x86.aesni.aesenclast.256"]
+    fn aesenclast_256(a: __m256i, round_key: __m256i) -> __m256i;
+    #[link_name = "llvm.x86.aesni.aesdec.256"]
+    fn aesdec_256(a: __m256i, round_key: __m256i) -> __m256i;
+    #[link_name = "llvm.x86.aesni.aesdeclast.256"]
+    fn aesdeclast_256(a: __m256i, round_key: __m256i) -> __m256i;
+    #[link_name = "llvm.x86.aesni.aesenc.512"]
+    fn aesenc_512(a: __m512i, round_key: __m512i) -> __m512i;
+    #[link_name = "llvm.x86.aesni.aesenclast.512"]
+    fn aesenclast_512(a: __m512i, round_key: __m512i) -> __m512i;
+    #[link_name = "llvm.x86.aesni.aesdec.512"]
+    fn aesdec_512(a: __m512i, round_key: __m512i) -> __m512i;
+    #[link_name = "llvm.x86.aesni.aesdeclast.512"]
+    fn aesdeclast_512(a: __m512i, round_key: __m512i) -> __m512i;
+}
+
+/// Perform one round of an AES encryption flow on each 128-bit lane (state)
+/// in `a` using the corresponding 128-bit lane of `round_key`.
+#[inline]
+#[target_feature(enable = "vaes")]
+#[cfg_attr(test, assert_instr(vaesenc))]
+pub unsafe fn _mm256_aesenc_epi128(a: __m256i, round_key: __m256i) -> __m256i {
+    aesenc_256(a, round_key)
+}
+
+/// Perform the last round of an AES encryption flow on each 128-bit lane
+/// (state) in `a` using the corresponding 128-bit lane of `round_key`.
+#[inline]
+#[target_feature(enable = "vaes")]
+#[cfg_attr(test, assert_instr(vaesenclast))]
+pub unsafe fn _mm256_aesenclast_epi128(
+    a: __m256i, round_key: __m256i
+) -> __m256i {
+    aesenclast_256(a, round_key)
+}
+
+/// Perform one round of an AES decryption flow on each 128-bit lane (state)
+/// in `a` using the corresponding 128-bit lane of `round_key`.
+#[inline]
+#[target_feature(enable = "vaes")]
+#[cfg_attr(test, assert_instr(vaesdec))]
+pub unsafe fn _mm256_aesdec_epi128(a: __m256i, round_key: __m256i) -> __m256i {
+    aesdec_256(a, round_key)
+}
+
+/// Perform the last round of an AES decryption flow on each 128-bit lane
+/// (state) in `a` using the corresponding 128-bit lane of `round_key`.
+#[inline]
+#[target_feature(enable = "vaes")]
+#[cfg_attr(test, assert_instr(vaesdeclast))]
+pub unsafe fn _mm256_aesdeclast_epi128(
+    a: __m256i, round_key: __m256i
+) -> __m256i {
+    aesdeclast_256(a, round_key)
+}
+
+/// Perform one round of an AES encryption flow on each 128-bit lane (state)
+/// in `a` using the corresponding 128-bit lane of `round_key`.
+#[inline]
+#[target_feature(enable = "vaes,avx512f")]
+#[cfg_attr(test, assert_instr(vaesenc))]
+pub unsafe fn _mm512_aesenc_epi128(a: __m512i, round_key: __m512i) -> __m512i {
+    aesenc_512(a, round_key)
+}
+
+/// Perform the last round of an AES encryption flow on each 128-bit lane
+/// (state) in `a` using the corresponding 128-bit lane of `round_key`.
+#[inline]
+#[target_feature(enable = "vaes,avx512f")]
+#[cfg_attr(test, assert_instr(vaesenclast))]
+pub unsafe fn _mm512_aesenclast_epi128(
+    a: __m512i, round_key: __m512i
+) -> __m512i {
+    aesenclast_512(a, round_key)
+}
+
+/// Perform one round of an AES decryption flow on each 128-bit lane (state)
+/// in `a` using the corresponding 128-bit lane of `round_key`.
+#[inline]
+#[target_feature(enable = "vaes,avx512f")]
+#[cfg_attr(test, assert_instr(vaesdec))]
+pub unsafe fn _mm512_aesdec_epi128(a: __m512i, round_key: __m512i) -> __m512i {
+    aesdec_512(a, round_key)
+}
+
+/// Perform the last round of an AES decryption flow on each 128-bit lane
+/// (state) in `a` using the corresponding 128-bit lane of `round_key`.
+#[inline]
+#[target_feature(enable = "vaes,avx512f")]
+#[cfg_attr(test, assert_instr(vaesdeclast))]
+pub unsafe fn _mm512_aesdeclast_epi128(
+    a: __m512i, round_key: __m512i
+) -> __m512i {
+    aesdeclast_512(a, round_key)
+}
+
+#[cfg(test)]
+mod tests {
+    // Each 128-bit lane must produce exactly what the AES-NI instruction
+    // produces for that lane, so the results are checked against
+    // `_mm_aes*_si128` applied lane by lane.
+
+    use std::mem;
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const STATE: [u64; 8] = [
+        0x63746f725d53475d, 0x7b5b546573745665,
+        0x0011223344556677, 0x8899aabbccddeeff,
+        0x0123456789abcdef, 0xfedcba9876543210,
+        0x0000000000000000, 0xffffffffffffffff,
+    ];
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const KEY: [u64; 8] = [
+        0x5b477565726f6e5d, 0x4869285368617929,
+        0x0001020304050607, 0x08090a0b0c0d0e0f,
+        0xffffffffffffffff, 0x0000000000000000,
+        0x2b7e151628aed2a6, 0xabf7158809cf4f3c,
+    ];
+
+    macro_rules! lanes {
+        ($n:expr, $f:ident) => {{
+            let a: [__m128i; 4] = mem::transmute(STATE);
+            let k: [__m128i; 4] = mem::transmute(KEY);
+            let mut r = [_mm_setzero_si128(); $n];
+            for i in 0..$n {
+                r[i] = $f(a[i], k[i]);
+            }
+            r
+        }};
+    }
+
+    #[simd_test = "vaes"]
+    unsafe fn test_mm256_aesenc_epi128() {
+        let a = _mm256_loadu_si256(STATE.as_ptr() as *const _);
+        let k = _mm256_loadu_si256(KEY.as_ptr() as *const _);
+        let r = _mm256_aesenc_epi128(a, k);
+        let e: __m256i = mem::transmute(lanes!(2, _mm_aesenc_si128));
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "vaes"]
+    unsafe fn test_mm256_aesenclast_epi128() {
+        let a = _mm256_loadu_si256(STATE.as_ptr() as *const _);
+        let k = _mm256_loadu_si256(KEY.as_ptr() as *const _);
+        let r = _mm256_aesenclast_epi128(a, k);
+        let e: __m256i = mem::transmute(lanes!(2, _mm_aesenclast_si128));
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "vaes"]
+    unsafe fn test_mm256_aesdec_epi128() {
+        let a = _mm256_loadu_si256(STATE.as_ptr() as *const _);
+        let k = _mm256_loadu_si256(KEY.as_ptr() as *const _);
+        let r = _mm256_aesdec_epi128(a, k);
+        let e: __m256i = mem::transmute(lanes!(2, _mm_aesdec_si128));
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "vaes"]
+    unsafe fn test_mm256_aesdeclast_epi128() {
+        let a = _mm256_loadu_si256(STATE.as_ptr() as *const _);
+        let k = _mm256_loadu_si256(KEY.as_ptr() as *const _);
+        let r = _mm256_aesdeclast_epi128(a, k);
+        let e: __m256i = mem::transmute(lanes!(2, _mm_aesdeclast_si128));
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "vaes,avx512f"]
+    unsafe fn test_mm512_aesenc_epi128() {
+        let a: __m512i = mem::transmute(STATE);
+        let k: __m512i = mem::transmute(KEY);
+        let r = _mm512_aesenc_epi128(a, k);
+        let e: __m512i = mem::transmute(lanes!(4, _mm_aesenc_si128));
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "vaes,avx512f"]
+    unsafe fn test_mm512_aesenclast_epi128() {
+        let a: __m512i = mem::transmute(STATE);
+        let k: __m512i = mem::transmute(KEY);
+        let r = _mm512_aesenclast_epi128(a, k);
+        let e: __m512i = mem::transmute(lanes!(4, _mm_aesenclast_si128));
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "vaes,avx512f"]
+    unsafe fn test_mm512_aesdec_epi128() {
+        let a: __m512i = mem::transmute(STATE);
+        let k: __m512i = mem::transmute(KEY);
+        let r = _mm512_aesdec_epi128(a, k);
+        let e: __m512i = mem::transmute(lanes!(4, _mm_aesdec_si128));
+        assert_eq_m512i(r, e);
+    }
+
+    #[simd_test = "vaes,avx512f"]
+    unsafe fn test_mm512_aesdeclast_epi128() {
+        let a: __m512i = mem::transmute(STATE);
+        let k: __m512i = mem::transmute(KEY);
+        let r = _mm512_aesdeclast_epi128(a, k);
+        let e: __m512i = mem::transmute(lanes!(4, _mm_aesdeclast_si128));
+        assert_eq_m512i(r, e);
+    }
+}
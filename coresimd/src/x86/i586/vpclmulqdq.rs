@@ -0,0 +1,144 @@
+//! Vector Carry-less Multiplication (VPCLMULQDQ)
+//!
+//! These extend `pclmulqdq` to 256-bit and 512-bit registers, performing
+//! one independent carry-less multiplication in each 128-bit lane.
+//!
+//! The reference is [Intel 64 and IA-32 Architectures Software Developer's
+//! Manual Volume 2: Instruction Set Reference, A-Z][intel64_ref].
+//!
+//! [intel64_ref]: http://www.intel.de/content/dam/www/public/us/en/documents/manuals/64-ia-32-architectures-software-developer-instruction-set-reference-manual-325383.pdf
+
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.pclmulqdq.256"]
+    fn pclmulqdq_256(a: __m256i, b: __m256i, imm8: u8) -> __m256i;
+    #[link_name = "llvm.x86.pclmulqdq.512"]
+    fn pclmulqdq_512(a: __m512i, b: __m512i, imm8: u8) -> __m512i;
+}
+
+/// Perform a carry-less multiplication of two 64-bit polynomials over the
+/// finite field GF(2^k) in each 128-bit lane of `a` and `b`.
+///
+/// The immediate byte is used for determining which halves of each lane of
+/// `a` and `b` should be used. Immediate bits other than 0 and 4 are
+/// ignored.
+#[inline]
+#[target_feature(enable = "vpclmulqdq")]
+#[cfg_attr(all(test, not(target_os = "linux")),
+           assert_instr(vpclmulqdq, imm8 = 0))]
+#[cfg_attr(all(test, target_os = "linux"),
+           assert_instr(vpclmullqlqdq, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm256_clmulepi64_epi128(
+    a: __m256i, b: __m256i, imm8: i32
+) -> __m256i {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pclmulqdq_256(a, b, $imm8)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+/// Perform a carry-less multiplication of two 64-bit polynomials over the
+/// finite field GF(2^k) in each 128-bit lane of `a` and `b`.
+///
+/// The immediate byte is used for determining which halves of each lane of
+/// `a` and `b` should be used. Immediate bits other than 0 and 4 are
+/// ignored.
+#[inline]
+#[target_feature(enable = "vpclmulqdq,avx512f")]
+#[cfg_attr(all(test, not(target_os = "linux")),
+           assert_instr(vpclmulqdq, imm8 = 0))]
+#[cfg_attr(all(test, target_os = "linux"),
+           assert_instr(vpclmullqlqdq, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm512_clmulepi64_epi128(
+    a: __m512i, b: __m512i, imm8: i32
+) -> __m512i {
+    macro_rules! call {
+        ($imm8:expr) => {
+            pclmulqdq_512(a, b, $imm8)
+        };
+    }
+    constify_imm8!(imm8, call)
+}
+
+#[cfg(test)]
+mod tests {
+    // Each 128-bit lane must produce exactly what `pclmulqdq` produces for
+    // that lane, so the results are checked against `_mm_clmulepi64_si128`
+    // applied lane by lane.
+
+    use std::mem;
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const A: [u64; 8] = [
+        0x63746f725d53475d, 0x7b5b546573745665,
+        0x0011223344556677, 0x8899aabbccddeeff,
+        0x0123456789abcdef, 0xfedcba9876543210,
+        0x0000000000000001, 0xffffffffffffffff,
+    ];
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    const B: [u64; 8] = [
+        0x5b477565726f6e5d, 0x4869285368617929,
+        0x0001020304050607, 0x08090a0b0c0d0e0f,
+        0xffffffffffffffff, 0x8000000000000000,
+        0x2b7e151628aed2a6, 0xabf7158809cf4f3c,
+    ];
+
+    macro_rules! lanes {
+        ($n:expr, $imm8:expr) => {{
+            let a: [__m128i; 4] = mem::transmute(A);
+            let b: [__m128i; 4] = mem::transmute(B);
+            let mut r = [_mm_setzero_si128(); $n];
+            for i in 0..$n {
+                r[i] = _mm_clmulepi64_si128(a[i], b[i], $imm8);
+            }
+            r
+        }};
+    }
+
+    #[simd_test = "vpclmulqdq"]
+    unsafe fn test_mm256_clmulepi64_epi128() {
+        let a = _mm256_loadu_si256(A.as_ptr() as *const _);
+        let b = _mm256_loadu_si256(B.as_ptr() as *const _);
+        macro_rules! check {
+            ($imm8:expr) => {
+                let r = _mm256_clmulepi64_epi128(a, b, $imm8);
+                let e: __m256i = mem::transmute(lanes!(2, $imm8));
+                assert_eq_m256i(r, e);
+            };
+        }
+        check!(0x00);
+        check!(0x01);
+        check!(0x10);
+        check!(0x11);
+    }
+
+    #[simd_test = "vpclmulqdq,avx512f"]
+    unsafe fn test_mm512_clmulepi64_epi128() {
+        let a: __m512i = mem::transmute(A);
+        let b: __m512i = mem::transmute(B);
+        macro_rules! check {
+            ($imm8:expr) => {
+                let r = _mm512_clmulepi64_epi128(a, b, $imm8);
+                let e: __m512i = mem::transmute(lanes!(4, $imm8));
+                assert_eq_m512i(r, e);
+            };
+        }
+        check!(0x00);
+        check!(0x01);
+        check!(0x10);
+        check!(0x11);
+    }
+}
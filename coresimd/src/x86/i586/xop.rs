@@ -0,0 +1,2210 @@
+//! eXtended Operations (XOP)
+//!
+//! XOP is an AMD-only extension of the 128-bit and 256-bit vector
+//! instruction set, introduced with the Bulldozer microarchitecture. It adds
+//! integer multiply-accumulate, horizontal add and subtract, per-element
+//! rotates and shifts, comparisons with an immediate predicate, byte and
+//! floating-point permutes with two sources, and fraction extraction.
+//!
+//! The reference is [AMD64 Architecture Programmer's Manual, Volume 6:
+//! 128-Bit and 256-Bit XOP and FMA4 Instructions][amd64_ref].
+//!
+//! [amd64_ref]: http://support.amd.com/TechDocs/43479.pdf
+
+use core::mem;
+
+use v128::*;
+use v256::*;
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Less-than predicate for the `_mm_com_*` intrinsics.
+pub const _MM_PCOMCTRL_LT: i32 = 0;
+/// Less-than-or-equal predicate for the `_mm_com_*` intrinsics.
+pub const _MM_PCOMCTRL_LE: i32 = 1;
+/// Greater-than predicate for the `_mm_com_*` intrinsics.
+pub const _MM_PCOMCTRL_GT: i32 = 2;
+/// Greater-than-or-equal predicate for the `_mm_com_*` intrinsics.
+pub const _MM_PCOMCTRL_GE: i32 = 3;
+/// Equality predicate for the `_mm_com_*` intrinsics.
+pub const _MM_PCOMCTRL_EQ: i32 = 4;
+/// Inequality predicate for the `_mm_com_*` intrinsics.
+pub const _MM_PCOMCTRL_NEQ: i32 = 5;
+/// Predicate that never holds for the `_mm_com_*` intrinsics.
+pub const _MM_PCOMCTRL_FALSE: i32 = 6;
+/// Predicate that always holds for the `_mm_com_*` intrinsics.
+pub const _MM_PCOMCTRL_TRUE: i32 = 7;
+
+#[allow(improper_ctypes)]
+extern "C" {
+    #[link_name = "llvm.x86.xop.vpmacssww"]
+    fn vpmacssww(a: i16x8, b: i16x8, c: i16x8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vpmacsww"]
+    fn vpmacsww(a: i16x8, b: i16x8, c: i16x8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vpmacsswd"]
+    fn vpmacsswd(a: i16x8, b: i16x8, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpmacswd"]
+    fn vpmacswd(a: i16x8, b: i16x8, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpmacssdd"]
+    fn vpmacssdd(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpmacsdd"]
+    fn vpmacsdd(a: i32x4, b: i32x4, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpmacssdql"]
+    fn vpmacssdql(a: i32x4, b: i32x4, c: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpmacsdql"]
+    fn vpmacsdql(a: i32x4, b: i32x4, c: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpmacssdqh"]
+    fn vpmacssdqh(a: i32x4, b: i32x4, c: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpmacsdqh"]
+    fn vpmacsdqh(a: i32x4, b: i32x4, c: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpmadcsswd"]
+    fn vpmadcsswd(a: i16x8, b: i16x8, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpmadcswd"]
+    fn vpmadcswd(a: i16x8, b: i16x8, c: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphaddbw"]
+    fn vphaddbw(a: i8x16) -> i16x8;
+    #[link_name = "llvm.x86.xop.vphaddbd"]
+    fn vphaddbd(a: i8x16) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphaddbq"]
+    fn vphaddbq(a: i8x16) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphaddwd"]
+    fn vphaddwd(a: i16x8) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphaddwq"]
+    fn vphaddwq(a: i16x8) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphadddq"]
+    fn vphadddq(a: i32x4) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphaddubw"]
+    fn vphaddubw(a: i8x16) -> i16x8;
+    #[link_name = "llvm.x86.xop.vphaddubd"]
+    fn vphaddubd(a: i8x16) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphaddubq"]
+    fn vphaddubq(a: i8x16) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphadduwd"]
+    fn vphadduwd(a: i16x8) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphadduwq"]
+    fn vphadduwq(a: i16x8) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphaddudq"]
+    fn vphaddudq(a: i32x4) -> i64x2;
+    #[link_name = "llvm.x86.xop.vphsubbw"]
+    fn vphsubbw(a: i8x16) -> i16x8;
+    #[link_name = "llvm.x86.xop.vphsubwd"]
+    fn vphsubwd(a: i16x8) -> i32x4;
+    #[link_name = "llvm.x86.xop.vphsubdq"]
+    fn vphsubdq(a: i32x4) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpcmov"]
+    fn vpcmov(a: i64x2, b: i64x2, c: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpcmov.256"]
+    fn vpcmov256(a: i64x4, b: i64x4, c: i64x4) -> i64x4;
+    #[link_name = "llvm.x86.xop.vpperm"]
+    fn vpperm(a: i8x16, b: i8x16, c: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.xop.vprotb"]
+    fn vprotb(a: i8x16, b: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.xop.vprotw"]
+    fn vprotw(a: i16x8, b: i16x8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vprotd"]
+    fn vprotd(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vprotq"]
+    fn vprotq(a: i64x2, b: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vprotbi"]
+    fn vprotbi(a: i8x16, imm8: i8) -> i8x16;
+    #[link_name = "llvm.x86.xop.vprotwi"]
+    fn vprotwi(a: i16x8, imm8: i8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vprotdi"]
+    fn vprotdi(a: i32x4, imm8: i8) -> i32x4;
+    #[link_name = "llvm.x86.xop.vprotqi"]
+    fn vprotqi(a: i64x2, imm8: i8) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpshlb"]
+    fn vpshlb(a: i8x16, b: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.xop.vpshlw"]
+    fn vpshlw(a: i16x8, b: i16x8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vpshld"]
+    fn vpshld(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpshlq"]
+    fn vpshlq(a: i64x2, b: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpshab"]
+    fn vpshab(a: i8x16, b: i8x16) -> i8x16;
+    #[link_name = "llvm.x86.xop.vpshaw"]
+    fn vpshaw(a: i16x8, b: i16x8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vpshad"]
+    fn vpshad(a: i32x4, b: i32x4) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpshaq"]
+    fn vpshaq(a: i64x2, b: i64x2) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpcomb"]
+    fn vpcomb(a: i8x16, b: i8x16, imm8: i8) -> i8x16;
+    #[link_name = "llvm.x86.xop.vpcomw"]
+    fn vpcomw(a: i16x8, b: i16x8, imm8: i8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vpcomd"]
+    fn vpcomd(a: i32x4, b: i32x4, imm8: i8) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpcomq"]
+    fn vpcomq(a: i64x2, b: i64x2, imm8: i8) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpcomub"]
+    fn vpcomub(a: i8x16, b: i8x16, imm8: i8) -> i8x16;
+    #[link_name = "llvm.x86.xop.vpcomuw"]
+    fn vpcomuw(a: i16x8, b: i16x8, imm8: i8) -> i16x8;
+    #[link_name = "llvm.x86.xop.vpcomud"]
+    fn vpcomud(a: i32x4, b: i32x4, imm8: i8) -> i32x4;
+    #[link_name = "llvm.x86.xop.vpcomuq"]
+    fn vpcomuq(a: i64x2, b: i64x2, imm8: i8) -> i64x2;
+    #[link_name = "llvm.x86.xop.vpermil2pd"]
+    fn vpermil2pd(a: __m128d, b: __m128d, c: i64x2, imm8: i8) -> __m128d;
+    #[link_name = "llvm.x86.xop.vpermil2pd.256"]
+    fn vpermil2pd256(a: __m256d, b: __m256d, c: i64x4, imm8: i8) -> __m256d;
+    #[link_name = "llvm.x86.xop.vpermil2ps"]
+    fn vpermil2ps(a: __m128, b: __m128, c: i32x4, imm8: i8) -> __m128;
+    #[link_name = "llvm.x86.xop.vpermil2ps.256"]
+    fn vpermil2ps256(a: __m256, b: __m256, c: i32x8, imm8: i8) -> __m256;
+    #[link_name = "llvm.x86.xop.vfrcz.ss"]
+    fn vfrczss(a: __m128) -> __m128;
+    #[link_name = "llvm.x86.xop.vfrcz.sd"]
+    fn vfrczsd(a: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.xop.vfrcz.ps"]
+    fn vfrczps(a: __m128) -> __m128;
+    #[link_name = "llvm.x86.xop.vfrcz.pd"]
+    fn vfrczpd(a: __m128d) -> __m128d;
+    #[link_name = "llvm.x86.xop.vfrcz.ps.256"]
+    fn vfrczps256(a: __m256) -> __m256;
+    #[link_name = "llvm.x86.xop.vfrcz.pd.256"]
+    fn vfrczpd256(a: __m256d) -> __m256d;
+}
+
+/// Multiply packed signed 16-bit integers in `a` and `b`, add the
+/// products to the packed 16-bit integers in `c` and saturate the results.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacssww))]
+pub unsafe fn _mm_maccs_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpmacssww(a.as_i16x8(), b.as_i16x8(), c.as_i16x8()))
+}
+
+/// Multiply packed 16-bit integers in `a` and `b` and add the low 16 bits
+/// of the products to the packed 16-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacsww))]
+pub unsafe fn _mm_macc_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpmacsww(a.as_i16x8(), b.as_i16x8(), c.as_i16x8()))
+}
+
+/// Multiply the odd-indexed signed 16-bit integers in `a` and `b`, add
+/// the 32-bit products to the packed 32-bit integers in `c` and saturate
+/// the results.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacsswd))]
+pub unsafe fn _mm_maccsd_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpmacsswd(a.as_i16x8(), b.as_i16x8(), c.as_i32x4()))
+}
+
+/// Multiply the odd-indexed signed 16-bit integers in `a` and `b` and add
+/// the 32-bit products to the packed 32-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacswd))]
+pub unsafe fn _mm_maccd_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpmacswd(a.as_i16x8(), b.as_i16x8(), c.as_i32x4()))
+}
+
+/// Multiply packed signed 32-bit integers in `a` and `b`, add the
+/// products to the packed 32-bit integers in `c` and saturate the results.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacssdd))]
+pub unsafe fn _mm_maccs_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpmacssdd(a.as_i32x4(), b.as_i32x4(), c.as_i32x4()))
+}
+
+/// Multiply packed 32-bit integers in `a` and `b` and add the low 32 bits
+/// of the products to the packed 32-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacsdd))]
+pub unsafe fn _mm_macc_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpmacsdd(a.as_i32x4(), b.as_i32x4(), c.as_i32x4()))
+}
+
+/// Multiply the signed 32-bit integers in elements 0 and 2 of `a` and `b`,
+/// add the 64-bit products to the packed 64-bit integers in `c` and
+/// saturate the results.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacssdql))]
+pub unsafe fn _mm_maccslo_epi32(
+    a: __m128i, b: __m128i, c: __m128i
+) -> __m128i {
+    mem::transmute(vpmacssdql(a.as_i32x4(), b.as_i32x4(), c.as_i64x2()))
+}
+
+/// Multiply the signed 32-bit integers in elements 0 and 2 of `a` and `b`
+/// and add the 64-bit products to the packed 64-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacsdql))]
+pub unsafe fn _mm_macclo_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpmacsdql(a.as_i32x4(), b.as_i32x4(), c.as_i64x2()))
+}
+
+/// Multiply the signed 32-bit integers in elements 1 and 3 of `a` and `b`,
+/// add the 64-bit products to the packed 64-bit integers in `c` and
+/// saturate the results.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacssdqh))]
+pub unsafe fn _mm_maccshi_epi32(
+    a: __m128i, b: __m128i, c: __m128i
+) -> __m128i {
+    mem::transmute(vpmacssdqh(a.as_i32x4(), b.as_i32x4(), c.as_i64x2()))
+}
+
+/// Multiply the signed 32-bit integers in elements 1 and 3 of `a` and `b`
+/// and add the 64-bit products to the packed 64-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmacsdqh))]
+pub unsafe fn _mm_macchi_epi32(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpmacsdqh(a.as_i32x4(), b.as_i32x4(), c.as_i64x2()))
+}
+
+/// Multiply packed signed 16-bit integers in `a` and `b`, horizontally
+/// add adjacent pairs of 32-bit products, add the sums to the packed
+/// 32-bit integers in `c` and saturate the results.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmadcsswd))]
+pub unsafe fn _mm_maddsd_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpmadcsswd(a.as_i16x8(), b.as_i16x8(), c.as_i32x4()))
+}
+
+/// Multiply packed signed 16-bit integers in `a` and `b`, horizontally
+/// add adjacent pairs of 32-bit products and add the sums to the packed
+/// 32-bit integers in `c`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpmadcswd))]
+pub unsafe fn _mm_maddd_epi16(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpmadcswd(a.as_i16x8(), b.as_i16x8(), c.as_i32x4()))
+}
+
+/// Horizontally add adjacent pairs of signed 8-bit integers in `a` and
+/// return the 16-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddbw))]
+pub unsafe fn _mm_haddw_epi8(a: __m128i) -> __m128i {
+    mem::transmute(vphaddbw(a.as_i8x16()))
+}
+
+/// Horizontally add each group of 4 adjacent signed 8-bit integers in `a` and
+/// return the 32-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddbd))]
+pub unsafe fn _mm_haddd_epi8(a: __m128i) -> __m128i {
+    mem::transmute(vphaddbd(a.as_i8x16()))
+}
+
+/// Horizontally add each group of 8 adjacent signed 8-bit integers in `a` and
+/// return the 64-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddbq))]
+pub unsafe fn _mm_haddq_epi8(a: __m128i) -> __m128i {
+    mem::transmute(vphaddbq(a.as_i8x16()))
+}
+
+/// Horizontally add adjacent pairs of signed 16-bit integers in `a` and
+/// return the 32-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddwd))]
+pub unsafe fn _mm_haddd_epi16(a: __m128i) -> __m128i {
+    mem::transmute(vphaddwd(a.as_i16x8()))
+}
+
+/// Horizontally add each group of 4 adjacent signed 16-bit integers in `a` and
+/// return the 64-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddwq))]
+pub unsafe fn _mm_haddq_epi16(a: __m128i) -> __m128i {
+    mem::transmute(vphaddwq(a.as_i16x8()))
+}
+
+/// Horizontally add adjacent pairs of signed 32-bit integers in `a` and
+/// return the 64-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphadddq))]
+pub unsafe fn _mm_haddq_epi32(a: __m128i) -> __m128i {
+    mem::transmute(vphadddq(a.as_i32x4()))
+}
+
+/// Horizontally add adjacent pairs of unsigned 8-bit integers in `a` and
+/// return the 16-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddubw))]
+pub unsafe fn _mm_haddw_epu8(a: __m128i) -> __m128i {
+    mem::transmute(vphaddubw(a.as_i8x16()))
+}
+
+/// Horizontally add each group of 4 adjacent unsigned 8-bit
+/// integers in `a` and return the 32-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddubd))]
+pub unsafe fn _mm_haddd_epu8(a: __m128i) -> __m128i {
+    mem::transmute(vphaddubd(a.as_i8x16()))
+}
+
+/// Horizontally add each group of 8 adjacent unsigned 8-bit
+/// integers in `a` and return the 64-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddubq))]
+pub unsafe fn _mm_haddq_epu8(a: __m128i) -> __m128i {
+    mem::transmute(vphaddubq(a.as_i8x16()))
+}
+
+/// Horizontally add adjacent pairs of unsigned 16-bit integers in `a` and
+/// return the 32-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphadduwd))]
+pub unsafe fn _mm_haddd_epu16(a: __m128i) -> __m128i {
+    mem::transmute(vphadduwd(a.as_i16x8()))
+}
+
+/// Horizontally add each group of 4 adjacent unsigned 16-bit
+/// integers in `a` and return the 64-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphadduwq))]
+pub unsafe fn _mm_haddq_epu16(a: __m128i) -> __m128i {
+    mem::transmute(vphadduwq(a.as_i16x8()))
+}
+
+/// Horizontally add adjacent pairs of unsigned 32-bit integers in `a` and
+/// return the 64-bit sums.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphaddudq))]
+pub unsafe fn _mm_haddq_epu32(a: __m128i) -> __m128i {
+    mem::transmute(vphaddudq(a.as_i32x4()))
+}
+
+/// Subtract the odd-indexed signed 8-bit integers in `a` from the
+/// adjacent even-indexed ones and return the 16-bit differences.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphsubbw))]
+pub unsafe fn _mm_hsubw_epi8(a: __m128i) -> __m128i {
+    mem::transmute(vphsubbw(a.as_i8x16()))
+}
+
+/// Subtract the odd-indexed signed 16-bit integers in `a` from the
+/// adjacent even-indexed ones and return the 32-bit differences.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphsubwd))]
+pub unsafe fn _mm_hsubd_epi16(a: __m128i) -> __m128i {
+    mem::transmute(vphsubwd(a.as_i16x8()))
+}
+
+/// Subtract the odd-indexed signed 32-bit integers in `a` from the
+/// adjacent even-indexed ones and return the 64-bit differences.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vphsubdq))]
+pub unsafe fn _mm_hsubq_epi32(a: __m128i) -> __m128i {
+    mem::transmute(vphsubdq(a.as_i32x4()))
+}
+
+/// Select bits from `a` where the corresponding bit of `c` is set and
+/// from `b` otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmov))]
+pub unsafe fn _mm_cmov_si128(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpcmov(a.as_i64x2(), b.as_i64x2(), c.as_i64x2()))
+}
+
+/// Select bits from `a` where the corresponding bit of `c` is set and
+/// from `b` otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcmov))]
+pub unsafe fn _mm256_cmov_si256(
+    a: __m256i, b: __m256i, c: __m256i
+) -> __m256i {
+    let r = vpcmov256(a.as_i64x4(), b.as_i64x4(), c.as_i64x4());
+    mem::transmute(r)
+}
+
+/// Select each byte of the result from the 32 bytes of `a` and `b`
+/// using the corresponding byte of `c`.
+///
+/// Bits `[4:0]` of each selector pick the source byte, where `0..16`
+/// index `a` and `16..32` index `b`. Bits `[7:5]` choose an operation to
+/// apply to it:
+///
+/// - `0`: the source byte,
+/// - `1`: the source byte inverted,
+/// - `2`: the source byte with its bits reversed,
+/// - `3`: the source byte with its bits reversed and inverted,
+/// - `4`: `0x00`,
+/// - `5`: `0xff`,
+/// - `6`: the sign bit of the source byte replicated,
+/// - `7`: the inverted sign bit of the source byte replicated.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpperm))]
+pub unsafe fn _mm_perm_epi8(a: __m128i, b: __m128i, c: __m128i) -> __m128i {
+    mem::transmute(vpperm(a.as_i8x16(), b.as_i8x16(), c.as_i8x16()))
+}
+
+/// Rotate each packed 8-bit integer in `a` by the signed count in the
+/// corresponding element of `b`, to the left if it is positive and to
+/// the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotb))]
+pub unsafe fn _mm_rot_epi8(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vprotb(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Rotate each packed 16-bit integer in `a` by the signed count in the
+/// corresponding element of `b`, to the left if it is positive and to
+/// the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotw))]
+pub unsafe fn _mm_rot_epi16(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vprotw(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Rotate each packed 32-bit integer in `a` by the signed count in the
+/// corresponding element of `b`, to the left if it is positive and to
+/// the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotd))]
+pub unsafe fn _mm_rot_epi32(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vprotd(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Rotate each packed 64-bit integer in `a` by the signed count in the
+/// corresponding element of `b`, to the left if it is positive and to
+/// the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotq))]
+pub unsafe fn _mm_rot_epi64(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vprotq(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Rotate each packed 8-bit integer in `a` by the signed 8-bit
+/// immediate `imm8`, to the left if it is positive and to the right if
+/// it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotb, imm8 = 1))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm_roti_epi8(a: __m128i, imm8: i32) -> __m128i {
+    let a = a.as_i8x16();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vprotbi(a, $imm8)
+        };
+    }
+    mem::transmute(constify_imm8!(imm8, call))
+}
+
+/// Rotate each packed 16-bit integer in `a` by the signed 8-bit
+/// immediate `imm8`, to the left if it is positive and to the right if
+/// it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotw, imm8 = 1))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm_roti_epi16(a: __m128i, imm8: i32) -> __m128i {
+    let a = a.as_i16x8();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vprotwi(a, $imm8)
+        };
+    }
+    mem::transmute(constify_imm8!(imm8, call))
+}
+
+/// Rotate each packed 32-bit integer in `a` by the signed 8-bit
+/// immediate `imm8`, to the left if it is positive and to the right if
+/// it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotd, imm8 = 1))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm_roti_epi32(a: __m128i, imm8: i32) -> __m128i {
+    let a = a.as_i32x4();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vprotdi(a, $imm8)
+        };
+    }
+    mem::transmute(constify_imm8!(imm8, call))
+}
+
+/// Rotate each packed 64-bit integer in `a` by the signed 8-bit
+/// immediate `imm8`, to the left if it is positive and to the right if
+/// it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vprotq, imm8 = 1))]
+#[rustc_args_required_const(1)]
+pub unsafe fn _mm_roti_epi64(a: __m128i, imm8: i32) -> __m128i {
+    let a = a.as_i64x2();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vprotqi(a, $imm8)
+        };
+    }
+    mem::transmute(constify_imm8!(imm8, call))
+}
+
+/// Shift each packed 8-bit integer in `a` logically by the signed
+/// count in the corresponding element of `b`, to the left if it is
+/// positive and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshlb))]
+pub unsafe fn _mm_shl_epi8(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vpshlb(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Shift each packed 16-bit integer in `a` logically by the signed
+/// count in the corresponding element of `b`, to the left if it is
+/// positive and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshlw))]
+pub unsafe fn _mm_shl_epi16(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vpshlw(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Shift each packed 32-bit integer in `a` logically by the signed
+/// count in the corresponding element of `b`, to the left if it is
+/// positive and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshld))]
+pub unsafe fn _mm_shl_epi32(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vpshld(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Shift each packed 64-bit integer in `a` logically by the signed
+/// count in the corresponding element of `b`, to the left if it is
+/// positive and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshlq))]
+pub unsafe fn _mm_shl_epi64(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vpshlq(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Shift each packed 8-bit integer in `a` arithmetically by the signed
+/// count in the corresponding element of `b`, to the left if it is
+/// positive and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshab))]
+pub unsafe fn _mm_sha_epi8(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vpshab(a.as_i8x16(), b.as_i8x16()))
+}
+
+/// Shift each packed 16-bit integer in `a` arithmetically by the signed
+/// count in the corresponding element of `b`, to the left if it is
+/// positive and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshaw))]
+pub unsafe fn _mm_sha_epi16(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vpshaw(a.as_i16x8(), b.as_i16x8()))
+}
+
+/// Shift each packed 32-bit integer in `a` arithmetically by the signed
+/// count in the corresponding element of `b`, to the left if it is
+/// positive and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshad))]
+pub unsafe fn _mm_sha_epi32(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vpshad(a.as_i32x4(), b.as_i32x4()))
+}
+
+/// Shift each packed 64-bit integer in `a` arithmetically by the signed
+/// count in the corresponding element of `b`, to the left if it is
+/// positive and to the right if it is negative.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpshaq))]
+pub unsafe fn _mm_sha_epi64(a: __m128i, b: __m128i) -> __m128i {
+    mem::transmute(vpshaq(a.as_i64x2(), b.as_i64x2()))
+}
+
+/// Compare packed signed 8-bit integers in `a` and `b` using the
+/// predicate `imm8`, one of the `_MM_PCOMCTRL_*` constants, and set
+/// each element of the result to all ones if it holds and to zero
+/// otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomb, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_com_epi8(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let (a, b) = (a.as_i8x16(), b.as_i8x16());
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpcomb(a, b, $imm8)
+        };
+    }
+    mem::transmute(constify_imm3!(imm8, call))
+}
+
+/// Compare packed signed 16-bit integers in `a` and `b` using the
+/// predicate `imm8`, one of the `_MM_PCOMCTRL_*` constants, and set
+/// each element of the result to all ones if it holds and to zero
+/// otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomw, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_com_epi16(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let (a, b) = (a.as_i16x8(), b.as_i16x8());
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpcomw(a, b, $imm8)
+        };
+    }
+    mem::transmute(constify_imm3!(imm8, call))
+}
+
+/// Compare packed signed 32-bit integers in `a` and `b` using the
+/// predicate `imm8`, one of the `_MM_PCOMCTRL_*` constants, and set
+/// each element of the result to all ones if it holds and to zero
+/// otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomd, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_com_epi32(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let (a, b) = (a.as_i32x4(), b.as_i32x4());
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpcomd(a, b, $imm8)
+        };
+    }
+    mem::transmute(constify_imm3!(imm8, call))
+}
+
+/// Compare packed signed 64-bit integers in `a` and `b` using the
+/// predicate `imm8`, one of the `_MM_PCOMCTRL_*` constants, and set
+/// each element of the result to all ones if it holds and to zero
+/// otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomq, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_com_epi64(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let (a, b) = (a.as_i64x2(), b.as_i64x2());
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpcomq(a, b, $imm8)
+        };
+    }
+    mem::transmute(constify_imm3!(imm8, call))
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` using the
+/// predicate `imm8`, one of the `_MM_PCOMCTRL_*` constants, and set
+/// each element of the result to all ones if it holds and to zero
+/// otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomub, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_com_epu8(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let (a, b) = (a.as_i8x16(), b.as_i8x16());
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpcomub(a, b, $imm8)
+        };
+    }
+    mem::transmute(constify_imm3!(imm8, call))
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` using the
+/// predicate `imm8`, one of the `_MM_PCOMCTRL_*` constants, and set
+/// each element of the result to all ones if it holds and to zero
+/// otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuw, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_com_epu16(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let (a, b) = (a.as_i16x8(), b.as_i16x8());
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpcomuw(a, b, $imm8)
+        };
+    }
+    mem::transmute(constify_imm3!(imm8, call))
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` using the
+/// predicate `imm8`, one of the `_MM_PCOMCTRL_*` constants, and set
+/// each element of the result to all ones if it holds and to zero
+/// otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomud, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_com_epu32(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let (a, b) = (a.as_i32x4(), b.as_i32x4());
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpcomud(a, b, $imm8)
+        };
+    }
+    mem::transmute(constify_imm3!(imm8, call))
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` using the
+/// predicate `imm8`, one of the `_MM_PCOMCTRL_*` constants, and set
+/// each element of the result to all ones if it holds and to zero
+/// otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuq, imm8 = 0))]
+#[rustc_args_required_const(2)]
+pub unsafe fn _mm_com_epu64(a: __m128i, b: __m128i, imm8: i32) -> __m128i {
+    let (a, b) = (a.as_i64x2(), b.as_i64x2());
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpcomuq(a, b, $imm8)
+        };
+    }
+    mem::transmute(constify_imm3!(imm8, call))
+}
+
+/// Compare packed signed 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomb))]
+pub unsafe fn _mm_comlt_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8(a, b, _MM_PCOMCTRL_LT)
+}
+
+/// Compare packed signed 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomb))]
+pub unsafe fn _mm_comle_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8(a, b, _MM_PCOMCTRL_LE)
+}
+
+/// Compare packed signed 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomb))]
+pub unsafe fn _mm_comgt_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8(a, b, _MM_PCOMCTRL_GT)
+}
+
+/// Compare packed signed 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomb))]
+pub unsafe fn _mm_comge_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8(a, b, _MM_PCOMCTRL_GE)
+}
+
+/// Compare packed signed 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomb))]
+pub unsafe fn _mm_comeq_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8(a, b, _MM_PCOMCTRL_EQ)
+}
+
+/// Compare packed signed 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// not equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomb))]
+pub unsafe fn _mm_comneq_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8(a, b, _MM_PCOMCTRL_NEQ)
+}
+
+/// Return a vector of zeros; the predicate of this comparison never
+/// holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomb))]
+pub unsafe fn _mm_comfalse_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8(a, b, _MM_PCOMCTRL_FALSE)
+}
+
+/// Return a vector of all ones; the predicate of this comparison
+/// always holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomb))]
+pub unsafe fn _mm_comtrue_epi8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi8(a, b, _MM_PCOMCTRL_TRUE)
+}
+
+/// Compare packed signed 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomw))]
+pub unsafe fn _mm_comlt_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16(a, b, _MM_PCOMCTRL_LT)
+}
+
+/// Compare packed signed 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomw))]
+pub unsafe fn _mm_comle_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16(a, b, _MM_PCOMCTRL_LE)
+}
+
+/// Compare packed signed 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomw))]
+pub unsafe fn _mm_comgt_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16(a, b, _MM_PCOMCTRL_GT)
+}
+
+/// Compare packed signed 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomw))]
+pub unsafe fn _mm_comge_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16(a, b, _MM_PCOMCTRL_GE)
+}
+
+/// Compare packed signed 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomw))]
+pub unsafe fn _mm_comeq_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16(a, b, _MM_PCOMCTRL_EQ)
+}
+
+/// Compare packed signed 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// not equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomw))]
+pub unsafe fn _mm_comneq_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16(a, b, _MM_PCOMCTRL_NEQ)
+}
+
+/// Return a vector of zeros; the predicate of this comparison never
+/// holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomw))]
+pub unsafe fn _mm_comfalse_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16(a, b, _MM_PCOMCTRL_FALSE)
+}
+
+/// Return a vector of all ones; the predicate of this comparison
+/// always holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomw))]
+pub unsafe fn _mm_comtrue_epi16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi16(a, b, _MM_PCOMCTRL_TRUE)
+}
+
+/// Compare packed signed 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomd))]
+pub unsafe fn _mm_comlt_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32(a, b, _MM_PCOMCTRL_LT)
+}
+
+/// Compare packed signed 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomd))]
+pub unsafe fn _mm_comle_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32(a, b, _MM_PCOMCTRL_LE)
+}
+
+/// Compare packed signed 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomd))]
+pub unsafe fn _mm_comgt_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32(a, b, _MM_PCOMCTRL_GT)
+}
+
+/// Compare packed signed 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomd))]
+pub unsafe fn _mm_comge_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32(a, b, _MM_PCOMCTRL_GE)
+}
+
+/// Compare packed signed 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomd))]
+pub unsafe fn _mm_comeq_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32(a, b, _MM_PCOMCTRL_EQ)
+}
+
+/// Compare packed signed 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// not equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomd))]
+pub unsafe fn _mm_comneq_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32(a, b, _MM_PCOMCTRL_NEQ)
+}
+
+/// Return a vector of zeros; the predicate of this comparison never
+/// holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomd))]
+pub unsafe fn _mm_comfalse_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32(a, b, _MM_PCOMCTRL_FALSE)
+}
+
+/// Return a vector of all ones; the predicate of this comparison
+/// always holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomd))]
+pub unsafe fn _mm_comtrue_epi32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi32(a, b, _MM_PCOMCTRL_TRUE)
+}
+
+/// Compare packed signed 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomq))]
+pub unsafe fn _mm_comlt_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64(a, b, _MM_PCOMCTRL_LT)
+}
+
+/// Compare packed signed 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomq))]
+pub unsafe fn _mm_comle_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64(a, b, _MM_PCOMCTRL_LE)
+}
+
+/// Compare packed signed 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomq))]
+pub unsafe fn _mm_comgt_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64(a, b, _MM_PCOMCTRL_GT)
+}
+
+/// Compare packed signed 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomq))]
+pub unsafe fn _mm_comge_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64(a, b, _MM_PCOMCTRL_GE)
+}
+
+/// Compare packed signed 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomq))]
+pub unsafe fn _mm_comeq_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64(a, b, _MM_PCOMCTRL_EQ)
+}
+
+/// Compare packed signed 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// not equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomq))]
+pub unsafe fn _mm_comneq_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64(a, b, _MM_PCOMCTRL_NEQ)
+}
+
+/// Return a vector of zeros; the predicate of this comparison never
+/// holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomq))]
+pub unsafe fn _mm_comfalse_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64(a, b, _MM_PCOMCTRL_FALSE)
+}
+
+/// Return a vector of all ones; the predicate of this comparison
+/// always holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomq))]
+pub unsafe fn _mm_comtrue_epi64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epi64(a, b, _MM_PCOMCTRL_TRUE)
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomub))]
+pub unsafe fn _mm_comlt_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8(a, b, _MM_PCOMCTRL_LT)
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomub))]
+pub unsafe fn _mm_comle_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8(a, b, _MM_PCOMCTRL_LE)
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomub))]
+pub unsafe fn _mm_comgt_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8(a, b, _MM_PCOMCTRL_GT)
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomub))]
+pub unsafe fn _mm_comge_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8(a, b, _MM_PCOMCTRL_GE)
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomub))]
+pub unsafe fn _mm_comeq_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8(a, b, _MM_PCOMCTRL_EQ)
+}
+
+/// Compare packed unsigned 8-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// not equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomub))]
+pub unsafe fn _mm_comneq_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8(a, b, _MM_PCOMCTRL_NEQ)
+}
+
+/// Return a vector of zeros; the predicate of this comparison never
+/// holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomub))]
+pub unsafe fn _mm_comfalse_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8(a, b, _MM_PCOMCTRL_FALSE)
+}
+
+/// Return a vector of all ones; the predicate of this comparison
+/// always holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomub))]
+pub unsafe fn _mm_comtrue_epu8(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu8(a, b, _MM_PCOMCTRL_TRUE)
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuw))]
+pub unsafe fn _mm_comlt_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16(a, b, _MM_PCOMCTRL_LT)
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuw))]
+pub unsafe fn _mm_comle_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16(a, b, _MM_PCOMCTRL_LE)
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuw))]
+pub unsafe fn _mm_comgt_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16(a, b, _MM_PCOMCTRL_GT)
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuw))]
+pub unsafe fn _mm_comge_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16(a, b, _MM_PCOMCTRL_GE)
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuw))]
+pub unsafe fn _mm_comeq_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16(a, b, _MM_PCOMCTRL_EQ)
+}
+
+/// Compare packed unsigned 16-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// not equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuw))]
+pub unsafe fn _mm_comneq_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16(a, b, _MM_PCOMCTRL_NEQ)
+}
+
+/// Return a vector of zeros; the predicate of this comparison never
+/// holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuw))]
+pub unsafe fn _mm_comfalse_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16(a, b, _MM_PCOMCTRL_FALSE)
+}
+
+/// Return a vector of all ones; the predicate of this comparison
+/// always holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuw))]
+pub unsafe fn _mm_comtrue_epu16(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu16(a, b, _MM_PCOMCTRL_TRUE)
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomud))]
+pub unsafe fn _mm_comlt_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32(a, b, _MM_PCOMCTRL_LT)
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomud))]
+pub unsafe fn _mm_comle_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32(a, b, _MM_PCOMCTRL_LE)
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomud))]
+pub unsafe fn _mm_comgt_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32(a, b, _MM_PCOMCTRL_GT)
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomud))]
+pub unsafe fn _mm_comge_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32(a, b, _MM_PCOMCTRL_GE)
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomud))]
+pub unsafe fn _mm_comeq_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32(a, b, _MM_PCOMCTRL_EQ)
+}
+
+/// Compare packed unsigned 32-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// not equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomud))]
+pub unsafe fn _mm_comneq_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32(a, b, _MM_PCOMCTRL_NEQ)
+}
+
+/// Return a vector of zeros; the predicate of this comparison never
+/// holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomud))]
+pub unsafe fn _mm_comfalse_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32(a, b, _MM_PCOMCTRL_FALSE)
+}
+
+/// Return a vector of all ones; the predicate of this comparison
+/// always holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomud))]
+pub unsafe fn _mm_comtrue_epu32(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu32(a, b, _MM_PCOMCTRL_TRUE)
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuq))]
+pub unsafe fn _mm_comlt_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64(a, b, _MM_PCOMCTRL_LT)
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// less than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuq))]
+pub unsafe fn _mm_comle_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64(a, b, _MM_PCOMCTRL_LE)
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuq))]
+pub unsafe fn _mm_comgt_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64(a, b, _MM_PCOMCTRL_GT)
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// greater than or equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuq))]
+pub unsafe fn _mm_comge_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64(a, b, _MM_PCOMCTRL_GE)
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuq))]
+pub unsafe fn _mm_comeq_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64(a, b, _MM_PCOMCTRL_EQ)
+}
+
+/// Compare packed unsigned 64-bit integers in `a` and `b` and set each
+/// element of the result to all ones if the element of `a` is
+/// not equal to the one of `b` and to zero otherwise.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuq))]
+pub unsafe fn _mm_comneq_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64(a, b, _MM_PCOMCTRL_NEQ)
+}
+
+/// Return a vector of zeros; the predicate of this comparison never
+/// holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuq))]
+pub unsafe fn _mm_comfalse_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64(a, b, _MM_PCOMCTRL_FALSE)
+}
+
+/// Return a vector of all ones; the predicate of this comparison
+/// always holds.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpcomuq))]
+pub unsafe fn _mm_comtrue_epu64(a: __m128i, b: __m128i) -> __m128i {
+    _mm_com_epu64(a, b, _MM_PCOMCTRL_TRUE)
+}
+
+/// Shuffle double-precision (64-bit) floating-point elements from `a`
+/// and `b` using the selectors in `c`.
+///
+/// Bit 1 of each selector picks the element within the 128-bit lane
+/// and bit 2 picks `a` (`0`) or `b` (`1`).
+///
+/// `imm8` controls zeroing based on bit 3 of each selector: with `2`
+/// the elements whose selector has it set are zeroed, with `3` the
+/// ones whose selector has it clear; `0` and `1` never zero.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpermil2pd, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_permute2_pd(
+    a: __m128d, b: __m128d, c: __m128i, imm8: i32
+) -> __m128d {
+    let c = c.as_i64x2();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpermil2pd(a, b, c, $imm8)
+        };
+    }
+    constify_imm2!(imm8, call)
+}
+
+/// Shuffle double-precision (64-bit) floating-point elements from `a`
+/// and `b` using the selectors in `c`.
+///
+/// Bit 1 of each selector picks the element within the 128-bit lane
+/// and bit 2 picks `a` (`0`) or `b` (`1`).
+///
+/// `imm8` controls zeroing based on bit 3 of each selector: with `2`
+/// the elements whose selector has it set are zeroed, with `3` the
+/// ones whose selector has it clear; `0` and `1` never zero.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpermil2pd, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_permute2_pd(
+    a: __m256d, b: __m256d, c: __m256i, imm8: i32
+) -> __m256d {
+    let c = c.as_i64x4();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpermil2pd256(a, b, c, $imm8)
+        };
+    }
+    constify_imm2!(imm8, call)
+}
+
+/// Shuffle single-precision (32-bit) floating-point elements from `a`
+/// and `b` using the selectors in `c`.
+///
+/// Bits `[1:0]` of each selector pick the element within the 128-bit
+/// lane and bit 2 picks `a` (`0`) or `b` (`1`).
+///
+/// `imm8` controls zeroing based on bit 3 of each selector: with `2`
+/// the elements whose selector has it set are zeroed, with `3` the
+/// ones whose selector has it clear; `0` and `1` never zero.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpermil2ps, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm_permute2_ps(
+    a: __m128, b: __m128, c: __m128i, imm8: i32
+) -> __m128 {
+    let c = c.as_i32x4();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpermil2ps(a, b, c, $imm8)
+        };
+    }
+    constify_imm2!(imm8, call)
+}
+
+/// Shuffle single-precision (32-bit) floating-point elements from `a`
+/// and `b` using the selectors in `c`.
+///
+/// Bits `[1:0]` of each selector pick the element within the 128-bit
+/// lane and bit 2 picks `a` (`0`) or `b` (`1`).
+///
+/// `imm8` controls zeroing based on bit 3 of each selector: with `2`
+/// the elements whose selector has it set are zeroed, with `3` the
+/// ones whose selector has it clear; `0` and `1` never zero.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vpermil2ps, imm8 = 0))]
+#[rustc_args_required_const(3)]
+pub unsafe fn _mm256_permute2_ps(
+    a: __m256, b: __m256, c: __m256i, imm8: i32
+) -> __m256 {
+    let c = c.as_i32x8();
+    macro_rules! call {
+        ($imm8:expr) => {
+            vpermil2ps256(a, b, c, $imm8)
+        };
+    }
+    constify_imm2!(imm8, call)
+}
+
+/// Extract the fractional part of the lower single-precision (32-bit)
+/// floating-point element in `a`, and zero the upper elements of the
+/// result.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczss))]
+pub unsafe fn _mm_frcz_ss(a: __m128) -> __m128 {
+    vfrczss(a)
+}
+
+/// Extract the fractional part of the lower double-precision (64-bit)
+/// floating-point element in `a`, and zero the upper elements of the
+/// result.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczsd))]
+pub unsafe fn _mm_frcz_sd(a: __m128d) -> __m128d {
+    vfrczsd(a)
+}
+
+/// Extract the fractional part of each packed single-precision (32-bit)
+/// floating-point element in `a`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczps))]
+pub unsafe fn _mm_frcz_ps(a: __m128) -> __m128 {
+    vfrczps(a)
+}
+
+/// Extract the fractional part of each packed double-precision (64-bit)
+/// floating-point element in `a`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczpd))]
+pub unsafe fn _mm_frcz_pd(a: __m128d) -> __m128d {
+    vfrczpd(a)
+}
+
+/// Extract the fractional part of each packed single-precision (32-bit)
+/// floating-point element in `a`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczps))]
+pub unsafe fn _mm256_frcz_ps(a: __m256) -> __m256 {
+    vfrczps256(a)
+}
+
+/// Extract the fractional part of each packed double-precision (64-bit)
+/// floating-point element in `a`.
+#[inline]
+#[target_feature(enable = "xop")]
+#[cfg_attr(test, assert_instr(vfrczpd))]
+pub unsafe fn _mm256_frcz_pd(a: __m256d) -> __m256d {
+    vfrczpd256(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_maccs_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, 5, 6, 300, -300);
+        let b = _mm_setr_epi16(7, 8, 9, 10, 11, 12, 300, 300);
+        let c = _mm_setr_epi16(1, -1, 0, 0, 0, 0, 0, 0);
+        let r = _mm_maccs_epi16(a, b, c);
+        let e =
+            _mm_setr_epi16(8, 15, 27, 40, 55, 72, i16::max_value(), -32768);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_macc_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, 5, 6, 300, -300);
+        let b = _mm_setr_epi16(7, 8, 9, 10, 11, 12, 300, 300);
+        let c = _mm_setr_epi16(1, -1, 0, 0, 0, 0, 0, 0);
+        let r = _mm_macc_epi16(a, b, c);
+        let e = _mm_setr_epi16(
+            8,
+            15,
+            27,
+            40,
+            55,
+            72,
+            90000i32 as i16,
+            -90000i32 as i16,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_maccsd_epi16() {
+        let a = _mm_set1_epi16(-32768);
+        let b = _mm_set1_epi16(-32768);
+        let c = _mm_setr_epi32(1, i32::max_value(), -1, 0);
+        let r = _mm_maccsd_epi16(a, b, c);
+        let e = _mm_setr_epi32(
+            (1 << 30) + 1,
+            i32::max_value(),
+            (1 << 30) - 1,
+            1 << 30,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_maccd_epi16() {
+        let a = _mm_set1_epi16(-32768);
+        let b = _mm_set1_epi16(-32768);
+        let c = _mm_setr_epi32(1, i32::max_value(), -1, 0);
+        let r = _mm_maccd_epi16(a, b, c);
+        let e = _mm_setr_epi32(
+            (1 << 30) + 1,
+            i32::max_value().wrapping_add(1 << 30),
+            (1 << 30) - 1,
+            1 << 30,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_maccs_epi32() {
+        let a = _mm_setr_epi32(2, 3, 1 << 16, -(1 << 16));
+        let b = _mm_setr_epi32(5, -7, 1 << 16, 1 << 16);
+        let c = _mm_setr_epi32(1, 1, 0, 0);
+        let r = _mm_maccs_epi32(a, b, c);
+        let e = _mm_setr_epi32(11, -20, i32::max_value(), i32::min_value());
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_macc_epi32() {
+        let a = _mm_setr_epi32(2, 3, 1 << 16, -(1 << 16));
+        let b = _mm_setr_epi32(5, -7, 1 << 16, 1 << 16);
+        let c = _mm_setr_epi32(1, 1, 1, 0);
+        let r = _mm_macc_epi32(a, b, c);
+        let e = _mm_setr_epi32(11, -20, 1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_maccslo_epi32() {
+        let a = _mm_setr_epi32(i32::min_value(), 9, 3, 9);
+        let b = _mm_setr_epi32(i32::min_value(), 9, -4, 9);
+        let c = _mm_set_epi64x(-1, i64::max_value());
+        let r = _mm_maccslo_epi32(a, b, c);
+        let e = _mm_set_epi64x(-13, i64::max_value());
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_macclo_epi32() {
+        let a = _mm_setr_epi32(i32::min_value(), 9, 3, 9);
+        let b = _mm_setr_epi32(i32::min_value(), 9, -4, 9);
+        let c = _mm_set_epi64x(-1, 1);
+        let r = _mm_macclo_epi32(a, b, c);
+        let e = _mm_set_epi64x(-13, (1 << 62) + 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_maccshi_epi32() {
+        let a = _mm_setr_epi32(9, i32::min_value(), 9, 3);
+        let b = _mm_setr_epi32(9, i32::min_value(), 9, -4);
+        let c = _mm_set_epi64x(-1, i64::max_value());
+        let r = _mm_maccshi_epi32(a, b, c);
+        let e = _mm_set_epi64x(-13, i64::max_value());
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_macchi_epi32() {
+        let a = _mm_setr_epi32(9, i32::min_value(), 9, 3);
+        let b = _mm_setr_epi32(9, i32::min_value(), 9, -4);
+        let c = _mm_set_epi64x(-1, 1);
+        let r = _mm_macchi_epi32(a, b, c);
+        let e = _mm_set_epi64x(-13, (1 << 62) + 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_maddsd_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, -32768, -32768, 0, 0);
+        let b = _mm_setr_epi16(5, 6, 7, 8, -32768, -32768, 0, 0);
+        let c = _mm_setr_epi32(1, -1, 0, 7);
+        let r = _mm_maddsd_epi16(a, b, c);
+        let e = _mm_setr_epi32(18, 52, i32::max_value(), 7);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_maddd_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, -32768, -32768, 0, 0);
+        let b = _mm_setr_epi16(5, 6, 7, 8, -32768, -32768, 0, 0);
+        let c = _mm_setr_epi32(1, -1, 0, 7);
+        let r = _mm_maddd_epi16(a, b, c);
+        let e = _mm_setr_epi32(18, 52, i32::min_value(), 7);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_hadd_epi8() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm_setr_epi8(
+            1, 2, 3, 4, 5, 6, 7, 8,
+            -1, -2, -3, -4, -5, -6, -7, -128,
+        );
+        let r = _mm_haddw_epi8(a);
+        let e = _mm_setr_epi16(3, 7, 11, 15, -3, -7, -11, -135);
+        assert_eq_m128i(r, e);
+        let r = _mm_haddd_epi8(a);
+        let e = _mm_setr_epi32(10, 26, -10, -146);
+        assert_eq_m128i(r, e);
+        let r = _mm_haddq_epi8(a);
+        let e = _mm_set_epi64x(-156, 36);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_hadd_epu8() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm_setr_epi8(
+            1, 2, 3, 4, 5, 6, 7, 8,
+            -1, -1, -1, -1, -1, -1, -1, -128,
+        );
+        let r = _mm_haddw_epu8(a);
+        let e = _mm_setr_epi16(3, 7, 11, 15, 510, 510, 510, 383);
+        assert_eq_m128i(r, e);
+        let r = _mm_haddd_epu8(a);
+        let e = _mm_setr_epi32(10, 26, 1020, 893);
+        assert_eq_m128i(r, e);
+        let r = _mm_haddq_epu8(a);
+        let e = _mm_set_epi64x(1913, 36);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_hadd_epi16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, -1, -2, -3, -32768);
+        let r = _mm_haddd_epi16(a);
+        let e = _mm_setr_epi32(3, 7, -3, -32771);
+        assert_eq_m128i(r, e);
+        let r = _mm_haddq_epi16(a);
+        let e = _mm_set_epi64x(-32774, 10);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_hadd_epu16() {
+        let a = _mm_setr_epi16(1, 2, 3, 4, -1, -1, -1, -32768);
+        let r = _mm_haddd_epu16(a);
+        let e = _mm_setr_epi32(3, 7, 131070, 98303);
+        assert_eq_m128i(r, e);
+        let r = _mm_haddq_epu16(a);
+        let e = _mm_set_epi64x(229373, 10);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_haddq_epi32() {
+        let a = _mm_setr_epi32(1, 2, -1, i32::min_value());
+        let r = _mm_haddq_epi32(a);
+        let e = _mm_set_epi64x(-1 - (1 << 31), 3);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_haddq_epu32() {
+        let a = _mm_setr_epi32(1, 2, -1, i32::min_value());
+        let r = _mm_haddq_epu32(a);
+        let e = _mm_set_epi64x((1 << 32) - 1 + (1 << 31), 3);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_hsubw_epi8() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm_setr_epi8(
+            5, 2, 2, 5, -128, 127, 127, -128,
+            0, 0, 1, 1, -1, 1, 1, -1,
+        );
+        let r = _mm_hsubw_epi8(a);
+        let e = _mm_setr_epi16(3, -3, -255, 255, 0, 0, -2, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_hsubd_epi16() {
+        let a = _mm_setr_epi16(5, 2, 2, 5, -32768, 32767, 32767, -32768);
+        let r = _mm_hsubd_epi16(a);
+        let e = _mm_setr_epi32(3, -3, -65535, 65535);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_hsubq_epi32() {
+        let a = _mm_setr_epi32(5, 2, i32::min_value(), i32::max_value());
+        let r = _mm_hsubq_epi32(a);
+        let e = _mm_set_epi64x(-(1 << 32) + 1, 3);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_cmov_si128() {
+        let a = _mm_set1_epi8(0x55);
+        let b = _mm_set1_epi8(0x2a);
+        let c = _mm_set_epi64x(0, 0x0f);
+        let r = _mm_cmov_si128(a, b, c);
+        let e = _mm_set_epi64x(0x2a2a2a2a2a2a2a2a, 0x2a2a2a2a2a2a2a25);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm256_cmov_si256() {
+        let a = _mm256_set1_epi8(0x55);
+        let b = _mm256_set1_epi8(0x2a);
+        let c = _mm256_set_epi64x(-1, 0, 0, 0x0f);
+        let r = _mm256_cmov_si256(a, b, c);
+        let e = _mm256_set_epi64x(
+            0x5555555555555555,
+            0x2a2a2a2a2a2a2a2a,
+            0x2a2a2a2a2a2a2a2a,
+            0x2a2a2a2a2a2a2a25,
+        );
+        assert_eq_m256i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_perm_epi8() {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let a = _mm_setr_epi8(
+            0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15,
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let b = _mm_setr_epi8(
+            16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, -31,
+        );
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let c: __m128i = mem::transmute([
+            0x1fu8, 0x00, 0x10, 0x0f, 0x21, 0x41, 0x61, 0x80,
+            0xa0, 0xdf, 0xe0, 0xc0, 0x05, 0x15, 0x3f, 0x1e,
+        ]);
+        let r = _mm_perm_epi8(a, b, c);
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let e = _mm_setr_epi8(
+            -31, 0, 16, 15, -2, -128, 127, 0,
+            -1, -1, -1, 0, 5, 21, 30, 30,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_rot_epi8() {
+        let a =
+            _mm_setr_epi8(1, -127, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0);
+        let b =
+            _mm_setr_epi8(1, 1, -1, 8, 9, 0, 7, -7, 0, 0, 0, 0, 0, 0, 0, 0);
+        let r = _mm_rot_epi8(a, b);
+        let e = _mm_setr_epi8(
+            2, 3, -127, 4, 10, 6, -125, 16, 0, 0, 0, 0, 0, 0, 0, 0,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_rot_epi16() {
+        let a = _mm_setr_epi16(1, -32767, 3, 4, 0, 0, 0, 0);
+        let b = _mm_setr_epi16(1, 1, -1, 16, 0, 0, 0, 0);
+        let r = _mm_rot_epi16(a, b);
+        let e = _mm_setr_epi16(2, 3, -32767, 4, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_rot_epi32() {
+        let a = _mm_setr_epi32(1, i32::min_value() + 1, 3, 4);
+        let b = _mm_setr_epi32(1, 1, -1, 32);
+        let r = _mm_rot_epi32(a, b);
+        let e = _mm_setr_epi32(2, 3, i32::min_value() + 1, 4);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_rot_epi64() {
+        let a = _mm_set_epi64x(i64::min_value() + 1, 1);
+        let b = _mm_set_epi64x(-1, 63);
+        let r = _mm_rot_epi64(a, b);
+        let e = _mm_set_epi64x(-(1 << 62), i64::min_value());
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_roti_epi8() {
+        let a =
+            _mm_setr_epi8(1, -127, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0);
+        let r = _mm_roti_epi8(a, 1);
+        let e =
+            _mm_setr_epi8(2, 3, 6, 8, 10, 12, 14, 16, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+        let r = _mm_roti_epi8(a, -1);
+        let e = _mm_setr_epi8(
+            -128, -64, -127, 2, -126, 3, -125, 4, 0, 0, 0, 0, 0, 0, 0, 0,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_roti_epi16() {
+        let a = _mm_setr_epi16(1, -32767, 3, 4, 0, 0, 0, 0);
+        let r = _mm_roti_epi16(a, 1);
+        let e = _mm_setr_epi16(2, 3, 6, 8, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+        let r = _mm_roti_epi16(a, -1);
+        let e = _mm_setr_epi16(-32768, -16384, -32767, 2, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_roti_epi32() {
+        let a = _mm_setr_epi32(1, i32::min_value() + 1, 3, 4);
+        let r = _mm_roti_epi32(a, 1);
+        let e = _mm_setr_epi32(2, 3, 6, 8);
+        assert_eq_m128i(r, e);
+        let r = _mm_roti_epi32(a, -1);
+        let e = _mm_setr_epi32(
+            i32::min_value(),
+            i32::min_value() / 2,
+            i32::min_value() + 1,
+            2,
+        );
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_roti_epi64() {
+        let a = _mm_set_epi64x(i64::min_value() + 1, 1);
+        let r = _mm_roti_epi64(a, 1);
+        let e = _mm_set_epi64x(3, 2);
+        assert_eq_m128i(r, e);
+        let r = _mm_roti_epi64(a, -1);
+        let e = _mm_set_epi64x(i64::min_value() / 2, i64::min_value());
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_shl_epi8() {
+        let a = _mm_setr_epi8(
+            1, -128, -128, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        );
+        let b =
+            _mm_setr_epi8(1, 1, -1, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        let r = _mm_shl_epi8(a, b);
+        let e = _mm_setr_epi8(2, 0, 64, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_shl_epi16() {
+        let a = _mm_setr_epi16(1, -32768, -32768, 3, 0, 0, 0, 0);
+        let b = _mm_setr_epi16(1, 1, -1, -1, 0, 0, 0, 0);
+        let r = _mm_shl_epi16(a, b);
+        let e = _mm_setr_epi16(2, 0, 16384, 1, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_shl_epi32() {
+        let a = _mm_setr_epi32(1, i32::min_value(), i32::min_value(), 3);
+        let b = _mm_setr_epi32(1, 1, -1, -1);
+        let r = _mm_shl_epi32(a, b);
+        let e = _mm_setr_epi32(2, 0, 1 << 30, 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_shl_epi64() {
+        let a = _mm_set_epi64x(i64::min_value(), 1);
+        let b = _mm_set_epi64x(-1, 1);
+        let r = _mm_shl_epi64(a, b);
+        let e = _mm_set_epi64x(1 << 62, 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_sha_epi8() {
+        let a = _mm_setr_epi8(
+            1, -128, -128, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        );
+        let b =
+            _mm_setr_epi8(1, 1, -1, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        let r = _mm_sha_epi8(a, b);
+        let e =
+            _mm_setr_epi8(2, 0, -64, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_sha_epi16() {
+        let a = _mm_setr_epi16(1, -32768, -32768, 3, 0, 0, 0, 0);
+        let b = _mm_setr_epi16(1, 1, -1, -1, 0, 0, 0, 0);
+        let r = _mm_sha_epi16(a, b);
+        let e = _mm_setr_epi16(2, 0, -16384, 1, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_sha_epi32() {
+        let a = _mm_setr_epi32(1, i32::min_value(), i32::min_value(), 3);
+        let b = _mm_setr_epi32(1, 1, -1, -1);
+        let r = _mm_sha_epi32(a, b);
+        let e = _mm_setr_epi32(2, 0, -(1 << 30), 1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_sha_epi64() {
+        let a = _mm_set_epi64x(i64::min_value(), 1);
+        let b = _mm_set_epi64x(-1, 1);
+        let r = _mm_sha_epi64(a, b);
+        let e = _mm_set_epi64x(-(1 << 62), 2);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_com_epi8() {
+        let a = _mm_setr_epi8(-1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        let b = _mm_set1_epi8(0);
+        let r = _mm_com_epi8(a, b, _MM_PCOMCTRL_LT);
+        let e = _mm_setr_epi8(-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+        let r = _mm_comge_epi8(a, b);
+        let e = _mm_setr_epi8(
+            0, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,
+        );
+        assert_eq_m128i(r, e);
+        assert_eq_m128i(_mm_comfalse_epi8(a, b), _mm_set1_epi8(0));
+        assert_eq_m128i(_mm_comtrue_epi8(a, b), _mm_set1_epi8(-1));
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_com_epu8() {
+        let a = _mm_setr_epi8(-1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        let b = _mm_set1_epi8(0);
+        let r = _mm_comgt_epu8(a, b);
+        let e =
+            _mm_setr_epi8(-1, 0, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq_m128i(r, e);
+        let r = _mm_comlt_epu8(a, b);
+        assert_eq_m128i(r, _mm_set1_epi8(0));
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_com_epi16() {
+        let a = _mm_setr_epi16(-1, 0, 1, 2, -32768, 32767, 5, 5);
+        let b = _mm_setr_epi16(0, 0, 0, 2, 32767, -32768, 4, 6);
+        let r = _mm_comle_epi16(a, b);
+        let e = _mm_setr_epi16(-1, -1, 0, -1, -1, 0, 0, -1);
+        assert_eq_m128i(r, e);
+        let r = _mm_comle_epu16(a, b);
+        let e = _mm_setr_epi16(0, -1, 0, -1, 0, -1, 0, -1);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_com_epi32() {
+        let a = _mm_setr_epi32(-1, 0, 1, 2);
+        let b = _mm_setr_epi32(0, 0, 0, 2);
+        let r = _mm_comeq_epi32(a, b);
+        let e = _mm_setr_epi32(0, -1, 0, -1);
+        assert_eq_m128i(r, e);
+        let r = _mm_comneq_epu32(a, b);
+        let e = _mm_setr_epi32(-1, 0, -1, 0);
+        assert_eq_m128i(r, e);
+        let r = _mm_comgt_epi32(a, b);
+        let e = _mm_setr_epi32(0, 0, -1, 0);
+        assert_eq_m128i(r, e);
+        let r = _mm_comgt_epu32(a, b);
+        let e = _mm_setr_epi32(-1, 0, -1, 0);
+        assert_eq_m128i(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_com_epi64() {
+        let a = _mm_set_epi64x(-1, 1);
+        let b = _mm_set_epi64x(0, 1);
+        let r = _mm_comge_epi64(a, b);
+        assert_eq_m128i(r, _mm_set_epi64x(0, -1));
+        let r = _mm_comge_epu64(a, b);
+        assert_eq_m128i(r, _mm_set_epi64x(-1, -1));
+        let r = _mm_com_epu64(a, b, _MM_PCOMCTRL_LT);
+        assert_eq_m128i(r, _mm_set_epi64x(0, 0));
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_permute2_pd() {
+        let a = _mm_setr_pd(1., 2.);
+        let b = _mm_setr_pd(3., 4.);
+        let c = _mm_set_epi64x(6, 0);
+        let r = _mm_permute2_pd(a, b, c, 0);
+        assert_eq_m128d(r, _mm_setr_pd(1., 4.));
+        let c = _mm_set_epi64x(2 | 8, 4);
+        let r = _mm_permute2_pd(a, b, c, 2);
+        assert_eq_m128d(r, _mm_setr_pd(3., 0.));
+        let r = _mm_permute2_pd(a, b, c, 3);
+        assert_eq_m128d(r, _mm_setr_pd(0., 2.));
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm256_permute2_pd() {
+        let a = _mm256_setr_pd(1., 2., 3., 4.);
+        let b = _mm256_setr_pd(5., 6., 7., 8.);
+        let c = _mm256_setr_epi64x(2, 4, 6, 0);
+        let r = _mm256_permute2_pd(a, b, c, 0);
+        assert_eq_m256d(r, _mm256_setr_pd(2., 5., 8., 3.));
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_permute2_ps() {
+        let a = _mm_setr_ps(1., 2., 3., 4.);
+        let b = _mm_setr_ps(5., 6., 7., 8.);
+        let c = _mm_setr_epi32(3, 4, 6 | 8, 1);
+        let r = _mm_permute2_ps(a, b, c, 0);
+        assert_eq_m128(r, _mm_setr_ps(4., 5., 7., 2.));
+        let r = _mm_permute2_ps(a, b, c, 2);
+        assert_eq_m128(r, _mm_setr_ps(4., 5., 0., 2.));
+        let r = _mm_permute2_ps(a, b, c, 3);
+        assert_eq_m128(r, _mm_setr_ps(0., 0., 7., 0.));
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm256_permute2_ps() {
+        let a = _mm256_setr_ps(1., 2., 3., 4., 5., 6., 7., 8.);
+        let b = _mm256_setr_ps(9., 10., 11., 12., 13., 14., 15., 16.);
+        let c = _mm256_setr_epi32(3, 4, 6, 1, 0, 7, 5, 2);
+        let r = _mm256_permute2_ps(a, b, c, 0);
+        let e = _mm256_setr_ps(4., 9., 11., 2., 5., 16., 14., 7.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_frcz_ss() {
+        let a = _mm_setr_ps(-2.75, 1.5, 2.5, 3.5);
+        let r = _mm_frcz_ss(a);
+        assert_eq_m128(r, _mm_setr_ps(-0.75, 0., 0., 0.));
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_frcz_sd() {
+        let a = _mm_setr_pd(5.25, 1.5);
+        let r = _mm_frcz_sd(a);
+        assert_eq_m128d(r, _mm_setr_pd(0.25, 0.));
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_frcz_ps() {
+        let a = _mm_setr_ps(-2.75, 1.5, 2., 0.125);
+        let r = _mm_frcz_ps(a);
+        assert_eq_m128(r, _mm_setr_ps(-0.75, 0.5, 0., 0.125));
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm_frcz_pd() {
+        let a = _mm_setr_pd(-2.75, 1.5);
+        let r = _mm_frcz_pd(a);
+        assert_eq_m128d(r, _mm_setr_pd(-0.75, 0.5));
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm256_frcz_ps() {
+        let a = _mm256_setr_ps(-2.75, 1.5, 2., 0.125, 7., -7.5, 9.25, 0.);
+        let r = _mm256_frcz_ps(a);
+        let e = _mm256_setr_ps(-0.75, 0.5, 0., 0.125, 0., -0.5, 0.25, 0.);
+        assert_eq_m256(r, e);
+    }
+
+    #[simd_test = "xop"]
+    unsafe fn test_mm256_frcz_pd() {
+        let a = _mm256_setr_pd(-2.75, 1.5, 2., 9.25);
+        let r = _mm256_frcz_pd(a);
+        assert_eq_m256d(r, _mm256_setr_pd(-0.75, 0.5, 0., 0.25));
+    }
+}
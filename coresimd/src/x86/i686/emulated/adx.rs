@@ -0,0 +1,139 @@
+//! `x86_64`'s multi-precision add-carry intrinsics on `x86`
+//!
+//! Each intrinsic chains the carry through two 32-bit `ADC`/`SBB`
+//! instructions.
+
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Add unsigned 64-bit integers `a` and `b` with unsigned 8-bit carry-in
+/// `c_in` (carry flag), and store the unsigned 64-bit result in `out`, and
+/// the carry-out is returned (carry or overflow flag).
+#[inline]
+#[cfg_attr(test, assert_instr(adc))]
+pub unsafe fn _addcarry_u64(c_in: u8, a: u64, b: u64, out: &mut u64) -> u8 {
+    let (mut lo, mut hi) = (0, 0);
+    let c = _addcarry_u32(c_in, a as u32, b as u32, &mut lo);
+    let c = _addcarry_u32(c, (a >> 32) as u32, (b >> 32) as u32, &mut hi);
+    *out = (hi as u64) << 32 | lo as u64;
+    c
+}
+
+/// Add unsigned 64-bit integers `a` and `b` with unsigned 8-bit carry-in
+/// `c_in` (carry or overflow flag), and store the unsigned 64-bit result in
+/// `out`, and the carry-out is returned (carry or overflow flag).
+#[inline]
+#[target_feature(enable = "adx")]
+#[cfg_attr(test, assert_instr(adc))]
+pub unsafe fn _addcarryx_u64(c_in: u8, a: u64, b: u64, out: &mut u64) -> u8 {
+    let (mut lo, mut hi) = (0, 0);
+    let c = _addcarryx_u32(c_in, a as u32, b as u32, &mut lo);
+    let c = _addcarryx_u32(c, (a >> 32) as u32, (b >> 32) as u32, &mut hi);
+    *out = (hi as u64) << 32 | lo as u64;
+    c
+}
+
+/// Subtract unsigned 64-bit integer `b` and unsigned 8-bit borrow-in `c_in`
+/// (carry flag) from unsigned 64-bit integer `a`, and store the unsigned
+/// 64-bit result in `out`, and the borrow-out is returned (carry flag).
+#[inline]
+#[cfg_attr(test, assert_instr(sbb))]
+pub unsafe fn _subborrow_u64(c_in: u8, a: u64, b: u64, out: &mut u64) -> u8 {
+    let (mut lo, mut hi) = (0, 0);
+    let c = _subborrow_u32(c_in, a as u32, b as u32, &mut lo);
+    let c = _subborrow_u32(c, (a >> 32) as u32, (b >> 32) as u32, &mut hi);
+    *out = (hi as u64) << 32 | lo as u64;
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    /// Operands whose limbs exercise carries and borrows in every position.
+    const OPERANDS: [u128; 7] = [
+        0,
+        1,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+        0x8000_0000_0000_0000_0000_0000_0000_0000,
+        0x0123_4567_89ab_cdef_fedc_ba98_7654_3210,
+        0xfedc_ba98_7654_3210_0123_4567_89ab_cdef,
+    ];
+
+    /// Adds `a` and `b` as two 64-bit limbs with `add`, returning the sum
+    /// and the final carry.
+    fn chain<F>(a: u128, b: u128, add: F) -> (u128, u8)
+    where
+        F: Fn(u8, u64, u64, &mut u64) -> u8,
+    {
+        let (mut lo, mut hi) = (0, 0);
+        let c = add(0, a as u64, b as u64, &mut lo);
+        let c = add(c, (a >> 64) as u64, (b >> 64) as u64, &mut hi);
+        ((hi as u128) << 64 | lo as u128, c)
+    }
+
+    #[test]
+    fn test_addcarry_u64() {
+        unsafe {
+            let mut out = 0;
+            assert_eq!(_addcarry_u64(0, 1, 2, &mut out), 0);
+            assert_eq!(out, 3);
+            assert_eq!(_addcarry_u64(1, !0, 0, &mut out), 1);
+            assert_eq!(out, 0);
+            assert_eq!(_addcarry_u64(1, !0, !0, &mut out), 1);
+            assert_eq!(out, !0);
+
+            for &a in OPERANDS.iter() {
+                for &b in OPERANDS.iter() {
+                    let (r, c) = chain(a, b, |c, a, b, out| {
+                        _addcarry_u64(c, a, b, out)
+                    });
+                    let (e, overflow) = a.overflowing_add(b);
+                    assert_eq!((r, c), (e, overflow as u8));
+                }
+            }
+        }
+    }
+
+    #[simd_test = "adx"]
+    unsafe fn test_addcarryx_u64() {
+        let mut out = 0;
+        assert_eq!(_addcarryx_u64(1, !0, 0, &mut out), 1);
+        assert_eq!(out, 0);
+
+        for &a in OPERANDS.iter() {
+            for &b in OPERANDS.iter() {
+                let (r, c) =
+                    chain(a, b, |c, a, b, out| _addcarryx_u64(c, a, b, out));
+                let (e, overflow) = a.overflowing_add(b);
+                assert_eq!((r, c), (e, overflow as u8));
+            }
+        }
+    }
+
+    #[test]
+    fn test_subborrow_u64() {
+        unsafe {
+            let mut out = 0;
+            assert_eq!(_subborrow_u64(0, 3, 2, &mut out), 0);
+            assert_eq!(out, 1);
+            assert_eq!(_subborrow_u64(1, 0, !0, &mut out), 1);
+            assert_eq!(out, 0);
+
+            for &a in OPERANDS.iter() {
+                for &b in OPERANDS.iter() {
+                    let (r, c) = chain(a, b, |c, a, b, out| {
+                        _subborrow_u64(c, a, b, out)
+                    });
+                    let (e, overflow) = a.overflowing_sub(b);
+                    assert_eq!((r, c), (e, overflow as u8));
+                }
+            }
+        }
+    }
+}
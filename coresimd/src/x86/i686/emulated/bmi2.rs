@@ -0,0 +1,149 @@
+//! `x86_64`'s Bit Manipulation Instruction (BMI) Set 2.0 on `x86`
+
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Unsigned multiply without affecting flags.
+///
+/// Unsigned multiplication of `a` with `b` returning a pair `(lo, hi)` with
+/// the low half and the high half of the result.
+///
+/// On `x86` the product is assembled from four 32-bit multiplications.
+#[inline]
+#[target_feature(enable = "bmi2")]
+pub unsafe fn _mulx_u64(a: u64, b: u64, hi: &mut u64) -> u64 {
+    let (a_lo, a_hi) = (a & 0xffff_ffff, a >> 32);
+    let (b_lo, b_hi) = (b & 0xffff_ffff, b >> 32);
+    let ll = a_lo * b_lo;
+    let lh = a_lo * b_hi;
+    let hl = a_hi * b_lo;
+    let hh = a_hi * b_hi;
+    let mid = (ll >> 32) + (lh & 0xffff_ffff) + (hl & 0xffff_ffff);
+    *hi = hh + (lh >> 32) + (hl >> 32) + (mid >> 32);
+    (mid << 32) | (ll & 0xffff_ffff)
+}
+
+/// Zero higher bits of `a` >= `index`.
+#[inline]
+#[target_feature(enable = "bmi2")]
+pub unsafe fn _bzhi_u64(a: u64, index: u32) -> u64 {
+    let index = index & 0xff;
+    if index >= 64 {
+        a
+    } else {
+        a & ((1 << index) - 1)
+    }
+}
+
+/// Scatter contiguous low order bits of `a` to the result at the positions
+/// specified by the `mask`.
+///
+/// On `x86` each half of `mask` is handled by a 32-bit `PDEP`.
+#[inline]
+#[target_feature(enable = "bmi2")]
+#[cfg_attr(test, assert_instr(pdep))]
+pub unsafe fn _pdep_u64(a: u64, mask: u64) -> u64 {
+    let mask_lo = mask as u32;
+    let lo = _pdep_u32(a as u32, mask_lo);
+    let a_hi = (a >> mask_lo.count_ones()) as u32;
+    let hi = _pdep_u32(a_hi, (mask >> 32) as u32);
+    (lo as u64) | ((hi as u64) << 32)
+}
+
+/// Gathers the bits of `x` specified by the `mask` into the contiguous low
+/// order bit positions of the result.
+///
+/// On `x86` each half of `mask` is handled by a 32-bit `PEXT`.
+#[inline]
+#[target_feature(enable = "bmi2")]
+#[cfg_attr(test, assert_instr(pext))]
+pub unsafe fn _pext_u64(a: u64, mask: u64) -> u64 {
+    let mask_lo = mask as u32;
+    let lo = _pext_u32(a as u32, mask_lo);
+    let hi = _pext_u32((a >> 32) as u32, (mask >> 32) as u32);
+    (lo as u64) | ((hi as u64) << mask_lo.count_ones())
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "bmi2"]
+    unsafe fn test_pext_u64() {
+        let n = 0b1011_1110_1001_0011u64;
+
+        let m0 = 0b0110_0011_1000_0101u64;
+        let s0 = 0b0000_0000_0011_0101u64;
+
+        let m1 = 0b1110_1011_1110_1111u64;
+        let s1 = 0b0001_0111_0100_0011u64;
+
+        assert_eq!(_pext_u64(n, m0), s0);
+        assert_eq!(_pext_u64(n, m1), s1);
+
+        let n = 0x0123_4567_89ab_cdefu64;
+        assert_eq!(_pext_u64(n, 0xffff_0000_0000_ffff), 0x0123_cdef);
+        assert_eq!(_pext_u64(n, 0xf000_0000_0000_0000), 0);
+        assert_eq!(_pext_u64(n, !0), n);
+    }
+
+    #[simd_test = "bmi2"]
+    unsafe fn test_pdep_u64() {
+        let n = 0b1011_1110_1001_0011u64;
+
+        let m0 = 0b0110_0011_1000_0101u64;
+        let s0 = 0b0000_0010_0000_0101u64;
+
+        let m1 = 0b1110_1011_1110_1111u64;
+        let s1 = 0b1110_1001_0010_0011u64;
+
+        assert_eq!(_pdep_u64(n, m0), s0);
+        assert_eq!(_pdep_u64(n, m1), s1);
+
+        let n = 0x0123_4567_89ab_cdefu64;
+        assert_eq!(_pdep_u64(n, 0xffff_0000_0000_ffff), 0x89ab_0000_0000_cdef);
+        assert_eq!(_pdep_u64(n, 0xf000_0000_0000_0000), 0xf000_0000_0000_0000);
+        assert_eq!(_pdep_u64(n, !0), n);
+    }
+
+    #[simd_test = "bmi2"]
+    unsafe fn test_bzhi_u64() {
+        let n = 0b1111_0010u64;
+        let s = 0b0001_0010u64;
+        assert_eq!(_bzhi_u64(n, 5), s);
+
+        let n = 0x0123_4567_89ab_cdefu64;
+        assert_eq!(_bzhi_u64(n, 40), 0x0067_89ab_cdef);
+        assert_eq!(_bzhi_u64(n, 0), 0);
+        assert_eq!(_bzhi_u64(n, 64), n);
+        assert_eq!(_bzhi_u64(n, 255), n);
+        assert_eq!(_bzhi_u64(n, 0x100 + 8), 0xef);
+    }
+
+    #[simd_test = "bmi2"]
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    unsafe fn test_mulx_u64() {
+        let a: u64 = 9_223_372_036_854_775_800;
+        let b: u64 = 100;
+        let mut hi = 0;
+        let lo = _mulx_u64(a, b, &mut hi);
+        /*
+result = 922337203685477580000 =
+0b00110001_1111111111111111_1111111111111111_1111111111111111_1111110011100000
+  ^~hi~~~~ ^~lo~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+        */
+        assert_eq!(
+            lo,
+            0b11111111_11111111_11111111_11111111_11111111_11111111_11111100_11100000u64
+        );
+        assert_eq!(hi, 0b00110001u64);
+
+        let lo = _mulx_u64(!0, !0, &mut hi);
+        assert_eq!(lo, 1);
+        assert_eq!(hi, !0 - 1);
+    }
+}
@@ -0,0 +1,27 @@
+//! Byte swap intrinsics on `x86`
+
+#![cfg_attr(feature = "cargo-clippy", allow(stutter))]
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Return an integer with the reversed byte order of x
+#[inline]
+#[cfg_attr(test, assert_instr(bswap))]
+pub unsafe fn _bswap64(x: i64) -> i64 {
+    x.swap_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bswap64() {
+        unsafe {
+            assert_eq!(_bswap64(0x0EADBEEFFADECA0E), 0x0ECADEFAEFBEAD0E);
+            assert_eq!(_bswap64(0x0000000000000000), 0x0000000000000000);
+            assert_eq!(_bswap64(0x0123456789ABCDEF), -0x1032547698BADCFF);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! `x86_64` intrinsics on `x86`
+//!
+//! These intrinsics operate on 64-bit general purpose registers and are only
+//! available as single instructions on `x86_64`. On `x86` they are composed
+//! from 32-bit instructions instead, with the same results as on `x86_64`.
+//!
+//! The `FXSAVE64`/`FXRSTOR64` and `XSAVE*64` intrinsics are not provided:
+//! they select a 64-bit save area layout that does not exist outside of
+//! 64-bit mode.
+
+mod sse;
+pub use self::sse::*;
+
+mod sse2;
+pub use self::sse2::*;
+
+mod sse41;
+pub use self::sse41::*;
+
+mod sse42;
+pub use self::sse42::*;
+
+mod avx;
+pub use self::avx::*;
+
+mod avx2;
+pub use self::avx2::*;
+
+mod abm;
+pub use self::abm::*;
+
+mod bmi;
+pub use self::bmi::*;
+
+mod bmi2;
+pub use self::bmi2::*;
+
+mod adx;
+pub use self::adx::*;
+
+mod bswap;
+pub use self::bswap::*;
+
+mod rdrand;
+pub use self::rdrand::*;
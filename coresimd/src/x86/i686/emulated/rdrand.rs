@@ -0,0 +1,62 @@
+//! `x86_64`'s RDRAND and RDSEED instructions on `x86`
+//!
+//! A 64-bit random value is assembled from two 32-bit ones.
+
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Read a hardware generated 64-bit random value and store the result in val.
+/// Return 1 if a random value was generated, and 0 otherwise.
+#[inline]
+#[target_feature(enable = "rdrand")]
+#[cfg_attr(test, assert_instr(rdrand))]
+pub unsafe fn _rdrand64_step(val: &mut u64) -> i32 {
+    let (mut lo, mut hi) = (0, 0);
+    let flag = _rdrand32_step(&mut lo) & _rdrand32_step(&mut hi);
+    *val = if flag == 1 {
+        (hi as u64) << 32 | lo as u64
+    } else {
+        0
+    };
+    flag
+}
+
+/// Read a 64-bit NIST SP800-90B and SP800-90C compliant random value and store
+/// in val. Return 1 if a random value was generated, and 0 otherwise.
+#[inline]
+#[target_feature(enable = "rdseed")]
+#[cfg_attr(test, assert_instr(rdseed))]
+pub unsafe fn _rdseed64_step(val: &mut u64) -> i32 {
+    let (mut lo, mut hi) = (0, 0);
+    let flag = _rdseed32_step(&mut lo) & _rdseed32_step(&mut hi);
+    *val = if flag == 1 {
+        (hi as u64) << 32 | lo as u64
+    } else {
+        0
+    };
+    flag
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::i586::retry;
+    use x86::*;
+
+    #[simd_test = "rdrand"]
+    unsafe fn test_rdrand64_step() {
+        let a = retry(!0, |v| _rdrand64_step(v));
+        let b = retry(!0, |v| _rdrand64_step(v));
+        assert_ne!(a, b);
+    }
+
+    #[simd_test = "rdseed"]
+    unsafe fn test_rdseed64_step() {
+        let a = retry(!0, |v| _rdseed64_step(v));
+        let b = retry(!0, |v| _rdseed64_step(v));
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,206 @@
+//! `x86_64`'s Streaming SIMD Extensions (SSE) on `x86`
+
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// `2^23`: every `f32` of at least this magnitude is an integer.
+const F32_INTEGRAL: f32 = 8_388_608.0;
+
+/// `2^63`: the magnitude of `i64::MIN`.
+const F32_I64_LIMIT: f32 = 9_223_372_036_854_775_808.0;
+
+/// Truncates `x` to an `i64`, returning `i64::MIN` (the "integer
+/// indefinite" value) if `x` is out of range or NaN.
+#[inline]
+fn f32_to_i64(x: f32) -> i64 {
+    if x >= -F32_I64_LIMIT && x < F32_I64_LIMIT {
+        x as i64
+    } else {
+        ::core::i64::MIN
+    }
+}
+
+/// Convert the lowest 32 bit float in the input vector to a 64 bit integer.
+///
+/// The result is rounded according to the current rounding mode. If the result
+/// cannot be represented as a 64 bit integer the result will be
+/// `0x8000_0000_0000_0000` (`std::i64::MIN`).
+///
+/// On `x86` this is emulated: the rounding is done by adding and subtracting
+/// `2^23` with `ADDSS`/`SUBSS`, which honour the rounding mode of `MXCSR`.
+#[inline]
+#[target_feature(enable = "sse")]
+#[cfg_attr(test, assert_instr(addss))]
+pub unsafe fn _mm_cvtss_si64(a: __m128) -> i64 {
+    let x = _mm_cvtss_f32(a);
+    let m = _mm_set_ss(F32_INTEGRAL);
+    let x = if x >= 0.0 && x < F32_INTEGRAL {
+        _mm_cvtss_f32(_mm_sub_ss(_mm_add_ss(a, m), m))
+    } else if x < 0.0 && x > -F32_INTEGRAL {
+        _mm_cvtss_f32(_mm_add_ss(_mm_sub_ss(a, m), m))
+    } else {
+        x
+    };
+    f32_to_i64(x)
+}
+
+/// Convert the lowest 32 bit float in the input vector to a 64 bit integer
+/// with truncation.
+///
+/// The result is rounded always using truncation (round towards zero). If the
+/// result cannot be represented as a 64 bit integer the result will be
+/// `0x8000_0000_0000_0000` (`std::i64::MIN`).
+#[inline]
+#[target_feature(enable = "sse")]
+pub unsafe fn _mm_cvttss_si64(a: __m128) -> i64 {
+    f32_to_i64(_mm_cvtss_f32(a))
+}
+
+/// Convert a 64 bit integer to a 32 bit float. The result vector is the input
+/// vector `a` with the lowest 32 bit float replaced by the converted integer.
+///
+/// On `x86` integers outside of the `i32` range are shifted into it first.
+/// The bits shifted out are kept as a sticky bit so that the final
+/// `CVTSI2SS` still rounds exactly once, according to the rounding mode of
+/// `MXCSR`.
+#[inline]
+#[target_feature(enable = "sse")]
+#[cfg_attr(test, assert_instr(cvtsi2ss))]
+pub unsafe fn _mm_cvtsi64_ss(a: __m128, b: i64) -> __m128 {
+    if b as i32 as i64 == b {
+        return _mm_cvtsi32_ss(a, b as i32);
+    }
+    let m = b.wrapping_abs() as u64;
+    let shift = 33 - m.leading_zeros();
+    let sticky = (m & ((1 << shift) - 1) != 0) as u64;
+    let v = ((m >> shift) | sticky) as i32;
+    let v = if b < 0 { -v } else { v };
+    let r = _mm_cvtsi32_ss(a, v);
+    _mm_mul_ss(r, _mm_set_ss((1_u64 << shift) as f32))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::NAN;
+    use std::i64::MIN;
+
+    use stdsimd_test::simd_test;
+    use test::black_box; // Used to inhibit constant-folding.
+
+    use x86::*;
+
+    #[simd_test = "sse"]
+    unsafe fn test_mm_cvtss_si64() {
+        let inputs = &[
+            (42.0f32, 42i64),
+            (-31.4, -31),
+            (-33.5, -34),
+            (-34.5, -34),
+            (0.5, 0),
+            (1.5, 2),
+            (-0.0, 0),
+            (4.0e10, 40_000_000_000),
+            (4.0e-10, 0),
+            (NAN, MIN),
+            (2147483500.1, 2147483520),
+            (9.223371e18, 9223370937343148032),
+            (9.223372e18, MIN),
+            (-9.223372e18, MIN),
+        ];
+        for i in 0..inputs.len() {
+            let (xi, e) = inputs[i];
+            let x = _mm_setr_ps(xi, 1.0, 3.0, 4.0);
+            let r = _mm_cvtss_si64(x);
+            assert_eq!(
+                e,
+                r,
+                "TestCase #{} _mm_cvtss_si64({:?}) = {}, expected: {}",
+                i,
+                x,
+                r,
+                e
+            );
+        }
+    }
+
+    #[simd_test = "sse"]
+    unsafe fn test_mm_cvtss_si64_rounding_mode() {
+        let modes = &[
+            (_MM_ROUND_NEAREST, [-2i64, -2, 2, 2]),
+            (_MM_ROUND_DOWN, [-3, -2, 1, 2]),
+            (_MM_ROUND_UP, [-2, -1, 2, 3]),
+            (_MM_ROUND_TOWARD_ZERO, [-2, -1, 1, 2]),
+        ];
+        let inputs = [-2.5f32, -1.5, 1.5, 2.5];
+        let saved = _MM_GET_ROUNDING_MODE();
+        for &(mode, expected) in modes.iter() {
+            _MM_SET_ROUNDING_MODE(mode);
+            let mut r = [0; 4];
+            for (r, &x) in r.iter_mut().zip(inputs.iter()) {
+                *r = _mm_cvtss_si64(_mm_set_ss(black_box(x)));
+            }
+            _MM_SET_ROUNDING_MODE(saved);
+            assert_eq!(r, expected, "rounding mode {:#x}", mode);
+        }
+    }
+
+    #[simd_test = "sse"]
+    unsafe fn test_mm_cvttss_si64() {
+        let inputs = &[
+            (42.0f32, 42i64),
+            (-31.4, -31),
+            (-33.5, -33),
+            (-34.5, -34),
+            (10.999, 10),
+            (-5.99, -5),
+            (4.0e10, 40_000_000_000),
+            (4.0e-10, 0),
+            (NAN, MIN),
+            (2147483500.1, 2147483520),
+            (9.223371e18, 9223370937343148032),
+            (9.223372e18, MIN),
+            (-9.223372e18, MIN),
+        ];
+        for i in 0..inputs.len() {
+            let (xi, e) = inputs[i];
+            let x = _mm_setr_ps(xi, 1.0, 3.0, 4.0);
+            let r = _mm_cvttss_si64(x);
+            assert_eq!(
+                e,
+                r,
+                "TestCase #{} _mm_cvttss_si64({:?}) = {}, expected: {}",
+                i,
+                x,
+                r,
+                e
+            );
+        }
+    }
+
+    #[simd_test = "sse"]
+    pub unsafe fn test_mm_cvtsi64_ss() {
+        let inputs = &[
+            (4555i64, 4555.0f32),
+            (322223333, 322223330.0),
+            (-432, -432.0),
+            (-322223333, -322223330.0),
+            (2147483648, 2147483648.0),
+            (-2147483649, -2147483648.0),
+            // Halfway between two `f32`s, only the sticky bit breaks the tie.
+            (0x100_0001_0001, 0x100_0002_0000_i64 as f32),
+            (0x100_0001_0000, 0x100_0000_0000_i64 as f32),
+            (9223372036854775807, 9.223372e18),
+            (-9223372036854775808, -9.223372e18),
+        ];
+
+        for i in 0..inputs.len() {
+            let (x, f) = inputs[i];
+            let a = _mm_setr_ps(5.0, 6.0, 7.0, 8.0);
+            let r = _mm_cvtsi64_ss(a, x);
+            let e = _mm_setr_ps(f, 6.0, 7.0, 8.0);
+            assert_eq_m128(e, r);
+        }
+    }
+}
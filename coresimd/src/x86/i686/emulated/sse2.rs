@@ -0,0 +1,268 @@
+//! `x86_64`'s Streaming SIMD Extensions 2 (SSE2) on `x86`
+
+use x86::*;
+use simd_llvm::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// `2^52`: every `f64` of at least this magnitude is an integer.
+const F64_INTEGRAL: f64 = 4_503_599_627_370_496.0;
+
+/// `2^63`: the magnitude of `i64::MIN`.
+const F64_I64_LIMIT: f64 = 9_223_372_036_854_775_808.0;
+
+/// Truncates `x` to an `i64`, returning `i64::MIN` (the "integer
+/// indefinite" value) if `x` is out of range or NaN.
+#[inline]
+fn f64_to_i64(x: f64) -> i64 {
+    if x >= -F64_I64_LIMIT && x < F64_I64_LIMIT {
+        x as i64
+    } else {
+        ::core::i64::MIN
+    }
+}
+
+/// Convert the lower double-precision (64-bit) floating-point element in a to
+/// a 64-bit integer.
+///
+/// On `x86` this is emulated: the rounding is done by adding and subtracting
+/// `2^52` with `ADDSD`/`SUBSD`, which honour the rounding mode of `MXCSR`.
+#[inline]
+#[target_feature(enable = "sse2")]
+#[cfg_attr(test, assert_instr(addsd))]
+pub unsafe fn _mm_cvtsd_si64(a: __m128d) -> i64 {
+    let x = _mm_cvtsd_f64(a);
+    let m = _mm_set_sd(F64_INTEGRAL);
+    let x = if x >= 0.0 && x < F64_INTEGRAL {
+        _mm_cvtsd_f64(_mm_sub_sd(_mm_add_sd(a, m), m))
+    } else if x < 0.0 && x > -F64_INTEGRAL {
+        _mm_cvtsd_f64(_mm_add_sd(_mm_sub_sd(a, m), m))
+    } else {
+        x
+    };
+    f64_to_i64(x)
+}
+
+/// Alias for [`_mm_cvtsd_si64`](fn._mm_cvtsd_si64.html).
+#[inline]
+#[target_feature(enable = "sse2")]
+#[cfg_attr(test, assert_instr(addsd))]
+pub unsafe fn _mm_cvtsd_si64x(a: __m128d) -> i64 {
+    _mm_cvtsd_si64(a)
+}
+
+/// Convert the lower double-precision (64-bit) floating-point element in `a`
+/// to a 64-bit integer with truncation.
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cvttsd_si64(a: __m128d) -> i64 {
+    f64_to_i64(_mm_cvtsd_f64(a))
+}
+
+/// Alias for [`_mm_cvttsd_si64`](fn._mm_cvttsd_si64.html).
+#[inline]
+#[target_feature(enable = "sse2")]
+pub unsafe fn _mm_cvttsd_si64x(a: __m128d) -> i64 {
+    _mm_cvttsd_si64(a)
+}
+
+/// Stores a 64-bit integer value in the specified memory location.
+/// To minimize caching, the data is flagged as non-temporal (unlikely to be
+/// used again soon).
+///
+/// On `x86` the two halves of `a` are stored separately with `MOVNTI`.
+#[inline]
+#[target_feature(enable = "sse2")]
+#[cfg_attr(test, assert_instr(movnti))]
+pub unsafe fn _mm_stream_si64(mem_addr: *mut i64, a: i64) {
+    let mem_addr = mem_addr as *mut i32;
+    _mm_stream_si32(mem_addr, a as i32);
+    _mm_stream_si32(mem_addr.offset(1), (a >> 32) as i32);
+}
+
+/// Return a vector whose lowest element is `a` and all higher elements are
+/// `0`.
+#[inline]
+#[target_feature(enable = "sse2")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm_cvtsi64_si128(a: i64) -> __m128i {
+    _mm_set_epi64x(0, a)
+}
+
+/// Return a vector whose lowest element is `a` and all higher elements are
+/// `0`.
+#[inline]
+#[target_feature(enable = "sse2")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm_cvtsi64x_si128(a: i64) -> __m128i {
+    _mm_cvtsi64_si128(a)
+}
+
+/// Return the lowest element of `a`.
+#[inline]
+#[target_feature(enable = "sse2")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm_cvtsi128_si64(a: __m128i) -> i64 {
+    simd_extract(a.as_i64x2(), 0)
+}
+
+/// Return the lowest element of `a`.
+#[inline]
+#[target_feature(enable = "sse2")]
+// This intrinsic has no corresponding instruction.
+pub unsafe fn _mm_cvtsi128_si64x(a: __m128i) -> i64 {
+    _mm_cvtsi128_si64(a)
+}
+
+/// Return `a` with its lower element replaced by `b` after converting it to
+/// an `f64`.
+///
+/// On `x86` both halves of `b` are converted exactly, so the final `ADDSD`
+/// is the only rounding step and honours the rounding mode of `MXCSR`.
+#[inline]
+#[target_feature(enable = "sse2")]
+#[cfg_attr(test, assert_instr(addsd))]
+pub unsafe fn _mm_cvtsi64_sd(a: __m128d, b: i64) -> __m128d {
+    let hi = _mm_set_sd((b >> 32) as i32 as f64 * 4_294_967_296.0);
+    let lo = _mm_set_sd(b as u32 as f64);
+    _mm_move_sd(a, _mm_add_sd(hi, lo))
+}
+
+/// Return `a` with its lower element replaced by `b` after converting it to
+/// an `f64`.
+#[inline]
+#[target_feature(enable = "sse2")]
+#[cfg_attr(test, assert_instr(addsd))]
+pub unsafe fn _mm_cvtsi64x_sd(a: __m128d, b: i64) -> __m128d {
+    _mm_cvtsi64_sd(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64, i64};
+
+    use stdsimd_test::simd_test;
+    use test::black_box; // Used to inhibit constant-folding.
+
+    use x86::*;
+
+    #[simd_test = "sse2"]
+    unsafe fn test_mm_cvtsd_si64() {
+        let r = _mm_cvtsd_si64(_mm_setr_pd(-2.0, 5.0));
+        assert_eq!(r, -2_i64);
+
+        let r = _mm_cvtsd_si64(_mm_setr_pd(2.5, 5.0));
+        assert_eq!(r, 2_i64);
+
+        let r = _mm_cvtsd_si64(_mm_setr_pd(-3.5, 5.0));
+        assert_eq!(r, -4_i64);
+
+        let r = _mm_cvtsd_si64(_mm_setr_pd(9.0e15 + 1.0, 5.0));
+        assert_eq!(r, 9_000_000_000_000_001_i64);
+
+        let r = _mm_cvtsd_si64(_mm_setr_pd(-9.223372036854775808e18, 5.0));
+        assert_eq!(r, i64::MIN);
+
+        let r = _mm_cvtsd_si64(_mm_setr_pd(9.223372036854775808e18, 5.0));
+        assert_eq!(r, i64::MIN);
+
+        let r = _mm_cvtsd_si64(_mm_setr_pd(f64::MAX, f64::MIN));
+        assert_eq!(r, i64::MIN);
+    }
+
+    #[simd_test = "sse2"]
+    unsafe fn test_mm_cvtsd_si64_rounding_mode() {
+        let modes = &[
+            (_MM_ROUND_NEAREST, [-2i64, -2, 2, 2]),
+            (_MM_ROUND_DOWN, [-3, -2, 1, 2]),
+            (_MM_ROUND_UP, [-2, -1, 2, 3]),
+            (_MM_ROUND_TOWARD_ZERO, [-2, -1, 1, 2]),
+        ];
+        let inputs = [-2.5f64, -1.5, 1.5, 2.5];
+        let saved = _MM_GET_ROUNDING_MODE();
+        for &(mode, expected) in modes.iter() {
+            _MM_SET_ROUNDING_MODE(mode);
+            let mut r = [0; 4];
+            for (r, &x) in r.iter_mut().zip(inputs.iter()) {
+                *r = _mm_cvtsd_si64(_mm_set_sd(black_box(x)));
+            }
+            _MM_SET_ROUNDING_MODE(saved);
+            assert_eq!(r, expected, "rounding mode {:#x}", mode);
+        }
+    }
+
+    #[simd_test = "sse2"]
+    unsafe fn test_mm_cvtsd_si64x() {
+        let r = _mm_cvtsd_si64x(_mm_setr_pd(f64::NAN, f64::NAN));
+        assert_eq!(r, i64::MIN);
+    }
+
+    #[simd_test = "sse2"]
+    unsafe fn test_mm_cvttsd_si64() {
+        let a = _mm_setr_pd(-1.1, 2.2);
+        let r = _mm_cvttsd_si64(a);
+        assert_eq!(r, -1_i64);
+
+        let a = _mm_setr_pd(-9.223372036854775808e18, 2.2);
+        let r = _mm_cvttsd_si64(a);
+        assert_eq!(r, i64::MIN);
+
+        let a = _mm_setr_pd(9.223372036854774784e18, 2.2);
+        let r = _mm_cvttsd_si64(a);
+        assert_eq!(r, 9_223_372_036_854_774_784_i64);
+    }
+
+    #[simd_test = "sse2"]
+    unsafe fn test_mm_cvttsd_si64x() {
+        let a = _mm_setr_pd(f64::NEG_INFINITY, f64::NAN);
+        let r = _mm_cvttsd_si64x(a);
+        assert_eq!(r, i64::MIN);
+    }
+
+    #[simd_test = "sse2"]
+    unsafe fn test_mm_stream_si64() {
+        let a: i64 = 0x0123_4567_89ab_cdef;
+        let mut mem = ::std::boxed::Box::<i64>::new(-1);
+        _mm_stream_si64(&mut *mem as *mut i64, a);
+        assert_eq!(a, *mem);
+    }
+
+    #[simd_test = "sse2"]
+    unsafe fn test_mm_cvtsi64_si128() {
+        let r = _mm_cvtsi64_si128(5);
+        assert_eq_m128i(r, _mm_setr_epi64x(5, 0));
+
+        let r = _mm_cvtsi64_si128(-0x0123_4567_89ab_cdef);
+        assert_eq_m128i(r, _mm_setr_epi64x(-0x0123_4567_89ab_cdef, 0));
+    }
+
+    #[simd_test = "sse2"]
+    unsafe fn test_mm_cvtsi128_si64() {
+        let r = _mm_cvtsi128_si64(_mm_setr_epi64x(5, 0));
+        assert_eq!(r, 5);
+
+        let r = _mm_cvtsi128_si64(_mm_setr_epi64x(i64::MIN, -1));
+        assert_eq!(r, i64::MIN);
+    }
+
+    #[simd_test = "sse2"]
+    unsafe fn test_mm_cvtsi64_sd() {
+        let a = _mm_set1_pd(3.5);
+        let r = _mm_cvtsi64_sd(a, 5);
+        assert_eq_m128d(r, _mm_setr_pd(5.0, 3.5));
+
+        let r = _mm_cvtsi64_sd(a, -5_000_000_000);
+        assert_eq_m128d(r, _mm_setr_pd(-5.0e9, 3.5));
+
+        let r = _mm_cvtsi64_sd(a, i64::MIN);
+        assert_eq_m128d(r, _mm_setr_pd(-9.223372036854775808e18, 3.5));
+
+        // Needs rounding: only the lowest bit is lost.
+        let r = _mm_cvtsi64_sd(a, (1 << 53) + 1);
+        assert_eq_m128d(r, _mm_setr_pd(9.007199254740992e15, 3.5));
+
+        let r = _mm_cvtsi64_sd(a, i64::MAX);
+        assert_eq_m128d(r, _mm_setr_pd(9.223372036854775808e18, 3.5));
+    }
+}
@@ -0,0 +1,42 @@
+//! `x86_64`'s Streaming SIMD Extensions 4.2 (SSE4.2) on `x86`
+
+use x86::*;
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+/// Starting with the initial value in `crc`, return the accumulated
+/// CRC32 value for unsigned 64-bit integer `v`.
+///
+/// On `x86` the low and then the high half of `v` are accumulated with two
+/// 32-bit `CRC32` instructions.
+#[inline]
+#[target_feature(enable = "sse4.2")]
+#[cfg_attr(test, assert_instr(crc32))]
+pub unsafe fn _mm_crc32_u64(crc: u64, v: u64) -> u64 {
+    let crc = _mm_crc32_u32(crc as u32, v as u32);
+    _mm_crc32_u32(crc, (v >> 32) as u32) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use x86::*;
+
+    use stdsimd_test::simd_test;
+
+    #[simd_test = "sse4.2"]
+    unsafe fn test_mm_crc32_u64() {
+        let crc = 0x7819dccd3e824;
+        let v = 0x2a22b845fed;
+        let i = _mm_crc32_u64(crc, v);
+        assert_eq!(i, 0xbb6cdc6c);
+
+        let crc = 0xffff_ffff;
+        let v = 0x0123_4567_89ab_cdef;
+        let e = _mm_crc32_u32(
+            _mm_crc32_u32(0xffff_ffff, 0x89ab_cdef),
+            0x0123_4567,
+        );
+        assert_eq!(_mm_crc32_u64(crc, v), e as u64);
+    }
+}
@@ -22,3 +22,9 @@ pub use self::sse42::*;
 mod sse4a;
 #[cfg(not(feature = "intel_sde"))]
 pub use self::sse4a::*;
+
+// `x86_64` intrinsics composed from 32-bit instructions.
+#[cfg(target_arch = "x86")]
+mod emulated;
+#[cfg(target_arch = "x86")]
+pub use self::emulated::*;
@@ -313,7 +313,16 @@ pub unsafe fn _m_maskmovq(a: __m64, mask: __m64, mem_addr: *mut i8) {
 #[inline]
 #[target_feature(enable = "sse,mmx")]
 #[cfg_attr(test, assert_instr(pextrw, imm2 = 0))]
+#[rustc_args_required_const(1)]
 pub unsafe fn _mm_extract_pi16(a: __m64, imm2: i32) -> i16 {
+    _mm_extract_pi16_impl(a, imm2)
+}
+
+/// Implementation detail: converts the immediate argument of the
+/// `_mm_extract_pi16` intrinsic into a compile time constant.
+#[inline]
+#[target_feature(enable = "sse,mmx")]
+unsafe fn _mm_extract_pi16_impl(a: __m64, imm2: i32) -> i16 {
     macro_rules! call {
         ($imm2:expr) => { pextrw(mem::transmute(a), $imm2) as i16 }
     }
@@ -325,8 +334,9 @@ pub unsafe fn _mm_extract_pi16(a: __m64, imm2: i32) -> i16 {
 #[inline]
 #[target_feature(enable = "sse,mmx")]
 #[cfg_attr(test, assert_instr(pextrw, imm2 = 0))]
+#[rustc_args_required_const(1)]
 pub unsafe fn _m_pextrw(a: __m64, imm2: i32) -> i16 {
-    _mm_extract_pi16(a, imm2)
+    _mm_extract_pi16_impl(a, imm2)
 }
 
 /// Copies data from the 64-bit vector of [4 x i16] to the destination,
@@ -335,7 +345,16 @@ pub unsafe fn _m_pextrw(a: __m64, imm2: i32) -> i16 {
 #[inline]
 #[target_feature(enable = "sse,mmx")]
 #[cfg_attr(test, assert_instr(pinsrw, imm2 = 0))]
+#[rustc_args_required_const(2)]
 pub unsafe fn _mm_insert_pi16(a: __m64, d: i32, imm2: i32) -> __m64 {
+    _mm_insert_pi16_impl(a, d, imm2)
+}
+
+/// Implementation detail: converts the immediate argument of the
+/// `_mm_insert_pi16` intrinsic into a compile time constant.
+#[inline]
+#[target_feature(enable = "sse,mmx")]
+unsafe fn _mm_insert_pi16_impl(a: __m64, d: i32, imm2: i32) -> __m64 {
     macro_rules! call {
         ($imm2:expr) => { pinsrw(a, d, $imm2) }
     }
@@ -348,8 +367,9 @@ pub unsafe fn _mm_insert_pi16(a: __m64, d: i32, imm2: i32) -> __m64 {
 #[inline]
 #[target_feature(enable = "sse,mmx")]
 #[cfg_attr(test, assert_instr(pinsrw, imm2 = 0))]
+#[rustc_args_required_const(2)]
 pub unsafe fn _m_pinsrw(a: __m64, d: i32, imm2: i32) -> __m64 {
-    _mm_insert_pi16(a, d, imm2)
+    _mm_insert_pi16_impl(a, d, imm2)
 }
 
 /// Takes the most significant bit from each 8-bit element in a 64-bit
@@ -377,7 +397,16 @@ pub unsafe fn _m_pmovmskb(a: __m64) -> i32 {
 #[inline]
 #[target_feature(enable = "sse,mmx")]
 #[cfg_attr(test, assert_instr(pshufw, imm8 = 0))]
+#[rustc_args_required_const(1)]
 pub unsafe fn _mm_shuffle_pi16(a: __m64, imm8: i32) -> __m64 {
+    _mm_shuffle_pi16_impl(a, imm8)
+}
+
+/// Implementation detail: converts the immediate argument of the
+/// `_mm_shuffle_pi16` intrinsic into a compile time constant.
+#[inline]
+#[target_feature(enable = "sse,mmx")]
+unsafe fn _mm_shuffle_pi16_impl(a: __m64, imm8: i32) -> __m64 {
     macro_rules! call {
         ($imm8:expr) => { pshufw(a, $imm8) }
     }
@@ -389,8 +418,9 @@ pub unsafe fn _mm_shuffle_pi16(a: __m64, imm8: i32) -> __m64 {
 #[inline]
 #[target_feature(enable = "sse,mmx")]
 #[cfg_attr(test, assert_instr(pshufw, imm8 = 0))]
+#[rustc_args_required_const(1)]
 pub unsafe fn _m_pshufw(a: __m64, imm8: i32) -> __m64 {
-    _mm_shuffle_pi16(a, imm8)
+    _mm_shuffle_pi16_impl(a, imm8)
 }
 
 /// Convert the two lower packed single-precision (32-bit) floating-point
@@ -1,4 +1,9 @@
 //! Utility macros.
+//!
+//! The `constify_imm*` macros expand a runtime immediate into a `match` over
+//! all of its possible values. Intrinsics using them must mark the immediate
+//! argument with `#[rustc_args_required_const]`, so that only constants are
+//! accepted and the `match` always folds away.
 
 macro_rules! constify_imm8 {
     ($imm8:expr, $expand:ident) => {
@@ -356,6 +361,96 @@ macro_rules! constify_imm2 {
     }
 }
 
+/// Loads a 512-bit vector from `$mem_addr` with the masked move `$instr`,
+/// keeping the elements of `$src` whose bit in the opmask `$k` is clear.
+///
+/// LLVM only provides masked memory accesses through the generic
+/// `llvm.masked.load` intrinsic, whose `i1` vector mask has no Rust
+/// equivalent. Inline assembly keeps the guarantee that masked-off elements
+/// are not accessed.
+macro_rules! masked_load {
+    ($instr:tt, $src:expr, $k:expr, $mem_addr:expr) => {{
+        let mut r = $src;
+        asm!(concat!($instr, " ($1), $0 {$2}")
+             : "+v"(r)
+             : "r"($mem_addr), "^Yk"($k)
+             : "memory"
+             : "volatile");
+        r
+    }};
+}
+
+/// Stores the elements of the 512-bit vector `$a` whose bit in the opmask
+/// `$k` is set to `$mem_addr` with the masked move `$instr`.
+///
+/// See `masked_load!` for why this uses inline assembly.
+macro_rules! masked_store {
+    ($instr:tt, $mem_addr:expr, $k:expr, $a:expr) => {
+        asm!(concat!($instr, " $0, ($1) {$2}")
+             :
+             : "v"($a), "r"($mem_addr), "^Yk"($k)
+             : "memory"
+             : "volatile")
+    };
+}
+
+/// Defines target feature tokens.
+///
+/// `Token = "feature" => [Implied, ...];` defines the `Token` type for
+/// `"feature"` and converts it into the tokens of the features that
+/// `"feature"` implies. The list of implied tokens must be transitively
+/// closed.
+macro_rules! tokens {
+    ($(
+        $(#[$attr:meta])*
+        $token:ident = $feature:tt => [$($implied:ident),*];
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, Copy, Debug)]
+            pub struct $token(());
+
+            impl $token {
+                /// Returns the token if the host CPU supports the target
+                /// feature.
+                #[inline]
+                pub fn detect() -> Option<$token> {
+                    if cfg_feature_enabled!($feature) {
+                        Some($token(()))
+                    } else {
+                        None
+                    }
+                }
+
+                /// Returns the token of a target feature that is enabled at
+                /// compile-time.
+                #[cfg(target_feature = $feature)]
+                #[inline]
+                pub fn new() -> $token {
+                    $token(())
+                }
+            }
+
+            #[cfg(target_feature = $feature)]
+            impl Default for $token {
+                #[inline]
+                fn default() -> $token {
+                    $token::new()
+                }
+            }
+
+            $(
+                impl From<$token> for $implied {
+                    #[inline]
+                    fn from(_: $token) -> $implied {
+                        $implied(())
+                    }
+                }
+            )*
+        )*
+    }
+}
+
 #[cfg(test)]
 macro_rules! assert_approx_eq {
     ($a:expr, $b:expr, $eps:expr) => ({
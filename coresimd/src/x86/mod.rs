@@ -295,8 +295,159 @@ types! {
     /// # }
     /// ```
     pub struct __m256d(f64, f64, f64, f64);
+
+    /// 512-bit wide integer vector type, x86-specific
+    ///
+    /// This type is the same as the `__m512i` type defined by Intel,
+    /// representing a 512-bit SIMD register. Usage of this type typically
+    /// corresponds to the `avx512*` target features for x86/x86_64.
+    ///
+    /// Internally this type may be viewed as:
+    ///
+    /// * `i8x64` - sixty-four `i8` variables packed together
+    /// * `i16x32` - thirty-two `i16` variables packed together
+    /// * `i32x16` - sixteen `i32` variables packed together
+    /// * `i64x8` - eight `i64` variables packed together
+    ///
+    /// (as well as unsgined versions). Each intrinsic may interpret the
+    /// internal bits differently, check the documentation of the intrinsic
+    /// to see how it's being used.
+    ///
+    /// Note that this means that an instance of `__m512i` typically just means
+    /// a "bag of bits" which is left up to interpretation at the point of use.
+    ///
+    /// Most intrinsics using `__m512i` are prefixed with `_mm512_` and the
+    /// integer types tend to correspond to suffixes like "epi32" or "epi64".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(cfg_target_feature, target_feature)]
+    /// # #[macro_use]
+    /// # extern crate stdsimd;
+    /// # fn main() {
+    /// # #[target_feature(enable = "avx512f")]
+    /// # unsafe fn foo() {
+    /// use stdsimd::vendor::*;
+    ///
+    /// let all_bytes_zero = _mm512_setzero_si512();
+    /// let all_i32_one = _mm512_set1_epi32(1);
+    /// let eight_i64 = _mm512_set_epi64(1, 2, 3, 4, 5, 6, 7, 8);
+    /// # }
+    /// # if cfg_feature_enabled!("avx512f") { unsafe { foo() } }
+    /// # }
+    /// ```
+    pub struct __m512i(i64, i64, i64, i64, i64, i64, i64, i64);
+
+    /// 512-bit wide set of sixteen `f32` types, x86-specific
+    ///
+    /// This type is the same as the `__m512` type defined by Intel,
+    /// representing a 512-bit SIMD register which internally is consisted of
+    /// sixteen packed `f32` instances. Usage of this type typically
+    /// corresponds to the `avx512*` target features for x86/x86_64.
+    ///
+    /// Note that unlike `__m512i`, the integer version of the 512-bit
+    /// registers, this `__m512` type has *one* interpretation. Each instance
+    /// of `__m512` always corresponds to `f32x16`, or sixteen `f32` types
+    /// packed together.
+    ///
+    /// Most intrinsics using `__m512` are prefixed with `_mm512_` and are
+    /// suffixed with "ps" (or otherwise contain "ps"). Not to be confused with
+    /// "pd" which is used for `__m512d`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(cfg_target_feature, target_feature)]
+    /// # #[macro_use]
+    /// # extern crate stdsimd;
+    /// # fn main() {
+    /// # #[target_feature(enable = "avx512f")]
+    /// # unsafe fn foo() {
+    /// use stdsimd::vendor::*;
+    ///
+    /// let sixteen_zeros = _mm512_setzero_ps();
+    /// let sixteen_ones = _mm512_set1_ps(1.0);
+    /// # }
+    /// # if cfg_feature_enabled!("avx512f") { unsafe { foo() } }
+    /// # }
+    /// ```
+    pub struct __m512(
+        f32, f32, f32, f32, f32, f32, f32, f32,
+        f32, f32, f32, f32, f32, f32, f32, f32,
+    );
+
+    /// 512-bit wide set of eight `f64` types, x86-specific
+    ///
+    /// This type is the same as the `__m512d` type defined by Intel,
+    /// representing a 512-bit SIMD register which internally is consisted of
+    /// eight packed `f64` instances. Usage of this type typically corresponds
+    /// to the `avx512*` target features for x86/x86_64.
+    ///
+    /// Note that unlike `__m512i`, the integer version of the 512-bit
+    /// registers, this `__m512d` type has *one* interpretation. Each instance
+    /// of `__m512d` always corresponds to `f64x8`, or eight `f64` types packed
+    /// together.
+    ///
+    /// Most intrinsics using `__m512d` are prefixed with `_mm512_` and are
+    /// suffixed with "pd" (or otherwise contain "pd"). Not to be confused with
+    /// "ps" which is used for `__m512`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(cfg_target_feature, target_feature)]
+    /// # #[macro_use]
+    /// # extern crate stdsimd;
+    /// # fn main() {
+    /// # #[target_feature(enable = "avx512f")]
+    /// # unsafe fn foo() {
+    /// use stdsimd::vendor::*;
+    ///
+    /// let eight_zeros = _mm512_setzero_pd();
+    /// let eight_ones = _mm512_set1_pd(1.0);
+    /// let eight_floats = _mm512_set_pd(1., 2., 3., 4., 5., 6., 7., 8.);
+    /// # }
+    /// # if cfg_feature_enabled!("avx512f") { unsafe { foo() } }
+    /// # }
+    /// ```
+    pub struct __m512d(f64, f64, f64, f64, f64, f64, f64, f64);
 }
 
+/// 8-bit AVX-512 opmask, x86-specific
+///
+/// This type is the same as the `__mmask8` type defined by Intel. Bit `i`
+/// of the mask corresponds to lane `i` of a vector with up to eight lanes,
+/// for example the result of comparing two `__m512d` or two `__m512i`
+/// viewed as `i64x8`.
+#[allow(non_camel_case_types)]
+pub type __mmask8 = u8;
+
+/// 16-bit AVX-512 opmask, x86-specific
+///
+/// This type is the same as the `__mmask16` type defined by Intel. Bit `i`
+/// of the mask corresponds to lane `i` of a vector with sixteen lanes, for
+/// example the result of comparing two `__m512` or two `__m512i` viewed as
+/// `i32x16`.
+#[allow(non_camel_case_types)]
+pub type __mmask16 = u16;
+
+/// 32-bit AVX-512 opmask, x86-specific
+///
+/// This type is the same as the `__mmask32` type defined by Intel. Bit `i`
+/// of the mask corresponds to lane `i` of a vector with thirty-two lanes,
+/// for example a `__m512i` viewed as `i16x32`.
+#[allow(non_camel_case_types)]
+pub type __mmask32 = u32;
+
+/// 64-bit AVX-512 opmask, x86-specific
+///
+/// This type is the same as the `__mmask64` type defined by Intel. Bit `i`
+/// of the mask corresponds to lane `i` of a vector with sixty-four lanes,
+/// for example a `__m512i` viewed as `i8x64`.
+#[allow(non_camel_case_types)]
+pub type __mmask64 = u64;
+
 #[cfg(test)]
 mod test;
 #[cfg(test)]
@@ -404,6 +555,57 @@ impl m256iExt for __m256i {
     fn as_m256i(self) -> __m256i { self }
 }
 
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+trait m512iExt: Sized {
+    fn as_m512i(self) -> __m512i;
+
+    #[inline]
+    fn as_u8x64(self) -> ::v512::u8x64 {
+        unsafe { mem::transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_u16x32(self) -> ::v512::u16x32 {
+        unsafe { mem::transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_u32x16(self) -> ::v512::u32x16 {
+        unsafe { mem::transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_u64x8(self) -> ::v512::u64x8 {
+        unsafe { mem::transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_i8x64(self) -> ::v512::i8x64 {
+        unsafe { mem::transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_i16x32(self) -> ::v512::i16x32 {
+        unsafe { mem::transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_i32x16(self) -> ::v512::i32x16 {
+        unsafe { mem::transmute(self.as_m512i()) }
+    }
+
+    #[inline]
+    fn as_i64x8(self) -> ::v512::i64x8 {
+        unsafe { mem::transmute(self.as_m512i()) }
+    }
+}
+
+impl m512iExt for __m512i {
+    #[inline]
+    fn as_m512i(self) -> __m512i { self }
+}
+
 mod i386;
 pub use self::i386::*;
 
@@ -422,3 +624,7 @@ pub use self::i686::*;
 mod x86_64;
 #[cfg(target_arch = "x86_64")]
 pub use self::x86_64::*;
+
+// Target feature tokens and their safe intrinsic methods.
+mod token;
+pub use self::token::*;
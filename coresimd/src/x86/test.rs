@@ -82,6 +82,38 @@ pub unsafe fn get_m256(a: __m256, idx: usize) -> f32 {
     A { a }.b[idx]
 }
 
+#[target_feature(enable = "avx512f")]
+pub unsafe fn assert_eq_m512i(a: __m512i, b: __m512i) {
+    union A { a: __m512i, b: [u64; 8] }
+    assert_eq!(A { a }.b, A { a: b }.b)
+}
+
+#[target_feature(enable = "avx512f")]
+pub unsafe fn assert_eq_m512d(a: __m512d, b: __m512d) {
+    if _mm512_cmp_pd_mask(a, b, _CMP_EQ_OQ) != 0b11111111 {
+        panic!("{:?} != {:?}", a, b);
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+pub unsafe fn get_m512d(a: __m512d, idx: usize) -> f64 {
+    union A { a: __m512d, b: [f64; 8] };
+    A { a }.b[idx]
+}
+
+#[target_feature(enable = "avx512f")]
+pub unsafe fn assert_eq_m512(a: __m512, b: __m512) {
+    if _mm512_cmp_ps_mask(a, b, _CMP_EQ_OQ) != 0b11111111_11111111 {
+        panic!("{:?} != {:?}", a, b);
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+pub unsafe fn get_m512(a: __m512, idx: usize) -> f32 {
+    union A { a: __m512, b: [f32; 16] };
+    A { a }.b[idx]
+}
+
 // These intrinsics doesn't exist on x86 b/c it requires a 64-bit registe,r which
 // doesn't exist on x86!
 #[cfg(target_arch = "x86")]
@@ -0,0 +1,3797 @@
+//! Safe methods of the target feature tokens.
+//!
+//! Every intrinsic that requires a single target feature and that neither
+//! accesses memory through a pointer, takes an immediate argument, nor uses
+//! `__m64` is wrapped here. The `verify_token_methods` test of
+//! `stdsimd-verify` fails if an intrinsic is missing from or stale in this
+//! list.
+
+use x86::*;
+
+token_methods! {
+    Sse = "sse" {
+        /// See [`_mm_add_ps`](fn._mm_add_ps.html).
+        fn add_ps(a: __m128, b: __m128) -> __m128 = _mm_add_ps;
+
+        /// See [`_mm_add_ss`](fn._mm_add_ss.html).
+        fn add_ss(a: __m128, b: __m128) -> __m128 = _mm_add_ss;
+
+        /// See [`_mm_and_ps`](fn._mm_and_ps.html).
+        fn and_ps(a: __m128, b: __m128) -> __m128 = _mm_and_ps;
+
+        /// See [`_mm_andnot_ps`](fn._mm_andnot_ps.html).
+        fn andnot_ps(a: __m128, b: __m128) -> __m128 = _mm_andnot_ps;
+
+        /// See [`_mm_cmpeq_ps`](fn._mm_cmpeq_ps.html).
+        fn cmpeq_ps(a: __m128, b: __m128) -> __m128 = _mm_cmpeq_ps;
+
+        /// See [`_mm_cmpeq_ss`](fn._mm_cmpeq_ss.html).
+        fn cmpeq_ss(a: __m128, b: __m128) -> __m128 = _mm_cmpeq_ss;
+
+        /// See [`_mm_cmpge_ps`](fn._mm_cmpge_ps.html).
+        fn cmpge_ps(a: __m128, b: __m128) -> __m128 = _mm_cmpge_ps;
+
+        /// See [`_mm_cmpge_ss`](fn._mm_cmpge_ss.html).
+        fn cmpge_ss(a: __m128, b: __m128) -> __m128 = _mm_cmpge_ss;
+
+        /// See [`_mm_cmpgt_ps`](fn._mm_cmpgt_ps.html).
+        fn cmpgt_ps(a: __m128, b: __m128) -> __m128 = _mm_cmpgt_ps;
+
+        /// See [`_mm_cmpgt_ss`](fn._mm_cmpgt_ss.html).
+        fn cmpgt_ss(a: __m128, b: __m128) -> __m128 = _mm_cmpgt_ss;
+
+        /// See [`_mm_cmple_ps`](fn._mm_cmple_ps.html).
+        fn cmple_ps(a: __m128, b: __m128) -> __m128 = _mm_cmple_ps;
+
+        /// See [`_mm_cmple_ss`](fn._mm_cmple_ss.html).
+        fn cmple_ss(a: __m128, b: __m128) -> __m128 = _mm_cmple_ss;
+
+        /// See [`_mm_cmplt_ps`](fn._mm_cmplt_ps.html).
+        fn cmplt_ps(a: __m128, b: __m128) -> __m128 = _mm_cmplt_ps;
+
+        /// See [`_mm_cmplt_ss`](fn._mm_cmplt_ss.html).
+        fn cmplt_ss(a: __m128, b: __m128) -> __m128 = _mm_cmplt_ss;
+
+        /// See [`_mm_cmpneq_ps`](fn._mm_cmpneq_ps.html).
+        fn cmpneq_ps(a: __m128, b: __m128) -> __m128 = _mm_cmpneq_ps;
+
+        /// See [`_mm_cmpneq_ss`](fn._mm_cmpneq_ss.html).
+        fn cmpneq_ss(a: __m128, b: __m128) -> __m128 = _mm_cmpneq_ss;
+
+        /// See [`_mm_cmpnge_ps`](fn._mm_cmpnge_ps.html).
+        fn cmpnge_ps(a: __m128, b: __m128) -> __m128 = _mm_cmpnge_ps;
+
+        /// See [`_mm_cmpnge_ss`](fn._mm_cmpnge_ss.html).
+        fn cmpnge_ss(a: __m128, b: __m128) -> __m128 = _mm_cmpnge_ss;
+
+        /// See [`_mm_cmpngt_ps`](fn._mm_cmpngt_ps.html).
+        fn cmpngt_ps(a: __m128, b: __m128) -> __m128 = _mm_cmpngt_ps;
+
+        /// See [`_mm_cmpngt_ss`](fn._mm_cmpngt_ss.html).
+        fn cmpngt_ss(a: __m128, b: __m128) -> __m128 = _mm_cmpngt_ss;
+
+        /// See [`_mm_cmpnle_ps`](fn._mm_cmpnle_ps.html).
+        fn cmpnle_ps(a: __m128, b: __m128) -> __m128 = _mm_cmpnle_ps;
+
+        /// See [`_mm_cmpnle_ss`](fn._mm_cmpnle_ss.html).
+        fn cmpnle_ss(a: __m128, b: __m128) -> __m128 = _mm_cmpnle_ss;
+
+        /// See [`_mm_cmpnlt_ps`](fn._mm_cmpnlt_ps.html).
+        fn cmpnlt_ps(a: __m128, b: __m128) -> __m128 = _mm_cmpnlt_ps;
+
+        /// See [`_mm_cmpnlt_ss`](fn._mm_cmpnlt_ss.html).
+        fn cmpnlt_ss(a: __m128, b: __m128) -> __m128 = _mm_cmpnlt_ss;
+
+        /// See [`_mm_cmpord_ps`](fn._mm_cmpord_ps.html).
+        fn cmpord_ps(a: __m128, b: __m128) -> __m128 = _mm_cmpord_ps;
+
+        /// See [`_mm_cmpord_ss`](fn._mm_cmpord_ss.html).
+        fn cmpord_ss(a: __m128, b: __m128) -> __m128 = _mm_cmpord_ss;
+
+        /// See [`_mm_cmpunord_ps`](fn._mm_cmpunord_ps.html).
+        fn cmpunord_ps(a: __m128, b: __m128) -> __m128 = _mm_cmpunord_ps;
+
+        /// See [`_mm_cmpunord_ss`](fn._mm_cmpunord_ss.html).
+        fn cmpunord_ss(a: __m128, b: __m128) -> __m128 = _mm_cmpunord_ss;
+
+        /// See [`_mm_comieq_ss`](fn._mm_comieq_ss.html).
+        fn comieq_ss(a: __m128, b: __m128) -> i32 = _mm_comieq_ss;
+
+        /// See [`_mm_comige_ss`](fn._mm_comige_ss.html).
+        fn comige_ss(a: __m128, b: __m128) -> i32 = _mm_comige_ss;
+
+        /// See [`_mm_comigt_ss`](fn._mm_comigt_ss.html).
+        fn comigt_ss(a: __m128, b: __m128) -> i32 = _mm_comigt_ss;
+
+        /// See [`_mm_comile_ss`](fn._mm_comile_ss.html).
+        fn comile_ss(a: __m128, b: __m128) -> i32 = _mm_comile_ss;
+
+        /// See [`_mm_comilt_ss`](fn._mm_comilt_ss.html).
+        fn comilt_ss(a: __m128, b: __m128) -> i32 = _mm_comilt_ss;
+
+        /// See [`_mm_comineq_ss`](fn._mm_comineq_ss.html).
+        fn comineq_ss(a: __m128, b: __m128) -> i32 = _mm_comineq_ss;
+
+        /// See [`_mm_cvt_si2ss`](fn._mm_cvt_si2ss.html).
+        fn cvt_si2ss(a: __m128, b: i32) -> __m128 = _mm_cvt_si2ss;
+
+        /// See [`_mm_cvt_ss2si`](fn._mm_cvt_ss2si.html).
+        fn cvt_ss2si(a: __m128) -> i32 = _mm_cvt_ss2si;
+
+        /// See [`_mm_cvtsi32_ss`](fn._mm_cvtsi32_ss.html).
+        fn cvtsi32_ss(a: __m128, b: i32) -> __m128 = _mm_cvtsi32_ss;
+
+        /// See [`_mm_cvtsi64_ss`](fn._mm_cvtsi64_ss.html).
+        fn cvtsi64_ss(a: __m128, b: i64) -> __m128 = _mm_cvtsi64_ss;
+
+        /// See [`_mm_cvtss_f32`](fn._mm_cvtss_f32.html).
+        fn cvtss_f32(a: __m128) -> f32 = _mm_cvtss_f32;
+
+        /// See [`_mm_cvtss_si32`](fn._mm_cvtss_si32.html).
+        fn cvtss_si32(a: __m128) -> i32 = _mm_cvtss_si32;
+
+        /// See [`_mm_cvtss_si64`](fn._mm_cvtss_si64.html).
+        fn cvtss_si64(a: __m128) -> i64 = _mm_cvtss_si64;
+
+        /// See [`_mm_cvtt_ss2si`](fn._mm_cvtt_ss2si.html).
+        fn cvtt_ss2si(a: __m128) -> i32 = _mm_cvtt_ss2si;
+
+        /// See [`_mm_cvttss_si32`](fn._mm_cvttss_si32.html).
+        fn cvttss_si32(a: __m128) -> i32 = _mm_cvttss_si32;
+
+        /// See [`_mm_cvttss_si64`](fn._mm_cvttss_si64.html).
+        fn cvttss_si64(a: __m128) -> i64 = _mm_cvttss_si64;
+
+        /// See [`_mm_div_ps`](fn._mm_div_ps.html).
+        fn div_ps(a: __m128, b: __m128) -> __m128 = _mm_div_ps;
+
+        /// See [`_mm_div_ss`](fn._mm_div_ss.html).
+        fn div_ss(a: __m128, b: __m128) -> __m128 = _mm_div_ss;
+
+        /// See [`_MM_GET_EXCEPTION_MASK`](fn._MM_GET_EXCEPTION_MASK.html).
+        fn get_exception_mask() -> u32 = _MM_GET_EXCEPTION_MASK;
+
+        /// See [`_MM_GET_EXCEPTION_STATE`](fn._MM_GET_EXCEPTION_STATE.html).
+        fn get_exception_state() -> u32 = _MM_GET_EXCEPTION_STATE;
+
+        /// See [`_MM_GET_FLUSH_ZERO_MODE`](fn._MM_GET_FLUSH_ZERO_MODE.html).
+        fn get_flush_zero_mode() -> u32 = _MM_GET_FLUSH_ZERO_MODE;
+
+        /// See [`_MM_GET_ROUNDING_MODE`](fn._MM_GET_ROUNDING_MODE.html).
+        fn get_rounding_mode() -> u32 = _MM_GET_ROUNDING_MODE;
+
+        /// See [`_mm_getcsr`](fn._mm_getcsr.html).
+        fn getcsr() -> u32 = _mm_getcsr;
+
+        /// See [`_mm_max_ps`](fn._mm_max_ps.html).
+        fn max_ps(a: __m128, b: __m128) -> __m128 = _mm_max_ps;
+
+        /// See [`_mm_max_ss`](fn._mm_max_ss.html).
+        fn max_ss(a: __m128, b: __m128) -> __m128 = _mm_max_ss;
+
+        /// See [`_mm_min_ps`](fn._mm_min_ps.html).
+        fn min_ps(a: __m128, b: __m128) -> __m128 = _mm_min_ps;
+
+        /// See [`_mm_min_ss`](fn._mm_min_ss.html).
+        fn min_ss(a: __m128, b: __m128) -> __m128 = _mm_min_ss;
+
+        /// See [`_mm_move_ss`](fn._mm_move_ss.html).
+        fn move_ss(a: __m128, b: __m128) -> __m128 = _mm_move_ss;
+
+        /// See [`_mm_movehl_ps`](fn._mm_movehl_ps.html).
+        fn movehl_ps(a: __m128, b: __m128) -> __m128 = _mm_movehl_ps;
+
+        /// See [`_mm_movelh_ps`](fn._mm_movelh_ps.html).
+        fn movelh_ps(a: __m128, b: __m128) -> __m128 = _mm_movelh_ps;
+
+        /// See [`_mm_movemask_ps`](fn._mm_movemask_ps.html).
+        fn movemask_ps(a: __m128) -> i32 = _mm_movemask_ps;
+
+        /// See [`_mm_mul_ps`](fn._mm_mul_ps.html).
+        fn mul_ps(a: __m128, b: __m128) -> __m128 = _mm_mul_ps;
+
+        /// See [`_mm_mul_ss`](fn._mm_mul_ss.html).
+        fn mul_ss(a: __m128, b: __m128) -> __m128 = _mm_mul_ss;
+
+        /// See [`_mm_or_ps`](fn._mm_or_ps.html).
+        fn or_ps(a: __m128, b: __m128) -> __m128 = _mm_or_ps;
+
+        /// See [`_mm_rcp_ps`](fn._mm_rcp_ps.html).
+        fn rcp_ps(a: __m128) -> __m128 = _mm_rcp_ps;
+
+        /// See [`_mm_rcp_ss`](fn._mm_rcp_ss.html).
+        fn rcp_ss(a: __m128) -> __m128 = _mm_rcp_ss;
+
+        /// See [`_mm_rsqrt_ps`](fn._mm_rsqrt_ps.html).
+        fn rsqrt_ps(a: __m128) -> __m128 = _mm_rsqrt_ps;
+
+        /// See [`_mm_rsqrt_ss`](fn._mm_rsqrt_ss.html).
+        fn rsqrt_ss(a: __m128) -> __m128 = _mm_rsqrt_ss;
+
+        /// See [`_mm_set1_ps`](fn._mm_set1_ps.html).
+        fn set1_ps(a: f32) -> __m128 = _mm_set1_ps;
+
+        /// See [`_mm_set_ps`](fn._mm_set_ps.html).
+        fn set_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 = _mm_set_ps;
+
+        /// See [`_mm_set_ps1`](fn._mm_set_ps1.html).
+        fn set_ps1(a: f32) -> __m128 = _mm_set_ps1;
+
+        /// See [`_mm_set_ss`](fn._mm_set_ss.html).
+        fn set_ss(a: f32) -> __m128 = _mm_set_ss;
+
+        /// See [`_mm_setr_ps`](fn._mm_setr_ps.html).
+        fn setr_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 = _mm_setr_ps;
+
+        /// See [`_mm_setzero_ps`](fn._mm_setzero_ps.html).
+        fn setzero_ps() -> __m128 = _mm_setzero_ps;
+
+        /// See [`_mm_sfence`](fn._mm_sfence.html).
+        fn sfence() = _mm_sfence;
+
+        /// See [`_mm_sqrt_ps`](fn._mm_sqrt_ps.html).
+        fn sqrt_ps(a: __m128) -> __m128 = _mm_sqrt_ps;
+
+        /// See [`_mm_sqrt_ss`](fn._mm_sqrt_ss.html).
+        fn sqrt_ss(a: __m128) -> __m128 = _mm_sqrt_ss;
+
+        /// See [`_mm_sub_ps`](fn._mm_sub_ps.html).
+        fn sub_ps(a: __m128, b: __m128) -> __m128 = _mm_sub_ps;
+
+        /// See [`_mm_sub_ss`](fn._mm_sub_ss.html).
+        fn sub_ss(a: __m128, b: __m128) -> __m128 = _mm_sub_ss;
+
+        /// See [`_mm_ucomieq_ss`](fn._mm_ucomieq_ss.html).
+        fn ucomieq_ss(a: __m128, b: __m128) -> i32 = _mm_ucomieq_ss;
+
+        /// See [`_mm_ucomige_ss`](fn._mm_ucomige_ss.html).
+        fn ucomige_ss(a: __m128, b: __m128) -> i32 = _mm_ucomige_ss;
+
+        /// See [`_mm_ucomigt_ss`](fn._mm_ucomigt_ss.html).
+        fn ucomigt_ss(a: __m128, b: __m128) -> i32 = _mm_ucomigt_ss;
+
+        /// See [`_mm_ucomile_ss`](fn._mm_ucomile_ss.html).
+        fn ucomile_ss(a: __m128, b: __m128) -> i32 = _mm_ucomile_ss;
+
+        /// See [`_mm_ucomilt_ss`](fn._mm_ucomilt_ss.html).
+        fn ucomilt_ss(a: __m128, b: __m128) -> i32 = _mm_ucomilt_ss;
+
+        /// See [`_mm_ucomineq_ss`](fn._mm_ucomineq_ss.html).
+        fn ucomineq_ss(a: __m128, b: __m128) -> i32 = _mm_ucomineq_ss;
+
+        /// See [`_mm_unpackhi_ps`](fn._mm_unpackhi_ps.html).
+        fn unpackhi_ps(a: __m128, b: __m128) -> __m128 = _mm_unpackhi_ps;
+
+        /// See [`_mm_unpacklo_ps`](fn._mm_unpacklo_ps.html).
+        fn unpacklo_ps(a: __m128, b: __m128) -> __m128 = _mm_unpacklo_ps;
+
+        /// See [`_mm_xor_ps`](fn._mm_xor_ps.html).
+        fn xor_ps(a: __m128, b: __m128) -> __m128 = _mm_xor_ps;
+    }
+}
+
+token_methods! {
+    Sse2 = "sse2" {
+        /// See [`_mm_add_epi16`](fn._mm_add_epi16.html).
+        fn add_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_add_epi16;
+
+        /// See [`_mm_add_epi32`](fn._mm_add_epi32.html).
+        fn add_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_add_epi32;
+
+        /// See [`_mm_add_epi64`](fn._mm_add_epi64.html).
+        fn add_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_add_epi64;
+
+        /// See [`_mm_add_epi8`](fn._mm_add_epi8.html).
+        fn add_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_add_epi8;
+
+        /// See [`_mm_add_pd`](fn._mm_add_pd.html).
+        fn add_pd(a: __m128d, b: __m128d) -> __m128d = _mm_add_pd;
+
+        /// See [`_mm_add_sd`](fn._mm_add_sd.html).
+        fn add_sd(a: __m128d, b: __m128d) -> __m128d = _mm_add_sd;
+
+        /// See [`_mm_adds_epi16`](fn._mm_adds_epi16.html).
+        fn adds_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_adds_epi16;
+
+        /// See [`_mm_adds_epi8`](fn._mm_adds_epi8.html).
+        fn adds_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_adds_epi8;
+
+        /// See [`_mm_adds_epu16`](fn._mm_adds_epu16.html).
+        fn adds_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_adds_epu16;
+
+        /// See [`_mm_adds_epu8`](fn._mm_adds_epu8.html).
+        fn adds_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_adds_epu8;
+
+        /// See [`_mm_and_pd`](fn._mm_and_pd.html).
+        fn and_pd(a: __m128d, b: __m128d) -> __m128d = _mm_and_pd;
+
+        /// See [`_mm_and_si128`](fn._mm_and_si128.html).
+        fn and_si128(a: __m128i, b: __m128i) -> __m128i = _mm_and_si128;
+
+        /// See [`_mm_andnot_pd`](fn._mm_andnot_pd.html).
+        fn andnot_pd(a: __m128d, b: __m128d) -> __m128d = _mm_andnot_pd;
+
+        /// See [`_mm_andnot_si128`](fn._mm_andnot_si128.html).
+        fn andnot_si128(a: __m128i, b: __m128i) -> __m128i = _mm_andnot_si128;
+
+        /// See [`_mm_avg_epu16`](fn._mm_avg_epu16.html).
+        fn avg_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_avg_epu16;
+
+        /// See [`_mm_avg_epu8`](fn._mm_avg_epu8.html).
+        fn avg_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_avg_epu8;
+
+        /// See [`_mm_castpd_ps`](fn._mm_castpd_ps.html).
+        fn castpd_ps(a: __m128d) -> __m128 = _mm_castpd_ps;
+
+        /// See [`_mm_castpd_si128`](fn._mm_castpd_si128.html).
+        fn castpd_si128(a: __m128d) -> __m128i = _mm_castpd_si128;
+
+        /// See [`_mm_castps_pd`](fn._mm_castps_pd.html).
+        fn castps_pd(a: __m128) -> __m128d = _mm_castps_pd;
+
+        /// See [`_mm_castps_si128`](fn._mm_castps_si128.html).
+        fn castps_si128(a: __m128) -> __m128i = _mm_castps_si128;
+
+        /// See [`_mm_castsi128_pd`](fn._mm_castsi128_pd.html).
+        fn castsi128_pd(a: __m128i) -> __m128d = _mm_castsi128_pd;
+
+        /// See [`_mm_castsi128_ps`](fn._mm_castsi128_ps.html).
+        fn castsi128_ps(a: __m128i) -> __m128 = _mm_castsi128_ps;
+
+        /// See [`_mm_cmpeq_epi16`](fn._mm_cmpeq_epi16.html).
+        fn cmpeq_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_cmpeq_epi16;
+
+        /// See [`_mm_cmpeq_epi32`](fn._mm_cmpeq_epi32.html).
+        fn cmpeq_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_cmpeq_epi32;
+
+        /// See [`_mm_cmpeq_epi8`](fn._mm_cmpeq_epi8.html).
+        fn cmpeq_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_cmpeq_epi8;
+
+        /// See [`_mm_cmpeq_pd`](fn._mm_cmpeq_pd.html).
+        fn cmpeq_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpeq_pd;
+
+        /// See [`_mm_cmpeq_sd`](fn._mm_cmpeq_sd.html).
+        fn cmpeq_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpeq_sd;
+
+        /// See [`_mm_cmpge_pd`](fn._mm_cmpge_pd.html).
+        fn cmpge_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpge_pd;
+
+        /// See [`_mm_cmpge_sd`](fn._mm_cmpge_sd.html).
+        fn cmpge_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpge_sd;
+
+        /// See [`_mm_cmpgt_epi16`](fn._mm_cmpgt_epi16.html).
+        fn cmpgt_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_cmpgt_epi16;
+
+        /// See [`_mm_cmpgt_epi32`](fn._mm_cmpgt_epi32.html).
+        fn cmpgt_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_cmpgt_epi32;
+
+        /// See [`_mm_cmpgt_epi8`](fn._mm_cmpgt_epi8.html).
+        fn cmpgt_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_cmpgt_epi8;
+
+        /// See [`_mm_cmpgt_pd`](fn._mm_cmpgt_pd.html).
+        fn cmpgt_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpgt_pd;
+
+        /// See [`_mm_cmpgt_sd`](fn._mm_cmpgt_sd.html).
+        fn cmpgt_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpgt_sd;
+
+        /// See [`_mm_cmple_pd`](fn._mm_cmple_pd.html).
+        fn cmple_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmple_pd;
+
+        /// See [`_mm_cmple_sd`](fn._mm_cmple_sd.html).
+        fn cmple_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmple_sd;
+
+        /// See [`_mm_cmplt_epi16`](fn._mm_cmplt_epi16.html).
+        fn cmplt_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_cmplt_epi16;
+
+        /// See [`_mm_cmplt_epi32`](fn._mm_cmplt_epi32.html).
+        fn cmplt_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_cmplt_epi32;
+
+        /// See [`_mm_cmplt_epi8`](fn._mm_cmplt_epi8.html).
+        fn cmplt_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_cmplt_epi8;
+
+        /// See [`_mm_cmplt_pd`](fn._mm_cmplt_pd.html).
+        fn cmplt_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmplt_pd;
+
+        /// See [`_mm_cmplt_sd`](fn._mm_cmplt_sd.html).
+        fn cmplt_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmplt_sd;
+
+        /// See [`_mm_cmpneq_pd`](fn._mm_cmpneq_pd.html).
+        fn cmpneq_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpneq_pd;
+
+        /// See [`_mm_cmpneq_sd`](fn._mm_cmpneq_sd.html).
+        fn cmpneq_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpneq_sd;
+
+        /// See [`_mm_cmpnge_pd`](fn._mm_cmpnge_pd.html).
+        fn cmpnge_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpnge_pd;
+
+        /// See [`_mm_cmpnge_sd`](fn._mm_cmpnge_sd.html).
+        fn cmpnge_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpnge_sd;
+
+        /// See [`_mm_cmpngt_pd`](fn._mm_cmpngt_pd.html).
+        fn cmpngt_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpngt_pd;
+
+        /// See [`_mm_cmpngt_sd`](fn._mm_cmpngt_sd.html).
+        fn cmpngt_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpngt_sd;
+
+        /// See [`_mm_cmpnle_pd`](fn._mm_cmpnle_pd.html).
+        fn cmpnle_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpnle_pd;
+
+        /// See [`_mm_cmpnle_sd`](fn._mm_cmpnle_sd.html).
+        fn cmpnle_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpnle_sd;
+
+        /// See [`_mm_cmpnlt_pd`](fn._mm_cmpnlt_pd.html).
+        fn cmpnlt_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpnlt_pd;
+
+        /// See [`_mm_cmpnlt_sd`](fn._mm_cmpnlt_sd.html).
+        fn cmpnlt_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpnlt_sd;
+
+        /// See [`_mm_cmpord_pd`](fn._mm_cmpord_pd.html).
+        fn cmpord_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpord_pd;
+
+        /// See [`_mm_cmpord_sd`](fn._mm_cmpord_sd.html).
+        fn cmpord_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpord_sd;
+
+        /// See [`_mm_cmpunord_pd`](fn._mm_cmpunord_pd.html).
+        fn cmpunord_pd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpunord_pd;
+
+        /// See [`_mm_cmpunord_sd`](fn._mm_cmpunord_sd.html).
+        fn cmpunord_sd(a: __m128d, b: __m128d) -> __m128d = _mm_cmpunord_sd;
+
+        /// See [`_mm_comieq_sd`](fn._mm_comieq_sd.html).
+        fn comieq_sd(a: __m128d, b: __m128d) -> bool = _mm_comieq_sd;
+
+        /// See [`_mm_comige_sd`](fn._mm_comige_sd.html).
+        fn comige_sd(a: __m128d, b: __m128d) -> bool = _mm_comige_sd;
+
+        /// See [`_mm_comigt_sd`](fn._mm_comigt_sd.html).
+        fn comigt_sd(a: __m128d, b: __m128d) -> bool = _mm_comigt_sd;
+
+        /// See [`_mm_comile_sd`](fn._mm_comile_sd.html).
+        fn comile_sd(a: __m128d, b: __m128d) -> bool = _mm_comile_sd;
+
+        /// See [`_mm_comilt_sd`](fn._mm_comilt_sd.html).
+        fn comilt_sd(a: __m128d, b: __m128d) -> bool = _mm_comilt_sd;
+
+        /// See [`_mm_comineq_sd`](fn._mm_comineq_sd.html).
+        fn comineq_sd(a: __m128d, b: __m128d) -> bool = _mm_comineq_sd;
+
+        /// See [`_mm_cvtepi32_pd`](fn._mm_cvtepi32_pd.html).
+        fn cvtepi32_pd(a: __m128i) -> __m128d = _mm_cvtepi32_pd;
+
+        /// See [`_mm_cvtepi32_ps`](fn._mm_cvtepi32_ps.html).
+        fn cvtepi32_ps(a: __m128i) -> __m128 = _mm_cvtepi32_ps;
+
+        /// See [`_mm_cvtpd_epi32`](fn._mm_cvtpd_epi32.html).
+        fn cvtpd_epi32(a: __m128d) -> __m128i = _mm_cvtpd_epi32;
+
+        /// See [`_mm_cvtpd_ps`](fn._mm_cvtpd_ps.html).
+        fn cvtpd_ps(a: __m128d) -> __m128 = _mm_cvtpd_ps;
+
+        /// See [`_mm_cvtps_epi32`](fn._mm_cvtps_epi32.html).
+        fn cvtps_epi32(a: __m128) -> __m128i = _mm_cvtps_epi32;
+
+        /// See [`_mm_cvtps_pd`](fn._mm_cvtps_pd.html).
+        fn cvtps_pd(a: __m128) -> __m128d = _mm_cvtps_pd;
+
+        /// See [`_mm_cvtsd_f64`](fn._mm_cvtsd_f64.html).
+        fn cvtsd_f64(a: __m128d) -> f64 = _mm_cvtsd_f64;
+
+        /// See [`_mm_cvtsd_si32`](fn._mm_cvtsd_si32.html).
+        fn cvtsd_si32(a: __m128d) -> i32 = _mm_cvtsd_si32;
+
+        /// See [`_mm_cvtsd_si64`](fn._mm_cvtsd_si64.html).
+        fn cvtsd_si64(a: __m128d) -> i64 = _mm_cvtsd_si64;
+
+        /// See [`_mm_cvtsd_si64x`](fn._mm_cvtsd_si64x.html).
+        fn cvtsd_si64x(a: __m128d) -> i64 = _mm_cvtsd_si64x;
+
+        /// See [`_mm_cvtsd_ss`](fn._mm_cvtsd_ss.html).
+        fn cvtsd_ss(a: __m128, b: __m128d) -> __m128 = _mm_cvtsd_ss;
+
+        /// See [`_mm_cvtsi128_si32`](fn._mm_cvtsi128_si32.html).
+        fn cvtsi128_si32(a: __m128i) -> i32 = _mm_cvtsi128_si32;
+
+        /// See [`_mm_cvtsi128_si64`](fn._mm_cvtsi128_si64.html).
+        fn cvtsi128_si64(a: __m128i) -> i64 = _mm_cvtsi128_si64;
+
+        /// See [`_mm_cvtsi128_si64x`](fn._mm_cvtsi128_si64x.html).
+        fn cvtsi128_si64x(a: __m128i) -> i64 = _mm_cvtsi128_si64x;
+
+        /// See [`_mm_cvtsi32_sd`](fn._mm_cvtsi32_sd.html).
+        fn cvtsi32_sd(a: __m128d, b: i32) -> __m128d = _mm_cvtsi32_sd;
+
+        /// See [`_mm_cvtsi32_si128`](fn._mm_cvtsi32_si128.html).
+        fn cvtsi32_si128(a: i32) -> __m128i = _mm_cvtsi32_si128;
+
+        /// See [`_mm_cvtsi64_sd`](fn._mm_cvtsi64_sd.html).
+        fn cvtsi64_sd(a: __m128d, b: i64) -> __m128d = _mm_cvtsi64_sd;
+
+        /// See [`_mm_cvtsi64_si128`](fn._mm_cvtsi64_si128.html).
+        fn cvtsi64_si128(a: i64) -> __m128i = _mm_cvtsi64_si128;
+
+        /// See [`_mm_cvtsi64x_sd`](fn._mm_cvtsi64x_sd.html).
+        fn cvtsi64x_sd(a: __m128d, b: i64) -> __m128d = _mm_cvtsi64x_sd;
+
+        /// See [`_mm_cvtsi64x_si128`](fn._mm_cvtsi64x_si128.html).
+        fn cvtsi64x_si128(a: i64) -> __m128i = _mm_cvtsi64x_si128;
+
+        /// See [`_mm_cvtss_sd`](fn._mm_cvtss_sd.html).
+        fn cvtss_sd(a: __m128d, b: __m128) -> __m128d = _mm_cvtss_sd;
+
+        /// See [`_mm_cvttpd_epi32`](fn._mm_cvttpd_epi32.html).
+        fn cvttpd_epi32(a: __m128d) -> __m128i = _mm_cvttpd_epi32;
+
+        /// See [`_mm_cvttps_epi32`](fn._mm_cvttps_epi32.html).
+        fn cvttps_epi32(a: __m128) -> __m128i = _mm_cvttps_epi32;
+
+        /// See [`_mm_cvttsd_si32`](fn._mm_cvttsd_si32.html).
+        fn cvttsd_si32(a: __m128d) -> i32 = _mm_cvttsd_si32;
+
+        /// See [`_mm_cvttsd_si64`](fn._mm_cvttsd_si64.html).
+        fn cvttsd_si64(a: __m128d) -> i64 = _mm_cvttsd_si64;
+
+        /// See [`_mm_cvttsd_si64x`](fn._mm_cvttsd_si64x.html).
+        fn cvttsd_si64x(a: __m128d) -> i64 = _mm_cvttsd_si64x;
+
+        /// See [`_mm_div_pd`](fn._mm_div_pd.html).
+        fn div_pd(a: __m128d, b: __m128d) -> __m128d = _mm_div_pd;
+
+        /// See [`_mm_div_sd`](fn._mm_div_sd.html).
+        fn div_sd(a: __m128d, b: __m128d) -> __m128d = _mm_div_sd;
+
+        /// See [`_mm_lfence`](fn._mm_lfence.html).
+        fn lfence() = _mm_lfence;
+
+        /// See [`_mm_madd_epi16`](fn._mm_madd_epi16.html).
+        fn madd_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_madd_epi16;
+
+        /// See [`_mm_max_epi16`](fn._mm_max_epi16.html).
+        fn max_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_max_epi16;
+
+        /// See [`_mm_max_epu8`](fn._mm_max_epu8.html).
+        fn max_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_max_epu8;
+
+        /// See [`_mm_max_pd`](fn._mm_max_pd.html).
+        fn max_pd(a: __m128d, b: __m128d) -> __m128d = _mm_max_pd;
+
+        /// See [`_mm_max_sd`](fn._mm_max_sd.html).
+        fn max_sd(a: __m128d, b: __m128d) -> __m128d = _mm_max_sd;
+
+        /// See [`_mm_mfence`](fn._mm_mfence.html).
+        fn mfence() = _mm_mfence;
+
+        /// See [`_mm_min_epi16`](fn._mm_min_epi16.html).
+        fn min_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_min_epi16;
+
+        /// See [`_mm_min_epu8`](fn._mm_min_epu8.html).
+        fn min_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_min_epu8;
+
+        /// See [`_mm_min_pd`](fn._mm_min_pd.html).
+        fn min_pd(a: __m128d, b: __m128d) -> __m128d = _mm_min_pd;
+
+        /// See [`_mm_min_sd`](fn._mm_min_sd.html).
+        fn min_sd(a: __m128d, b: __m128d) -> __m128d = _mm_min_sd;
+
+        /// See [`_mm_move_epi64`](fn._mm_move_epi64.html).
+        fn move_epi64(a: __m128i) -> __m128i = _mm_move_epi64;
+
+        /// See [`_mm_move_sd`](fn._mm_move_sd.html).
+        fn move_sd(a: __m128d, b: __m128d) -> __m128d = _mm_move_sd;
+
+        /// See [`_mm_movemask_epi8`](fn._mm_movemask_epi8.html).
+        fn movemask_epi8(a: __m128i) -> i32 = _mm_movemask_epi8;
+
+        /// See [`_mm_movemask_pd`](fn._mm_movemask_pd.html).
+        fn movemask_pd(a: __m128d) -> i32 = _mm_movemask_pd;
+
+        /// See [`_mm_mul_epu32`](fn._mm_mul_epu32.html).
+        fn mul_epu32(a: __m128i, b: __m128i) -> __m128i = _mm_mul_epu32;
+
+        /// See [`_mm_mul_pd`](fn._mm_mul_pd.html).
+        fn mul_pd(a: __m128d, b: __m128d) -> __m128d = _mm_mul_pd;
+
+        /// See [`_mm_mul_sd`](fn._mm_mul_sd.html).
+        fn mul_sd(a: __m128d, b: __m128d) -> __m128d = _mm_mul_sd;
+
+        /// See [`_mm_mulhi_epi16`](fn._mm_mulhi_epi16.html).
+        fn mulhi_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_mulhi_epi16;
+
+        /// See [`_mm_mulhi_epu16`](fn._mm_mulhi_epu16.html).
+        fn mulhi_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_mulhi_epu16;
+
+        /// See [`_mm_mullo_epi16`](fn._mm_mullo_epi16.html).
+        fn mullo_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_mullo_epi16;
+
+        /// See [`_mm_or_pd`](fn._mm_or_pd.html).
+        fn or_pd(a: __m128d, b: __m128d) -> __m128d = _mm_or_pd;
+
+        /// See [`_mm_or_si128`](fn._mm_or_si128.html).
+        fn or_si128(a: __m128i, b: __m128i) -> __m128i = _mm_or_si128;
+
+        /// See [`_mm_packs_epi16`](fn._mm_packs_epi16.html).
+        fn packs_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_packs_epi16;
+
+        /// See [`_mm_packs_epi32`](fn._mm_packs_epi32.html).
+        fn packs_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_packs_epi32;
+
+        /// See [`_mm_packus_epi16`](fn._mm_packus_epi16.html).
+        fn packus_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_packus_epi16;
+
+        /// See [`_mm_pause`](fn._mm_pause.html).
+        fn pause() = _mm_pause;
+
+        /// See [`_mm_sad_epu8`](fn._mm_sad_epu8.html).
+        fn sad_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_sad_epu8;
+
+        /// See [`_mm_set1_epi16`](fn._mm_set1_epi16.html).
+        fn set1_epi16(a: i16) -> __m128i = _mm_set1_epi16;
+
+        /// See [`_mm_set1_epi32`](fn._mm_set1_epi32.html).
+        fn set1_epi32(a: i32) -> __m128i = _mm_set1_epi32;
+
+        /// See [`_mm_set1_epi64x`](fn._mm_set1_epi64x.html).
+        fn set1_epi64x(a: i64) -> __m128i = _mm_set1_epi64x;
+
+        /// See [`_mm_set1_epi8`](fn._mm_set1_epi8.html).
+        fn set1_epi8(a: i8) -> __m128i = _mm_set1_epi8;
+
+        /// See [`_mm_set1_pd`](fn._mm_set1_pd.html).
+        fn set1_pd(a: f64) -> __m128d = _mm_set1_pd;
+
+        /// See [`_mm_set_epi16`](fn._mm_set_epi16.html).
+        fn set_epi16(
+            e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16,
+            e0: i16
+        ) -> __m128i = _mm_set_epi16;
+
+        /// See [`_mm_set_epi32`](fn._mm_set_epi32.html).
+        fn set_epi32(e3: i32, e2: i32, e1: i32, e0: i32)
+            -> __m128i = _mm_set_epi32;
+
+        /// See [`_mm_set_epi64x`](fn._mm_set_epi64x.html).
+        fn set_epi64x(e1: i64, e0: i64) -> __m128i = _mm_set_epi64x;
+
+        /// See [`_mm_set_epi8`](fn._mm_set_epi8.html).
+        fn set_epi8(
+            e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8,
+            e8: i8, e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8,
+            e0: i8
+        ) -> __m128i = _mm_set_epi8;
+
+        /// See [`_mm_set_pd`](fn._mm_set_pd.html).
+        fn set_pd(a: f64, b: f64) -> __m128d = _mm_set_pd;
+
+        /// See [`_mm_set_pd1`](fn._mm_set_pd1.html).
+        fn set_pd1(a: f64) -> __m128d = _mm_set_pd1;
+
+        /// See [`_mm_set_sd`](fn._mm_set_sd.html).
+        fn set_sd(a: f64) -> __m128d = _mm_set_sd;
+
+        /// See [`_mm_setr_epi16`](fn._mm_setr_epi16.html).
+        fn setr_epi16(
+            e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16,
+            e0: i16
+        ) -> __m128i = _mm_setr_epi16;
+
+        /// See [`_mm_setr_epi32`](fn._mm_setr_epi32.html).
+        fn setr_epi32(e3: i32, e2: i32, e1: i32, e0: i32)
+            -> __m128i = _mm_setr_epi32;
+
+        /// See [`_mm_setr_epi8`](fn._mm_setr_epi8.html).
+        fn setr_epi8(
+            e15: i8, e14: i8, e13: i8, e12: i8, e11: i8, e10: i8, e9: i8,
+            e8: i8, e7: i8, e6: i8, e5: i8, e4: i8, e3: i8, e2: i8, e1: i8,
+            e0: i8
+        ) -> __m128i = _mm_setr_epi8;
+
+        /// See [`_mm_setr_pd`](fn._mm_setr_pd.html).
+        fn setr_pd(a: f64, b: f64) -> __m128d = _mm_setr_pd;
+
+        /// See [`_mm_setzero_pd`](fn._mm_setzero_pd.html).
+        fn setzero_pd() -> __m128d = _mm_setzero_pd;
+
+        /// See [`_mm_setzero_si128`](fn._mm_setzero_si128.html).
+        fn setzero_si128() -> __m128i = _mm_setzero_si128;
+
+        /// See [`_mm_sll_epi16`](fn._mm_sll_epi16.html).
+        fn sll_epi16(a: __m128i, count: __m128i) -> __m128i = _mm_sll_epi16;
+
+        /// See [`_mm_sll_epi32`](fn._mm_sll_epi32.html).
+        fn sll_epi32(a: __m128i, count: __m128i) -> __m128i = _mm_sll_epi32;
+
+        /// See [`_mm_sll_epi64`](fn._mm_sll_epi64.html).
+        fn sll_epi64(a: __m128i, count: __m128i) -> __m128i = _mm_sll_epi64;
+
+        /// See [`_mm_slli_epi16`](fn._mm_slli_epi16.html).
+        fn slli_epi16(a: __m128i, imm8: i32) -> __m128i = _mm_slli_epi16;
+
+        /// See [`_mm_slli_epi32`](fn._mm_slli_epi32.html).
+        fn slli_epi32(a: __m128i, imm8: i32) -> __m128i = _mm_slli_epi32;
+
+        /// See [`_mm_slli_epi64`](fn._mm_slli_epi64.html).
+        fn slli_epi64(a: __m128i, imm8: i32) -> __m128i = _mm_slli_epi64;
+
+        /// See [`_mm_sqrt_pd`](fn._mm_sqrt_pd.html).
+        fn sqrt_pd(a: __m128d) -> __m128d = _mm_sqrt_pd;
+
+        /// See [`_mm_sqrt_sd`](fn._mm_sqrt_sd.html).
+        fn sqrt_sd(a: __m128d, b: __m128d) -> __m128d = _mm_sqrt_sd;
+
+        /// See [`_mm_sra_epi16`](fn._mm_sra_epi16.html).
+        fn sra_epi16(a: __m128i, count: __m128i) -> __m128i = _mm_sra_epi16;
+
+        /// See [`_mm_sra_epi32`](fn._mm_sra_epi32.html).
+        fn sra_epi32(a: __m128i, count: __m128i) -> __m128i = _mm_sra_epi32;
+
+        /// See [`_mm_srai_epi16`](fn._mm_srai_epi16.html).
+        fn srai_epi16(a: __m128i, imm8: i32) -> __m128i = _mm_srai_epi16;
+
+        /// See [`_mm_srai_epi32`](fn._mm_srai_epi32.html).
+        fn srai_epi32(a: __m128i, imm8: i32) -> __m128i = _mm_srai_epi32;
+
+        /// See [`_mm_srl_epi16`](fn._mm_srl_epi16.html).
+        fn srl_epi16(a: __m128i, count: __m128i) -> __m128i = _mm_srl_epi16;
+
+        /// See [`_mm_srl_epi32`](fn._mm_srl_epi32.html).
+        fn srl_epi32(a: __m128i, count: __m128i) -> __m128i = _mm_srl_epi32;
+
+        /// See [`_mm_srl_epi64`](fn._mm_srl_epi64.html).
+        fn srl_epi64(a: __m128i, count: __m128i) -> __m128i = _mm_srl_epi64;
+
+        /// See [`_mm_srli_epi16`](fn._mm_srli_epi16.html).
+        fn srli_epi16(a: __m128i, imm8: i32) -> __m128i = _mm_srli_epi16;
+
+        /// See [`_mm_srli_epi32`](fn._mm_srli_epi32.html).
+        fn srli_epi32(a: __m128i, imm8: i32) -> __m128i = _mm_srli_epi32;
+
+        /// See [`_mm_srli_epi64`](fn._mm_srli_epi64.html).
+        fn srli_epi64(a: __m128i, imm8: i32) -> __m128i = _mm_srli_epi64;
+
+        /// See [`_mm_sub_epi16`](fn._mm_sub_epi16.html).
+        fn sub_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_sub_epi16;
+
+        /// See [`_mm_sub_epi32`](fn._mm_sub_epi32.html).
+        fn sub_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_sub_epi32;
+
+        /// See [`_mm_sub_epi64`](fn._mm_sub_epi64.html).
+        fn sub_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_sub_epi64;
+
+        /// See [`_mm_sub_epi8`](fn._mm_sub_epi8.html).
+        fn sub_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_sub_epi8;
+
+        /// See [`_mm_sub_pd`](fn._mm_sub_pd.html).
+        fn sub_pd(a: __m128d, b: __m128d) -> __m128d = _mm_sub_pd;
+
+        /// See [`_mm_sub_sd`](fn._mm_sub_sd.html).
+        fn sub_sd(a: __m128d, b: __m128d) -> __m128d = _mm_sub_sd;
+
+        /// See [`_mm_subs_epi16`](fn._mm_subs_epi16.html).
+        fn subs_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_subs_epi16;
+
+        /// See [`_mm_subs_epi8`](fn._mm_subs_epi8.html).
+        fn subs_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_subs_epi8;
+
+        /// See [`_mm_subs_epu16`](fn._mm_subs_epu16.html).
+        fn subs_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_subs_epu16;
+
+        /// See [`_mm_subs_epu8`](fn._mm_subs_epu8.html).
+        fn subs_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_subs_epu8;
+
+        /// See [`_mm_ucomieq_sd`](fn._mm_ucomieq_sd.html).
+        fn ucomieq_sd(a: __m128d, b: __m128d) -> bool = _mm_ucomieq_sd;
+
+        /// See [`_mm_ucomige_sd`](fn._mm_ucomige_sd.html).
+        fn ucomige_sd(a: __m128d, b: __m128d) -> bool = _mm_ucomige_sd;
+
+        /// See [`_mm_ucomigt_sd`](fn._mm_ucomigt_sd.html).
+        fn ucomigt_sd(a: __m128d, b: __m128d) -> bool = _mm_ucomigt_sd;
+
+        /// See [`_mm_ucomile_sd`](fn._mm_ucomile_sd.html).
+        fn ucomile_sd(a: __m128d, b: __m128d) -> bool = _mm_ucomile_sd;
+
+        /// See [`_mm_ucomilt_sd`](fn._mm_ucomilt_sd.html).
+        fn ucomilt_sd(a: __m128d, b: __m128d) -> bool = _mm_ucomilt_sd;
+
+        /// See [`_mm_ucomineq_sd`](fn._mm_ucomineq_sd.html).
+        fn ucomineq_sd(a: __m128d, b: __m128d) -> bool = _mm_ucomineq_sd;
+
+        /// See [`_mm_unpackhi_epi16`](fn._mm_unpackhi_epi16.html).
+        fn unpackhi_epi16(a: __m128i, b: __m128i)
+            -> __m128i = _mm_unpackhi_epi16;
+
+        /// See [`_mm_unpackhi_epi32`](fn._mm_unpackhi_epi32.html).
+        fn unpackhi_epi32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_unpackhi_epi32;
+
+        /// See [`_mm_unpackhi_epi64`](fn._mm_unpackhi_epi64.html).
+        fn unpackhi_epi64(a: __m128i, b: __m128i)
+            -> __m128i = _mm_unpackhi_epi64;
+
+        /// See [`_mm_unpackhi_epi8`](fn._mm_unpackhi_epi8.html).
+        fn unpackhi_epi8(a: __m128i, b: __m128i)
+            -> __m128i = _mm_unpackhi_epi8;
+
+        /// See [`_mm_unpackhi_pd`](fn._mm_unpackhi_pd.html).
+        fn unpackhi_pd(a: __m128d, b: __m128d) -> __m128d = _mm_unpackhi_pd;
+
+        /// See [`_mm_unpacklo_epi16`](fn._mm_unpacklo_epi16.html).
+        fn unpacklo_epi16(a: __m128i, b: __m128i)
+            -> __m128i = _mm_unpacklo_epi16;
+
+        /// See [`_mm_unpacklo_epi32`](fn._mm_unpacklo_epi32.html).
+        fn unpacklo_epi32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_unpacklo_epi32;
+
+        /// See [`_mm_unpacklo_epi64`](fn._mm_unpacklo_epi64.html).
+        fn unpacklo_epi64(a: __m128i, b: __m128i)
+            -> __m128i = _mm_unpacklo_epi64;
+
+        /// See [`_mm_unpacklo_epi8`](fn._mm_unpacklo_epi8.html).
+        fn unpacklo_epi8(a: __m128i, b: __m128i)
+            -> __m128i = _mm_unpacklo_epi8;
+
+        /// See [`_mm_unpacklo_pd`](fn._mm_unpacklo_pd.html).
+        fn unpacklo_pd(a: __m128d, b: __m128d) -> __m128d = _mm_unpacklo_pd;
+
+        /// See [`_mm_xor_pd`](fn._mm_xor_pd.html).
+        fn xor_pd(a: __m128d, b: __m128d) -> __m128d = _mm_xor_pd;
+
+        /// See [`_mm_xor_si128`](fn._mm_xor_si128.html).
+        fn xor_si128(a: __m128i, b: __m128i) -> __m128i = _mm_xor_si128;
+    }
+}
+
+token_methods! {
+    Sse3 = "sse3" {
+        /// See [`_mm_addsub_pd`](fn._mm_addsub_pd.html).
+        fn addsub_pd(a: __m128d, b: __m128d) -> __m128d = _mm_addsub_pd;
+
+        /// See [`_mm_addsub_ps`](fn._mm_addsub_ps.html).
+        fn addsub_ps(a: __m128, b: __m128) -> __m128 = _mm_addsub_ps;
+
+        /// See [`_mm_hadd_pd`](fn._mm_hadd_pd.html).
+        fn hadd_pd(a: __m128d, b: __m128d) -> __m128d = _mm_hadd_pd;
+
+        /// See [`_mm_hadd_ps`](fn._mm_hadd_ps.html).
+        fn hadd_ps(a: __m128, b: __m128) -> __m128 = _mm_hadd_ps;
+
+        /// See [`_mm_hsub_pd`](fn._mm_hsub_pd.html).
+        fn hsub_pd(a: __m128d, b: __m128d) -> __m128d = _mm_hsub_pd;
+
+        /// See [`_mm_hsub_ps`](fn._mm_hsub_ps.html).
+        fn hsub_ps(a: __m128, b: __m128) -> __m128 = _mm_hsub_ps;
+
+        /// See [`_mm_movedup_pd`](fn._mm_movedup_pd.html).
+        fn movedup_pd(a: __m128d) -> __m128d = _mm_movedup_pd;
+
+        /// See [`_mm_movehdup_ps`](fn._mm_movehdup_ps.html).
+        fn movehdup_ps(a: __m128) -> __m128 = _mm_movehdup_ps;
+
+        /// See [`_mm_moveldup_ps`](fn._mm_moveldup_ps.html).
+        fn moveldup_ps(a: __m128) -> __m128 = _mm_moveldup_ps;
+    }
+}
+
+token_methods! {
+    Ssse3 = "ssse3" {
+        /// See [`_mm_abs_epi16`](fn._mm_abs_epi16.html).
+        fn abs_epi16(a: __m128i) -> __m128i = _mm_abs_epi16;
+
+        /// See [`_mm_abs_epi32`](fn._mm_abs_epi32.html).
+        fn abs_epi32(a: __m128i) -> __m128i = _mm_abs_epi32;
+
+        /// See [`_mm_abs_epi8`](fn._mm_abs_epi8.html).
+        fn abs_epi8(a: __m128i) -> __m128i = _mm_abs_epi8;
+
+        /// See [`_mm_hadd_epi16`](fn._mm_hadd_epi16.html).
+        fn hadd_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_hadd_epi16;
+
+        /// See [`_mm_hadd_epi32`](fn._mm_hadd_epi32.html).
+        fn hadd_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_hadd_epi32;
+
+        /// See [`_mm_hadds_epi16`](fn._mm_hadds_epi16.html).
+        fn hadds_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_hadds_epi16;
+
+        /// See [`_mm_hsub_epi16`](fn._mm_hsub_epi16.html).
+        fn hsub_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_hsub_epi16;
+
+        /// See [`_mm_hsub_epi32`](fn._mm_hsub_epi32.html).
+        fn hsub_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_hsub_epi32;
+
+        /// See [`_mm_hsubs_epi16`](fn._mm_hsubs_epi16.html).
+        fn hsubs_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_hsubs_epi16;
+
+        /// See [`_mm_maddubs_epi16`](fn._mm_maddubs_epi16.html).
+        fn maddubs_epi16(a: __m128i, b: __m128i)
+            -> __m128i = _mm_maddubs_epi16;
+
+        /// See [`_mm_mulhrs_epi16`](fn._mm_mulhrs_epi16.html).
+        fn mulhrs_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_mulhrs_epi16;
+
+        /// See [`_mm_shuffle_epi8`](fn._mm_shuffle_epi8.html).
+        fn shuffle_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_shuffle_epi8;
+
+        /// See [`_mm_sign_epi16`](fn._mm_sign_epi16.html).
+        fn sign_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_sign_epi16;
+
+        /// See [`_mm_sign_epi32`](fn._mm_sign_epi32.html).
+        fn sign_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_sign_epi32;
+
+        /// See [`_mm_sign_epi8`](fn._mm_sign_epi8.html).
+        fn sign_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_sign_epi8;
+    }
+}
+
+token_methods! {
+    Sse41 = "sse4.1" {
+        /// See [`_mm_blendv_epi8`](fn._mm_blendv_epi8.html).
+        fn blendv_epi8(a: __m128i, b: __m128i, mask: __m128i)
+            -> __m128i = _mm_blendv_epi8;
+
+        /// See [`_mm_blendv_pd`](fn._mm_blendv_pd.html).
+        fn blendv_pd(a: __m128d, b: __m128d, mask: __m128d)
+            -> __m128d = _mm_blendv_pd;
+
+        /// See [`_mm_blendv_ps`](fn._mm_blendv_ps.html).
+        fn blendv_ps(a: __m128, b: __m128, mask: __m128)
+            -> __m128 = _mm_blendv_ps;
+
+        /// See [`_mm_ceil_pd`](fn._mm_ceil_pd.html).
+        fn ceil_pd(a: __m128d) -> __m128d = _mm_ceil_pd;
+
+        /// See [`_mm_ceil_ps`](fn._mm_ceil_ps.html).
+        fn ceil_ps(a: __m128) -> __m128 = _mm_ceil_ps;
+
+        /// See [`_mm_ceil_sd`](fn._mm_ceil_sd.html).
+        fn ceil_sd(a: __m128d, b: __m128d) -> __m128d = _mm_ceil_sd;
+
+        /// See [`_mm_ceil_ss`](fn._mm_ceil_ss.html).
+        fn ceil_ss(a: __m128, b: __m128) -> __m128 = _mm_ceil_ss;
+
+        /// See [`_mm_cmpeq_epi64`](fn._mm_cmpeq_epi64.html).
+        fn cmpeq_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_cmpeq_epi64;
+
+        /// See [`_mm_cvtepi16_epi32`](fn._mm_cvtepi16_epi32.html).
+        fn cvtepi16_epi32(a: __m128i) -> __m128i = _mm_cvtepi16_epi32;
+
+        /// See [`_mm_cvtepi16_epi64`](fn._mm_cvtepi16_epi64.html).
+        fn cvtepi16_epi64(a: __m128i) -> __m128i = _mm_cvtepi16_epi64;
+
+        /// See [`_mm_cvtepi32_epi64`](fn._mm_cvtepi32_epi64.html).
+        fn cvtepi32_epi64(a: __m128i) -> __m128i = _mm_cvtepi32_epi64;
+
+        /// See [`_mm_cvtepi8_epi16`](fn._mm_cvtepi8_epi16.html).
+        fn cvtepi8_epi16(a: __m128i) -> __m128i = _mm_cvtepi8_epi16;
+
+        /// See [`_mm_cvtepi8_epi32`](fn._mm_cvtepi8_epi32.html).
+        fn cvtepi8_epi32(a: __m128i) -> __m128i = _mm_cvtepi8_epi32;
+
+        /// See [`_mm_cvtepi8_epi64`](fn._mm_cvtepi8_epi64.html).
+        fn cvtepi8_epi64(a: __m128i) -> __m128i = _mm_cvtepi8_epi64;
+
+        /// See [`_mm_cvtepu16_epi32`](fn._mm_cvtepu16_epi32.html).
+        fn cvtepu16_epi32(a: __m128i) -> __m128i = _mm_cvtepu16_epi32;
+
+        /// See [`_mm_cvtepu16_epi64`](fn._mm_cvtepu16_epi64.html).
+        fn cvtepu16_epi64(a: __m128i) -> __m128i = _mm_cvtepu16_epi64;
+
+        /// See [`_mm_cvtepu32_epi64`](fn._mm_cvtepu32_epi64.html).
+        fn cvtepu32_epi64(a: __m128i) -> __m128i = _mm_cvtepu32_epi64;
+
+        /// See [`_mm_cvtepu8_epi16`](fn._mm_cvtepu8_epi16.html).
+        fn cvtepu8_epi16(a: __m128i) -> __m128i = _mm_cvtepu8_epi16;
+
+        /// See [`_mm_cvtepu8_epi32`](fn._mm_cvtepu8_epi32.html).
+        fn cvtepu8_epi32(a: __m128i) -> __m128i = _mm_cvtepu8_epi32;
+
+        /// See [`_mm_cvtepu8_epi64`](fn._mm_cvtepu8_epi64.html).
+        fn cvtepu8_epi64(a: __m128i) -> __m128i = _mm_cvtepu8_epi64;
+
+        /// See [`_mm_floor_pd`](fn._mm_floor_pd.html).
+        fn floor_pd(a: __m128d) -> __m128d = _mm_floor_pd;
+
+        /// See [`_mm_floor_ps`](fn._mm_floor_ps.html).
+        fn floor_ps(a: __m128) -> __m128 = _mm_floor_ps;
+
+        /// See [`_mm_floor_sd`](fn._mm_floor_sd.html).
+        fn floor_sd(a: __m128d, b: __m128d) -> __m128d = _mm_floor_sd;
+
+        /// See [`_mm_floor_ss`](fn._mm_floor_ss.html).
+        fn floor_ss(a: __m128, b: __m128) -> __m128 = _mm_floor_ss;
+
+        /// See [`_mm_max_epi32`](fn._mm_max_epi32.html).
+        fn max_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_max_epi32;
+
+        /// See [`_mm_max_epi8`](fn._mm_max_epi8.html).
+        fn max_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_max_epi8;
+
+        /// See [`_mm_max_epu16`](fn._mm_max_epu16.html).
+        fn max_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_max_epu16;
+
+        /// See [`_mm_max_epu32`](fn._mm_max_epu32.html).
+        fn max_epu32(a: __m128i, b: __m128i) -> __m128i = _mm_max_epu32;
+
+        /// See [`_mm_min_epi32`](fn._mm_min_epi32.html).
+        fn min_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_min_epi32;
+
+        /// See [`_mm_min_epi8`](fn._mm_min_epi8.html).
+        fn min_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_min_epi8;
+
+        /// See [`_mm_min_epu16`](fn._mm_min_epu16.html).
+        fn min_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_min_epu16;
+
+        /// See [`_mm_min_epu32`](fn._mm_min_epu32.html).
+        fn min_epu32(a: __m128i, b: __m128i) -> __m128i = _mm_min_epu32;
+
+        /// See [`_mm_minpos_epu16`](fn._mm_minpos_epu16.html).
+        fn minpos_epu16(a: __m128i) -> __m128i = _mm_minpos_epu16;
+
+        /// See [`_mm_mul_epi32`](fn._mm_mul_epi32.html).
+        fn mul_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_mul_epi32;
+
+        /// See [`_mm_mullo_epi32`](fn._mm_mullo_epi32.html).
+        fn mullo_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_mullo_epi32;
+
+        /// See [`_mm_packus_epi32`](fn._mm_packus_epi32.html).
+        fn packus_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_packus_epi32;
+
+        /// See [`_mm_test_all_ones`](fn._mm_test_all_ones.html).
+        fn test_all_ones(a: __m128i) -> i32 = _mm_test_all_ones;
+
+        /// See [`_mm_test_all_zeros`](fn._mm_test_all_zeros.html).
+        fn test_all_zeros(a: __m128i, mask: __m128i)
+            -> i32 = _mm_test_all_zeros;
+
+        /// See [`_mm_test_mix_ones_zeros`](fn._mm_test_mix_ones_zeros.html).
+        fn test_mix_ones_zeros(a: __m128i, mask: __m128i)
+            -> i32 = _mm_test_mix_ones_zeros;
+
+        /// See [`_mm_testc_si128`](fn._mm_testc_si128.html).
+        fn testc_si128(a: __m128i, mask: __m128i) -> i32 = _mm_testc_si128;
+
+        /// See [`_mm_testnzc_si128`](fn._mm_testnzc_si128.html).
+        fn testnzc_si128(a: __m128i, mask: __m128i) -> i32 = _mm_testnzc_si128;
+
+        /// See [`_mm_testz_si128`](fn._mm_testz_si128.html).
+        fn testz_si128(a: __m128i, mask: __m128i) -> i32 = _mm_testz_si128;
+    }
+}
+
+token_methods! {
+    Sse42 = "sse4.2" {
+        /// See [`_mm_cmpgt_epi64`](fn._mm_cmpgt_epi64.html).
+        fn cmpgt_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_cmpgt_epi64;
+
+        /// See [`_mm_crc32_u16`](fn._mm_crc32_u16.html).
+        fn crc32_u16(crc: u32, v: u16) -> u32 = _mm_crc32_u16;
+
+        /// See [`_mm_crc32_u32`](fn._mm_crc32_u32.html).
+        fn crc32_u32(crc: u32, v: u32) -> u32 = _mm_crc32_u32;
+
+        /// See [`_mm_crc32_u64`](fn._mm_crc32_u64.html).
+        fn crc32_u64(crc: u64, v: u64) -> u64 = _mm_crc32_u64;
+
+        /// See [`_mm_crc32_u8`](fn._mm_crc32_u8.html).
+        fn crc32_u8(crc: u32, v: u8) -> u32 = _mm_crc32_u8;
+    }
+}
+
+#[cfg(not(feature = "intel_sde"))]
+token_methods! {
+    Sse4a = "sse4a" {
+        /// See [`_mm_extract_si64`](fn._mm_extract_si64.html).
+        fn extract_si64(x: __m128i, y: __m128i) -> __m128i = _mm_extract_si64;
+
+        /// See [`_mm_insert_si64`](fn._mm_insert_si64.html).
+        fn insert_si64(x: __m128i, y: __m128i) -> __m128i = _mm_insert_si64;
+    }
+}
+
+token_methods! {
+    Avx = "avx" {
+        /// See [`_mm256_add_pd`](fn._mm256_add_pd.html).
+        fn add_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_add_pd;
+
+        /// See [`_mm256_add_ps`](fn._mm256_add_ps.html).
+        fn add_ps(a: __m256, b: __m256) -> __m256 = _mm256_add_ps;
+
+        /// See [`_mm256_addsub_pd`](fn._mm256_addsub_pd.html).
+        fn addsub_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_addsub_pd;
+
+        /// See [`_mm256_addsub_ps`](fn._mm256_addsub_ps.html).
+        fn addsub_ps(a: __m256, b: __m256) -> __m256 = _mm256_addsub_ps;
+
+        /// See [`_mm256_and_pd`](fn._mm256_and_pd.html).
+        fn and_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_and_pd;
+
+        /// See [`_mm256_and_ps`](fn._mm256_and_ps.html).
+        fn and_ps(a: __m256, b: __m256) -> __m256 = _mm256_and_ps;
+
+        /// See [`_mm256_andnot_pd`](fn._mm256_andnot_pd.html).
+        fn andnot_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_andnot_pd;
+
+        /// See [`_mm256_andnot_ps`](fn._mm256_andnot_ps.html).
+        fn andnot_ps(a: __m256, b: __m256) -> __m256 = _mm256_andnot_ps;
+
+        /// See [`_mm256_blendv_pd`](fn._mm256_blendv_pd.html).
+        fn blendv_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_blendv_pd;
+
+        /// See [`_mm256_blendv_ps`](fn._mm256_blendv_ps.html).
+        fn blendv_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_blendv_ps;
+
+        /// See [`_mm256_castpd128_pd256`](fn._mm256_castpd128_pd256.html).
+        fn castpd128_pd256(a: __m128d) -> __m256d = _mm256_castpd128_pd256;
+
+        /// See [`_mm256_castpd256_pd128`](fn._mm256_castpd256_pd128.html).
+        fn castpd256_pd128(a: __m256d) -> __m128d = _mm256_castpd256_pd128;
+
+        /// See [`_mm256_castpd_ps`](fn._mm256_castpd_ps.html).
+        fn castpd_ps(a: __m256d) -> __m256 = _mm256_castpd_ps;
+
+        /// See [`_mm256_castpd_si256`](fn._mm256_castpd_si256.html).
+        fn castpd_si256(a: __m256d) -> __m256i = _mm256_castpd_si256;
+
+        /// See [`_mm256_castps128_ps256`](fn._mm256_castps128_ps256.html).
+        fn castps128_ps256(a: __m128) -> __m256 = _mm256_castps128_ps256;
+
+        /// See [`_mm256_castps256_ps128`](fn._mm256_castps256_ps128.html).
+        fn castps256_ps128(a: __m256) -> __m128 = _mm256_castps256_ps128;
+
+        /// See [`_mm256_castps_pd`](fn._mm256_castps_pd.html).
+        fn castps_pd(a: __m256) -> __m256d = _mm256_castps_pd;
+
+        /// See [`_mm256_castps_si256`](fn._mm256_castps_si256.html).
+        fn castps_si256(a: __m256) -> __m256i = _mm256_castps_si256;
+
+        /// See [`_mm256_castsi128_si256`](fn._mm256_castsi128_si256.html).
+        fn castsi128_si256(a: __m128i) -> __m256i = _mm256_castsi128_si256;
+
+        /// See [`_mm256_castsi256_pd`](fn._mm256_castsi256_pd.html).
+        fn castsi256_pd(a: __m256i) -> __m256d = _mm256_castsi256_pd;
+
+        /// See [`_mm256_castsi256_ps`](fn._mm256_castsi256_ps.html).
+        fn castsi256_ps(a: __m256i) -> __m256 = _mm256_castsi256_ps;
+
+        /// See [`_mm256_castsi256_si128`](fn._mm256_castsi256_si128.html).
+        fn castsi256_si128(a: __m256i) -> __m128i = _mm256_castsi256_si128;
+
+        /// See [`_mm256_ceil_pd`](fn._mm256_ceil_pd.html).
+        fn ceil_pd(a: __m256d) -> __m256d = _mm256_ceil_pd;
+
+        /// See [`_mm256_ceil_ps`](fn._mm256_ceil_ps.html).
+        fn ceil_ps(a: __m256) -> __m256 = _mm256_ceil_ps;
+
+        /// See [`_mm256_cvtepi32_pd`](fn._mm256_cvtepi32_pd.html).
+        fn cvtepi32_pd(a: __m128i) -> __m256d = _mm256_cvtepi32_pd;
+
+        /// See [`_mm256_cvtepi32_ps`](fn._mm256_cvtepi32_ps.html).
+        fn cvtepi32_ps(a: __m256i) -> __m256 = _mm256_cvtepi32_ps;
+
+        /// See [`_mm256_cvtpd_epi32`](fn._mm256_cvtpd_epi32.html).
+        fn cvtpd_epi32(a: __m256d) -> __m128i = _mm256_cvtpd_epi32;
+
+        /// See [`_mm256_cvtpd_ps`](fn._mm256_cvtpd_ps.html).
+        fn cvtpd_ps(a: __m256d) -> __m128 = _mm256_cvtpd_ps;
+
+        /// See [`_mm256_cvtps_epi32`](fn._mm256_cvtps_epi32.html).
+        fn cvtps_epi32(a: __m256) -> __m256i = _mm256_cvtps_epi32;
+
+        /// See [`_mm256_cvtps_pd`](fn._mm256_cvtps_pd.html).
+        fn cvtps_pd(a: __m128) -> __m256d = _mm256_cvtps_pd;
+
+        /// See [`_mm256_cvtss_f32`](fn._mm256_cvtss_f32.html).
+        fn cvtss_f32(a: __m256) -> f32 = _mm256_cvtss_f32;
+
+        /// See [`_mm256_cvttpd_epi32`](fn._mm256_cvttpd_epi32.html).
+        fn cvttpd_epi32(a: __m256d) -> __m128i = _mm256_cvttpd_epi32;
+
+        /// See [`_mm256_cvttps_epi32`](fn._mm256_cvttps_epi32.html).
+        fn cvttps_epi32(a: __m256) -> __m256i = _mm256_cvttps_epi32;
+
+        /// See [`_mm256_div_pd`](fn._mm256_div_pd.html).
+        fn div_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_div_pd;
+
+        /// See [`_mm256_div_ps`](fn._mm256_div_ps.html).
+        fn div_ps(a: __m256, b: __m256) -> __m256 = _mm256_div_ps;
+
+        /// See [`_mm256_floor_pd`](fn._mm256_floor_pd.html).
+        fn floor_pd(a: __m256d) -> __m256d = _mm256_floor_pd;
+
+        /// See [`_mm256_floor_ps`](fn._mm256_floor_ps.html).
+        fn floor_ps(a: __m256) -> __m256 = _mm256_floor_ps;
+
+        /// See [`_mm256_hadd_pd`](fn._mm256_hadd_pd.html).
+        fn hadd_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_hadd_pd;
+
+        /// See [`_mm256_hadd_ps`](fn._mm256_hadd_ps.html).
+        fn hadd_ps(a: __m256, b: __m256) -> __m256 = _mm256_hadd_ps;
+
+        /// See [`_mm256_hsub_pd`](fn._mm256_hsub_pd.html).
+        fn hsub_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_hsub_pd;
+
+        /// See [`_mm256_hsub_ps`](fn._mm256_hsub_ps.html).
+        fn hsub_ps(a: __m256, b: __m256) -> __m256 = _mm256_hsub_ps;
+
+        /// See [`_mm256_max_pd`](fn._mm256_max_pd.html).
+        fn max_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_max_pd;
+
+        /// See [`_mm256_max_ps`](fn._mm256_max_ps.html).
+        fn max_ps(a: __m256, b: __m256) -> __m256 = _mm256_max_ps;
+
+        /// See [`_mm256_min_pd`](fn._mm256_min_pd.html).
+        fn min_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_min_pd;
+
+        /// See [`_mm256_min_ps`](fn._mm256_min_ps.html).
+        fn min_ps(a: __m256, b: __m256) -> __m256 = _mm256_min_ps;
+
+        /// See [`_mm_permutevar_pd`](fn._mm_permutevar_pd.html).
+        fn mm_permutevar_pd(a: __m128d, b: __m128i)
+            -> __m128d = _mm_permutevar_pd;
+
+        /// See [`_mm_permutevar_ps`](fn._mm_permutevar_ps.html).
+        fn mm_permutevar_ps(a: __m128, b: __m128i)
+            -> __m128 = _mm_permutevar_ps;
+
+        /// See [`_mm_testc_pd`](fn._mm_testc_pd.html).
+        fn mm_testc_pd(a: __m128d, b: __m128d) -> i32 = _mm_testc_pd;
+
+        /// See [`_mm_testc_ps`](fn._mm_testc_ps.html).
+        fn mm_testc_ps(a: __m128, b: __m128) -> i32 = _mm_testc_ps;
+
+        /// See [`_mm_testnzc_pd`](fn._mm_testnzc_pd.html).
+        fn mm_testnzc_pd(a: __m128d, b: __m128d) -> i32 = _mm_testnzc_pd;
+
+        /// See [`_mm_testnzc_ps`](fn._mm_testnzc_ps.html).
+        fn mm_testnzc_ps(a: __m128, b: __m128) -> i32 = _mm_testnzc_ps;
+
+        /// See [`_mm_testz_pd`](fn._mm_testz_pd.html).
+        fn mm_testz_pd(a: __m128d, b: __m128d) -> i32 = _mm_testz_pd;
+
+        /// See [`_mm_testz_ps`](fn._mm_testz_ps.html).
+        fn mm_testz_ps(a: __m128, b: __m128) -> i32 = _mm_testz_ps;
+
+        /// See [`_mm256_movedup_pd`](fn._mm256_movedup_pd.html).
+        fn movedup_pd(a: __m256d) -> __m256d = _mm256_movedup_pd;
+
+        /// See [`_mm256_movehdup_ps`](fn._mm256_movehdup_ps.html).
+        fn movehdup_ps(a: __m256) -> __m256 = _mm256_movehdup_ps;
+
+        /// See [`_mm256_moveldup_ps`](fn._mm256_moveldup_ps.html).
+        fn moveldup_ps(a: __m256) -> __m256 = _mm256_moveldup_ps;
+
+        /// See [`_mm256_movemask_pd`](fn._mm256_movemask_pd.html).
+        fn movemask_pd(a: __m256d) -> i32 = _mm256_movemask_pd;
+
+        /// See [`_mm256_movemask_ps`](fn._mm256_movemask_ps.html).
+        fn movemask_ps(a: __m256) -> i32 = _mm256_movemask_ps;
+
+        /// See [`_mm256_mul_pd`](fn._mm256_mul_pd.html).
+        fn mul_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_mul_pd;
+
+        /// See [`_mm256_mul_ps`](fn._mm256_mul_ps.html).
+        fn mul_ps(a: __m256, b: __m256) -> __m256 = _mm256_mul_ps;
+
+        /// See [`_mm256_or_pd`](fn._mm256_or_pd.html).
+        fn or_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_or_pd;
+
+        /// See [`_mm256_or_ps`](fn._mm256_or_ps.html).
+        fn or_ps(a: __m256, b: __m256) -> __m256 = _mm256_or_ps;
+
+        /// See [`_mm256_permutevar_pd`](fn._mm256_permutevar_pd.html).
+        fn permutevar_pd(a: __m256d, b: __m256i)
+            -> __m256d = _mm256_permutevar_pd;
+
+        /// See [`_mm256_permutevar_ps`](fn._mm256_permutevar_ps.html).
+        fn permutevar_ps(a: __m256, b: __m256i)
+            -> __m256 = _mm256_permutevar_ps;
+
+        /// See [`_mm256_rcp_ps`](fn._mm256_rcp_ps.html).
+        fn rcp_ps(a: __m256) -> __m256 = _mm256_rcp_ps;
+
+        /// See [`_mm256_rsqrt_ps`](fn._mm256_rsqrt_ps.html).
+        fn rsqrt_ps(a: __m256) -> __m256 = _mm256_rsqrt_ps;
+
+        /// See [`_mm256_set1_epi16`](fn._mm256_set1_epi16.html).
+        fn set1_epi16(a: i16) -> __m256i = _mm256_set1_epi16;
+
+        /// See [`_mm256_set1_epi32`](fn._mm256_set1_epi32.html).
+        fn set1_epi32(a: i32) -> __m256i = _mm256_set1_epi32;
+
+        /// See [`_mm256_set1_epi64x`](fn._mm256_set1_epi64x.html).
+        fn set1_epi64x(a: i64) -> __m256i = _mm256_set1_epi64x;
+
+        /// See [`_mm256_set1_epi8`](fn._mm256_set1_epi8.html).
+        fn set1_epi8(a: i8) -> __m256i = _mm256_set1_epi8;
+
+        /// See [`_mm256_set1_pd`](fn._mm256_set1_pd.html).
+        fn set1_pd(a: f64) -> __m256d = _mm256_set1_pd;
+
+        /// See [`_mm256_set1_ps`](fn._mm256_set1_ps.html).
+        fn set1_ps(a: f32) -> __m256 = _mm256_set1_ps;
+
+        /// See [`_mm256_set_epi16`](fn._mm256_set_epi16.html).
+        fn set_epi16(
+            e00: i16, e01: i16, e02: i16, e03: i16, e04: i16, e05: i16,
+            e06: i16, e07: i16, e08: i16, e09: i16, e10: i16, e11: i16,
+            e12: i16, e13: i16, e14: i16, e15: i16
+        ) -> __m256i = _mm256_set_epi16;
+
+        /// See [`_mm256_set_epi32`](fn._mm256_set_epi32.html).
+        fn set_epi32(
+            e0: i32, e1: i32, e2: i32, e3: i32, e4: i32, e5: i32, e6: i32,
+            e7: i32
+        ) -> __m256i = _mm256_set_epi32;
+
+        /// See [`_mm256_set_epi64x`](fn._mm256_set_epi64x.html).
+        fn set_epi64x(a: i64, b: i64, c: i64, d: i64)
+            -> __m256i = _mm256_set_epi64x;
+
+        /// See [`_mm256_set_epi8`](fn._mm256_set_epi8.html).
+        fn set_epi8(
+            e00: i8, e01: i8, e02: i8, e03: i8, e04: i8, e05: i8, e06: i8,
+            e07: i8, e08: i8, e09: i8, e10: i8, e11: i8, e12: i8, e13: i8,
+            e14: i8, e15: i8, e16: i8, e17: i8, e18: i8, e19: i8, e20: i8,
+            e21: i8, e22: i8, e23: i8, e24: i8, e25: i8, e26: i8, e27: i8,
+            e28: i8, e29: i8, e30: i8, e31: i8
+        ) -> __m256i = _mm256_set_epi8;
+
+        /// See [`_mm256_set_m128`](fn._mm256_set_m128.html).
+        fn set_m128(hi: __m128, lo: __m128) -> __m256 = _mm256_set_m128;
+
+        /// See [`_mm256_set_m128d`](fn._mm256_set_m128d.html).
+        fn set_m128d(hi: __m128d, lo: __m128d) -> __m256d = _mm256_set_m128d;
+
+        /// See [`_mm256_set_m128i`](fn._mm256_set_m128i.html).
+        fn set_m128i(hi: __m128i, lo: __m128i) -> __m256i = _mm256_set_m128i;
+
+        /// See [`_mm256_set_pd`](fn._mm256_set_pd.html).
+        fn set_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d = _mm256_set_pd;
+
+        /// See [`_mm256_set_ps`](fn._mm256_set_ps.html).
+        fn set_ps(
+            a: f32, b: f32, c: f32, d: f32, e: f32, f: f32, g: f32, h: f32
+        ) -> __m256 = _mm256_set_ps;
+
+        /// See [`_mm256_setr_epi16`](fn._mm256_setr_epi16.html).
+        fn setr_epi16(
+            e00: i16, e01: i16, e02: i16, e03: i16, e04: i16, e05: i16,
+            e06: i16, e07: i16, e08: i16, e09: i16, e10: i16, e11: i16,
+            e12: i16, e13: i16, e14: i16, e15: i16
+        ) -> __m256i = _mm256_setr_epi16;
+
+        /// See [`_mm256_setr_epi32`](fn._mm256_setr_epi32.html).
+        fn setr_epi32(
+            e0: i32, e1: i32, e2: i32, e3: i32, e4: i32, e5: i32, e6: i32,
+            e7: i32
+        ) -> __m256i = _mm256_setr_epi32;
+
+        /// See [`_mm256_setr_epi64x`](fn._mm256_setr_epi64x.html).
+        fn setr_epi64x(a: i64, b: i64, c: i64, d: i64)
+            -> __m256i = _mm256_setr_epi64x;
+
+        /// See [`_mm256_setr_epi8`](fn._mm256_setr_epi8.html).
+        fn setr_epi8(
+            e00: i8, e01: i8, e02: i8, e03: i8, e04: i8, e05: i8, e06: i8,
+            e07: i8, e08: i8, e09: i8, e10: i8, e11: i8, e12: i8, e13: i8,
+            e14: i8, e15: i8, e16: i8, e17: i8, e18: i8, e19: i8, e20: i8,
+            e21: i8, e22: i8, e23: i8, e24: i8, e25: i8, e26: i8, e27: i8,
+            e28: i8, e29: i8, e30: i8, e31: i8
+        ) -> __m256i = _mm256_setr_epi8;
+
+        /// See [`_mm256_setr_m128`](fn._mm256_setr_m128.html).
+        fn setr_m128(lo: __m128, hi: __m128) -> __m256 = _mm256_setr_m128;
+
+        /// See [`_mm256_setr_m128d`](fn._mm256_setr_m128d.html).
+        fn setr_m128d(lo: __m128d, hi: __m128d) -> __m256d = _mm256_setr_m128d;
+
+        /// See [`_mm256_setr_m128i`](fn._mm256_setr_m128i.html).
+        fn setr_m128i(lo: __m128i, hi: __m128i) -> __m256i = _mm256_setr_m128i;
+
+        /// See [`_mm256_setr_pd`](fn._mm256_setr_pd.html).
+        fn setr_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d = _mm256_setr_pd;
+
+        /// See [`_mm256_setr_ps`](fn._mm256_setr_ps.html).
+        fn setr_ps(
+            a: f32, b: f32, c: f32, d: f32, e: f32, f: f32, g: f32, h: f32
+        ) -> __m256 = _mm256_setr_ps;
+
+        /// See [`_mm256_setzero_pd`](fn._mm256_setzero_pd.html).
+        fn setzero_pd() -> __m256d = _mm256_setzero_pd;
+
+        /// See [`_mm256_setzero_ps`](fn._mm256_setzero_ps.html).
+        fn setzero_ps() -> __m256 = _mm256_setzero_ps;
+
+        /// See [`_mm256_setzero_si256`](fn._mm256_setzero_si256.html).
+        fn setzero_si256() -> __m256i = _mm256_setzero_si256;
+
+        /// See [`_mm256_sqrt_pd`](fn._mm256_sqrt_pd.html).
+        fn sqrt_pd(a: __m256d) -> __m256d = _mm256_sqrt_pd;
+
+        /// See [`_mm256_sqrt_ps`](fn._mm256_sqrt_ps.html).
+        fn sqrt_ps(a: __m256) -> __m256 = _mm256_sqrt_ps;
+
+        /// See [`_mm256_sub_pd`](fn._mm256_sub_pd.html).
+        fn sub_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_sub_pd;
+
+        /// See [`_mm256_sub_ps`](fn._mm256_sub_ps.html).
+        fn sub_ps(a: __m256, b: __m256) -> __m256 = _mm256_sub_ps;
+
+        /// See [`_mm256_testc_pd`](fn._mm256_testc_pd.html).
+        fn testc_pd(a: __m256d, b: __m256d) -> i32 = _mm256_testc_pd;
+
+        /// See [`_mm256_testc_ps`](fn._mm256_testc_ps.html).
+        fn testc_ps(a: __m256, b: __m256) -> i32 = _mm256_testc_ps;
+
+        /// See [`_mm256_testc_si256`](fn._mm256_testc_si256.html).
+        fn testc_si256(a: __m256i, b: __m256i) -> i32 = _mm256_testc_si256;
+
+        /// See [`_mm256_testnzc_pd`](fn._mm256_testnzc_pd.html).
+        fn testnzc_pd(a: __m256d, b: __m256d) -> i32 = _mm256_testnzc_pd;
+
+        /// See [`_mm256_testnzc_ps`](fn._mm256_testnzc_ps.html).
+        fn testnzc_ps(a: __m256, b: __m256) -> i32 = _mm256_testnzc_ps;
+
+        /// See [`_mm256_testnzc_si256`](fn._mm256_testnzc_si256.html).
+        fn testnzc_si256(a: __m256i, b: __m256i) -> i32 = _mm256_testnzc_si256;
+
+        /// See [`_mm256_testz_pd`](fn._mm256_testz_pd.html).
+        fn testz_pd(a: __m256d, b: __m256d) -> i32 = _mm256_testz_pd;
+
+        /// See [`_mm256_testz_ps`](fn._mm256_testz_ps.html).
+        fn testz_ps(a: __m256, b: __m256) -> i32 = _mm256_testz_ps;
+
+        /// See [`_mm256_testz_si256`](fn._mm256_testz_si256.html).
+        fn testz_si256(a: __m256i, b: __m256i) -> i32 = _mm256_testz_si256;
+
+        /// See [`_mm256_unpackhi_pd`](fn._mm256_unpackhi_pd.html).
+        fn unpackhi_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_unpackhi_pd;
+
+        /// See [`_mm256_unpackhi_ps`](fn._mm256_unpackhi_ps.html).
+        fn unpackhi_ps(a: __m256, b: __m256) -> __m256 = _mm256_unpackhi_ps;
+
+        /// See [`_mm256_unpacklo_pd`](fn._mm256_unpacklo_pd.html).
+        fn unpacklo_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_unpacklo_pd;
+
+        /// See [`_mm256_unpacklo_ps`](fn._mm256_unpacklo_ps.html).
+        fn unpacklo_ps(a: __m256, b: __m256) -> __m256 = _mm256_unpacklo_ps;
+
+        /// See [`_mm256_xor_pd`](fn._mm256_xor_pd.html).
+        fn xor_pd(a: __m256d, b: __m256d) -> __m256d = _mm256_xor_pd;
+
+        /// See [`_mm256_xor_ps`](fn._mm256_xor_ps.html).
+        fn xor_ps(a: __m256, b: __m256) -> __m256 = _mm256_xor_ps;
+
+        /// See [`_mm256_zeroall`](fn._mm256_zeroall.html).
+        fn zeroall() = _mm256_zeroall;
+
+        /// See [`_mm256_zeroupper`](fn._mm256_zeroupper.html).
+        fn zeroupper() = _mm256_zeroupper;
+    }
+}
+
+token_methods! {
+    Avx2 = "avx2" {
+        /// See [`_mm256_abs_epi16`](fn._mm256_abs_epi16.html).
+        fn abs_epi16(a: __m256i) -> __m256i = _mm256_abs_epi16;
+
+        /// See [`_mm256_abs_epi32`](fn._mm256_abs_epi32.html).
+        fn abs_epi32(a: __m256i) -> __m256i = _mm256_abs_epi32;
+
+        /// See [`_mm256_abs_epi8`](fn._mm256_abs_epi8.html).
+        fn abs_epi8(a: __m256i) -> __m256i = _mm256_abs_epi8;
+
+        /// See [`_mm256_add_epi16`](fn._mm256_add_epi16.html).
+        fn add_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_add_epi16;
+
+        /// See [`_mm256_add_epi32`](fn._mm256_add_epi32.html).
+        fn add_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_add_epi32;
+
+        /// See [`_mm256_add_epi64`](fn._mm256_add_epi64.html).
+        fn add_epi64(a: __m256i, b: __m256i) -> __m256i = _mm256_add_epi64;
+
+        /// See [`_mm256_add_epi8`](fn._mm256_add_epi8.html).
+        fn add_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_add_epi8;
+
+        /// See [`_mm256_adds_epi16`](fn._mm256_adds_epi16.html).
+        fn adds_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_adds_epi16;
+
+        /// See [`_mm256_adds_epi8`](fn._mm256_adds_epi8.html).
+        fn adds_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_adds_epi8;
+
+        /// See [`_mm256_adds_epu16`](fn._mm256_adds_epu16.html).
+        fn adds_epu16(a: __m256i, b: __m256i) -> __m256i = _mm256_adds_epu16;
+
+        /// See [`_mm256_adds_epu8`](fn._mm256_adds_epu8.html).
+        fn adds_epu8(a: __m256i, b: __m256i) -> __m256i = _mm256_adds_epu8;
+
+        /// See [`_mm256_and_si256`](fn._mm256_and_si256.html).
+        fn and_si256(a: __m256i, b: __m256i) -> __m256i = _mm256_and_si256;
+
+        /// See [`_mm256_andnot_si256`](fn._mm256_andnot_si256.html).
+        fn andnot_si256(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_andnot_si256;
+
+        /// See [`_mm256_avg_epu16`](fn._mm256_avg_epu16.html).
+        fn avg_epu16(a: __m256i, b: __m256i) -> __m256i = _mm256_avg_epu16;
+
+        /// See [`_mm256_avg_epu8`](fn._mm256_avg_epu8.html).
+        fn avg_epu8(a: __m256i, b: __m256i) -> __m256i = _mm256_avg_epu8;
+
+        /// See [`_mm256_blendv_epi8`](fn._mm256_blendv_epi8.html).
+        fn blendv_epi8(a: __m256i, b: __m256i, mask: __m256i)
+            -> __m256i = _mm256_blendv_epi8;
+
+        /// See [`_mm256_broadcastb_epi8`](fn._mm256_broadcastb_epi8.html).
+        fn broadcastb_epi8(a: __m128i) -> __m256i = _mm256_broadcastb_epi8;
+
+        /// See [`_mm256_broadcastd_epi32`](fn._mm256_broadcastd_epi32.html).
+        fn broadcastd_epi32(a: __m128i) -> __m256i = _mm256_broadcastd_epi32;
+
+        /// See [`_mm256_broadcastq_epi64`](fn._mm256_broadcastq_epi64.html).
+        fn broadcastq_epi64(a: __m128i) -> __m256i = _mm256_broadcastq_epi64;
+
+        /// See [`_mm256_broadcastsd_pd`](fn._mm256_broadcastsd_pd.html).
+        fn broadcastsd_pd(a: __m128d) -> __m256d = _mm256_broadcastsd_pd;
+
+        /// See `_mm256_broadcastsi128_si256`.
+        fn broadcastsi128_si256(a: __m128i)
+            -> __m256i = _mm256_broadcastsi128_si256;
+
+        /// See [`_mm256_broadcastss_ps`](fn._mm256_broadcastss_ps.html).
+        fn broadcastss_ps(a: __m128) -> __m256 = _mm256_broadcastss_ps;
+
+        /// See [`_mm256_broadcastw_epi16`](fn._mm256_broadcastw_epi16.html).
+        fn broadcastw_epi16(a: __m128i) -> __m256i = _mm256_broadcastw_epi16;
+
+        /// See [`_mm256_cmpeq_epi16`](fn._mm256_cmpeq_epi16.html).
+        fn cmpeq_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_cmpeq_epi16;
+
+        /// See [`_mm256_cmpeq_epi32`](fn._mm256_cmpeq_epi32.html).
+        fn cmpeq_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_cmpeq_epi32;
+
+        /// See [`_mm256_cmpeq_epi64`](fn._mm256_cmpeq_epi64.html).
+        fn cmpeq_epi64(a: __m256i, b: __m256i) -> __m256i = _mm256_cmpeq_epi64;
+
+        /// See [`_mm256_cmpeq_epi8`](fn._mm256_cmpeq_epi8.html).
+        fn cmpeq_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_cmpeq_epi8;
+
+        /// See [`_mm256_cmpgt_epi16`](fn._mm256_cmpgt_epi16.html).
+        fn cmpgt_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_cmpgt_epi16;
+
+        /// See [`_mm256_cmpgt_epi32`](fn._mm256_cmpgt_epi32.html).
+        fn cmpgt_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_cmpgt_epi32;
+
+        /// See [`_mm256_cmpgt_epi64`](fn._mm256_cmpgt_epi64.html).
+        fn cmpgt_epi64(a: __m256i, b: __m256i) -> __m256i = _mm256_cmpgt_epi64;
+
+        /// See [`_mm256_cmpgt_epi8`](fn._mm256_cmpgt_epi8.html).
+        fn cmpgt_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_cmpgt_epi8;
+
+        /// See [`_mm256_cvtepi16_epi32`](fn._mm256_cvtepi16_epi32.html).
+        fn cvtepi16_epi32(a: __m128i) -> __m256i = _mm256_cvtepi16_epi32;
+
+        /// See [`_mm256_cvtepi16_epi64`](fn._mm256_cvtepi16_epi64.html).
+        fn cvtepi16_epi64(a: __m128i) -> __m256i = _mm256_cvtepi16_epi64;
+
+        /// See [`_mm256_cvtepi32_epi64`](fn._mm256_cvtepi32_epi64.html).
+        fn cvtepi32_epi64(a: __m128i) -> __m256i = _mm256_cvtepi32_epi64;
+
+        /// See [`_mm256_cvtepi8_epi16`](fn._mm256_cvtepi8_epi16.html).
+        fn cvtepi8_epi16(a: __m128i) -> __m256i = _mm256_cvtepi8_epi16;
+
+        /// See [`_mm256_cvtepi8_epi32`](fn._mm256_cvtepi8_epi32.html).
+        fn cvtepi8_epi32(a: __m128i) -> __m256i = _mm256_cvtepi8_epi32;
+
+        /// See [`_mm256_cvtepi8_epi64`](fn._mm256_cvtepi8_epi64.html).
+        fn cvtepi8_epi64(a: __m128i) -> __m256i = _mm256_cvtepi8_epi64;
+
+        /// See [`_mm256_cvtepu16_epi32`](fn._mm256_cvtepu16_epi32.html).
+        fn cvtepu16_epi32(a: __m128i) -> __m256i = _mm256_cvtepu16_epi32;
+
+        /// See [`_mm256_cvtepu16_epi64`](fn._mm256_cvtepu16_epi64.html).
+        fn cvtepu16_epi64(a: __m128i) -> __m256i = _mm256_cvtepu16_epi64;
+
+        /// See [`_mm256_cvtepu32_epi64`](fn._mm256_cvtepu32_epi64.html).
+        fn cvtepu32_epi64(a: __m128i) -> __m256i = _mm256_cvtepu32_epi64;
+
+        /// See [`_mm256_cvtepu8_epi16`](fn._mm256_cvtepu8_epi16.html).
+        fn cvtepu8_epi16(a: __m128i) -> __m256i = _mm256_cvtepu8_epi16;
+
+        /// See [`_mm256_cvtepu8_epi32`](fn._mm256_cvtepu8_epi32.html).
+        fn cvtepu8_epi32(a: __m128i) -> __m256i = _mm256_cvtepu8_epi32;
+
+        /// See [`_mm256_cvtepu8_epi64`](fn._mm256_cvtepu8_epi64.html).
+        fn cvtepu8_epi64(a: __m128i) -> __m256i = _mm256_cvtepu8_epi64;
+
+        /// See [`_mm256_cvtsd_f64`](fn._mm256_cvtsd_f64.html).
+        fn cvtsd_f64(a: __m256d) -> f64 = _mm256_cvtsd_f64;
+
+        /// See [`_mm256_cvtsi256_si32`](fn._mm256_cvtsi256_si32.html).
+        fn cvtsi256_si32(a: __m256i) -> i32 = _mm256_cvtsi256_si32;
+
+        /// See [`_mm256_hadd_epi16`](fn._mm256_hadd_epi16.html).
+        fn hadd_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_hadd_epi16;
+
+        /// See [`_mm256_hadd_epi32`](fn._mm256_hadd_epi32.html).
+        fn hadd_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_hadd_epi32;
+
+        /// See [`_mm256_hadds_epi16`](fn._mm256_hadds_epi16.html).
+        fn hadds_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_hadds_epi16;
+
+        /// See [`_mm256_hsub_epi16`](fn._mm256_hsub_epi16.html).
+        fn hsub_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_hsub_epi16;
+
+        /// See [`_mm256_hsub_epi32`](fn._mm256_hsub_epi32.html).
+        fn hsub_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_hsub_epi32;
+
+        /// See [`_mm256_hsubs_epi16`](fn._mm256_hsubs_epi16.html).
+        fn hsubs_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_hsubs_epi16;
+
+        /// See [`_mm256_madd_epi16`](fn._mm256_madd_epi16.html).
+        fn madd_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_madd_epi16;
+
+        /// See [`_mm256_maddubs_epi16`](fn._mm256_maddubs_epi16.html).
+        fn maddubs_epi16(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_maddubs_epi16;
+
+        /// See [`_mm256_max_epi16`](fn._mm256_max_epi16.html).
+        fn max_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_max_epi16;
+
+        /// See [`_mm256_max_epi32`](fn._mm256_max_epi32.html).
+        fn max_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_max_epi32;
+
+        /// See [`_mm256_max_epi8`](fn._mm256_max_epi8.html).
+        fn max_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_max_epi8;
+
+        /// See [`_mm256_max_epu16`](fn._mm256_max_epu16.html).
+        fn max_epu16(a: __m256i, b: __m256i) -> __m256i = _mm256_max_epu16;
+
+        /// See [`_mm256_max_epu32`](fn._mm256_max_epu32.html).
+        fn max_epu32(a: __m256i, b: __m256i) -> __m256i = _mm256_max_epu32;
+
+        /// See [`_mm256_max_epu8`](fn._mm256_max_epu8.html).
+        fn max_epu8(a: __m256i, b: __m256i) -> __m256i = _mm256_max_epu8;
+
+        /// See [`_mm256_min_epi16`](fn._mm256_min_epi16.html).
+        fn min_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_min_epi16;
+
+        /// See [`_mm256_min_epi32`](fn._mm256_min_epi32.html).
+        fn min_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_min_epi32;
+
+        /// See [`_mm256_min_epi8`](fn._mm256_min_epi8.html).
+        fn min_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_min_epi8;
+
+        /// See [`_mm256_min_epu16`](fn._mm256_min_epu16.html).
+        fn min_epu16(a: __m256i, b: __m256i) -> __m256i = _mm256_min_epu16;
+
+        /// See [`_mm256_min_epu32`](fn._mm256_min_epu32.html).
+        fn min_epu32(a: __m256i, b: __m256i) -> __m256i = _mm256_min_epu32;
+
+        /// See [`_mm256_min_epu8`](fn._mm256_min_epu8.html).
+        fn min_epu8(a: __m256i, b: __m256i) -> __m256i = _mm256_min_epu8;
+
+        /// See [`_mm_broadcastb_epi8`](fn._mm_broadcastb_epi8.html).
+        fn mm_broadcastb_epi8(a: __m128i) -> __m128i = _mm_broadcastb_epi8;
+
+        /// See [`_mm_broadcastd_epi32`](fn._mm_broadcastd_epi32.html).
+        fn mm_broadcastd_epi32(a: __m128i) -> __m128i = _mm_broadcastd_epi32;
+
+        /// See [`_mm_broadcastq_epi64`](fn._mm_broadcastq_epi64.html).
+        fn mm_broadcastq_epi64(a: __m128i) -> __m128i = _mm_broadcastq_epi64;
+
+        /// See [`_mm_broadcastsd_pd`](fn._mm_broadcastsd_pd.html).
+        fn mm_broadcastsd_pd(a: __m128d) -> __m128d = _mm_broadcastsd_pd;
+
+        /// See [`_mm_broadcastsi128_si256`](fn._mm_broadcastsi128_si256.html).
+        fn mm_broadcastsi128_si256(a: __m128i)
+            -> __m256i = _mm_broadcastsi128_si256;
+
+        /// See [`_mm_broadcastss_ps`](fn._mm_broadcastss_ps.html).
+        fn mm_broadcastss_ps(a: __m128) -> __m128 = _mm_broadcastss_ps;
+
+        /// See [`_mm_broadcastw_epi16`](fn._mm_broadcastw_epi16.html).
+        fn mm_broadcastw_epi16(a: __m128i) -> __m128i = _mm_broadcastw_epi16;
+
+        /// See [`_mm_sllv_epi32`](fn._mm_sllv_epi32.html).
+        fn mm_sllv_epi32(a: __m128i, count: __m128i)
+            -> __m128i = _mm_sllv_epi32;
+
+        /// See [`_mm_sllv_epi64`](fn._mm_sllv_epi64.html).
+        fn mm_sllv_epi64(a: __m128i, count: __m128i)
+            -> __m128i = _mm_sllv_epi64;
+
+        /// See [`_mm_srav_epi32`](fn._mm_srav_epi32.html).
+        fn mm_srav_epi32(a: __m128i, count: __m128i)
+            -> __m128i = _mm_srav_epi32;
+
+        /// See [`_mm_srlv_epi32`](fn._mm_srlv_epi32.html).
+        fn mm_srlv_epi32(a: __m128i, count: __m128i)
+            -> __m128i = _mm_srlv_epi32;
+
+        /// See [`_mm_srlv_epi64`](fn._mm_srlv_epi64.html).
+        fn mm_srlv_epi64(a: __m128i, count: __m128i)
+            -> __m128i = _mm_srlv_epi64;
+
+        /// See [`_mm256_movemask_epi8`](fn._mm256_movemask_epi8.html).
+        fn movemask_epi8(a: __m256i) -> i32 = _mm256_movemask_epi8;
+
+        /// See [`_mm256_mul_epi32`](fn._mm256_mul_epi32.html).
+        fn mul_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_mul_epi32;
+
+        /// See [`_mm256_mul_epu32`](fn._mm256_mul_epu32.html).
+        fn mul_epu32(a: __m256i, b: __m256i) -> __m256i = _mm256_mul_epu32;
+
+        /// See [`_mm256_mulhi_epi16`](fn._mm256_mulhi_epi16.html).
+        fn mulhi_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_mulhi_epi16;
+
+        /// See [`_mm256_mulhi_epu16`](fn._mm256_mulhi_epu16.html).
+        fn mulhi_epu16(a: __m256i, b: __m256i) -> __m256i = _mm256_mulhi_epu16;
+
+        /// See [`_mm256_mulhrs_epi16`](fn._mm256_mulhrs_epi16.html).
+        fn mulhrs_epi16(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_mulhrs_epi16;
+
+        /// See [`_mm256_mullo_epi16`](fn._mm256_mullo_epi16.html).
+        fn mullo_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_mullo_epi16;
+
+        /// See [`_mm256_mullo_epi32`](fn._mm256_mullo_epi32.html).
+        fn mullo_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_mullo_epi32;
+
+        /// See [`_mm256_or_si256`](fn._mm256_or_si256.html).
+        fn or_si256(a: __m256i, b: __m256i) -> __m256i = _mm256_or_si256;
+
+        /// See [`_mm256_packs_epi16`](fn._mm256_packs_epi16.html).
+        fn packs_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_packs_epi16;
+
+        /// See [`_mm256_packs_epi32`](fn._mm256_packs_epi32.html).
+        fn packs_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_packs_epi32;
+
+        /// See [`_mm256_packus_epi16`](fn._mm256_packus_epi16.html).
+        fn packus_epi16(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_packus_epi16;
+
+        /// See [`_mm256_packus_epi32`](fn._mm256_packus_epi32.html).
+        fn packus_epi32(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_packus_epi32;
+
+        /// See `_mm256_permutevar8x32_epi32`.
+        fn permutevar8x32_epi32(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_permutevar8x32_epi32;
+
+        /// See [`_mm256_permutevar8x32_ps`](fn._mm256_permutevar8x32_ps.html).
+        fn permutevar8x32_ps(a: __m256, idx: __m256i)
+            -> __m256 = _mm256_permutevar8x32_ps;
+
+        /// See [`_mm256_sad_epu8`](fn._mm256_sad_epu8.html).
+        fn sad_epu8(a: __m256i, b: __m256i) -> __m256i = _mm256_sad_epu8;
+
+        /// See [`_mm256_shuffle_epi8`](fn._mm256_shuffle_epi8.html).
+        fn shuffle_epi8(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_shuffle_epi8;
+
+        /// See [`_mm256_sign_epi16`](fn._mm256_sign_epi16.html).
+        fn sign_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_sign_epi16;
+
+        /// See [`_mm256_sign_epi32`](fn._mm256_sign_epi32.html).
+        fn sign_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_sign_epi32;
+
+        /// See [`_mm256_sign_epi8`](fn._mm256_sign_epi8.html).
+        fn sign_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_sign_epi8;
+
+        /// See [`_mm256_sll_epi16`](fn._mm256_sll_epi16.html).
+        fn sll_epi16(a: __m256i, count: __m128i) -> __m256i = _mm256_sll_epi16;
+
+        /// See [`_mm256_sll_epi32`](fn._mm256_sll_epi32.html).
+        fn sll_epi32(a: __m256i, count: __m128i) -> __m256i = _mm256_sll_epi32;
+
+        /// See [`_mm256_sll_epi64`](fn._mm256_sll_epi64.html).
+        fn sll_epi64(a: __m256i, count: __m128i) -> __m256i = _mm256_sll_epi64;
+
+        /// See [`_mm256_slli_epi16`](fn._mm256_slli_epi16.html).
+        fn slli_epi16(a: __m256i, imm8: i32) -> __m256i = _mm256_slli_epi16;
+
+        /// See [`_mm256_slli_epi32`](fn._mm256_slli_epi32.html).
+        fn slli_epi32(a: __m256i, imm8: i32) -> __m256i = _mm256_slli_epi32;
+
+        /// See [`_mm256_slli_epi64`](fn._mm256_slli_epi64.html).
+        fn slli_epi64(a: __m256i, imm8: i32) -> __m256i = _mm256_slli_epi64;
+
+        /// See [`_mm256_sllv_epi32`](fn._mm256_sllv_epi32.html).
+        fn sllv_epi32(a: __m256i, count: __m256i)
+            -> __m256i = _mm256_sllv_epi32;
+
+        /// See [`_mm256_sllv_epi64`](fn._mm256_sllv_epi64.html).
+        fn sllv_epi64(a: __m256i, count: __m256i)
+            -> __m256i = _mm256_sllv_epi64;
+
+        /// See [`_mm256_sra_epi16`](fn._mm256_sra_epi16.html).
+        fn sra_epi16(a: __m256i, count: __m128i) -> __m256i = _mm256_sra_epi16;
+
+        /// See [`_mm256_sra_epi32`](fn._mm256_sra_epi32.html).
+        fn sra_epi32(a: __m256i, count: __m128i) -> __m256i = _mm256_sra_epi32;
+
+        /// See [`_mm256_srai_epi16`](fn._mm256_srai_epi16.html).
+        fn srai_epi16(a: __m256i, imm8: i32) -> __m256i = _mm256_srai_epi16;
+
+        /// See [`_mm256_srai_epi32`](fn._mm256_srai_epi32.html).
+        fn srai_epi32(a: __m256i, imm8: i32) -> __m256i = _mm256_srai_epi32;
+
+        /// See [`_mm256_srav_epi32`](fn._mm256_srav_epi32.html).
+        fn srav_epi32(a: __m256i, count: __m256i)
+            -> __m256i = _mm256_srav_epi32;
+
+        /// See [`_mm256_srl_epi16`](fn._mm256_srl_epi16.html).
+        fn srl_epi16(a: __m256i, count: __m128i) -> __m256i = _mm256_srl_epi16;
+
+        /// See [`_mm256_srl_epi32`](fn._mm256_srl_epi32.html).
+        fn srl_epi32(a: __m256i, count: __m128i) -> __m256i = _mm256_srl_epi32;
+
+        /// See [`_mm256_srl_epi64`](fn._mm256_srl_epi64.html).
+        fn srl_epi64(a: __m256i, count: __m128i) -> __m256i = _mm256_srl_epi64;
+
+        /// See [`_mm256_srli_epi16`](fn._mm256_srli_epi16.html).
+        fn srli_epi16(a: __m256i, imm8: i32) -> __m256i = _mm256_srli_epi16;
+
+        /// See [`_mm256_srli_epi32`](fn._mm256_srli_epi32.html).
+        fn srli_epi32(a: __m256i, imm8: i32) -> __m256i = _mm256_srli_epi32;
+
+        /// See [`_mm256_srli_epi64`](fn._mm256_srli_epi64.html).
+        fn srli_epi64(a: __m256i, imm8: i32) -> __m256i = _mm256_srli_epi64;
+
+        /// See [`_mm256_srlv_epi32`](fn._mm256_srlv_epi32.html).
+        fn srlv_epi32(a: __m256i, count: __m256i)
+            -> __m256i = _mm256_srlv_epi32;
+
+        /// See [`_mm256_srlv_epi64`](fn._mm256_srlv_epi64.html).
+        fn srlv_epi64(a: __m256i, count: __m256i)
+            -> __m256i = _mm256_srlv_epi64;
+
+        /// See [`_mm256_sub_epi16`](fn._mm256_sub_epi16.html).
+        fn sub_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_sub_epi16;
+
+        /// See [`_mm256_sub_epi32`](fn._mm256_sub_epi32.html).
+        fn sub_epi32(a: __m256i, b: __m256i) -> __m256i = _mm256_sub_epi32;
+
+        /// See [`_mm256_sub_epi64`](fn._mm256_sub_epi64.html).
+        fn sub_epi64(a: __m256i, b: __m256i) -> __m256i = _mm256_sub_epi64;
+
+        /// See [`_mm256_sub_epi8`](fn._mm256_sub_epi8.html).
+        fn sub_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_sub_epi8;
+
+        /// See [`_mm256_subs_epi16`](fn._mm256_subs_epi16.html).
+        fn subs_epi16(a: __m256i, b: __m256i) -> __m256i = _mm256_subs_epi16;
+
+        /// See [`_mm256_subs_epi8`](fn._mm256_subs_epi8.html).
+        fn subs_epi8(a: __m256i, b: __m256i) -> __m256i = _mm256_subs_epi8;
+
+        /// See [`_mm256_subs_epu16`](fn._mm256_subs_epu16.html).
+        fn subs_epu16(a: __m256i, b: __m256i) -> __m256i = _mm256_subs_epu16;
+
+        /// See [`_mm256_subs_epu8`](fn._mm256_subs_epu8.html).
+        fn subs_epu8(a: __m256i, b: __m256i) -> __m256i = _mm256_subs_epu8;
+
+        /// See [`_mm256_unpackhi_epi16`](fn._mm256_unpackhi_epi16.html).
+        fn unpackhi_epi16(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_unpackhi_epi16;
+
+        /// See [`_mm256_unpackhi_epi32`](fn._mm256_unpackhi_epi32.html).
+        fn unpackhi_epi32(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_unpackhi_epi32;
+
+        /// See [`_mm256_unpackhi_epi64`](fn._mm256_unpackhi_epi64.html).
+        fn unpackhi_epi64(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_unpackhi_epi64;
+
+        /// See [`_mm256_unpackhi_epi8`](fn._mm256_unpackhi_epi8.html).
+        fn unpackhi_epi8(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_unpackhi_epi8;
+
+        /// See [`_mm256_unpacklo_epi16`](fn._mm256_unpacklo_epi16.html).
+        fn unpacklo_epi16(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_unpacklo_epi16;
+
+        /// See [`_mm256_unpacklo_epi32`](fn._mm256_unpacklo_epi32.html).
+        fn unpacklo_epi32(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_unpacklo_epi32;
+
+        /// See [`_mm256_unpacklo_epi64`](fn._mm256_unpacklo_epi64.html).
+        fn unpacklo_epi64(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_unpacklo_epi64;
+
+        /// See [`_mm256_unpacklo_epi8`](fn._mm256_unpacklo_epi8.html).
+        fn unpacklo_epi8(a: __m256i, b: __m256i)
+            -> __m256i = _mm256_unpacklo_epi8;
+
+        /// See [`_mm256_xor_si256`](fn._mm256_xor_si256.html).
+        fn xor_si256(a: __m256i, b: __m256i) -> __m256i = _mm256_xor_si256;
+    }
+}
+
+token_methods! {
+    Fma = "fma" {
+        /// See [`_mm256_fmadd_pd`](fn._mm256_fmadd_pd.html).
+        fn fmadd_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_fmadd_pd;
+
+        /// See [`_mm256_fmadd_ps`](fn._mm256_fmadd_ps.html).
+        fn fmadd_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_fmadd_ps;
+
+        /// See [`_mm256_fmaddsub_pd`](fn._mm256_fmaddsub_pd.html).
+        fn fmaddsub_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_fmaddsub_pd;
+
+        /// See [`_mm256_fmaddsub_ps`](fn._mm256_fmaddsub_ps.html).
+        fn fmaddsub_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_fmaddsub_ps;
+
+        /// See [`_mm256_fmsub_pd`](fn._mm256_fmsub_pd.html).
+        fn fmsub_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_fmsub_pd;
+
+        /// See [`_mm256_fmsub_ps`](fn._mm256_fmsub_ps.html).
+        fn fmsub_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_fmsub_ps;
+
+        /// See [`_mm256_fmsubadd_pd`](fn._mm256_fmsubadd_pd.html).
+        fn fmsubadd_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_fmsubadd_pd;
+
+        /// See [`_mm256_fmsubadd_ps`](fn._mm256_fmsubadd_ps.html).
+        fn fmsubadd_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_fmsubadd_ps;
+
+        /// See [`_mm256_fnmadd_pd`](fn._mm256_fnmadd_pd.html).
+        fn fnmadd_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_fnmadd_pd;
+
+        /// See [`_mm256_fnmadd_ps`](fn._mm256_fnmadd_ps.html).
+        fn fnmadd_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_fnmadd_ps;
+
+        /// See [`_mm256_fnmsub_pd`](fn._mm256_fnmsub_pd.html).
+        fn fnmsub_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_fnmsub_pd;
+
+        /// See [`_mm256_fnmsub_ps`](fn._mm256_fnmsub_ps.html).
+        fn fnmsub_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_fnmsub_ps;
+
+        /// See [`_mm_fmadd_pd`](fn._mm_fmadd_pd.html).
+        fn mm_fmadd_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_fmadd_pd;
+
+        /// See [`_mm_fmadd_ps`](fn._mm_fmadd_ps.html).
+        fn mm_fmadd_ps(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_fmadd_ps;
+
+        /// See [`_mm_fmadd_sd`](fn._mm_fmadd_sd.html).
+        fn mm_fmadd_sd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_fmadd_sd;
+
+        /// See [`_mm_fmadd_ss`](fn._mm_fmadd_ss.html).
+        fn mm_fmadd_ss(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_fmadd_ss;
+
+        /// See [`_mm_fmaddsub_pd`](fn._mm_fmaddsub_pd.html).
+        fn mm_fmaddsub_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_fmaddsub_pd;
+
+        /// See [`_mm_fmaddsub_ps`](fn._mm_fmaddsub_ps.html).
+        fn mm_fmaddsub_ps(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_fmaddsub_ps;
+
+        /// See [`_mm_fmsub_pd`](fn._mm_fmsub_pd.html).
+        fn mm_fmsub_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_fmsub_pd;
+
+        /// See [`_mm_fmsub_ps`](fn._mm_fmsub_ps.html).
+        fn mm_fmsub_ps(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_fmsub_ps;
+
+        /// See [`_mm_fmsub_sd`](fn._mm_fmsub_sd.html).
+        fn mm_fmsub_sd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_fmsub_sd;
+
+        /// See [`_mm_fmsub_ss`](fn._mm_fmsub_ss.html).
+        fn mm_fmsub_ss(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_fmsub_ss;
+
+        /// See [`_mm_fmsubadd_pd`](fn._mm_fmsubadd_pd.html).
+        fn mm_fmsubadd_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_fmsubadd_pd;
+
+        /// See [`_mm_fmsubadd_ps`](fn._mm_fmsubadd_ps.html).
+        fn mm_fmsubadd_ps(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_fmsubadd_ps;
+
+        /// See [`_mm_fnmadd_pd`](fn._mm_fnmadd_pd.html).
+        fn mm_fnmadd_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_fnmadd_pd;
+
+        /// See [`_mm_fnmadd_ps`](fn._mm_fnmadd_ps.html).
+        fn mm_fnmadd_ps(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_fnmadd_ps;
+
+        /// See [`_mm_fnmadd_sd`](fn._mm_fnmadd_sd.html).
+        fn mm_fnmadd_sd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_fnmadd_sd;
+
+        /// See [`_mm_fnmadd_ss`](fn._mm_fnmadd_ss.html).
+        fn mm_fnmadd_ss(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_fnmadd_ss;
+
+        /// See [`_mm_fnmsub_pd`](fn._mm_fnmsub_pd.html).
+        fn mm_fnmsub_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_fnmsub_pd;
+
+        /// See [`_mm_fnmsub_ps`](fn._mm_fnmsub_ps.html).
+        fn mm_fnmsub_ps(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_fnmsub_ps;
+
+        /// See [`_mm_fnmsub_sd`](fn._mm_fnmsub_sd.html).
+        fn mm_fnmsub_sd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_fnmsub_sd;
+
+        /// See [`_mm_fnmsub_ss`](fn._mm_fnmsub_ss.html).
+        fn mm_fnmsub_ss(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_fnmsub_ss;
+    }
+}
+
+#[cfg(not(feature = "intel_sde"))]
+token_methods! {
+    Fma4 = "fma4" {
+        /// See [`_mm256_macc_pd`](fn._mm256_macc_pd.html).
+        fn macc_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_macc_pd;
+
+        /// See [`_mm256_macc_ps`](fn._mm256_macc_ps.html).
+        fn macc_ps(a: __m256, b: __m256, c: __m256) -> __m256 = _mm256_macc_ps;
+
+        /// See [`_mm256_maddsub_pd`](fn._mm256_maddsub_pd.html).
+        fn maddsub_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_maddsub_pd;
+
+        /// See [`_mm256_maddsub_ps`](fn._mm256_maddsub_ps.html).
+        fn maddsub_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_maddsub_ps;
+
+        /// See [`_mm_macc_pd`](fn._mm_macc_pd.html).
+        fn mm_macc_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_macc_pd;
+
+        /// See [`_mm_macc_ps`](fn._mm_macc_ps.html).
+        fn mm_macc_ps(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_macc_ps;
+
+        /// See [`_mm_macc_sd`](fn._mm_macc_sd.html).
+        fn mm_macc_sd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_macc_sd;
+
+        /// See [`_mm_macc_ss`](fn._mm_macc_ss.html).
+        fn mm_macc_ss(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_macc_ss;
+
+        /// See [`_mm_maddsub_pd`](fn._mm_maddsub_pd.html).
+        fn mm_maddsub_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_maddsub_pd;
+
+        /// See [`_mm_maddsub_ps`](fn._mm_maddsub_ps.html).
+        fn mm_maddsub_ps(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_maddsub_ps;
+
+        /// See [`_mm_msub_pd`](fn._mm_msub_pd.html).
+        fn mm_msub_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_msub_pd;
+
+        /// See [`_mm_msub_ps`](fn._mm_msub_ps.html).
+        fn mm_msub_ps(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_msub_ps;
+
+        /// See [`_mm_msub_sd`](fn._mm_msub_sd.html).
+        fn mm_msub_sd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_msub_sd;
+
+        /// See [`_mm_msub_ss`](fn._mm_msub_ss.html).
+        fn mm_msub_ss(a: __m128, b: __m128, c: __m128) -> __m128 = _mm_msub_ss;
+
+        /// See [`_mm_msubadd_pd`](fn._mm_msubadd_pd.html).
+        fn mm_msubadd_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_msubadd_pd;
+
+        /// See [`_mm_msubadd_ps`](fn._mm_msubadd_ps.html).
+        fn mm_msubadd_ps(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_msubadd_ps;
+
+        /// See [`_mm_nmacc_pd`](fn._mm_nmacc_pd.html).
+        fn mm_nmacc_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_nmacc_pd;
+
+        /// See [`_mm_nmacc_ps`](fn._mm_nmacc_ps.html).
+        fn mm_nmacc_ps(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_nmacc_ps;
+
+        /// See [`_mm_nmacc_sd`](fn._mm_nmacc_sd.html).
+        fn mm_nmacc_sd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_nmacc_sd;
+
+        /// See [`_mm_nmacc_ss`](fn._mm_nmacc_ss.html).
+        fn mm_nmacc_ss(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_nmacc_ss;
+
+        /// See [`_mm_nmsub_pd`](fn._mm_nmsub_pd.html).
+        fn mm_nmsub_pd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_nmsub_pd;
+
+        /// See [`_mm_nmsub_ps`](fn._mm_nmsub_ps.html).
+        fn mm_nmsub_ps(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_nmsub_ps;
+
+        /// See [`_mm_nmsub_sd`](fn._mm_nmsub_sd.html).
+        fn mm_nmsub_sd(a: __m128d, b: __m128d, c: __m128d)
+            -> __m128d = _mm_nmsub_sd;
+
+        /// See [`_mm_nmsub_ss`](fn._mm_nmsub_ss.html).
+        fn mm_nmsub_ss(a: __m128, b: __m128, c: __m128)
+            -> __m128 = _mm_nmsub_ss;
+
+        /// See [`_mm256_msub_pd`](fn._mm256_msub_pd.html).
+        fn msub_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_msub_pd;
+
+        /// See [`_mm256_msub_ps`](fn._mm256_msub_ps.html).
+        fn msub_ps(a: __m256, b: __m256, c: __m256) -> __m256 = _mm256_msub_ps;
+
+        /// See [`_mm256_msubadd_pd`](fn._mm256_msubadd_pd.html).
+        fn msubadd_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_msubadd_pd;
+
+        /// See [`_mm256_msubadd_ps`](fn._mm256_msubadd_ps.html).
+        fn msubadd_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_msubadd_ps;
+
+        /// See [`_mm256_nmacc_pd`](fn._mm256_nmacc_pd.html).
+        fn nmacc_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_nmacc_pd;
+
+        /// See [`_mm256_nmacc_ps`](fn._mm256_nmacc_ps.html).
+        fn nmacc_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_nmacc_ps;
+
+        /// See [`_mm256_nmsub_pd`](fn._mm256_nmsub_pd.html).
+        fn nmsub_pd(a: __m256d, b: __m256d, c: __m256d)
+            -> __m256d = _mm256_nmsub_pd;
+
+        /// See [`_mm256_nmsub_ps`](fn._mm256_nmsub_ps.html).
+        fn nmsub_ps(a: __m256, b: __m256, c: __m256)
+            -> __m256 = _mm256_nmsub_ps;
+    }
+}
+
+#[cfg(not(feature = "intel_sde"))]
+token_methods! {
+    Xop = "xop" {
+        /// See [`_mm256_cmov_si256`](fn._mm256_cmov_si256.html).
+        fn cmov_si256(a: __m256i, b: __m256i, c: __m256i)
+            -> __m256i = _mm256_cmov_si256;
+
+        /// See [`_mm256_frcz_pd`](fn._mm256_frcz_pd.html).
+        fn frcz_pd(a: __m256d) -> __m256d = _mm256_frcz_pd;
+
+        /// See [`_mm256_frcz_ps`](fn._mm256_frcz_ps.html).
+        fn frcz_ps(a: __m256) -> __m256 = _mm256_frcz_ps;
+
+        /// See [`_mm_cmov_si128`](fn._mm_cmov_si128.html).
+        fn mm_cmov_si128(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_cmov_si128;
+
+        /// See [`_mm_comeq_epi16`](fn._mm_comeq_epi16.html).
+        fn mm_comeq_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_comeq_epi16;
+
+        /// See [`_mm_comeq_epi32`](fn._mm_comeq_epi32.html).
+        fn mm_comeq_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_comeq_epi32;
+
+        /// See [`_mm_comeq_epi64`](fn._mm_comeq_epi64.html).
+        fn mm_comeq_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_comeq_epi64;
+
+        /// See [`_mm_comeq_epi8`](fn._mm_comeq_epi8.html).
+        fn mm_comeq_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_comeq_epi8;
+
+        /// See [`_mm_comeq_epu16`](fn._mm_comeq_epu16.html).
+        fn mm_comeq_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_comeq_epu16;
+
+        /// See [`_mm_comeq_epu32`](fn._mm_comeq_epu32.html).
+        fn mm_comeq_epu32(a: __m128i, b: __m128i) -> __m128i = _mm_comeq_epu32;
+
+        /// See [`_mm_comeq_epu64`](fn._mm_comeq_epu64.html).
+        fn mm_comeq_epu64(a: __m128i, b: __m128i) -> __m128i = _mm_comeq_epu64;
+
+        /// See [`_mm_comeq_epu8`](fn._mm_comeq_epu8.html).
+        fn mm_comeq_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_comeq_epu8;
+
+        /// See [`_mm_comfalse_epi16`](fn._mm_comfalse_epi16.html).
+        fn mm_comfalse_epi16(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comfalse_epi16;
+
+        /// See [`_mm_comfalse_epi32`](fn._mm_comfalse_epi32.html).
+        fn mm_comfalse_epi32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comfalse_epi32;
+
+        /// See [`_mm_comfalse_epi64`](fn._mm_comfalse_epi64.html).
+        fn mm_comfalse_epi64(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comfalse_epi64;
+
+        /// See [`_mm_comfalse_epi8`](fn._mm_comfalse_epi8.html).
+        fn mm_comfalse_epi8(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comfalse_epi8;
+
+        /// See [`_mm_comfalse_epu16`](fn._mm_comfalse_epu16.html).
+        fn mm_comfalse_epu16(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comfalse_epu16;
+
+        /// See [`_mm_comfalse_epu32`](fn._mm_comfalse_epu32.html).
+        fn mm_comfalse_epu32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comfalse_epu32;
+
+        /// See [`_mm_comfalse_epu64`](fn._mm_comfalse_epu64.html).
+        fn mm_comfalse_epu64(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comfalse_epu64;
+
+        /// See [`_mm_comfalse_epu8`](fn._mm_comfalse_epu8.html).
+        fn mm_comfalse_epu8(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comfalse_epu8;
+
+        /// See [`_mm_comge_epi16`](fn._mm_comge_epi16.html).
+        fn mm_comge_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_comge_epi16;
+
+        /// See [`_mm_comge_epi32`](fn._mm_comge_epi32.html).
+        fn mm_comge_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_comge_epi32;
+
+        /// See [`_mm_comge_epi64`](fn._mm_comge_epi64.html).
+        fn mm_comge_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_comge_epi64;
+
+        /// See [`_mm_comge_epi8`](fn._mm_comge_epi8.html).
+        fn mm_comge_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_comge_epi8;
+
+        /// See [`_mm_comge_epu16`](fn._mm_comge_epu16.html).
+        fn mm_comge_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_comge_epu16;
+
+        /// See [`_mm_comge_epu32`](fn._mm_comge_epu32.html).
+        fn mm_comge_epu32(a: __m128i, b: __m128i) -> __m128i = _mm_comge_epu32;
+
+        /// See [`_mm_comge_epu64`](fn._mm_comge_epu64.html).
+        fn mm_comge_epu64(a: __m128i, b: __m128i) -> __m128i = _mm_comge_epu64;
+
+        /// See [`_mm_comge_epu8`](fn._mm_comge_epu8.html).
+        fn mm_comge_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_comge_epu8;
+
+        /// See [`_mm_comgt_epi16`](fn._mm_comgt_epi16.html).
+        fn mm_comgt_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_comgt_epi16;
+
+        /// See [`_mm_comgt_epi32`](fn._mm_comgt_epi32.html).
+        fn mm_comgt_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_comgt_epi32;
+
+        /// See [`_mm_comgt_epi64`](fn._mm_comgt_epi64.html).
+        fn mm_comgt_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_comgt_epi64;
+
+        /// See [`_mm_comgt_epi8`](fn._mm_comgt_epi8.html).
+        fn mm_comgt_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_comgt_epi8;
+
+        /// See [`_mm_comgt_epu16`](fn._mm_comgt_epu16.html).
+        fn mm_comgt_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_comgt_epu16;
+
+        /// See [`_mm_comgt_epu32`](fn._mm_comgt_epu32.html).
+        fn mm_comgt_epu32(a: __m128i, b: __m128i) -> __m128i = _mm_comgt_epu32;
+
+        /// See [`_mm_comgt_epu64`](fn._mm_comgt_epu64.html).
+        fn mm_comgt_epu64(a: __m128i, b: __m128i) -> __m128i = _mm_comgt_epu64;
+
+        /// See [`_mm_comgt_epu8`](fn._mm_comgt_epu8.html).
+        fn mm_comgt_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_comgt_epu8;
+
+        /// See [`_mm_comle_epi16`](fn._mm_comle_epi16.html).
+        fn mm_comle_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_comle_epi16;
+
+        /// See [`_mm_comle_epi32`](fn._mm_comle_epi32.html).
+        fn mm_comle_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_comle_epi32;
+
+        /// See [`_mm_comle_epi64`](fn._mm_comle_epi64.html).
+        fn mm_comle_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_comle_epi64;
+
+        /// See [`_mm_comle_epi8`](fn._mm_comle_epi8.html).
+        fn mm_comle_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_comle_epi8;
+
+        /// See [`_mm_comle_epu16`](fn._mm_comle_epu16.html).
+        fn mm_comle_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_comle_epu16;
+
+        /// See [`_mm_comle_epu32`](fn._mm_comle_epu32.html).
+        fn mm_comle_epu32(a: __m128i, b: __m128i) -> __m128i = _mm_comle_epu32;
+
+        /// See [`_mm_comle_epu64`](fn._mm_comle_epu64.html).
+        fn mm_comle_epu64(a: __m128i, b: __m128i) -> __m128i = _mm_comle_epu64;
+
+        /// See [`_mm_comle_epu8`](fn._mm_comle_epu8.html).
+        fn mm_comle_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_comle_epu8;
+
+        /// See [`_mm_comlt_epi16`](fn._mm_comlt_epi16.html).
+        fn mm_comlt_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_comlt_epi16;
+
+        /// See [`_mm_comlt_epi32`](fn._mm_comlt_epi32.html).
+        fn mm_comlt_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_comlt_epi32;
+
+        /// See [`_mm_comlt_epi64`](fn._mm_comlt_epi64.html).
+        fn mm_comlt_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_comlt_epi64;
+
+        /// See [`_mm_comlt_epi8`](fn._mm_comlt_epi8.html).
+        fn mm_comlt_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_comlt_epi8;
+
+        /// See [`_mm_comlt_epu16`](fn._mm_comlt_epu16.html).
+        fn mm_comlt_epu16(a: __m128i, b: __m128i) -> __m128i = _mm_comlt_epu16;
+
+        /// See [`_mm_comlt_epu32`](fn._mm_comlt_epu32.html).
+        fn mm_comlt_epu32(a: __m128i, b: __m128i) -> __m128i = _mm_comlt_epu32;
+
+        /// See [`_mm_comlt_epu64`](fn._mm_comlt_epu64.html).
+        fn mm_comlt_epu64(a: __m128i, b: __m128i) -> __m128i = _mm_comlt_epu64;
+
+        /// See [`_mm_comlt_epu8`](fn._mm_comlt_epu8.html).
+        fn mm_comlt_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_comlt_epu8;
+
+        /// See [`_mm_comneq_epi16`](fn._mm_comneq_epi16.html).
+        fn mm_comneq_epi16(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comneq_epi16;
+
+        /// See [`_mm_comneq_epi32`](fn._mm_comneq_epi32.html).
+        fn mm_comneq_epi32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comneq_epi32;
+
+        /// See [`_mm_comneq_epi64`](fn._mm_comneq_epi64.html).
+        fn mm_comneq_epi64(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comneq_epi64;
+
+        /// See [`_mm_comneq_epi8`](fn._mm_comneq_epi8.html).
+        fn mm_comneq_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_comneq_epi8;
+
+        /// See [`_mm_comneq_epu16`](fn._mm_comneq_epu16.html).
+        fn mm_comneq_epu16(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comneq_epu16;
+
+        /// See [`_mm_comneq_epu32`](fn._mm_comneq_epu32.html).
+        fn mm_comneq_epu32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comneq_epu32;
+
+        /// See [`_mm_comneq_epu64`](fn._mm_comneq_epu64.html).
+        fn mm_comneq_epu64(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comneq_epu64;
+
+        /// See [`_mm_comneq_epu8`](fn._mm_comneq_epu8.html).
+        fn mm_comneq_epu8(a: __m128i, b: __m128i) -> __m128i = _mm_comneq_epu8;
+
+        /// See [`_mm_comtrue_epi16`](fn._mm_comtrue_epi16.html).
+        fn mm_comtrue_epi16(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comtrue_epi16;
+
+        /// See [`_mm_comtrue_epi32`](fn._mm_comtrue_epi32.html).
+        fn mm_comtrue_epi32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comtrue_epi32;
+
+        /// See [`_mm_comtrue_epi64`](fn._mm_comtrue_epi64.html).
+        fn mm_comtrue_epi64(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comtrue_epi64;
+
+        /// See [`_mm_comtrue_epi8`](fn._mm_comtrue_epi8.html).
+        fn mm_comtrue_epi8(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comtrue_epi8;
+
+        /// See [`_mm_comtrue_epu16`](fn._mm_comtrue_epu16.html).
+        fn mm_comtrue_epu16(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comtrue_epu16;
+
+        /// See [`_mm_comtrue_epu32`](fn._mm_comtrue_epu32.html).
+        fn mm_comtrue_epu32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comtrue_epu32;
+
+        /// See [`_mm_comtrue_epu64`](fn._mm_comtrue_epu64.html).
+        fn mm_comtrue_epu64(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comtrue_epu64;
+
+        /// See [`_mm_comtrue_epu8`](fn._mm_comtrue_epu8.html).
+        fn mm_comtrue_epu8(a: __m128i, b: __m128i)
+            -> __m128i = _mm_comtrue_epu8;
+
+        /// See [`_mm_frcz_pd`](fn._mm_frcz_pd.html).
+        fn mm_frcz_pd(a: __m128d) -> __m128d = _mm_frcz_pd;
+
+        /// See [`_mm_frcz_ps`](fn._mm_frcz_ps.html).
+        fn mm_frcz_ps(a: __m128) -> __m128 = _mm_frcz_ps;
+
+        /// See [`_mm_frcz_sd`](fn._mm_frcz_sd.html).
+        fn mm_frcz_sd(a: __m128d) -> __m128d = _mm_frcz_sd;
+
+        /// See [`_mm_frcz_ss`](fn._mm_frcz_ss.html).
+        fn mm_frcz_ss(a: __m128) -> __m128 = _mm_frcz_ss;
+
+        /// See [`_mm_haddd_epi16`](fn._mm_haddd_epi16.html).
+        fn mm_haddd_epi16(a: __m128i) -> __m128i = _mm_haddd_epi16;
+
+        /// See [`_mm_haddd_epi8`](fn._mm_haddd_epi8.html).
+        fn mm_haddd_epi8(a: __m128i) -> __m128i = _mm_haddd_epi8;
+
+        /// See [`_mm_haddd_epu16`](fn._mm_haddd_epu16.html).
+        fn mm_haddd_epu16(a: __m128i) -> __m128i = _mm_haddd_epu16;
+
+        /// See [`_mm_haddd_epu8`](fn._mm_haddd_epu8.html).
+        fn mm_haddd_epu8(a: __m128i) -> __m128i = _mm_haddd_epu8;
+
+        /// See [`_mm_haddq_epi16`](fn._mm_haddq_epi16.html).
+        fn mm_haddq_epi16(a: __m128i) -> __m128i = _mm_haddq_epi16;
+
+        /// See [`_mm_haddq_epi32`](fn._mm_haddq_epi32.html).
+        fn mm_haddq_epi32(a: __m128i) -> __m128i = _mm_haddq_epi32;
+
+        /// See [`_mm_haddq_epi8`](fn._mm_haddq_epi8.html).
+        fn mm_haddq_epi8(a: __m128i) -> __m128i = _mm_haddq_epi8;
+
+        /// See [`_mm_haddq_epu16`](fn._mm_haddq_epu16.html).
+        fn mm_haddq_epu16(a: __m128i) -> __m128i = _mm_haddq_epu16;
+
+        /// See [`_mm_haddq_epu32`](fn._mm_haddq_epu32.html).
+        fn mm_haddq_epu32(a: __m128i) -> __m128i = _mm_haddq_epu32;
+
+        /// See [`_mm_haddq_epu8`](fn._mm_haddq_epu8.html).
+        fn mm_haddq_epu8(a: __m128i) -> __m128i = _mm_haddq_epu8;
+
+        /// See [`_mm_haddw_epi8`](fn._mm_haddw_epi8.html).
+        fn mm_haddw_epi8(a: __m128i) -> __m128i = _mm_haddw_epi8;
+
+        /// See [`_mm_haddw_epu8`](fn._mm_haddw_epu8.html).
+        fn mm_haddw_epu8(a: __m128i) -> __m128i = _mm_haddw_epu8;
+
+        /// See [`_mm_hsubd_epi16`](fn._mm_hsubd_epi16.html).
+        fn mm_hsubd_epi16(a: __m128i) -> __m128i = _mm_hsubd_epi16;
+
+        /// See [`_mm_hsubq_epi32`](fn._mm_hsubq_epi32.html).
+        fn mm_hsubq_epi32(a: __m128i) -> __m128i = _mm_hsubq_epi32;
+
+        /// See [`_mm_hsubw_epi8`](fn._mm_hsubw_epi8.html).
+        fn mm_hsubw_epi8(a: __m128i) -> __m128i = _mm_hsubw_epi8;
+
+        /// See [`_mm_macc_epi16`](fn._mm_macc_epi16.html).
+        fn mm_macc_epi16(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_macc_epi16;
+
+        /// See [`_mm_macc_epi32`](fn._mm_macc_epi32.html).
+        fn mm_macc_epi32(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_macc_epi32;
+
+        /// See [`_mm_maccd_epi16`](fn._mm_maccd_epi16.html).
+        fn mm_maccd_epi16(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_maccd_epi16;
+
+        /// See [`_mm_macchi_epi32`](fn._mm_macchi_epi32.html).
+        fn mm_macchi_epi32(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_macchi_epi32;
+
+        /// See [`_mm_macclo_epi32`](fn._mm_macclo_epi32.html).
+        fn mm_macclo_epi32(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_macclo_epi32;
+
+        /// See [`_mm_maccs_epi16`](fn._mm_maccs_epi16.html).
+        fn mm_maccs_epi16(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_maccs_epi16;
+
+        /// See [`_mm_maccs_epi32`](fn._mm_maccs_epi32.html).
+        fn mm_maccs_epi32(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_maccs_epi32;
+
+        /// See [`_mm_maccsd_epi16`](fn._mm_maccsd_epi16.html).
+        fn mm_maccsd_epi16(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_maccsd_epi16;
+
+        /// See [`_mm_maccshi_epi32`](fn._mm_maccshi_epi32.html).
+        fn mm_maccshi_epi32(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_maccshi_epi32;
+
+        /// See [`_mm_maccslo_epi32`](fn._mm_maccslo_epi32.html).
+        fn mm_maccslo_epi32(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_maccslo_epi32;
+
+        /// See [`_mm_maddd_epi16`](fn._mm_maddd_epi16.html).
+        fn mm_maddd_epi16(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_maddd_epi16;
+
+        /// See [`_mm_maddsd_epi16`](fn._mm_maddsd_epi16.html).
+        fn mm_maddsd_epi16(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_maddsd_epi16;
+
+        /// See [`_mm_perm_epi8`](fn._mm_perm_epi8.html).
+        fn mm_perm_epi8(a: __m128i, b: __m128i, c: __m128i)
+            -> __m128i = _mm_perm_epi8;
+
+        /// See [`_mm_rot_epi16`](fn._mm_rot_epi16.html).
+        fn mm_rot_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_rot_epi16;
+
+        /// See [`_mm_rot_epi32`](fn._mm_rot_epi32.html).
+        fn mm_rot_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_rot_epi32;
+
+        /// See [`_mm_rot_epi64`](fn._mm_rot_epi64.html).
+        fn mm_rot_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_rot_epi64;
+
+        /// See [`_mm_rot_epi8`](fn._mm_rot_epi8.html).
+        fn mm_rot_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_rot_epi8;
+
+        /// See [`_mm_sha_epi16`](fn._mm_sha_epi16.html).
+        fn mm_sha_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_sha_epi16;
+
+        /// See [`_mm_sha_epi32`](fn._mm_sha_epi32.html).
+        fn mm_sha_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_sha_epi32;
+
+        /// See [`_mm_sha_epi64`](fn._mm_sha_epi64.html).
+        fn mm_sha_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_sha_epi64;
+
+        /// See [`_mm_sha_epi8`](fn._mm_sha_epi8.html).
+        fn mm_sha_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_sha_epi8;
+
+        /// See [`_mm_shl_epi16`](fn._mm_shl_epi16.html).
+        fn mm_shl_epi16(a: __m128i, b: __m128i) -> __m128i = _mm_shl_epi16;
+
+        /// See [`_mm_shl_epi32`](fn._mm_shl_epi32.html).
+        fn mm_shl_epi32(a: __m128i, b: __m128i) -> __m128i = _mm_shl_epi32;
+
+        /// See [`_mm_shl_epi64`](fn._mm_shl_epi64.html).
+        fn mm_shl_epi64(a: __m128i, b: __m128i) -> __m128i = _mm_shl_epi64;
+
+        /// See [`_mm_shl_epi8`](fn._mm_shl_epi8.html).
+        fn mm_shl_epi8(a: __m128i, b: __m128i) -> __m128i = _mm_shl_epi8;
+    }
+}
+
+token_methods! {
+    F16c = "f16c" {
+        /// See [`_mm256_cvtph_ps`](fn._mm256_cvtph_ps.html).
+        fn cvtph_ps(a: __m128i) -> __m256 = _mm256_cvtph_ps;
+
+        /// See [`_mm_cvtph_ps`](fn._mm_cvtph_ps.html).
+        fn mm_cvtph_ps(a: __m128i) -> __m128 = _mm_cvtph_ps;
+    }
+}
+
+token_methods! {
+    Avx512f = "avx512f" {
+        /// See [`_mm512_add_epi32`](fn._mm512_add_epi32.html).
+        fn add_epi32(a: __m512i, b: __m512i) -> __m512i = _mm512_add_epi32;
+
+        /// See [`_mm512_add_epi64`](fn._mm512_add_epi64.html).
+        fn add_epi64(a: __m512i, b: __m512i) -> __m512i = _mm512_add_epi64;
+
+        /// See [`_mm512_add_pd`](fn._mm512_add_pd.html).
+        fn add_pd(a: __m512d, b: __m512d) -> __m512d = _mm512_add_pd;
+
+        /// See [`_mm512_add_ps`](fn._mm512_add_ps.html).
+        fn add_ps(a: __m512, b: __m512) -> __m512 = _mm512_add_ps;
+
+        /// See [`_mm512_and_epi32`](fn._mm512_and_epi32.html).
+        fn and_epi32(a: __m512i, b: __m512i) -> __m512i = _mm512_and_epi32;
+
+        /// See [`_mm512_and_epi64`](fn._mm512_and_epi64.html).
+        fn and_epi64(a: __m512i, b: __m512i) -> __m512i = _mm512_and_epi64;
+
+        /// See [`_mm512_and_si512`](fn._mm512_and_si512.html).
+        fn and_si512(a: __m512i, b: __m512i) -> __m512i = _mm512_and_si512;
+
+        /// See [`_mm512_andnot_epi32`](fn._mm512_andnot_epi32.html).
+        fn andnot_epi32(a: __m512i, b: __m512i)
+            -> __m512i = _mm512_andnot_epi32;
+
+        /// See [`_mm512_andnot_epi64`](fn._mm512_andnot_epi64.html).
+        fn andnot_epi64(a: __m512i, b: __m512i)
+            -> __m512i = _mm512_andnot_epi64;
+
+        /// See [`_mm512_andnot_si512`](fn._mm512_andnot_si512.html).
+        fn andnot_si512(a: __m512i, b: __m512i)
+            -> __m512i = _mm512_andnot_si512;
+
+        /// See [`_mm512_castpd256_pd512`](fn._mm512_castpd256_pd512.html).
+        fn castpd256_pd512(a: __m256d) -> __m512d = _mm512_castpd256_pd512;
+
+        /// See [`_mm512_castpd512_pd128`](fn._mm512_castpd512_pd128.html).
+        fn castpd512_pd128(a: __m512d) -> __m128d = _mm512_castpd512_pd128;
+
+        /// See [`_mm512_castpd512_pd256`](fn._mm512_castpd512_pd256.html).
+        fn castpd512_pd256(a: __m512d) -> __m256d = _mm512_castpd512_pd256;
+
+        /// See [`_mm512_castpd_ps`](fn._mm512_castpd_ps.html).
+        fn castpd_ps(a: __m512d) -> __m512 = _mm512_castpd_ps;
+
+        /// See [`_mm512_castpd_si512`](fn._mm512_castpd_si512.html).
+        fn castpd_si512(a: __m512d) -> __m512i = _mm512_castpd_si512;
+
+        /// See [`_mm512_castps256_ps512`](fn._mm512_castps256_ps512.html).
+        fn castps256_ps512(a: __m256) -> __m512 = _mm512_castps256_ps512;
+
+        /// See [`_mm512_castps512_ps128`](fn._mm512_castps512_ps128.html).
+        fn castps512_ps128(a: __m512) -> __m128 = _mm512_castps512_ps128;
+
+        /// See [`_mm512_castps512_ps256`](fn._mm512_castps512_ps256.html).
+        fn castps512_ps256(a: __m512) -> __m256 = _mm512_castps512_ps256;
+
+        /// See [`_mm512_castps_pd`](fn._mm512_castps_pd.html).
+        fn castps_pd(a: __m512) -> __m512d = _mm512_castps_pd;
+
+        /// See [`_mm512_castps_si512`](fn._mm512_castps_si512.html).
+        fn castps_si512(a: __m512) -> __m512i = _mm512_castps_si512;
+
+        /// See [`_mm512_castsi256_si512`](fn._mm512_castsi256_si512.html).
+        fn castsi256_si512(a: __m256i) -> __m512i = _mm512_castsi256_si512;
+
+        /// See [`_mm512_castsi512_pd`](fn._mm512_castsi512_pd.html).
+        fn castsi512_pd(a: __m512i) -> __m512d = _mm512_castsi512_pd;
+
+        /// See [`_mm512_castsi512_ps`](fn._mm512_castsi512_ps.html).
+        fn castsi512_ps(a: __m512i) -> __m512 = _mm512_castsi512_ps;
+
+        /// See [`_mm512_castsi512_si128`](fn._mm512_castsi512_si128.html).
+        fn castsi512_si128(a: __m512i) -> __m128i = _mm512_castsi512_si128;
+
+        /// See [`_mm512_castsi512_si256`](fn._mm512_castsi512_si256.html).
+        fn castsi512_si256(a: __m512i) -> __m256i = _mm512_castsi512_si256;
+
+        /// See [`_mm512_cmpeq_epi32_mask`](fn._mm512_cmpeq_epi32_mask.html).
+        fn cmpeq_epi32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmpeq_epi32_mask;
+
+        /// See [`_mm512_cmpeq_epi64_mask`](fn._mm512_cmpeq_epi64_mask.html).
+        fn cmpeq_epi64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmpeq_epi64_mask;
+
+        /// See [`_mm512_cmpeq_epu32_mask`](fn._mm512_cmpeq_epu32_mask.html).
+        fn cmpeq_epu32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmpeq_epu32_mask;
+
+        /// See [`_mm512_cmpeq_epu64_mask`](fn._mm512_cmpeq_epu64_mask.html).
+        fn cmpeq_epu64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmpeq_epu64_mask;
+
+        /// See [`_mm512_cmpeq_pd_mask`](fn._mm512_cmpeq_pd_mask.html).
+        fn cmpeq_pd_mask(a: __m512d, b: __m512d)
+            -> __mmask8 = _mm512_cmpeq_pd_mask;
+
+        /// See [`_mm512_cmpeq_ps_mask`](fn._mm512_cmpeq_ps_mask.html).
+        fn cmpeq_ps_mask(a: __m512, b: __m512)
+            -> __mmask16 = _mm512_cmpeq_ps_mask;
+
+        /// See [`_mm512_cmpge_epi32_mask`](fn._mm512_cmpge_epi32_mask.html).
+        fn cmpge_epi32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmpge_epi32_mask;
+
+        /// See [`_mm512_cmpge_epi64_mask`](fn._mm512_cmpge_epi64_mask.html).
+        fn cmpge_epi64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmpge_epi64_mask;
+
+        /// See [`_mm512_cmpge_epu32_mask`](fn._mm512_cmpge_epu32_mask.html).
+        fn cmpge_epu32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmpge_epu32_mask;
+
+        /// See [`_mm512_cmpge_epu64_mask`](fn._mm512_cmpge_epu64_mask.html).
+        fn cmpge_epu64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmpge_epu64_mask;
+
+        /// See [`_mm512_cmpgt_epi32_mask`](fn._mm512_cmpgt_epi32_mask.html).
+        fn cmpgt_epi32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmpgt_epi32_mask;
+
+        /// See [`_mm512_cmpgt_epi64_mask`](fn._mm512_cmpgt_epi64_mask.html).
+        fn cmpgt_epi64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmpgt_epi64_mask;
+
+        /// See [`_mm512_cmpgt_epu32_mask`](fn._mm512_cmpgt_epu32_mask.html).
+        fn cmpgt_epu32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmpgt_epu32_mask;
+
+        /// See [`_mm512_cmpgt_epu64_mask`](fn._mm512_cmpgt_epu64_mask.html).
+        fn cmpgt_epu64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmpgt_epu64_mask;
+
+        /// See [`_mm512_cmple_epi32_mask`](fn._mm512_cmple_epi32_mask.html).
+        fn cmple_epi32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmple_epi32_mask;
+
+        /// See [`_mm512_cmple_epi64_mask`](fn._mm512_cmple_epi64_mask.html).
+        fn cmple_epi64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmple_epi64_mask;
+
+        /// See [`_mm512_cmple_epu32_mask`](fn._mm512_cmple_epu32_mask.html).
+        fn cmple_epu32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmple_epu32_mask;
+
+        /// See [`_mm512_cmple_epu64_mask`](fn._mm512_cmple_epu64_mask.html).
+        fn cmple_epu64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmple_epu64_mask;
+
+        /// See [`_mm512_cmple_pd_mask`](fn._mm512_cmple_pd_mask.html).
+        fn cmple_pd_mask(a: __m512d, b: __m512d)
+            -> __mmask8 = _mm512_cmple_pd_mask;
+
+        /// See [`_mm512_cmple_ps_mask`](fn._mm512_cmple_ps_mask.html).
+        fn cmple_ps_mask(a: __m512, b: __m512)
+            -> __mmask16 = _mm512_cmple_ps_mask;
+
+        /// See [`_mm512_cmplt_epi32_mask`](fn._mm512_cmplt_epi32_mask.html).
+        fn cmplt_epi32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmplt_epi32_mask;
+
+        /// See [`_mm512_cmplt_epi64_mask`](fn._mm512_cmplt_epi64_mask.html).
+        fn cmplt_epi64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmplt_epi64_mask;
+
+        /// See [`_mm512_cmplt_epu32_mask`](fn._mm512_cmplt_epu32_mask.html).
+        fn cmplt_epu32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmplt_epu32_mask;
+
+        /// See [`_mm512_cmplt_epu64_mask`](fn._mm512_cmplt_epu64_mask.html).
+        fn cmplt_epu64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmplt_epu64_mask;
+
+        /// See [`_mm512_cmplt_pd_mask`](fn._mm512_cmplt_pd_mask.html).
+        fn cmplt_pd_mask(a: __m512d, b: __m512d)
+            -> __mmask8 = _mm512_cmplt_pd_mask;
+
+        /// See [`_mm512_cmplt_ps_mask`](fn._mm512_cmplt_ps_mask.html).
+        fn cmplt_ps_mask(a: __m512, b: __m512)
+            -> __mmask16 = _mm512_cmplt_ps_mask;
+
+        /// See [`_mm512_cmpneq_epi32_mask`](fn._mm512_cmpneq_epi32_mask.html).
+        fn cmpneq_epi32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmpneq_epi32_mask;
+
+        /// See [`_mm512_cmpneq_epi64_mask`](fn._mm512_cmpneq_epi64_mask.html).
+        fn cmpneq_epi64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmpneq_epi64_mask;
+
+        /// See [`_mm512_cmpneq_epu32_mask`](fn._mm512_cmpneq_epu32_mask.html).
+        fn cmpneq_epu32_mask(a: __m512i, b: __m512i)
+            -> __mmask16 = _mm512_cmpneq_epu32_mask;
+
+        /// See [`_mm512_cmpneq_epu64_mask`](fn._mm512_cmpneq_epu64_mask.html).
+        fn cmpneq_epu64_mask(a: __m512i, b: __m512i)
+            -> __mmask8 = _mm512_cmpneq_epu64_mask;
+
+        /// See [`_mm512_cmpneq_pd_mask`](fn._mm512_cmpneq_pd_mask.html).
+        fn cmpneq_pd_mask(a: __m512d, b: __m512d)
+            -> __mmask8 = _mm512_cmpneq_pd_mask;
+
+        /// See [`_mm512_cmpneq_ps_mask`](fn._mm512_cmpneq_ps_mask.html).
+        fn cmpneq_ps_mask(a: __m512, b: __m512)
+            -> __mmask16 = _mm512_cmpneq_ps_mask;
+
+        /// See [`_mm512_cmpnle_pd_mask`](fn._mm512_cmpnle_pd_mask.html).
+        fn cmpnle_pd_mask(a: __m512d, b: __m512d)
+            -> __mmask8 = _mm512_cmpnle_pd_mask;
+
+        /// See [`_mm512_cmpnle_ps_mask`](fn._mm512_cmpnle_ps_mask.html).
+        fn cmpnle_ps_mask(a: __m512, b: __m512)
+            -> __mmask16 = _mm512_cmpnle_ps_mask;
+
+        /// See [`_mm512_cmpnlt_pd_mask`](fn._mm512_cmpnlt_pd_mask.html).
+        fn cmpnlt_pd_mask(a: __m512d, b: __m512d)
+            -> __mmask8 = _mm512_cmpnlt_pd_mask;
+
+        /// See [`_mm512_cmpnlt_ps_mask`](fn._mm512_cmpnlt_ps_mask.html).
+        fn cmpnlt_ps_mask(a: __m512, b: __m512)
+            -> __mmask16 = _mm512_cmpnlt_ps_mask;
+
+        /// See [`_mm512_cmpord_pd_mask`](fn._mm512_cmpord_pd_mask.html).
+        fn cmpord_pd_mask(a: __m512d, b: __m512d)
+            -> __mmask8 = _mm512_cmpord_pd_mask;
+
+        /// See [`_mm512_cmpord_ps_mask`](fn._mm512_cmpord_ps_mask.html).
+        fn cmpord_ps_mask(a: __m512, b: __m512)
+            -> __mmask16 = _mm512_cmpord_ps_mask;
+
+        /// See [`_mm512_cmpunord_pd_mask`](fn._mm512_cmpunord_pd_mask.html).
+        fn cmpunord_pd_mask(a: __m512d, b: __m512d)
+            -> __mmask8 = _mm512_cmpunord_pd_mask;
+
+        /// See [`_mm512_cmpunord_ps_mask`](fn._mm512_cmpunord_ps_mask.html).
+        fn cmpunord_ps_mask(a: __m512, b: __m512)
+            -> __mmask16 = _mm512_cmpunord_ps_mask;
+
+        /// See [`_mm512_cvtepi32_epi64`](fn._mm512_cvtepi32_epi64.html).
+        fn cvtepi32_epi64(a: __m256i) -> __m512i = _mm512_cvtepi32_epi64;
+
+        /// See [`_mm512_cvtepi32_pd`](fn._mm512_cvtepi32_pd.html).
+        fn cvtepi32_pd(a: __m256i) -> __m512d = _mm512_cvtepi32_pd;
+
+        /// See [`_mm512_cvtepi32_ps`](fn._mm512_cvtepi32_ps.html).
+        fn cvtepi32_ps(a: __m512i) -> __m512 = _mm512_cvtepi32_ps;
+
+        /// See [`_mm512_cvtepu32_epi64`](fn._mm512_cvtepu32_epi64.html).
+        fn cvtepu32_epi64(a: __m256i) -> __m512i = _mm512_cvtepu32_epi64;
+
+        /// See [`_mm512_cvtpd_ps`](fn._mm512_cvtpd_ps.html).
+        fn cvtpd_ps(a: __m512d) -> __m256 = _mm512_cvtpd_ps;
+
+        /// See [`_mm512_cvtps_epi32`](fn._mm512_cvtps_epi32.html).
+        fn cvtps_epi32(a: __m512) -> __m512i = _mm512_cvtps_epi32;
+
+        /// See [`_mm512_cvtps_pd`](fn._mm512_cvtps_pd.html).
+        fn cvtps_pd(a: __m256) -> __m512d = _mm512_cvtps_pd;
+
+        /// See [`_mm512_cvttps_epi32`](fn._mm512_cvttps_epi32.html).
+        fn cvttps_epi32(a: __m512) -> __m512i = _mm512_cvttps_epi32;
+
+        /// See [`_mm512_div_pd`](fn._mm512_div_pd.html).
+        fn div_pd(a: __m512d, b: __m512d) -> __m512d = _mm512_div_pd;
+
+        /// See [`_mm512_div_ps`](fn._mm512_div_ps.html).
+        fn div_ps(a: __m512, b: __m512) -> __m512 = _mm512_div_ps;
+
+        /// See [`_mm512_kand`](fn._mm512_kand.html).
+        fn kand(a: __mmask16, b: __mmask16) -> __mmask16 = _mm512_kand;
+
+        /// See [`_kand_mask16`](fn._kand_mask16.html).
+        fn kand_mask16(a: __mmask16, b: __mmask16) -> __mmask16 = _kand_mask16;
+
+        /// See [`_mm512_kandn`](fn._mm512_kandn.html).
+        fn kandn(a: __mmask16, b: __mmask16) -> __mmask16 = _mm512_kandn;
+
+        /// See [`_kandn_mask16`](fn._kandn_mask16.html).
+        fn kandn_mask16(a: __mmask16, b: __mmask16)
+            -> __mmask16 = _kandn_mask16;
+
+        /// See [`_mm512_knot`](fn._mm512_knot.html).
+        fn knot(a: __mmask16) -> __mmask16 = _mm512_knot;
+
+        /// See [`_knot_mask16`](fn._knot_mask16.html).
+        fn knot_mask16(a: __mmask16) -> __mmask16 = _knot_mask16;
+
+        /// See [`_mm512_kor`](fn._mm512_kor.html).
+        fn kor(a: __mmask16, b: __mmask16) -> __mmask16 = _mm512_kor;
+
+        /// See [`_kor_mask16`](fn._kor_mask16.html).
+        fn kor_mask16(a: __mmask16, b: __mmask16) -> __mmask16 = _kor_mask16;
+
+        /// See [`_mm512_kortestc`](fn._mm512_kortestc.html).
+        fn kortestc(a: __mmask16, b: __mmask16) -> i32 = _mm512_kortestc;
+
+        /// See [`_kortestc_mask16_u8`](fn._kortestc_mask16_u8.html).
+        fn kortestc_mask16_u8(a: __mmask16, b: __mmask16)
+            -> u8 = _kortestc_mask16_u8;
+
+        /// See [`_mm512_kortestz`](fn._mm512_kortestz.html).
+        fn kortestz(a: __mmask16, b: __mmask16) -> i32 = _mm512_kortestz;
+
+        /// See [`_kortestz_mask16_u8`](fn._kortestz_mask16_u8.html).
+        fn kortestz_mask16_u8(a: __mmask16, b: __mmask16)
+            -> u8 = _kortestz_mask16_u8;
+
+        /// See [`_mm512_kxnor`](fn._mm512_kxnor.html).
+        fn kxnor(a: __mmask16, b: __mmask16) -> __mmask16 = _mm512_kxnor;
+
+        /// See [`_kxnor_mask16`](fn._kxnor_mask16.html).
+        fn kxnor_mask16(a: __mmask16, b: __mmask16)
+            -> __mmask16 = _kxnor_mask16;
+
+        /// See [`_mm512_kxor`](fn._mm512_kxor.html).
+        fn kxor(a: __mmask16, b: __mmask16) -> __mmask16 = _mm512_kxor;
+
+        /// See [`_kxor_mask16`](fn._kxor_mask16.html).
+        fn kxor_mask16(a: __mmask16, b: __mmask16) -> __mmask16 = _kxor_mask16;
+
+        /// See [`_mm512_mask_add_epi32`](fn._mm512_mask_add_epi32.html).
+        fn mask_add_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i)
+            -> __m512i = _mm512_mask_add_epi32;
+
+        /// See [`_mm512_mask_add_epi64`](fn._mm512_mask_add_epi64.html).
+        fn mask_add_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i)
+            -> __m512i = _mm512_mask_add_epi64;
+
+        /// See [`_mm512_mask_add_pd`](fn._mm512_mask_add_pd.html).
+        fn mask_add_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d)
+            -> __m512d = _mm512_mask_add_pd;
+
+        /// See [`_mm512_mask_add_ps`](fn._mm512_mask_add_ps.html).
+        fn mask_add_ps(src: __m512, k: __mmask16, a: __m512, b: __m512)
+            -> __m512 = _mm512_mask_add_ps;
+
+        /// See [`_mm512_mask_div_pd`](fn._mm512_mask_div_pd.html).
+        fn mask_div_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d)
+            -> __m512d = _mm512_mask_div_pd;
+
+        /// See [`_mm512_mask_div_ps`](fn._mm512_mask_div_ps.html).
+        fn mask_div_ps(src: __m512, k: __mmask16, a: __m512, b: __m512)
+            -> __m512 = _mm512_mask_div_ps;
+
+        /// See [`_mm512_mask_mov_epi32`](fn._mm512_mask_mov_epi32.html).
+        fn mask_mov_epi32(src: __m512i, k: __mmask16, a: __m512i)
+            -> __m512i = _mm512_mask_mov_epi32;
+
+        /// See [`_mm512_mask_mov_epi64`](fn._mm512_mask_mov_epi64.html).
+        fn mask_mov_epi64(src: __m512i, k: __mmask8, a: __m512i)
+            -> __m512i = _mm512_mask_mov_epi64;
+
+        /// See [`_mm512_mask_mov_pd`](fn._mm512_mask_mov_pd.html).
+        fn mask_mov_pd(src: __m512d, k: __mmask8, a: __m512d)
+            -> __m512d = _mm512_mask_mov_pd;
+
+        /// See [`_mm512_mask_mov_ps`](fn._mm512_mask_mov_ps.html).
+        fn mask_mov_ps(src: __m512, k: __mmask16, a: __m512)
+            -> __m512 = _mm512_mask_mov_ps;
+
+        /// See [`_mm512_mask_mul_pd`](fn._mm512_mask_mul_pd.html).
+        fn mask_mul_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d)
+            -> __m512d = _mm512_mask_mul_pd;
+
+        /// See [`_mm512_mask_mul_ps`](fn._mm512_mask_mul_ps.html).
+        fn mask_mul_ps(src: __m512, k: __mmask16, a: __m512, b: __m512)
+            -> __m512 = _mm512_mask_mul_ps;
+
+        /// See [`_mm512_mask_mullo_epi32`](fn._mm512_mask_mullo_epi32.html).
+        fn mask_mullo_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i)
+            -> __m512i = _mm512_mask_mullo_epi32;
+
+        /// See [`_mm512_mask_sqrt_pd`](fn._mm512_mask_sqrt_pd.html).
+        fn mask_sqrt_pd(src: __m512d, k: __mmask8, a: __m512d)
+            -> __m512d = _mm512_mask_sqrt_pd;
+
+        /// See [`_mm512_mask_sqrt_ps`](fn._mm512_mask_sqrt_ps.html).
+        fn mask_sqrt_ps(src: __m512, k: __mmask16, a: __m512)
+            -> __m512 = _mm512_mask_sqrt_ps;
+
+        /// See [`_mm512_mask_sub_epi32`](fn._mm512_mask_sub_epi32.html).
+        fn mask_sub_epi32(src: __m512i, k: __mmask16, a: __m512i, b: __m512i)
+            -> __m512i = _mm512_mask_sub_epi32;
+
+        /// See [`_mm512_mask_sub_epi64`](fn._mm512_mask_sub_epi64.html).
+        fn mask_sub_epi64(src: __m512i, k: __mmask8, a: __m512i, b: __m512i)
+            -> __m512i = _mm512_mask_sub_epi64;
+
+        /// See [`_mm512_mask_sub_pd`](fn._mm512_mask_sub_pd.html).
+        fn mask_sub_pd(src: __m512d, k: __mmask8, a: __m512d, b: __m512d)
+            -> __m512d = _mm512_mask_sub_pd;
+
+        /// See [`_mm512_mask_sub_ps`](fn._mm512_mask_sub_ps.html).
+        fn mask_sub_ps(src: __m512, k: __mmask16, a: __m512, b: __m512)
+            -> __m512 = _mm512_mask_sub_ps;
+
+        /// See [`_mm512_maskz_add_epi32`](fn._mm512_maskz_add_epi32.html).
+        fn maskz_add_epi32(k: __mmask16, a: __m512i, b: __m512i)
+            -> __m512i = _mm512_maskz_add_epi32;
+
+        /// See [`_mm512_maskz_add_epi64`](fn._mm512_maskz_add_epi64.html).
+        fn maskz_add_epi64(k: __mmask8, a: __m512i, b: __m512i)
+            -> __m512i = _mm512_maskz_add_epi64;
+
+        /// See [`_mm512_maskz_add_pd`](fn._mm512_maskz_add_pd.html).
+        fn maskz_add_pd(k: __mmask8, a: __m512d, b: __m512d)
+            -> __m512d = _mm512_maskz_add_pd;
+
+        /// See [`_mm512_maskz_add_ps`](fn._mm512_maskz_add_ps.html).
+        fn maskz_add_ps(k: __mmask16, a: __m512, b: __m512)
+            -> __m512 = _mm512_maskz_add_ps;
+
+        /// See [`_mm512_maskz_div_pd`](fn._mm512_maskz_div_pd.html).
+        fn maskz_div_pd(k: __mmask8, a: __m512d, b: __m512d)
+            -> __m512d = _mm512_maskz_div_pd;
+
+        /// See [`_mm512_maskz_div_ps`](fn._mm512_maskz_div_ps.html).
+        fn maskz_div_ps(k: __mmask16, a: __m512, b: __m512)
+            -> __m512 = _mm512_maskz_div_ps;
+
+        /// See [`_mm512_maskz_mov_epi32`](fn._mm512_maskz_mov_epi32.html).
+        fn maskz_mov_epi32(k: __mmask16, a: __m512i)
+            -> __m512i = _mm512_maskz_mov_epi32;
+
+        /// See [`_mm512_maskz_mov_epi64`](fn._mm512_maskz_mov_epi64.html).
+        fn maskz_mov_epi64(k: __mmask8, a: __m512i)
+            -> __m512i = _mm512_maskz_mov_epi64;
+
+        /// See [`_mm512_maskz_mov_pd`](fn._mm512_maskz_mov_pd.html).
+        fn maskz_mov_pd(k: __mmask8, a: __m512d)
+            -> __m512d = _mm512_maskz_mov_pd;
+
+        /// See [`_mm512_maskz_mov_ps`](fn._mm512_maskz_mov_ps.html).
+        fn maskz_mov_ps(k: __mmask16, a: __m512)
+            -> __m512 = _mm512_maskz_mov_ps;
+
+        /// See [`_mm512_maskz_mul_pd`](fn._mm512_maskz_mul_pd.html).
+        fn maskz_mul_pd(k: __mmask8, a: __m512d, b: __m512d)
+            -> __m512d = _mm512_maskz_mul_pd;
+
+        /// See [`_mm512_maskz_mul_ps`](fn._mm512_maskz_mul_ps.html).
+        fn maskz_mul_ps(k: __mmask16, a: __m512, b: __m512)
+            -> __m512 = _mm512_maskz_mul_ps;
+
+        /// See [`_mm512_maskz_mullo_epi32`](fn._mm512_maskz_mullo_epi32.html).
+        fn maskz_mullo_epi32(k: __mmask16, a: __m512i, b: __m512i)
+            -> __m512i = _mm512_maskz_mullo_epi32;
+
+        /// See [`_mm512_maskz_sqrt_pd`](fn._mm512_maskz_sqrt_pd.html).
+        fn maskz_sqrt_pd(k: __mmask8, a: __m512d)
+            -> __m512d = _mm512_maskz_sqrt_pd;
+
+        /// See [`_mm512_maskz_sqrt_ps`](fn._mm512_maskz_sqrt_ps.html).
+        fn maskz_sqrt_ps(k: __mmask16, a: __m512)
+            -> __m512 = _mm512_maskz_sqrt_ps;
+
+        /// See [`_mm512_maskz_sub_epi32`](fn._mm512_maskz_sub_epi32.html).
+        fn maskz_sub_epi32(k: __mmask16, a: __m512i, b: __m512i)
+            -> __m512i = _mm512_maskz_sub_epi32;
+
+        /// See [`_mm512_maskz_sub_epi64`](fn._mm512_maskz_sub_epi64.html).
+        fn maskz_sub_epi64(k: __mmask8, a: __m512i, b: __m512i)
+            -> __m512i = _mm512_maskz_sub_epi64;
+
+        /// See [`_mm512_maskz_sub_pd`](fn._mm512_maskz_sub_pd.html).
+        fn maskz_sub_pd(k: __mmask8, a: __m512d, b: __m512d)
+            -> __m512d = _mm512_maskz_sub_pd;
+
+        /// See [`_mm512_maskz_sub_ps`](fn._mm512_maskz_sub_ps.html).
+        fn maskz_sub_ps(k: __mmask16, a: __m512, b: __m512)
+            -> __m512 = _mm512_maskz_sub_ps;
+
+        /// See [`_mm512_mul_pd`](fn._mm512_mul_pd.html).
+        fn mul_pd(a: __m512d, b: __m512d) -> __m512d = _mm512_mul_pd;
+
+        /// See [`_mm512_mul_ps`](fn._mm512_mul_ps.html).
+        fn mul_ps(a: __m512, b: __m512) -> __m512 = _mm512_mul_ps;
+
+        /// See [`_mm512_mullo_epi32`](fn._mm512_mullo_epi32.html).
+        fn mullo_epi32(a: __m512i, b: __m512i) -> __m512i = _mm512_mullo_epi32;
+
+        /// See [`_mm512_or_epi32`](fn._mm512_or_epi32.html).
+        fn or_epi32(a: __m512i, b: __m512i) -> __m512i = _mm512_or_epi32;
+
+        /// See [`_mm512_or_epi64`](fn._mm512_or_epi64.html).
+        fn or_epi64(a: __m512i, b: __m512i) -> __m512i = _mm512_or_epi64;
+
+        /// See [`_mm512_or_si512`](fn._mm512_or_si512.html).
+        fn or_si512(a: __m512i, b: __m512i) -> __m512i = _mm512_or_si512;
+
+        /// See [`_mm512_set1_epi16`](fn._mm512_set1_epi16.html).
+        fn set1_epi16(a: i16) -> __m512i = _mm512_set1_epi16;
+
+        /// See [`_mm512_set1_epi32`](fn._mm512_set1_epi32.html).
+        fn set1_epi32(a: i32) -> __m512i = _mm512_set1_epi32;
+
+        /// See [`_mm512_set1_epi64`](fn._mm512_set1_epi64.html).
+        fn set1_epi64(a: i64) -> __m512i = _mm512_set1_epi64;
+
+        /// See [`_mm512_set1_epi8`](fn._mm512_set1_epi8.html).
+        fn set1_epi8(a: i8) -> __m512i = _mm512_set1_epi8;
+
+        /// See [`_mm512_set1_pd`](fn._mm512_set1_pd.html).
+        fn set1_pd(a: f64) -> __m512d = _mm512_set1_pd;
+
+        /// See [`_mm512_set1_ps`](fn._mm512_set1_ps.html).
+        fn set1_ps(a: f32) -> __m512 = _mm512_set1_ps;
+
+        /// See [`_mm512_set_epi32`](fn._mm512_set_epi32.html).
+        fn set_epi32(
+            e00: i32, e01: i32, e02: i32, e03: i32, e04: i32, e05: i32,
+            e06: i32, e07: i32, e08: i32, e09: i32, e10: i32, e11: i32,
+            e12: i32, e13: i32, e14: i32, e15: i32
+        ) -> __m512i = _mm512_set_epi32;
+
+        /// See [`_mm512_set_epi64`](fn._mm512_set_epi64.html).
+        fn set_epi64(
+            e0: i64, e1: i64, e2: i64, e3: i64, e4: i64, e5: i64, e6: i64,
+            e7: i64
+        ) -> __m512i = _mm512_set_epi64;
+
+        /// See [`_mm512_set_pd`](fn._mm512_set_pd.html).
+        fn set_pd(
+            e0: f64, e1: f64, e2: f64, e3: f64, e4: f64, e5: f64, e6: f64,
+            e7: f64
+        ) -> __m512d = _mm512_set_pd;
+
+        /// See [`_mm512_set_ps`](fn._mm512_set_ps.html).
+        fn set_ps(
+            e00: f32, e01: f32, e02: f32, e03: f32, e04: f32, e05: f32,
+            e06: f32, e07: f32, e08: f32, e09: f32, e10: f32, e11: f32,
+            e12: f32, e13: f32, e14: f32, e15: f32
+        ) -> __m512 = _mm512_set_ps;
+
+        /// See [`_mm512_setr_epi32`](fn._mm512_setr_epi32.html).
+        fn setr_epi32(
+            e00: i32, e01: i32, e02: i32, e03: i32, e04: i32, e05: i32,
+            e06: i32, e07: i32, e08: i32, e09: i32, e10: i32, e11: i32,
+            e12: i32, e13: i32, e14: i32, e15: i32
+        ) -> __m512i = _mm512_setr_epi32;
+
+        /// See [`_mm512_setr_epi64`](fn._mm512_setr_epi64.html).
+        fn setr_epi64(
+            e0: i64, e1: i64, e2: i64, e3: i64, e4: i64, e5: i64, e6: i64,
+            e7: i64
+        ) -> __m512i = _mm512_setr_epi64;
+
+        /// See [`_mm512_setr_pd`](fn._mm512_setr_pd.html).
+        fn setr_pd(
+            e0: f64, e1: f64, e2: f64, e3: f64, e4: f64, e5: f64, e6: f64,
+            e7: f64
+        ) -> __m512d = _mm512_setr_pd;
+
+        /// See [`_mm512_setr_ps`](fn._mm512_setr_ps.html).
+        fn setr_ps(
+            e00: f32, e01: f32, e02: f32, e03: f32, e04: f32, e05: f32,
+            e06: f32, e07: f32, e08: f32, e09: f32, e10: f32, e11: f32,
+            e12: f32, e13: f32, e14: f32, e15: f32
+        ) -> __m512 = _mm512_setr_ps;
+
+        /// See [`_mm512_setzero_pd`](fn._mm512_setzero_pd.html).
+        fn setzero_pd() -> __m512d = _mm512_setzero_pd;
+
+        /// See [`_mm512_setzero_ps`](fn._mm512_setzero_ps.html).
+        fn setzero_ps() -> __m512 = _mm512_setzero_ps;
+
+        /// See [`_mm512_setzero_si512`](fn._mm512_setzero_si512.html).
+        fn setzero_si512() -> __m512i = _mm512_setzero_si512;
+
+        /// See [`_mm512_sqrt_pd`](fn._mm512_sqrt_pd.html).
+        fn sqrt_pd(a: __m512d) -> __m512d = _mm512_sqrt_pd;
+
+        /// See [`_mm512_sqrt_ps`](fn._mm512_sqrt_ps.html).
+        fn sqrt_ps(a: __m512) -> __m512 = _mm512_sqrt_ps;
+
+        /// See [`_mm512_sub_epi32`](fn._mm512_sub_epi32.html).
+        fn sub_epi32(a: __m512i, b: __m512i) -> __m512i = _mm512_sub_epi32;
+
+        /// See [`_mm512_sub_epi64`](fn._mm512_sub_epi64.html).
+        fn sub_epi64(a: __m512i, b: __m512i) -> __m512i = _mm512_sub_epi64;
+
+        /// See [`_mm512_sub_pd`](fn._mm512_sub_pd.html).
+        fn sub_pd(a: __m512d, b: __m512d) -> __m512d = _mm512_sub_pd;
+
+        /// See [`_mm512_sub_ps`](fn._mm512_sub_ps.html).
+        fn sub_ps(a: __m512, b: __m512) -> __m512 = _mm512_sub_ps;
+
+        /// See [`_mm512_xor_epi32`](fn._mm512_xor_epi32.html).
+        fn xor_epi32(a: __m512i, b: __m512i) -> __m512i = _mm512_xor_epi32;
+
+        /// See [`_mm512_xor_epi64`](fn._mm512_xor_epi64.html).
+        fn xor_epi64(a: __m512i, b: __m512i) -> __m512i = _mm512_xor_epi64;
+
+        /// See [`_mm512_xor_si512`](fn._mm512_xor_si512.html).
+        fn xor_si512(a: __m512i, b: __m512i) -> __m512i = _mm512_xor_si512;
+    }
+}
+
+token_methods! {
+    Avx512bw = "avx512bw" {
+        /// See [`_mm512_add_epi16`](fn._mm512_add_epi16.html).
+        fn add_epi16(a: __m512i, b: __m512i) -> __m512i = _mm512_add_epi16;
+
+        /// See [`_mm512_add_epi8`](fn._mm512_add_epi8.html).
+        fn add_epi8(a: __m512i, b: __m512i) -> __m512i = _mm512_add_epi8;
+
+        /// See [`_mm512_avg_epu16`](fn._mm512_avg_epu16.html).
+        fn avg_epu16(a: __m512i, b: __m512i) -> __m512i = _mm512_avg_epu16;
+
+        /// See [`_mm512_avg_epu8`](fn._mm512_avg_epu8.html).
+        fn avg_epu8(a: __m512i, b: __m512i) -> __m512i = _mm512_avg_epu8;
+
+        /// See [`_mm512_cmpeq_epi16_mask`](fn._mm512_cmpeq_epi16_mask.html).
+        fn cmpeq_epi16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmpeq_epi16_mask;
+
+        /// See [`_mm512_cmpeq_epi8_mask`](fn._mm512_cmpeq_epi8_mask.html).
+        fn cmpeq_epi8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmpeq_epi8_mask;
+
+        /// See [`_mm512_cmpeq_epu16_mask`](fn._mm512_cmpeq_epu16_mask.html).
+        fn cmpeq_epu16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmpeq_epu16_mask;
+
+        /// See [`_mm512_cmpeq_epu8_mask`](fn._mm512_cmpeq_epu8_mask.html).
+        fn cmpeq_epu8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmpeq_epu8_mask;
+
+        /// See [`_mm512_cmpge_epi16_mask`](fn._mm512_cmpge_epi16_mask.html).
+        fn cmpge_epi16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmpge_epi16_mask;
+
+        /// See [`_mm512_cmpge_epi8_mask`](fn._mm512_cmpge_epi8_mask.html).
+        fn cmpge_epi8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmpge_epi8_mask;
+
+        /// See [`_mm512_cmpge_epu16_mask`](fn._mm512_cmpge_epu16_mask.html).
+        fn cmpge_epu16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmpge_epu16_mask;
+
+        /// See [`_mm512_cmpge_epu8_mask`](fn._mm512_cmpge_epu8_mask.html).
+        fn cmpge_epu8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmpge_epu8_mask;
+
+        /// See [`_mm512_cmpgt_epi16_mask`](fn._mm512_cmpgt_epi16_mask.html).
+        fn cmpgt_epi16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmpgt_epi16_mask;
+
+        /// See [`_mm512_cmpgt_epi8_mask`](fn._mm512_cmpgt_epi8_mask.html).
+        fn cmpgt_epi8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmpgt_epi8_mask;
+
+        /// See [`_mm512_cmpgt_epu16_mask`](fn._mm512_cmpgt_epu16_mask.html).
+        fn cmpgt_epu16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmpgt_epu16_mask;
+
+        /// See [`_mm512_cmpgt_epu8_mask`](fn._mm512_cmpgt_epu8_mask.html).
+        fn cmpgt_epu8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmpgt_epu8_mask;
+
+        /// See [`_mm512_cmple_epi16_mask`](fn._mm512_cmple_epi16_mask.html).
+        fn cmple_epi16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmple_epi16_mask;
+
+        /// See [`_mm512_cmple_epi8_mask`](fn._mm512_cmple_epi8_mask.html).
+        fn cmple_epi8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmple_epi8_mask;
+
+        /// See [`_mm512_cmple_epu16_mask`](fn._mm512_cmple_epu16_mask.html).
+        fn cmple_epu16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmple_epu16_mask;
+
+        /// See [`_mm512_cmple_epu8_mask`](fn._mm512_cmple_epu8_mask.html).
+        fn cmple_epu8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmple_epu8_mask;
+
+        /// See [`_mm512_cmplt_epi16_mask`](fn._mm512_cmplt_epi16_mask.html).
+        fn cmplt_epi16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmplt_epi16_mask;
+
+        /// See [`_mm512_cmplt_epi8_mask`](fn._mm512_cmplt_epi8_mask.html).
+        fn cmplt_epi8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmplt_epi8_mask;
+
+        /// See [`_mm512_cmplt_epu16_mask`](fn._mm512_cmplt_epu16_mask.html).
+        fn cmplt_epu16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmplt_epu16_mask;
+
+        /// See [`_mm512_cmplt_epu8_mask`](fn._mm512_cmplt_epu8_mask.html).
+        fn cmplt_epu8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmplt_epu8_mask;
+
+        /// See [`_mm512_cmpneq_epi16_mask`](fn._mm512_cmpneq_epi16_mask.html).
+        fn cmpneq_epi16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmpneq_epi16_mask;
+
+        /// See [`_mm512_cmpneq_epi8_mask`](fn._mm512_cmpneq_epi8_mask.html).
+        fn cmpneq_epi8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmpneq_epi8_mask;
+
+        /// See [`_mm512_cmpneq_epu16_mask`](fn._mm512_cmpneq_epu16_mask.html).
+        fn cmpneq_epu16_mask(a: __m512i, b: __m512i)
+            -> __mmask32 = _mm512_cmpneq_epu16_mask;
+
+        /// See [`_mm512_cmpneq_epu8_mask`](fn._mm512_cmpneq_epu8_mask.html).
+        fn cmpneq_epu8_mask(a: __m512i, b: __m512i)
+            -> __mmask64 = _mm512_cmpneq_epu8_mask;
+
+        /// See [`_kand_mask32`](fn._kand_mask32.html).
+        fn kand_mask32(a: __mmask32, b: __mmask32) -> __mmask32 = _kand_mask32;
+
+        /// See [`_kand_mask64`](fn._kand_mask64.html).
+        fn kand_mask64(a: __mmask64, b: __mmask64) -> __mmask64 = _kand_mask64;
+
+        /// See [`_kandn_mask32`](fn._kandn_mask32.html).
+        fn kandn_mask32(a: __mmask32, b: __mmask32)
+            -> __mmask32 = _kandn_mask32;
+
+        /// See [`_kandn_mask64`](fn._kandn_mask64.html).
+        fn kandn_mask64(a: __mmask64, b: __mmask64)
+            -> __mmask64 = _kandn_mask64;
+
+        /// See [`_knot_mask32`](fn._knot_mask32.html).
+        fn knot_mask32(a: __mmask32) -> __mmask32 = _knot_mask32;
+
+        /// See [`_knot_mask64`](fn._knot_mask64.html).
+        fn knot_mask64(a: __mmask64) -> __mmask64 = _knot_mask64;
+
+        /// See [`_kor_mask32`](fn._kor_mask32.html).
+        fn kor_mask32(a: __mmask32, b: __mmask32) -> __mmask32 = _kor_mask32;
+
+        /// See [`_kor_mask64`](fn._kor_mask64.html).
+        fn kor_mask64(a: __mmask64, b: __mmask64) -> __mmask64 = _kor_mask64;
+
+        /// See [`_kortestc_mask32_u8`](fn._kortestc_mask32_u8.html).
+        fn kortestc_mask32_u8(a: __mmask32, b: __mmask32)
+            -> u8 = _kortestc_mask32_u8;
+
+        /// See [`_kortestc_mask64_u8`](fn._kortestc_mask64_u8.html).
+        fn kortestc_mask64_u8(a: __mmask64, b: __mmask64)
+            -> u8 = _kortestc_mask64_u8;
+
+        /// See [`_kortestz_mask32_u8`](fn._kortestz_mask32_u8.html).
+        fn kortestz_mask32_u8(a: __mmask32, b: __mmask32)
+            -> u8 = _kortestz_mask32_u8;
+
+        /// See [`_kortestz_mask64_u8`](fn._kortestz_mask64_u8.html).
+        fn kortestz_mask64_u8(a: __mmask64, b: __mmask64)
+            -> u8 = _kortestz_mask64_u8;
+
+        /// See [`_kxnor_mask32`](fn._kxnor_mask32.html).
+        fn kxnor_mask32(a: __mmask32, b: __mmask32)
+            -> __mmask32 = _kxnor_mask32;
+
+        /// See [`_kxnor_mask64`](fn._kxnor_mask64.html).
+        fn kxnor_mask64(a: __mmask64, b: __mmask64)
+            -> __mmask64 = _kxnor_mask64;
+
+        /// See [`_kxor_mask32`](fn._kxor_mask32.html).
+        fn kxor_mask32(a: __mmask32, b: __mmask32) -> __mmask32 = _kxor_mask32;
+
+        /// See [`_kxor_mask64`](fn._kxor_mask64.html).
+        fn kxor_mask64(a: __mmask64, b: __mmask64) -> __mmask64 = _kxor_mask64;
+
+        /// See [`_mm512_madd_epi16`](fn._mm512_madd_epi16.html).
+        fn madd_epi16(a: __m512i, b: __m512i) -> __m512i = _mm512_madd_epi16;
+
+        /// See [`_mm512_maddubs_epi16`](fn._mm512_maddubs_epi16.html).
+        fn maddubs_epi16(a: __m512i, b: __m512i)
+            -> __m512i = _mm512_maddubs_epi16;
+
+        /// See [`_mm512_mask_mov_epi16`](fn._mm512_mask_mov_epi16.html).
+        fn mask_mov_epi16(src: __m512i, k: __mmask32, a: __m512i)
+            -> __m512i = _mm512_mask_mov_epi16;
+
+        /// See [`_mm512_mask_mov_epi8`](fn._mm512_mask_mov_epi8.html).
+        fn mask_mov_epi8(src: __m512i, k: __mmask64, a: __m512i)
+            -> __m512i = _mm512_mask_mov_epi8;
+
+        /// See [`_mm512_maskz_mov_epi16`](fn._mm512_maskz_mov_epi16.html).
+        fn maskz_mov_epi16(k: __mmask32, a: __m512i)
+            -> __m512i = _mm512_maskz_mov_epi16;
+
+        /// See [`_mm512_maskz_mov_epi8`](fn._mm512_maskz_mov_epi8.html).
+        fn maskz_mov_epi8(k: __mmask64, a: __m512i)
+            -> __m512i = _mm512_maskz_mov_epi8;
+
+        /// See [`_mm512_movepi16_mask`](fn._mm512_movepi16_mask.html).
+        fn movepi16_mask(a: __m512i) -> __mmask32 = _mm512_movepi16_mask;
+
+        /// See [`_mm512_movepi8_mask`](fn._mm512_movepi8_mask.html).
+        fn movepi8_mask(a: __m512i) -> __mmask64 = _mm512_movepi8_mask;
+
+        /// See [`_mm512_movm_epi16`](fn._mm512_movm_epi16.html).
+        fn movm_epi16(k: __mmask32) -> __m512i = _mm512_movm_epi16;
+
+        /// See [`_mm512_movm_epi8`](fn._mm512_movm_epi8.html).
+        fn movm_epi8(k: __mmask64) -> __m512i = _mm512_movm_epi8;
+
+        /// See [`_mm512_mulhi_epi16`](fn._mm512_mulhi_epi16.html).
+        fn mulhi_epi16(a: __m512i, b: __m512i) -> __m512i = _mm512_mulhi_epi16;
+
+        /// See [`_mm512_mulhi_epu16`](fn._mm512_mulhi_epu16.html).
+        fn mulhi_epu16(a: __m512i, b: __m512i) -> __m512i = _mm512_mulhi_epu16;
+
+        /// See [`_mm512_mulhrs_epi16`](fn._mm512_mulhrs_epi16.html).
+        fn mulhrs_epi16(a: __m512i, b: __m512i)
+            -> __m512i = _mm512_mulhrs_epi16;
+
+        /// See [`_mm512_mullo_epi16`](fn._mm512_mullo_epi16.html).
+        fn mullo_epi16(a: __m512i, b: __m512i) -> __m512i = _mm512_mullo_epi16;
+
+        /// See [`_mm512_packs_epi16`](fn._mm512_packs_epi16.html).
+        fn packs_epi16(a: __m512i, b: __m512i) -> __m512i = _mm512_packs_epi16;
+
+        /// See [`_mm512_packs_epi32`](fn._mm512_packs_epi32.html).
+        fn packs_epi32(a: __m512i, b: __m512i) -> __m512i = _mm512_packs_epi32;
+
+        /// See [`_mm512_packus_epi16`](fn._mm512_packus_epi16.html).
+        fn packus_epi16(a: __m512i, b: __m512i)
+            -> __m512i = _mm512_packus_epi16;
+
+        /// See [`_mm512_packus_epi32`](fn._mm512_packus_epi32.html).
+        fn packus_epi32(a: __m512i, b: __m512i)
+            -> __m512i = _mm512_packus_epi32;
+
+        /// See [`_mm512_sad_epu8`](fn._mm512_sad_epu8.html).
+        fn sad_epu8(a: __m512i, b: __m512i) -> __m512i = _mm512_sad_epu8;
+
+        /// See [`_mm512_shuffle_epi8`](fn._mm512_shuffle_epi8.html).
+        fn shuffle_epi8(a: __m512i, b: __m512i)
+            -> __m512i = _mm512_shuffle_epi8;
+
+        /// See [`_mm512_sub_epi16`](fn._mm512_sub_epi16.html).
+        fn sub_epi16(a: __m512i, b: __m512i) -> __m512i = _mm512_sub_epi16;
+
+        /// See [`_mm512_sub_epi8`](fn._mm512_sub_epi8.html).
+        fn sub_epi8(a: __m512i, b: __m512i) -> __m512i = _mm512_sub_epi8;
+    }
+}
+
+token_methods! {
+    Avx512cd = "avx512cd" {
+        /// See [`_mm512_conflict_epi32`](fn._mm512_conflict_epi32.html).
+        fn conflict_epi32(a: __m512i) -> __m512i = _mm512_conflict_epi32;
+
+        /// See [`_mm512_conflict_epi64`](fn._mm512_conflict_epi64.html).
+        fn conflict_epi64(a: __m512i) -> __m512i = _mm512_conflict_epi64;
+
+        /// See [`_mm512_lzcnt_epi32`](fn._mm512_lzcnt_epi32.html).
+        fn lzcnt_epi32(a: __m512i) -> __m512i = _mm512_lzcnt_epi32;
+
+        /// See [`_mm512_lzcnt_epi64`](fn._mm512_lzcnt_epi64.html).
+        fn lzcnt_epi64(a: __m512i) -> __m512i = _mm512_lzcnt_epi64;
+    }
+}
+
+token_methods! {
+    Avx512dq = "avx512dq" {
+        /// See [`_mm512_and_pd`](fn._mm512_and_pd.html).
+        fn and_pd(a: __m512d, b: __m512d) -> __m512d = _mm512_and_pd;
+
+        /// See [`_mm512_and_ps`](fn._mm512_and_ps.html).
+        fn and_ps(a: __m512, b: __m512) -> __m512 = _mm512_and_ps;
+
+        /// See [`_mm512_andnot_pd`](fn._mm512_andnot_pd.html).
+        fn andnot_pd(a: __m512d, b: __m512d) -> __m512d = _mm512_andnot_pd;
+
+        /// See [`_mm512_andnot_ps`](fn._mm512_andnot_ps.html).
+        fn andnot_ps(a: __m512, b: __m512) -> __m512 = _mm512_andnot_ps;
+
+        /// See [`_mm512_cvtepi64_pd`](fn._mm512_cvtepi64_pd.html).
+        fn cvtepi64_pd(a: __m512i) -> __m512d = _mm512_cvtepi64_pd;
+
+        /// See [`_mm512_cvtepi64_ps`](fn._mm512_cvtepi64_ps.html).
+        fn cvtepi64_ps(a: __m512i) -> __m256 = _mm512_cvtepi64_ps;
+
+        /// See [`_mm512_cvtepu64_pd`](fn._mm512_cvtepu64_pd.html).
+        fn cvtepu64_pd(a: __m512i) -> __m512d = _mm512_cvtepu64_pd;
+
+        /// See [`_mm512_cvtepu64_ps`](fn._mm512_cvtepu64_ps.html).
+        fn cvtepu64_ps(a: __m512i) -> __m256 = _mm512_cvtepu64_ps;
+
+        /// See [`_mm512_cvtpd_epi64`](fn._mm512_cvtpd_epi64.html).
+        fn cvtpd_epi64(a: __m512d) -> __m512i = _mm512_cvtpd_epi64;
+
+        /// See [`_mm512_cvtpd_epu64`](fn._mm512_cvtpd_epu64.html).
+        fn cvtpd_epu64(a: __m512d) -> __m512i = _mm512_cvtpd_epu64;
+
+        /// See [`_mm512_cvttpd_epi64`](fn._mm512_cvttpd_epi64.html).
+        fn cvttpd_epi64(a: __m512d) -> __m512i = _mm512_cvttpd_epi64;
+
+        /// See [`_mm512_cvttpd_epu64`](fn._mm512_cvttpd_epu64.html).
+        fn cvttpd_epu64(a: __m512d) -> __m512i = _mm512_cvttpd_epu64;
+
+        /// See [`_kand_mask8`](fn._kand_mask8.html).
+        fn kand_mask8(a: __mmask8, b: __mmask8) -> __mmask8 = _kand_mask8;
+
+        /// See [`_kandn_mask8`](fn._kandn_mask8.html).
+        fn kandn_mask8(a: __mmask8, b: __mmask8) -> __mmask8 = _kandn_mask8;
+
+        /// See [`_knot_mask8`](fn._knot_mask8.html).
+        fn knot_mask8(a: __mmask8) -> __mmask8 = _knot_mask8;
+
+        /// See [`_kor_mask8`](fn._kor_mask8.html).
+        fn kor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 = _kor_mask8;
+
+        /// See [`_kortestc_mask8_u8`](fn._kortestc_mask8_u8.html).
+        fn kortestc_mask8_u8(a: __mmask8, b: __mmask8)
+            -> u8 = _kortestc_mask8_u8;
+
+        /// See [`_kortestz_mask8_u8`](fn._kortestz_mask8_u8.html).
+        fn kortestz_mask8_u8(a: __mmask8, b: __mmask8)
+            -> u8 = _kortestz_mask8_u8;
+
+        /// See [`_kxnor_mask8`](fn._kxnor_mask8.html).
+        fn kxnor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 = _kxnor_mask8;
+
+        /// See [`_kxor_mask8`](fn._kxor_mask8.html).
+        fn kxor_mask8(a: __mmask8, b: __mmask8) -> __mmask8 = _kxor_mask8;
+
+        /// See [`_mm512_movepi32_mask`](fn._mm512_movepi32_mask.html).
+        fn movepi32_mask(a: __m512i) -> __mmask16 = _mm512_movepi32_mask;
+
+        /// See [`_mm512_movepi64_mask`](fn._mm512_movepi64_mask.html).
+        fn movepi64_mask(a: __m512i) -> __mmask8 = _mm512_movepi64_mask;
+
+        /// See [`_mm512_movm_epi32`](fn._mm512_movm_epi32.html).
+        fn movm_epi32(k: __mmask16) -> __m512i = _mm512_movm_epi32;
+
+        /// See [`_mm512_movm_epi64`](fn._mm512_movm_epi64.html).
+        fn movm_epi64(k: __mmask8) -> __m512i = _mm512_movm_epi64;
+
+        /// See [`_mm512_mullo_epi64`](fn._mm512_mullo_epi64.html).
+        fn mullo_epi64(a: __m512i, b: __m512i) -> __m512i = _mm512_mullo_epi64;
+
+        /// See [`_mm512_or_pd`](fn._mm512_or_pd.html).
+        fn or_pd(a: __m512d, b: __m512d) -> __m512d = _mm512_or_pd;
+
+        /// See [`_mm512_or_ps`](fn._mm512_or_ps.html).
+        fn or_ps(a: __m512, b: __m512) -> __m512 = _mm512_or_ps;
+
+        /// See [`_mm512_xor_pd`](fn._mm512_xor_pd.html).
+        fn xor_pd(a: __m512d, b: __m512d) -> __m512d = _mm512_xor_pd;
+
+        /// See [`_mm512_xor_ps`](fn._mm512_xor_ps.html).
+        fn xor_ps(a: __m512, b: __m512) -> __m512 = _mm512_xor_ps;
+    }
+}
+
+token_methods! {
+    Avx512ifma = "avx512ifma" {
+        /// See [`_mm512_madd52hi_epu64`](fn._mm512_madd52hi_epu64.html).
+        fn madd52hi_epu64(a: __m512i, b: __m512i, c: __m512i)
+            -> __m512i = _mm512_madd52hi_epu64;
+
+        /// See [`_mm512_madd52lo_epu64`](fn._mm512_madd52lo_epu64.html).
+        fn madd52lo_epu64(a: __m512i, b: __m512i, c: __m512i)
+            -> __m512i = _mm512_madd52lo_epu64;
+    }
+}
+
+token_methods! {
+    Avx512vbmi = "avx512vbmi" {
+        /// See `_mm512_multishift_epi64_epi8`.
+        fn multishift_epi64_epi8(a: __m512i, b: __m512i)
+            -> __m512i = _mm512_multishift_epi64_epi8;
+
+        /// See [`_mm512_permutex2var_epi8`](fn._mm512_permutex2var_epi8.html).
+        fn permutex2var_epi8(a: __m512i, idx: __m512i, b: __m512i)
+            -> __m512i = _mm512_permutex2var_epi8;
+
+        /// See [`_mm512_permutexvar_epi8`](fn._mm512_permutexvar_epi8.html).
+        fn permutexvar_epi8(idx: __m512i, a: __m512i)
+            -> __m512i = _mm512_permutexvar_epi8;
+    }
+}
+
+token_methods! {
+    Avx512vpopcntdq = "avx512vpopcntdq" {
+        /// See [`_mm512_popcnt_epi32`](fn._mm512_popcnt_epi32.html).
+        fn popcnt_epi32(a: __m512i) -> __m512i = _mm512_popcnt_epi32;
+
+        /// See [`_mm512_popcnt_epi64`](fn._mm512_popcnt_epi64.html).
+        fn popcnt_epi64(a: __m512i) -> __m512i = _mm512_popcnt_epi64;
+    }
+}
+
+token_methods! {
+    Bmi = "bmi" {
+        /// See [`_andn_u32`](fn._andn_u32.html).
+        fn andn_u32(a: u32, b: u32) -> u32 = _andn_u32;
+
+        /// See [`_andn_u64`](fn._andn_u64.html).
+        fn andn_u64(a: u64, b: u64) -> u64 = _andn_u64;
+
+        /// See [`_bextr2_u32`](fn._bextr2_u32.html).
+        fn bextr2_u32(a: u32, control: u32) -> u32 = _bextr2_u32;
+
+        /// See [`_bextr2_u64`](fn._bextr2_u64.html).
+        fn bextr2_u64(a: u64, control: u64) -> u64 = _bextr2_u64;
+
+        /// See [`_bextr_u32`](fn._bextr_u32.html).
+        fn bextr_u32(a: u32, start: u32, len: u32) -> u32 = _bextr_u32;
+
+        /// See [`_bextr_u64`](fn._bextr_u64.html).
+        fn bextr_u64(a: u64, start: u32, len: u32) -> u64 = _bextr_u64;
+
+        /// See [`_blsi_u32`](fn._blsi_u32.html).
+        fn blsi_u32(x: u32) -> u32 = _blsi_u32;
+
+        /// See [`_blsi_u64`](fn._blsi_u64.html).
+        fn blsi_u64(x: u64) -> u64 = _blsi_u64;
+
+        /// See [`_blsmsk_u32`](fn._blsmsk_u32.html).
+        fn blsmsk_u32(x: u32) -> u32 = _blsmsk_u32;
+
+        /// See [`_blsmsk_u64`](fn._blsmsk_u64.html).
+        fn blsmsk_u64(x: u64) -> u64 = _blsmsk_u64;
+
+        /// See [`_blsr_u32`](fn._blsr_u32.html).
+        fn blsr_u32(x: u32) -> u32 = _blsr_u32;
+
+        /// See [`_blsr_u64`](fn._blsr_u64.html).
+        fn blsr_u64(x: u64) -> u64 = _blsr_u64;
+
+        /// See [`_mm_tzcnt_32`](fn._mm_tzcnt_32.html).
+        fn tzcnt_32(x: u32) -> i32 = _mm_tzcnt_32;
+
+        /// See [`_mm_tzcnt_64`](fn._mm_tzcnt_64.html).
+        fn tzcnt_64(x: u64) -> i64 = _mm_tzcnt_64;
+
+        /// See [`_tzcnt_u16`](fn._tzcnt_u16.html).
+        fn tzcnt_u16(x: u16) -> u16 = _tzcnt_u16;
+
+        /// See [`_tzcnt_u32`](fn._tzcnt_u32.html).
+        fn tzcnt_u32(x: u32) -> u32 = _tzcnt_u32;
+
+        /// See [`_tzcnt_u64`](fn._tzcnt_u64.html).
+        fn tzcnt_u64(x: u64) -> u64 = _tzcnt_u64;
+    }
+}
+
+token_methods! {
+    Bmi2 = "bmi2" {
+        /// See [`_bzhi_u32`](fn._bzhi_u32.html).
+        fn bzhi_u32(a: u32, index: u32) -> u32 = _bzhi_u32;
+
+        /// See [`_bzhi_u64`](fn._bzhi_u64.html).
+        fn bzhi_u64(a: u64, index: u32) -> u64 = _bzhi_u64;
+
+        /// See [`_pdep_u32`](fn._pdep_u32.html).
+        fn pdep_u32(a: u32, mask: u32) -> u32 = _pdep_u32;
+
+        /// See [`_pdep_u64`](fn._pdep_u64.html).
+        fn pdep_u64(a: u64, mask: u64) -> u64 = _pdep_u64;
+
+        /// See [`_pext_u32`](fn._pext_u32.html).
+        fn pext_u32(a: u32, mask: u32) -> u32 = _pext_u32;
+
+        /// See [`_pext_u64`](fn._pext_u64.html).
+        fn pext_u64(a: u64, mask: u64) -> u64 = _pext_u64;
+    }
+}
+
+token_methods! {
+    Lzcnt = "lzcnt" {
+        /// See [`_lzcnt_u32`](fn._lzcnt_u32.html).
+        fn lzcnt_u32(x: u32) -> u32 = _lzcnt_u32;
+
+        /// See [`_lzcnt_u64`](fn._lzcnt_u64.html).
+        fn lzcnt_u64(x: u64) -> u64 = _lzcnt_u64;
+    }
+}
+
+token_methods! {
+    Popcnt = "popcnt" {
+        /// See [`_popcnt32`](fn._popcnt32.html).
+        fn popcnt32(x: i32) -> i32 = _popcnt32;
+
+        /// See [`_popcnt64`](fn._popcnt64.html).
+        fn popcnt64(x: i64) -> i32 = _popcnt64;
+
+        /// See [`_mm_popcnt_u32`](fn._mm_popcnt_u32.html).
+        fn popcnt_u32(x: u32) -> i32 = _mm_popcnt_u32;
+
+        /// See [`_mm_popcnt_u64`](fn._mm_popcnt_u64.html).
+        fn popcnt_u64(x: u64) -> i64 = _mm_popcnt_u64;
+    }
+}
+
+#[cfg(not(feature = "intel_sde"))]
+token_methods! {
+    Tbm = "tbm" {
+        /// See [`_blcfill_u32`](fn._blcfill_u32.html).
+        fn blcfill_u32(x: u32) -> u32 = _blcfill_u32;
+
+        /// See [`_blcfill_u64`](fn._blcfill_u64.html).
+        #[cfg(not(target_arch = "x86"))]
+        fn blcfill_u64(x: u64) -> u64 = _blcfill_u64;
+
+        /// See [`_blci_u32`](fn._blci_u32.html).
+        fn blci_u32(x: u32) -> u32 = _blci_u32;
+
+        /// See [`_blci_u64`](fn._blci_u64.html).
+        #[cfg(not(target_arch = "x86"))]
+        fn blci_u64(x: u64) -> u64 = _blci_u64;
+
+        /// See [`_blcic_u32`](fn._blcic_u32.html).
+        fn blcic_u32(x: u32) -> u32 = _blcic_u32;
+
+        /// See [`_blcic_u64`](fn._blcic_u64.html).
+        #[cfg(not(target_arch = "x86"))]
+        fn blcic_u64(x: u64) -> u64 = _blcic_u64;
+
+        /// See [`_blcmsk_u32`](fn._blcmsk_u32.html).
+        fn blcmsk_u32(x: u32) -> u32 = _blcmsk_u32;
+
+        /// See [`_blcmsk_u64`](fn._blcmsk_u64.html).
+        #[cfg(not(target_arch = "x86"))]
+        fn blcmsk_u64(x: u64) -> u64 = _blcmsk_u64;
+
+        /// See [`_blcs_u32`](fn._blcs_u32.html).
+        fn blcs_u32(x: u32) -> u32 = _blcs_u32;
+
+        /// See [`_blcs_u64`](fn._blcs_u64.html).
+        #[cfg(not(target_arch = "x86"))]
+        fn blcs_u64(x: u64) -> u64 = _blcs_u64;
+
+        /// See [`_blsfill_u32`](fn._blsfill_u32.html).
+        fn blsfill_u32(x: u32) -> u32 = _blsfill_u32;
+
+        /// See [`_blsfill_u64`](fn._blsfill_u64.html).
+        #[cfg(not(target_arch = "x86"))]
+        fn blsfill_u64(x: u64) -> u64 = _blsfill_u64;
+
+        /// See [`_blsic_u32`](fn._blsic_u32.html).
+        fn blsic_u32(x: u32) -> u32 = _blsic_u32;
+
+        /// See [`_blsic_u64`](fn._blsic_u64.html).
+        #[cfg(not(target_arch = "x86"))]
+        fn blsic_u64(x: u64) -> u64 = _blsic_u64;
+
+        /// See [`_t1mskc_u32`](fn._t1mskc_u32.html).
+        fn t1mskc_u32(x: u32) -> u32 = _t1mskc_u32;
+
+        /// See [`_t1mskc_u64`](fn._t1mskc_u64.html).
+        #[cfg(not(target_arch = "x86"))]
+        fn t1mskc_u64(x: u64) -> u64 = _t1mskc_u64;
+
+        /// See [`_tzmsk_u32`](fn._tzmsk_u32.html).
+        fn tzmsk_u32(x: u32) -> u32 = _tzmsk_u32;
+
+        /// See [`_tzmsk_u64`](fn._tzmsk_u64.html).
+        #[cfg(not(target_arch = "x86"))]
+        fn tzmsk_u64(x: u64) -> u64 = _tzmsk_u64;
+    }
+}
+
+token_methods! {
+    Aes = "aes" {
+        /// See [`_mm_aesdec_si128`](fn._mm_aesdec_si128.html).
+        fn aesdec_si128(a: __m128i, round_key: __m128i)
+            -> __m128i = _mm_aesdec_si128;
+
+        /// See [`_mm_aesdeclast_si128`](fn._mm_aesdeclast_si128.html).
+        fn aesdeclast_si128(a: __m128i, round_key: __m128i)
+            -> __m128i = _mm_aesdeclast_si128;
+
+        /// See [`_mm_aesenc_si128`](fn._mm_aesenc_si128.html).
+        fn aesenc_si128(a: __m128i, round_key: __m128i)
+            -> __m128i = _mm_aesenc_si128;
+
+        /// See [`_mm_aesenclast_si128`](fn._mm_aesenclast_si128.html).
+        fn aesenclast_si128(a: __m128i, round_key: __m128i)
+            -> __m128i = _mm_aesenclast_si128;
+
+        /// See [`_mm_aesimc_si128`](fn._mm_aesimc_si128.html).
+        fn aesimc_si128(a: __m128i) -> __m128i = _mm_aesimc_si128;
+    }
+}
+
+token_methods! {
+    Sha = "sha" {
+        /// See [`_mm_sha1msg1_epu32`](fn._mm_sha1msg1_epu32.html).
+        fn sha1msg1_epu32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_sha1msg1_epu32;
+
+        /// See [`_mm_sha1msg2_epu32`](fn._mm_sha1msg2_epu32.html).
+        fn sha1msg2_epu32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_sha1msg2_epu32;
+
+        /// See [`_mm_sha1nexte_epu32`](fn._mm_sha1nexte_epu32.html).
+        fn sha1nexte_epu32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_sha1nexte_epu32;
+
+        /// See [`_mm_sha256msg1_epu32`](fn._mm_sha256msg1_epu32.html).
+        fn sha256msg1_epu32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_sha256msg1_epu32;
+
+        /// See [`_mm_sha256msg2_epu32`](fn._mm_sha256msg2_epu32.html).
+        fn sha256msg2_epu32(a: __m128i, b: __m128i)
+            -> __m128i = _mm_sha256msg2_epu32;
+
+        /// See [`_mm_sha256rnds2_epu32`](fn._mm_sha256rnds2_epu32.html).
+        fn sha256rnds2_epu32(a: __m128i, b: __m128i, k: __m128i)
+            -> __m128i = _mm_sha256rnds2_epu32;
+    }
+}
+
+token_methods! {
+    Gfni = "gfni" {
+        /// See [`_mm_gf2p8mul_epi8`](fn._mm_gf2p8mul_epi8.html).
+        fn gf2p8mul_epi8(a: __m128i, b: __m128i)
+            -> __m128i = _mm_gf2p8mul_epi8;
+    }
+}
+
+token_methods! {
+    Vaes = "vaes" {
+        /// See [`_mm256_aesdec_epi128`](fn._mm256_aesdec_epi128.html).
+        fn aesdec_epi128(a: __m256i, round_key: __m256i)
+            -> __m256i = _mm256_aesdec_epi128;
+
+        /// See [`_mm256_aesdeclast_epi128`](fn._mm256_aesdeclast_epi128.html).
+        fn aesdeclast_epi128(a: __m256i, round_key: __m256i)
+            -> __m256i = _mm256_aesdeclast_epi128;
+
+        /// See [`_mm256_aesenc_epi128`](fn._mm256_aesenc_epi128.html).
+        fn aesenc_epi128(a: __m256i, round_key: __m256i)
+            -> __m256i = _mm256_aesenc_epi128;
+
+        /// See [`_mm256_aesenclast_epi128`](fn._mm256_aesenclast_epi128.html).
+        fn aesenclast_epi128(a: __m256i, round_key: __m256i)
+            -> __m256i = _mm256_aesenclast_epi128;
+    }
+}
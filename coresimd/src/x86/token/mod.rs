@@ -0,0 +1,173 @@
+//! Target feature tokens.
+//!
+//! Calling an intrinsic on a CPU that does not support its target feature is
+//! undefined behavior, which is why the intrinsics are `unsafe fn`s. A token
+//! is a zero-sized proof that a target feature is available: it can only be
+//! obtained through run-time detection with `detect`, or with `new` when the
+//! feature is enabled at compile-time. Holding a token makes it safe to call
+//! the intrinsics of its feature, which are exposed as safe methods:
+//!
+//! ```rust
+//! # #![feature(cfg_target_feature)]
+//! # #![feature(target_feature)]
+//! #
+//! # #[macro_use] extern crate stdsimd;
+//! #
+//! # fn main() {
+//! #   use stdsimd::vendor::*;
+//! #
+//! if let Some(avx2) = Avx2::detect() {
+//!     let a = avx2.set1_epi32(1);
+//!     let b = avx2.add_epi32(a, a);
+//!     let eq = avx2.cmpeq_epi32(b, avx2.set1_epi32(2));
+//!     assert_eq!(avx2.movemask_epi8(eq), -1);
+//! }
+//! # }
+//! ```
+//!
+//! A token of a feature converts into the tokens of the features it implies,
+//! for example `Sse2::from(avx2)`.
+//!
+//! A method is named after its intrinsic without the widest vector prefix
+//! of its token's intrinsics: `_mm256_` for `Avx`, `Avx2`, `Fma` and the
+//! other tokens with 256-bit intrinsics, `_mm512_` for the AVX-512 tokens,
+//! and `_mm_` otherwise. The narrower intrinsics of these tokens keep their
+//! prefix without the leading underscore. For example, `_mm_add_epi32` is
+//! `Sse2::add_epi32`, `_mm256_fmadd_ps` is `Fma::fmadd_ps` and
+//! `_mm_fmadd_ps` is `Fma::mm_fmadd_ps`. Intrinsics without a vector prefix
+//! only lose their leading underscore, like `Bmi::bextr_u32` for
+//! `_bextr_u32`, and the `_MM_` macros are lowercased, like
+//! `Sse::get_rounding_mode` for `_MM_GET_ROUNDING_MODE`.
+//!
+//! The methods cover the intrinsics that require a single target feature and
+//! that neither access memory through pointers nor take immediate arguments.
+//! Intrinsics with an immediate argument, like `_mm_shuffle_ps`,
+//! `_mm_blend_epi16`, `_mm_extract_epi16` or `_mm256_permute2f128_ps`, have
+//! no method because a method argument cannot be required to be a constant.
+//! The shifts by an immediate count, like `_mm_slli_epi32`, are not among
+//! them: they also accept a count that is only known at run-time, so they
+//! are methods.
+//! The intrinsics that take or return `__m64` have no method either: they
+//! leave the x87 registers tagged as in use until `_mm_empty` is called,
+//! which corrupts any x87 arithmetic in between, so the `Mmx` token has no
+//! methods. While holding the token all of these can still be called in an
+//! `unsafe` block.
+//!
+//! Each method calls its intrinsic from an inner `#[target_feature]`
+//! function, so the intrinsic is compiled with the feature enabled even when
+//! the caller is not.
+
+mod methods;
+
+tokens! {
+    /// `mmx` target feature token.
+    Mmx = "mmx" => [];
+    /// `sse` target feature token.
+    Sse = "sse" => [];
+    /// `sse2` target feature token.
+    Sse2 = "sse2" => [Sse];
+    /// `sse3` target feature token.
+    Sse3 = "sse3" => [Sse2, Sse];
+    /// `ssse3` target feature token.
+    Ssse3 = "ssse3" => [Sse3, Sse2, Sse];
+    /// `sse4.1` target feature token.
+    Sse41 = "sse4.1" => [Ssse3, Sse3, Sse2, Sse];
+    /// `sse4.2` target feature token.
+    Sse42 = "sse4.2" => [Sse41, Ssse3, Sse3, Sse2, Sse];
+    /// `avx` target feature token.
+    Avx = "avx" => [Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    /// `avx2` target feature token.
+    Avx2 = "avx2" => [Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    /// `fma` target feature token.
+    Fma = "fma" => [Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    /// `f16c` target feature token.
+    F16c = "f16c" => [Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    /// `avx512f` target feature token.
+    Avx512f = "avx512f" => [
+        Avx2, Fma, F16c, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse
+    ];
+    /// `avx512bw` target feature token.
+    Avx512bw = "avx512bw" => [
+        Avx512f, Avx2, Fma, F16c, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse
+    ];
+    /// `avx512cd` target feature token.
+    Avx512cd = "avx512cd" => [
+        Avx512f, Avx2, Fma, F16c, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse
+    ];
+    /// `avx512dq` target feature token.
+    Avx512dq = "avx512dq" => [
+        Avx512f, Avx2, Fma, F16c, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse
+    ];
+    /// `avx512ifma` target feature token.
+    Avx512ifma = "avx512ifma" => [
+        Avx512f, Avx2, Fma, F16c, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse
+    ];
+    /// `avx512vbmi` target feature token.
+    Avx512vbmi = "avx512vbmi" => [
+        Avx512f, Avx2, Fma, F16c, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse
+    ];
+    /// `avx512vpopcntdq` target feature token.
+    Avx512vpopcntdq = "avx512vpopcntdq" => [
+        Avx512f, Avx2, Fma, F16c, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse
+    ];
+    /// `bmi` target feature token.
+    Bmi = "bmi" => [];
+    /// `bmi2` target feature token.
+    Bmi2 = "bmi2" => [];
+    /// `lzcnt` target feature token.
+    Lzcnt = "lzcnt" => [];
+    /// `popcnt` target feature token.
+    Popcnt = "popcnt" => [];
+    /// `aes` target feature token.
+    Aes = "aes" => [Sse2, Sse];
+    /// `sha` target feature token.
+    Sha = "sha" => [Sse2, Sse];
+    /// `gfni` target feature token.
+    Gfni = "gfni" => [Sse2, Sse];
+    /// `vaes` target feature token.
+    Vaes = "vaes" => [Aes, Avx, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+}
+
+#[cfg(not(feature = "intel_sde"))]
+tokens! {
+    /// `sse4a` target feature token.
+    Sse4a = "sse4a" => [Sse3, Sse2, Sse];
+    /// `fma4` target feature token.
+    Fma4 = "fma4" => [Avx, Sse4a, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse];
+    /// `xop` target feature token.
+    Xop = "xop" => [
+        Fma4, Avx, Sse4a, Sse42, Sse41, Ssse3, Sse3, Sse2, Sse
+    ];
+    /// `tbm` target feature token.
+    Tbm = "tbm" => [];
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::*;
+
+    #[simd_test = "avx2"]
+    unsafe fn avx2_detect() {
+        let avx2 = Avx2::detect().unwrap();
+        let a = avx2.set1_epi32(1);
+        let r = avx2.add_epi32(a, a);
+        assert_eq_m256i(r, _mm256_set1_epi32(2));
+    }
+
+    #[simd_test = "avx2"]
+    unsafe fn avx2_implies_sse2() {
+        let sse2 = Sse2::from(Avx2::detect().unwrap());
+        let a = sse2.set1_epi32(1);
+        assert_eq_m128i(sse2.add_epi32(a, a), _mm_set1_epi32(2));
+    }
+
+    #[test]
+    #[cfg(target_feature = "sse2")]
+    fn sse2_new() {
+        let sse2 = Sse2::new();
+        let a = sse2.set_epi32(3, 2, 1, 0);
+        assert_eq!(sse2.cvtsi128_si32(a), 0);
+    }
+}
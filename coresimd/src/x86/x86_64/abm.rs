@@ -19,6 +19,14 @@ pub unsafe fn _popcnt64(x: i64) -> i32 {
     x.count_ones() as i32
 }
 
+/// Counts the bits that are set.
+#[inline]
+#[target_feature(enable = "popcnt")]
+#[cfg_attr(test, assert_instr(popcnt))]
+pub unsafe fn _mm_popcnt_u64(x: u64) -> i64 {
+    x.count_ones() as i64
+}
+
 #[cfg(test)]
 mod tests {
     use stdsimd_test::simd_test;
@@ -34,4 +42,10 @@ mod tests {
     unsafe fn test_popcnt64() {
         assert_eq!(_popcnt64(0b0101_1010), 4);
     }
+
+    #[simd_test = "popcnt"]
+    unsafe fn test_mm_popcnt_u64() {
+        assert_eq!(_mm_popcnt_u64(0b0101_1010), 4);
+        assert_eq!(_mm_popcnt_u64(!0), 64);
+    }
 }
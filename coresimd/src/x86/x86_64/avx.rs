@@ -8,6 +8,7 @@ use x86::*;
 #[inline]
 #[target_feature(enable = "avx")]
 // This intrinsic has no corresponding instruction.
+#[rustc_args_required_const(2)]
 pub unsafe fn _mm256_insert_epi64(a: __m256i, i: i64, index: i32) -> __m256i {
     mem::transmute(simd_insert(a.as_i64x4(), (index as u32) & 3, i))
 }
@@ -5,6 +5,7 @@ use x86::*;
 #[inline]
 #[target_feature(enable = "avx2")]
 // This intrinsic has no corresponding instruction.
+#[rustc_args_required_const(1)]
 pub unsafe fn _mm256_extract_epi64(a: __m256i, imm8: i32) -> i64 {
     let imm8 = (imm8 & 3) as u32;
     simd_extract(a.as_i64x4(), imm8)
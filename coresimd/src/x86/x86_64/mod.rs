@@ -32,8 +32,14 @@ pub use self::bmi::*;
 mod bmi2;
 pub use self::bmi2::*;
 
+mod adx;
+pub use self::adx::*;
+
 mod avx2;
 pub use self::avx2::*;
 
 mod bswap;
 pub use self::bswap::*;
+
+mod rdrand;
+pub use self::rdrand::*;
@@ -0,0 +1,59 @@
+//! RDRAND and RDSEED instructions for returning random numbers from an Intel
+//! on-chip hardware random number generator which has been seeded by an
+//! on-chip entropy source.
+
+#[cfg(test)]
+use stdsimd_test::assert_instr;
+
+extern "unadjusted" {
+    #[link_name = "llvm.x86.rdrand.64"]
+    fn x86_rdrand64_step() -> (u64, i32);
+    #[link_name = "llvm.x86.rdseed.64"]
+    fn x86_rdseed64_step() -> (u64, i32);
+}
+
+/// Read a hardware generated 64-bit random value and store the result in val.
+/// Return 1 if a random value was generated, and 0 otherwise.
+#[inline]
+#[target_feature(enable = "rdrand")]
+#[cfg_attr(test, assert_instr(rdrand))]
+#[cfg(not(target_arch = "x86"))]
+pub unsafe fn _rdrand64_step(val: &mut u64) -> i32 {
+    let (v, flag) = x86_rdrand64_step();
+    *val = v;
+    flag
+}
+
+/// Read a 64-bit NIST SP800-90B and SP800-90C compliant random value and store
+/// in val. Return 1 if a random value was generated, and 0 otherwise.
+#[inline]
+#[target_feature(enable = "rdseed")]
+#[cfg_attr(test, assert_instr(rdseed))]
+#[cfg(not(target_arch = "x86"))]
+pub unsafe fn _rdseed64_step(val: &mut u64) -> i32 {
+    let (v, flag) = x86_rdseed64_step();
+    *val = v;
+    flag
+}
+
+#[cfg(test)]
+mod tests {
+    use stdsimd_test::simd_test;
+
+    use x86::i586::retry;
+    use x86::*;
+
+    #[simd_test = "rdrand"]
+    unsafe fn test_rdrand64_step() {
+        let a = retry(!0, |v| _rdrand64_step(v));
+        let b = retry(!0, |v| _rdrand64_step(v));
+        assert_ne!(a, b);
+    }
+
+    #[simd_test = "rdseed"]
+    unsafe fn test_rdseed64_step() {
+        let a = retry(!0, |v| _rdseed64_step(v));
+        let b = retry(!0, |v| _rdseed64_step(v));
+        assert_ne!(a, b);
+    }
+}
@@ -141,6 +141,9 @@ pub use coresimd::__unstable_detect_feature;
 pub mod vendor {
     #[doc(inline)]
     pub use coresimd::vendor::*;
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub use x86::*;
 }
 
 /// Run-time feature detection.
@@ -167,6 +170,9 @@ pub mod simd {
                   target_arch = "powerpc64"))))]
 mod runtime;
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86;
+
 /// Error gracefully in architectures without run-time detection support.
 #[cfg(not(any(target_arch = "x86", target_arch = "x86_64",
               all(target_os = "linux",
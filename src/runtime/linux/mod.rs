@@ -17,7 +17,7 @@ mod auxv;
 mod cpuinfo;
 
 /// Detects CPU features:
-pub fn detect_features() -> usize {
+pub fn detect_features() -> u64 {
     // Try to read the ELF Auxiliary Vector using libc's getauxval:
     if let Ok(v) = auxv::libc::auxv() {
         return arch::detect_features(v);
@@ -0,0 +1,4 @@
+//! `x86` and `x86_64` additions that require `std`.
+
+mod xsave;
+pub use self::xsave::*;
@@ -0,0 +1,364 @@
+//! Safe XSAVE areas.
+//!
+//! The `_xsave`, `_xrstor` and `_xsavec` intrinsics of `coresimd` operate on
+//! raw pointers to a 64-byte aligned area whose size depends on the host CPU
+//! and on the saved state components. [`XSaveArea`](struct.XSaveArea.html)
+//! sizes that area from CPUID leaf `0xD` and exposes its layout.
+//!
+//! The format of the XSAVE area is detailed in Section 13.4, “XSAVE Area,” of
+//! Intel® 64 and IA-32 Architectures Software Developer’s Manual, Volume 1.
+
+use std::{cmp, fmt};
+
+use coresimd::vendor::{__cpuid_count, _xgetbv};
+// The 64-bit forms also save the 64-bit x87 instruction and data pointers.
+#[cfg(target_arch = "x86")]
+use coresimd::vendor::{_xrstor, _xsave, _xsavec};
+#[cfg(target_arch = "x86_64")]
+use coresimd::vendor::{_xrstor64 as _xrstor, _xsave64 as _xsave,
+                       _xsavec64 as _xsavec};
+
+/// Alignment of an XSAVE area.
+const ALIGN: usize = 64;
+/// Size of the legacy region, which holds the x87 and SSE state.
+const LEGACY_SIZE: usize = 512;
+/// Size of the legacy region and of the XSAVE header.
+const HEADER_END: usize = LEGACY_SIZE + 64;
+/// Offset of the `XSTATE_BV` field of the XSAVE header.
+const XSTATE_BV: usize = LEGACY_SIZE;
+/// Offset of the `XCOMP_BV` field of the XSAVE header.
+const XCOMP_BV: usize = LEGACY_SIZE + 8;
+/// Bit of `XCOMP_BV` that is set for areas in the compacted format.
+const COMPACTED: u64 = 1 << 63;
+/// CPUID leaf that enumerates the XSAVE state components.
+const XSAVE_LEAF: u32 = 0xd;
+
+/// Layout of an extended state component, as enumerated by CPUID.
+struct Component {
+    size: usize,
+    offset: usize,
+    aligned: bool,
+}
+
+/// Returns the layout of the extended state component `i`, `i >= 2`.
+fn component(i: u32) -> Component {
+    // This is safe because `xsave` implies `cpuid` with leaf `0xD`.
+    let r = unsafe { __cpuid_count(XSAVE_LEAF, i) };
+    Component {
+        size: r.eax as usize,
+        offset: r.ebx as usize,
+        aligned: r.ecx & 0b10 != 0,
+    }
+}
+
+/// Returns the offset of the extended state component `i` in the compacted
+/// format of `mask`, or the size of that format if `i` is `64`.
+fn compacted_offset(mask: u64, i: u32) -> usize {
+    let align = |offset: usize| (offset + ALIGN - 1) & !(ALIGN - 1);
+    let mut offset = HEADER_END;
+    for j in 2..i {
+        if mask & (1 << j) == 0 {
+            continue;
+        }
+        let c = component(j);
+        if c.aligned {
+            offset = align(offset);
+        }
+        offset += c.size;
+    }
+    if i < 64 && component(i).aligned {
+        offset = align(offset);
+    }
+    offset
+}
+
+/// A 64-byte aligned XSAVE area for a set of state components.
+///
+/// State component `i` is selected by bit `i` of the component mask: `0` is
+/// the x87 state, `1` the SSE state, `2` the upper halves of the AVX
+/// registers, and so on. The area is large enough for both the standard and
+/// the compacted format of the requested components.
+///
+/// ```rust
+/// # #![feature(cfg_target_feature)]
+/// # #![feature(target_feature)]
+/// #
+/// # #[macro_use] extern crate stdsimd;
+/// #
+/// # fn main() {
+/// #   use stdsimd::vendor::*;
+/// #
+/// // Save the x87 and SSE state.
+/// if let Some(mut area) = XSaveArea::new(0b11) {
+///     area.save();
+///     assert_eq!(area.component_offset(1), Some(160));
+/// }
+/// # }
+/// ```
+pub struct XSaveArea {
+    buf: Vec<u8>,
+    start: usize,
+    size: usize,
+    mask: u64,
+}
+
+impl XSaveArea {
+    /// Returns an area for the state components selected by `mask`.
+    ///
+    /// Returns `None` if the host CPU does not support `xsave`, or if `mask`
+    /// selects a component that the operating system has not enabled in
+    /// `XCR0`.
+    pub fn new(mask: u64) -> Option<XSaveArea> {
+        if !cfg_feature_enabled!("xsave") {
+            return None;
+        }
+        // This is safe because the CPU supports `xsave`.
+        let xcr0 = unsafe { _xgetbv(0) };
+        if mask & !xcr0 != 0 {
+            return None;
+        }
+
+        let mut size = HEADER_END;
+        for i in 2..64 {
+            if mask & (1 << i) != 0 {
+                let c = component(i);
+                size = cmp::max(size, c.offset + c.size);
+            }
+        }
+        if cfg_feature_enabled!("xsavec") {
+            size = cmp::max(size, compacted_offset(mask, 64));
+        }
+
+        // Moving a `Vec` does not move its contents, so `start` stays
+        // aligned.
+        let buf = vec![0; size + ALIGN - 1];
+        let start = (ALIGN - buf.as_ptr() as usize % ALIGN) % ALIGN;
+        Some(XSaveArea {
+            buf,
+            start,
+            size,
+            mask,
+        })
+    }
+
+    /// Returns the state components of the area.
+    #[inline]
+    pub fn mask(&self) -> u64 {
+        self.mask
+    }
+
+    /// Returns the size of the area in bytes.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the contents of the area.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[self.start..self.start + self.size]
+    }
+
+    /// Saves the state components of the current thread with `XSAVE`, in the
+    /// standard format.
+    pub fn save(&mut self) {
+        // `XSAVE` does not write `XCOMP_BV`, clear a compacted format left
+        // by `save_compacted`.
+        let start = self.start + XCOMP_BV;
+        for b in &mut self.buf[start..start + 8] {
+            *b = 0;
+        }
+        unsafe { _xsave(self.as_mut_ptr(), self.mask) }
+    }
+
+    /// Saves the state components of the current thread with `XSAVEC`, in the
+    /// compacted format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the host CPU does not support `xsavec`.
+    pub fn save_compacted(&mut self) {
+        assert!(
+            cfg_feature_enabled!("xsavec"),
+            "the compacted format requires xsavec"
+        );
+        unsafe { _xsavec(self.as_mut_ptr(), self.mask) }
+    }
+
+    /// Restores the state components of the current thread with `XRSTOR`.
+    ///
+    /// Components that have not been saved, see
+    /// [`saved_components`](#method.saved_components), are restored to
+    /// their initial state.
+    ///
+    /// # Safety
+    ///
+    /// This replaces registers that the compiler may be using, like the
+    /// vector registers and `MXCSR`. The caller must ensure that nothing
+    /// relies on their current values, for example by only restoring when
+    /// switching between execution contexts.
+    #[inline(never)]
+    pub unsafe fn restore(&self) {
+        _xrstor(self.as_bytes().as_ptr(), self.mask)
+    }
+
+    /// Returns whether the area is in the compacted format.
+    #[inline]
+    pub fn is_compacted(&self) -> bool {
+        self.read_u64(XCOMP_BV) & COMPACTED != 0
+    }
+
+    /// Returns the state components that the last save stored, those that
+    /// were not in their initial state.
+    #[inline]
+    pub fn saved_components(&self) -> u64 {
+        self.read_u64(XSTATE_BV) & self.mask
+    }
+
+    /// Returns the offset of the state component `i` in the current format
+    /// of the area, or `None` if the area does not hold component `i`.
+    pub fn component_offset(&self, i: u32) -> Option<usize> {
+        if i >= 64 || self.mask & (1 << i) == 0 {
+            return None;
+        }
+        Some(match i {
+            0 => 0,
+            1 => 160,
+            _ if self.is_compacted() => compacted_offset(self.mask, i),
+            _ => component(i).offset,
+        })
+    }
+
+    /// Returns the size of the state component `i`, or `None` if the area
+    /// does not hold component `i`.
+    ///
+    /// The x87 and SSE components share the legacy region with other
+    /// fields, their sizes only cover their registers.
+    pub fn component_size(&self, i: u32) -> Option<usize> {
+        if i >= 64 || self.mask & (1 << i) == 0 {
+            return None;
+        }
+        Some(match i {
+            0 => 160,
+            1 => 256,
+            _ => component(i).size,
+        })
+    }
+
+    /// Returns the bytes of the state component `i`, or `None` if the area
+    /// does not hold component `i`.
+    pub fn component(&self, i: u32) -> Option<&[u8]> {
+        let offset = self.component_offset(i)?;
+        let size = self.component_size(i)?;
+        Some(&self.as_bytes()[offset..offset + size])
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.buf[self.start..].as_mut_ptr()
+    }
+
+    fn read_u64(&self, offset: usize) -> u64 {
+        self.as_bytes()[offset..offset + 8]
+            .iter()
+            .rev()
+            .fold(0, |v, &b| (v << 8) | u64::from(b))
+    }
+}
+
+impl fmt::Debug for XSaveArea {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("XSaveArea")
+            .field("mask", &self.mask)
+            .field("size", &self.size)
+            .field("compacted", &self.is_compacted())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vendor::*;
+
+    /// The x87 and SSE state components.
+    const LEGACY: u64 = 0b11;
+    /// The x87, SSE and AVX state components.
+    const AVX: u64 = 0b111;
+
+    #[test]
+    fn layout() {
+        let area = match XSaveArea::new(LEGACY) {
+            Some(area) => area,
+            None => return,
+        };
+        assert_eq!(area.as_bytes().as_ptr() as usize % 64, 0);
+        assert!(area.size() >= 576);
+        assert_eq!(area.component_offset(0), Some(0));
+        assert_eq!(area.component_offset(1), Some(160));
+        assert_eq!(area.component_offset(2), None);
+        assert!(XSaveArea::new(1 << 62).is_none());
+    }
+
+    #[test]
+    fn avx_component() {
+        let mut area = match XSaveArea::new(AVX) {
+            Some(area) => area,
+            None => return,
+        };
+        let offset = unsafe { __cpuid_count(0xd, 2).ebx as usize };
+        area.save();
+        assert!(!area.is_compacted());
+        assert_eq!(area.component_offset(2), Some(offset));
+        assert_eq!(area.component_size(2), Some(256));
+        assert_eq!(area.component(2).unwrap().len(), 256);
+        assert!(area.size() >= offset + 256);
+    }
+
+    #[test]
+    fn compacted() {
+        if !cfg_feature_enabled!("xsavec") {
+            return;
+        }
+        let mut area = match XSaveArea::new(AVX) {
+            Some(area) => area,
+            None => return,
+        };
+        area.save_compacted();
+        assert!(area.is_compacted());
+        // AVX directly follows the header in the compacted format.
+        assert_eq!(area.component_offset(2), Some(576));
+
+        area.save();
+        assert!(!area.is_compacted());
+    }
+
+    // Restoring clobbers callee-saved vector registers on Windows.
+    #[test]
+    #[cfg(not(windows))]
+    fn save_restore() {
+        let sse = match Sse::detect() {
+            Some(sse) => sse,
+            None => return,
+        };
+        let mut area = match XSaveArea::new(LEGACY) {
+            Some(area) => area,
+            None => return,
+        };
+        let saved = Mxcsr::current(sse);
+        let up = saved.with_rounding_mode(RoundingMode::Up);
+        {
+            let _guard = unsafe { MxcsrGuard::new(sse, up) };
+            area.save();
+        }
+        assert_eq!(area.saved_components() & 0b10, 0b10);
+        // `MXCSR` is stored at offset 24 of the legacy region.
+        let mxcsr = &area.as_bytes()[24..28];
+        assert_eq!(mxcsr, &[up.bits() as u8, (up.bits() >> 8) as u8, 0, 0]);
+
+        {
+            let _guard = unsafe { MxcsrGuard::new(sse, saved) };
+            unsafe { area.restore() };
+            assert_eq!(Mxcsr::current(sse), up);
+        }
+        assert_eq!(Mxcsr::current(sse), saved);
+    }
+}
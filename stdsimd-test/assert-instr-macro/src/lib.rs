@@ -7,6 +7,13 @@
 //! The procedural macro here is relatively simple, it simply appends a
 //! `#[test]` function to the original token stream which asserts that the
 //! function itself contains the relevant instruction.
+//!
+//! Arguments given a constant in the `#[assert_instr]` invocation are passed
+//! that constant by the test, the others are arguments of the test's shim.
+//! Arguments listed in the function's `#[rustc_args_required_const]`
+//! attribute therefore need a constant here or rustc rejects the shim. That
+//! no other argument is given a constant is checked for the x86 intrinsics by
+//! `stdsimd-verify`, which sees all of the function's attributes.
 
 #![feature(proc_macro)]
 
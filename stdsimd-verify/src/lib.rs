@@ -73,6 +73,8 @@ pub fn x86_functions(input: TokenStream) -> TokenStream {
                 }
             };
             let instrs = find_instrs(&f.attrs);
+            let required_const = find_required_const(&f.attrs);
+            let assert_instr_const = find_assert_instr_const(f);
             let target_feature = match find_target_feature(&f.attrs) {
                 Some(i) => my_quote! { Some(#i) },
                 None => my_quote! { None },
@@ -84,6 +86,8 @@ pub fn x86_functions(input: TokenStream) -> TokenStream {
                     ret: #ret,
                     target_feature: #target_feature,
                     instrs: &[#(stringify!(#instrs)),*],
+                    required_const: &[#(#required_const),*],
+                    assert_instr_const: &[#(#assert_instr_const),*],
                     file: stringify!(#path),
                 }
             }
@@ -95,6 +99,114 @@ pub fn x86_functions(input: TokenStream) -> TokenStream {
     ret.into()
 }
 
+#[proc_macro]
+pub fn x86_token_methods(input: TokenStream) -> TokenStream {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let root = dir.parent().unwrap();
+    let root = root.join("coresimd/src/x86/token");
+
+    // The features of the tokens declared with `tokens!`.
+    let mut features = Vec::new();
+    for tts in macro_invocations(&parse(&root.join("mod.rs")), "tokens") {
+        for w in tts.windows(3) {
+            if let Some((token, feature)) = find_token_feature(w) {
+                features.push((token, feature));
+            }
+        }
+    }
+    assert!(features.len() > 0);
+
+    // The intrinsic called by each method of `token_methods!`.
+    let mut methods = Vec::new();
+    let file = parse(&root.join("methods.rs"));
+    for tts in macro_invocations(&file, "token_methods") {
+        let (token, feature) =
+            find_token_feature(&tts).expect("malformed `token_methods!`");
+        if !features.contains(&(token.clone(), feature.clone())) {
+            panic!("`{}` is not the token of `{}`", token, feature);
+        }
+        let body = match tts.last().map(|tt| &tt.kind) {
+            Some(&proc_macro2::TokenNode::Group(_, ref body)) => body.clone(),
+            _ => panic!("missing methods of `{}`", token),
+        };
+        let body = body.into_iter().collect::<Vec<_>>();
+        for (i, tt) in body.iter().enumerate() {
+            match tt.kind {
+                proc_macro2::TokenNode::Term(t) if t.as_str() == "fn" => {}
+                _ => continue,
+            }
+            // `fn name(args) -> ret = intrinsic;`, where `args` is a single
+            // group so the first `=` precedes the intrinsic.
+            let intrinsic = body[i..]
+                .iter()
+                .position(|tt| match tt.kind {
+                    proc_macro2::TokenNode::Op('=', _) => true,
+                    _ => false,
+                })
+                .and_then(|j| body.get(i + j + 1))
+                .and_then(|tt| match tt.kind {
+                    proc_macro2::TokenNode::Term(t) => {
+                        Some(t.as_str().to_string())
+                    }
+                    _ => None,
+                })
+                .unwrap_or_else(|| panic!("malformed method of `{}`", token));
+            methods.push((feature.clone(), intrinsic));
+        }
+    }
+    assert!(methods.len() > 0);
+
+    let input = proc_macro2::TokenStream::from(input);
+
+    let methods = methods
+        .iter()
+        .map(|&(ref feature, ref intrinsic)| {
+            my_quote! { (#feature, #intrinsic) }
+        })
+        .collect::<Vec<_>>();
+
+    let ret = my_quote! { #input: &[(&str, &str)] = &[#(#methods),*]; };
+    ret.into()
+}
+
+/// Returns the token trees of the top-level `name!` invocations in `file`.
+fn macro_invocations(
+    file: &syn::File, name: &str,
+) -> Vec<Vec<proc_macro2::TokenTree>> {
+    file.items
+        .iter()
+        .filter_map(|item| match *item {
+            syn::Item::Macro(ref m) => Some(&m.mac),
+            _ => None,
+        })
+        .filter(|mac| extract_path_ident(&mac.path) == name)
+        .map(|mac| mac.tts.clone().into_iter().collect())
+        .collect()
+}
+
+/// Parses the `Token = "feature"` at the start of `tts`.
+fn find_token_feature(
+    tts: &[proc_macro2::TokenTree],
+) -> Option<(String, String)> {
+    use proc_macro2::TokenNode;
+
+    match (
+        tts.get(0).map(|tt| &tt.kind),
+        tts.get(1).map(|tt| &tt.kind),
+        tts.get(2).map(|tt| &tt.kind),
+    ) {
+        (
+            Some(&TokenNode::Term(token)),
+            Some(&TokenNode::Op('=', _)),
+            Some(&TokenNode::Literal(ref feature)),
+        ) => Some((
+            token.as_str().to_string(),
+            feature.to_string().trim_matches('"').to_string(),
+        )),
+        _ => None,
+    }
+}
+
 fn to_type(t: &syn::Type) -> Tokens {
     match *t {
         syn::Type::Path(ref p) => match extract_path_ident(&p.path).as_ref() {
@@ -104,6 +216,13 @@ fn to_type(t: &syn::Type) -> Tokens {
             "__m256" => my_quote! { &M256 },
             "__m256d" => my_quote! { &M256D },
             "__m256i" => my_quote! { &M256I },
+            "__m512" => my_quote! { &M512 },
+            "__m512d" => my_quote! { &M512D },
+            "__m512i" => my_quote! { &M512I },
+            "__mmask8" => my_quote! { &MMASK8 },
+            "__mmask16" => my_quote! { &MMASK16 },
+            "__mmask32" => my_quote! { &MMASK32 },
+            "__mmask64" => my_quote! { &MMASK64 },
             "__m64" => my_quote! { &M64 },
             "bool" => my_quote! { &BOOL },
             "f32" => my_quote! { &F32 },
@@ -161,19 +280,19 @@ fn walk(root: &Path, files: &mut Vec<(syn::File, String)>) {
             continue
         }
 
-        let mut contents = String::new();
-        File::open(&path)
-            .unwrap()
-            .read_to_string(&mut contents)
-            .unwrap();
-
-        files.push((
-            syn::parse_str::<syn::File>(&contents).expect("failed to parse"),
-            path.display().to_string(),
-        ));
+        files.push((parse(&path), path.display().to_string()));
     }
 }
 
+fn parse(path: &Path) -> syn::File {
+    let mut contents = String::new();
+    File::open(path)
+        .unwrap()
+        .read_to_string(&mut contents)
+        .unwrap();
+    syn::parse_str::<syn::File>(&contents).expect("failed to parse")
+}
+
 fn find_instrs(attrs: &[syn::Attribute]) -> Vec<syn::Ident> {
     attrs
         .iter()
@@ -240,3 +359,87 @@ fn find_target_feature(attrs: &[syn::Attribute]) -> Option<syn::Lit> {
         })
         .next()
 }
+
+fn find_required_const(attrs: &[syn::Attribute]) -> Vec<usize> {
+    attrs
+        .iter()
+        .filter_map(|a| a.interpret_meta())
+        .filter_map(|a| match a {
+            syn::Meta::List(i) => {
+                if i.ident == "rustc_args_required_const" {
+                    Some(i.nested)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        })
+        .flat_map(|list| list)
+        .map(|nested| match nested {
+            syn::NestedMeta::Literal(syn::Lit::Int(i)) => i.value() as usize,
+            _ => panic!("expected #[rustc_args_required_const(n, ...)]"),
+        })
+        .collect()
+}
+
+/// Returns the indices of the arguments that are given a constant in the
+/// `#[assert_instr]` attributes of `f`.
+///
+/// All of the attributes of `f` are visible here, whatever their order.
+fn find_assert_instr_const(f: &syn::ItemFn) -> Vec<usize> {
+    let mut indices = f.attrs
+        .iter()
+        .flat_map(find_assert_instr_args)
+        .map(|name| {
+            f.decl
+                .inputs
+                .iter()
+                .position(|input| match *input {
+                    syn::FnArg::Captured(syn::ArgCaptured {
+                        pat: syn::Pat::Ident(ref i),
+                        ..
+                    }) => i.ident == name,
+                    _ => false,
+                })
+                .unwrap_or_else(|| {
+                    panic!("`{}` has no argument `{}`", f.ident, name)
+                })
+        })
+        .collect::<Vec<_>>();
+    indices.sort();
+    indices.dedup();
+    indices
+}
+
+/// Returns the names of the arguments given a constant in a
+/// `#[cfg_attr(.., assert_instr(instr, a = b, ...))]` attribute.
+fn find_assert_instr_args(attr: &syn::Attribute) -> Vec<String> {
+    use proc_macro2::TokenNode;
+
+    let trees = |tts: &proc_macro2::TokenStream| {
+        tts.clone().into_iter().collect::<Vec<_>>()
+    };
+    let cfg_attr = match trees(&attr.tts).first().map(|tt| &tt.kind) {
+        Some(&TokenNode::Group(_, ref tts)) => trees(tts),
+        _ => return Vec::new(),
+    };
+    let mut names = Vec::new();
+    for w in cfg_attr.windows(2) {
+        let args = match (&w[0].kind, &w[1].kind) {
+            (&TokenNode::Term(t), &TokenNode::Group(_, ref args))
+                if t.as_str() == "assert_instr" =>
+            {
+                trees(args)
+            }
+            _ => continue,
+        };
+        for w in args.windows(2) {
+            if let (&TokenNode::Term(name), &TokenNode::Op('=', _)) =
+                (&w[0].kind, &w[1].kind)
+            {
+                names.push(name.as_str().to_string());
+            }
+        }
+    }
+    names
+}
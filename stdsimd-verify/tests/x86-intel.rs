@@ -8,20 +8,29 @@ extern crate serde_derive;
 extern crate serde_xml_rs;
 extern crate stdsimd_verify;
 
-use std::collections::{HashMap, BTreeMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
-use stdsimd_verify::x86_functions;
+use stdsimd_verify::{x86_functions, x86_token_methods};
 
 const PRINT_INSTRUCTION_VIOLATIONS: bool = false;
 const PRINT_MISSING_LISTS: bool = false;
 const PRINT_MISSING_LISTS_MARKDOWN: bool = false;
 
+// Every Intel intrinsic which only requires some of these CPUID flags must be
+// implemented, `verify_all_signatures` fails otherwise.
+const REQUIRED_CPUIDS: &[&str] = &[
+    "SSE", "SSE2", "SSE3", "SSSE3", "SSE4.1", "SSE4.2", "AVX", "AVX2", "BMI1",
+    "LZCNT", "POPCNT",
+];
+
 struct Function {
     name: &'static str,
     arguments: &'static [&'static Type],
     ret: Option<&'static Type>,
     target_feature: Option<&'static str>,
     instrs: &'static [&'static str],
+    required_const: &'static [usize],
+    assert_instr_const: &'static [usize],
     file: &'static str,
 }
 
@@ -44,11 +53,18 @@ static M128D: Type = Type::M128D;
 static M256: Type = Type::M256;
 static M256I: Type = Type::M256I;
 static M256D: Type = Type::M256D;
+static M512: Type = Type::M512;
+static M512I: Type = Type::M512I;
+static M512D: Type = Type::M512D;
+static MMASK8: Type = Type::MMask8;
+static MMASK16: Type = Type::MMask16;
+static MMASK32: Type = Type::MMask32;
+static MMASK64: Type = Type::MMask64;
 
 static TUPLE: Type = Type::Tuple;
 static CPUID: Type = Type::CpuidResult;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 enum Type {
     PrimFloat(u8),
     PrimSigned(u8),
@@ -61,12 +77,20 @@ enum Type {
     M256,
     M256D,
     M256I,
+    M512,
+    M512D,
+    M512I,
+    MMask8,
+    MMask16,
+    MMask32,
+    MMask64,
     Bool,
     Tuple,
     CpuidResult,
 }
 
 x86_functions!(static FUNCTIONS);
+x86_token_methods!(static TOKEN_METHODS);
 
 #[derive(Deserialize)]
 struct Data {
@@ -77,6 +101,7 @@ struct Data {
 struct Intrinsic {
     rettype: String,
     name: String,
+    #[serde(default)] tech: String,
     #[serde(rename = "CPUID", default)] cpuid: Vec<String>,
     #[serde(rename = "parameter", default)] parameters: Vec<Parameter>,
     #[serde(default)] instruction: Vec<Instruction>,
@@ -106,6 +131,10 @@ fn verify_all_signatures() {
     // Open up the network console and you'll see an xml file was downloaded
     // (currently called data-3.4.xml). That's the file we downloaded
     // here.
+    //
+    // The file isn't part of this source tree: before running this test save
+    // it as `stdsimd-verify/x86-intel.xml`, otherwise this test fails to
+    // compile.
     let xml = include_bytes!("../x86-intel.xml");
 
     let xml = &xml[..];
@@ -133,11 +162,17 @@ fn verify_all_signatures() {
             _ => {}
         }
 
-        // these are all AMD-specific intrinsics
-        if let Some(feature) = rust.target_feature {
-            if feature.contains("sse4a") || feature.contains("tbm") {
-                continue;
-            }
+        // these are all AMD-specific intrinsics, they're verified against
+        // `x86-amd.txt` by `verify_amd_signatures` below
+        if is_amd(rust) {
+            continue;
+        }
+
+        // the `x86_64` intrinsics emulated on `x86` share the names of their
+        // `x86_64` definitions, `verify_emulated_signatures` checks that they
+        // also share their signatures
+        if is_emulated(rust) {
+            continue;
         }
 
         let intel = match map.remove(rust.name) {
@@ -161,7 +196,25 @@ fn verify_all_signatures() {
     assert!(all_valid);
 
     let mut missing = BTreeMap::new();
+    let mut missing_required = Vec::new();
     for (name, intel) in map.iter() {
+        for intel in intel {
+            // The Short Vector Math Library functions are listed with the
+            // CPUID flags they are optimized for, but they're library
+            // functions and not instructions.
+            if intel.tech == "SVML" {
+                continue;
+            }
+            let required = !intel.cpuid.is_empty()
+                && intel
+                    .cpuid
+                    .iter()
+                    .all(|c| REQUIRED_CPUIDS.contains(&&c[..]));
+            if required {
+                missing_required.push(*name);
+            }
+        }
+
         // currently focused mainly on missing SIMD intrinsics, but there's
         // definitely some other assorted ones that we're missing.
         if !name.starts_with("_mm") {
@@ -204,6 +257,232 @@ fn verify_all_signatures() {
             }
         }
     }
+
+    missing_required.sort();
+    missing_required.dedup();
+    for name in &missing_required {
+        println!("missing intrinsic `{}`", name);
+    }
+    assert!(missing_required.is_empty());
+}
+
+#[test]
+fn verify_emulated_signatures() {
+    let mut all_valid = true;
+    for emulated in FUNCTIONS.iter().filter(|f| is_emulated(f)) {
+        let native = FUNCTIONS
+            .iter()
+            .find(|f| f.name == emulated.name && f.file.contains("x86_64"));
+        let native = match native {
+            Some(f) => f,
+            None => panic!("missing x86_64 definition for {}", emulated.name),
+        };
+        if emulated.arguments != native.arguments
+            || emulated.ret != native.ret
+            || emulated.target_feature != native.target_feature
+        {
+            println!("`{}` differs from its x86_64 definition", emulated.name);
+            all_valid = false;
+        }
+    }
+    assert!(all_valid);
+}
+
+#[test]
+fn verify_amd_signatures() {
+    // AMD doesn't publish a machine readable list of its intrinsics like
+    // Intel does, so the signatures of the AMD-specific intrinsics are
+    // maintained by hand in `x86-amd.txt`.
+    let txt = include_str!("../x86-amd.txt");
+    let mut map = HashMap::new();
+    for line in txt.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let parts = line.split('|').map(|s| s.trim()).collect::<Vec<_>>();
+        assert_eq!(parts.len(), 4, "malformed line in x86-amd.txt: {}", line);
+        let parameters = parts[3]
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| Parameter {
+                type_: s.to_string(),
+            })
+            .collect();
+        let amd = Intrinsic {
+            rettype: parts[1].to_string(),
+            name: parts[2].to_string(),
+            cpuid: vec![parts[0].to_string()],
+            parameters: parameters,
+            instruction: Vec::new(),
+        };
+        if map.insert(parts[2], amd).is_some() {
+            panic!("duplicate definition of {} in x86-amd.txt", parts[2]);
+        }
+    }
+
+    let mut all_valid = true;
+    for rust in FUNCTIONS {
+        if !is_amd(rust) {
+            continue;
+        }
+        let amd = match map.remove(rust.name) {
+            Some(i) => i,
+            None => panic!("missing AMD definition for {}", rust.name),
+        };
+        if let Err(e) = matches_amd(rust, &amd) {
+            println!("failed to verify `{}`", rust.name);
+            println!("  * {}", e);
+            all_valid = false;
+        }
+    }
+    assert!(all_valid);
+
+    // Unlike Intel's list, this one only contains what we implement, so any
+    // leftover entry is most likely a typo.
+    let mut unknown = map.keys().collect::<Vec<_>>();
+    unknown.sort();
+    assert!(
+        unknown.is_empty(),
+        "x86-amd.txt lists unknown intrinsics: {:?}",
+        unknown
+    );
+}
+
+#[test]
+fn verify_required_const() {
+    // The arguments listed in `#[rustc_args_required_const]` are the
+    // immediates of an intrinsic, `#[assert_instr]` has to give a constant to
+    // each of them and to nothing else.
+    let mut failed = false;
+    for rust in FUNCTIONS {
+        if rust.instrs.is_empty() {
+            continue;
+        }
+        let mut required_const = rust.required_const.to_vec();
+        required_const.sort();
+        if required_const != rust.assert_instr_const {
+            println!(
+                "`{}` requires constant arguments {:?} but #[assert_instr] \
+                 gives constants to arguments {:?}",
+                rust.name, required_const, rust.assert_instr_const
+            );
+            failed = true;
+        }
+    }
+    assert!(!failed);
+}
+
+#[test]
+fn verify_token_methods() {
+    // Every intrinsic that can be safely called given a target feature token
+    // must be exposed as a method of that token, see
+    // `coresimd/src/x86/token`.
+    let found = TOKEN_METHODS.iter().cloned().collect::<BTreeSet<_>>();
+
+    let expected = FUNCTIONS
+        .iter()
+        .filter(|rust| has_token_method(rust))
+        .map(|rust| (rust.target_feature.unwrap(), rust.name))
+        .collect::<BTreeSet<_>>();
+    for &(feature, name) in expected.difference(&found) {
+        println!("missing `{}` token method for `{}`", feature, name);
+    }
+    for &(feature, name) in found.difference(&expected) {
+        println!("stale `{}` token method for `{}`", feature, name);
+    }
+    assert!(expected == found);
+}
+
+/// Returns whether `rust` is safe to call given a token of its target
+/// feature.
+fn has_token_method(rust: &Function) -> bool {
+    // Intrinsics that change global state, whose result is unspecified, or
+    // that fault in user mode are left out, even though they only require a
+    // target feature.
+    const EXCLUDED: &[&str] = &[
+        "_mm_setcsr",
+        "_MM_SET_EXCEPTION_STATE",
+        "_MM_SET_EXCEPTION_MASK",
+        "_MM_SET_ROUNDING_MODE",
+        "_MM_SET_FLUSH_ZERO_MODE",
+        "_xbegin",
+        "_xend",
+        "_xabort",
+        "_xtest",
+        "_xgetbv",
+        "_xsetbv",
+        "_mm_mwait",
+    ];
+    match rust.target_feature {
+        Some(feature) if !feature.contains(',') => {}
+        _ => return false,
+    }
+    if !rust.required_const.is_empty()
+        || EXCLUDED.contains(&rust.name)
+        || rust.name.contains("_undefined_")
+    {
+        return false;
+    }
+    // The MMX intrinsics leave the x87 registers tagged as in use until
+    // `_mm_empty` is called, which would corrupt later x87 arithmetic.
+    !rust
+        .arguments
+        .iter()
+        .chain(rust.ret.iter())
+        .any(|t| match **t {
+            Type::Ptr(_) | Type::M64 => true,
+            _ => false,
+        })
+}
+
+/// Returns whether `rust` is an `x86_64` intrinsic emulated on `x86`.
+fn is_emulated(rust: &Function) -> bool {
+    rust.file.contains("emulated")
+}
+
+/// Returns whether `rust` is an AMD-specific intrinsic, which Intel doesn't
+/// document.
+fn is_amd(rust: &Function) -> bool {
+    match rust.target_feature {
+        Some(feature) => ["sse4a", "tbm", "xop", "fma4"]
+            .iter()
+            .any(|amd| feature.contains(amd)),
+        None => false,
+    }
+}
+
+fn matches_amd(rust: &Function, amd: &Intrinsic) -> Result<(), String> {
+    let cpuid = amd.cpuid[0].to_lowercase();
+    let rust_feature = rust.target_feature
+        .expect(&format!("no target feature listed for {}", rust.name));
+    if !rust_feature.contains(&cpuid) {
+        bail!(
+            "amd cpuid `{}` not in `{}` for {}",
+            cpuid,
+            rust_feature,
+            rust.name
+        )
+    }
+
+    if let Some(t) = rust.ret {
+        equate(t, &amd.rettype, rust.name)?;
+    } else if amd.rettype != "void" {
+        bail!(
+            "{} returns `{}` with amd, void in rust",
+            rust.name,
+            amd.rettype
+        )
+    }
+
+    if rust.arguments.len() != amd.parameters.len() {
+        bail!("wrong number of arguments on {}", rust.name)
+    }
+    for (a, b) in amd.parameters.iter().zip(rust.arguments) {
+        equate(b, &a.type_, &amd.name)?;
+    }
+    Ok(())
 }
 
 fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
@@ -213,6 +492,10 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
     match rust.name {
         "_bswap" => {}
         "_bswap64" => {}
+        // `adc`/`sbb` are part of the base instruction set, Intel lists no
+        // CPUID flag for these:
+        "_addcarry_u32" | "_addcarry_u64" => {}
+        "_subborrow_u32" | "_subborrow_u64" => {}
         _ => {
             if intel.cpuid.is_empty() {
                 bail!("missing cpuid for {}", rust.name);
@@ -248,6 +531,24 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
             cpuid
         };
 
+        // Intel spells some of the AVX-512 extensions as `avx512_vbmi`,
+        // `avx512_vpopcntdq` and `avx512ifma52`, while the target
+        // features are `avx512vbmi`, `avx512vpopcntdq` and `avx512ifma`.
+        let cpuid = cpuid.replace("avx512_", "avx512");
+        let cpuid = if cpuid == "avx512ifma52" {
+            String::from("avx512ifma")
+        } else {
+            cpuid
+        };
+
+        // There is no `monitor` target feature, `_mm_monitor` and
+        // `_mm_mwait` are enabled by `sse3` like in the C headers.
+        let cpuid = if cpuid == "monitor" {
+            String::from("sse3")
+        } else {
+            cpuid
+        };
+
         let rust_feature = rust.target_feature
             .expect(&format!("no target feature listed for {}", rust.name));
         if rust_feature.contains(&cpuid) {
@@ -355,7 +656,9 @@ fn matches(rust: &Function, intel: &Intrinsic) -> Result<(), String> {
         _ => false,
     };
     if any_i64 && !any_i64_exempt {
-        if !rust.file.contains("x86_64") {
+        // The `x86_64` intrinsics that are emulated on `x86` live in
+        // `i686/emulated`.
+        if !rust.file.contains("x86_64") && !rust.file.contains("emulated") {
             bail!("intrinsic `{}` uses a 64-bit bare type but may be \
                    available on 32-bit platforms",
                   rust.name)
@@ -375,12 +678,14 @@ fn equate(t: &Type, intel: &str, intrinsic: &str) -> Result<(), String> {
         (&Type::PrimSigned(32), "__int32") => {}
         (&Type::PrimSigned(32), "const int") => {}
         (&Type::PrimSigned(32), "int") => {}
+        (&Type::PrimSigned(32), "const _MM_CMPINT_ENUM") => {}
         (&Type::PrimSigned(64), "__int64") => {}
         (&Type::PrimSigned(64), "long long") => {}
         (&Type::PrimSigned(8), "__int8") => {}
         (&Type::PrimSigned(8), "char") => {}
         (&Type::PrimUnsigned(16), "unsigned short") => {}
         (&Type::PrimUnsigned(32), "unsigned int") => {}
+        (&Type::PrimUnsigned(32), "const unsigned int") => {}
         (&Type::PrimUnsigned(64), "unsigned __int64") => {}
         (&Type::PrimUnsigned(8), "unsigned char") => {}
 
@@ -389,10 +694,12 @@ fn equate(t: &Type, intel: &str, intrinsic: &str) -> Result<(), String> {
         (&Type::Ptr(&Type::PrimSigned(32)), "int*") => {}
         (&Type::Ptr(&Type::PrimSigned(64)), "__int64*") => {}
         (&Type::Ptr(&Type::PrimSigned(8)), "char*") => {}
+        (&Type::Ptr(&Type::PrimUnsigned(16)), "unsigned short*") => {}
         (&Type::Ptr(&Type::PrimUnsigned(32)), "unsigned int*") => {}
         (&Type::Ptr(&Type::PrimUnsigned(64)), "unsigned __int64*") => {}
         (&Type::Ptr(&Type::PrimUnsigned(8)), "const void*") => {}
         (&Type::Ptr(&Type::PrimUnsigned(8)), "void*") => {}
+        (&Type::Ptr(&Type::PrimUnsigned(8)), "unsigned char*") => {}
 
         (&Type::M64, "__m64")
         | (&Type::Ptr(&Type::M64), "__m64*") => {}
@@ -411,6 +718,37 @@ fn equate(t: &Type, intel: &str, intrinsic: &str) -> Result<(), String> {
         | (&Type::M256, "__m256")
         | (&Type::Ptr(&Type::M256), "__m256*") => {}
 
+        (&Type::M512I, "__m512i")
+        | (&Type::M512D, "__m512d")
+        | (&Type::M512, "__m512") => {}
+
+        (&Type::MMask8, "__mmask8")
+        | (&Type::MMask16, "__mmask16")
+        | (&Type::MMask32, "__mmask32")
+        | (&Type::MMask64, "__mmask64") => {}
+
+        // The AVX-512 loads and stores take a `void*` in C, but in Rust
+        // they take a pointer to the element type of the vector.
+        (&Type::Ptr(&Type::PrimFloat(32)), "void*")
+            if intrinsic.starts_with("_mm512_")
+                && intrinsic.ends_with("_ps") => {}
+        (&Type::Ptr(&Type::PrimFloat(64)), "void*")
+            if intrinsic.starts_with("_mm512_")
+                && intrinsic.ends_with("_pd") => {}
+        (&Type::Ptr(&Type::PrimSigned(8)), "void*")
+            if intrinsic.starts_with("_mm512_")
+                && intrinsic.ends_with("_epi8") => {}
+        (&Type::Ptr(&Type::PrimSigned(16)), "void*")
+            if intrinsic.starts_with("_mm512_")
+                && intrinsic.ends_with("_epi16") => {}
+        (&Type::Ptr(&Type::PrimSigned(32)), "void*")
+            if intrinsic.starts_with("_mm512_")
+                && (intrinsic.ends_with("_epi32")
+                    || intrinsic.ends_with("_si512")) => {}
+        (&Type::Ptr(&Type::PrimSigned(64)), "void*")
+            if intrinsic.starts_with("_mm512_")
+                && intrinsic.ends_with("_epi64") => {}
+
         // These two intrinsics return a 16-bit element but in Intel's
         // intrinsics they're listed as returning an `int`.
         (&Type::PrimSigned(16), "int") if intrinsic == "_mm_extract_pi16" => {}
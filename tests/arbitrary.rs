@@ -0,0 +1,70 @@
+//! Property tests using the `quickcheck::Arbitrary` implementations of the
+//! portable vector types.
+#![cfg(feature = "quickcheck_impls")]
+#![cfg_attr(feature = "strict", deny(warnings))]
+
+#[macro_use]
+extern crate quickcheck;
+extern crate stdsimd;
+
+use quickcheck::Arbitrary;
+use stdsimd::simd::*;
+
+quickcheck! {
+    fn add_commutes(a: i32x4, b: i32x4) -> bool {
+        // Wrapping vector arithmetic commutes for all inputs.
+        a + b == b + a
+    }
+
+    fn xor_is_involutive(a: u8x32, b: u8x32) -> bool {
+        (a ^ b) ^ b == a
+    }
+
+    fn u128_lanes_round_trip(a: u128x2) -> bool {
+        u128x2::from(u8x32::from(a)) == a
+    }
+
+    fn complex_conj_is_involutive(a: c32x4) -> bool {
+        // NaN lanes never compare equal.
+        let has_nan = a.interleaved().ne(a.interleaved()) != i32x8::splat(0);
+        has_nan || a.conj().conj() == a
+    }
+}
+
+#[test]
+fn shrink_one_lane_at_a_time() {
+    let v = i32x4::new(0, 4, 0, 0);
+    let shrunk: Vec<i32x4> = v.shrink().collect();
+    assert!(!shrunk.is_empty());
+    for s in shrunk {
+        assert_eq!(s.extract(0), 0);
+        assert_eq!(s.extract(2), 0);
+        assert_eq!(s.extract(3), 0);
+        assert!(s.extract(1).abs() < 4);
+    }
+}
+
+#[test]
+fn shrink_minimal_vector_is_empty() {
+    assert_eq!(u16x8::splat(0).shrink().count(), 0);
+    assert_eq!(f64x4::splat(0.).shrink().count(), 0);
+}
+
+#[test]
+fn shrinking_finds_minimal_counterexample() {
+    // The smallest vector with a lane greater than 10 has one lane equal to
+    // 11 and all other lanes zero.
+    let mut v = u32x4::new(97, 23, 0, 1000);
+    'outer: loop {
+        for s in v.shrink() {
+            if s.gt(u32x4::splat(10)) != i32x4::splat(0) {
+                v = s;
+                continue 'outer;
+            }
+        }
+        break;
+    }
+    let lanes = [v.extract(0), v.extract(1), v.extract(2), v.extract(3)];
+    assert_eq!(lanes.iter().filter(|&&x| x == 11).count(), 1);
+    assert_eq!(lanes.iter().filter(|&&x| x == 0).count(), 3);
+}
@@ -0,0 +1,77 @@
+//! Tests for the `rand` integration of the portable vector types.
+#![cfg(feature = "rand_impls")]
+#![cfg_attr(feature = "strict", deny(warnings))]
+
+extern crate rand;
+extern crate stdsimd;
+
+use rand::{Rng, SeedableRng, XorShiftRng};
+use rand::distributions::{IndependentSample, Sample};
+use stdsimd::simd::*;
+
+fn rng() -> XorShiftRng {
+    XorShiftRng::from_seed([1, 2, 3, 4])
+}
+
+#[test]
+fn rand_floats_are_in_unit_interval() {
+    let mut rng = rng();
+    for _ in 0..1000 {
+        let v: f32x8 = rng.gen();
+        assert_eq!(v.ge(f32x8::splat(0.)), i32x8::splat(-1));
+        assert_eq!(v.lt(f32x8::splat(1.)), i32x8::splat(-1));
+
+        let v: f16x8 = rng.gen();
+        assert_eq!(v.to_f32x8().lt(f32x8::splat(1.)), i32x8::splat(-1));
+    }
+}
+
+#[test]
+fn rand_lanes_are_independent() {
+    let mut rng = rng();
+    let v: u64x8 = rng.gen();
+    for i in 1..8 {
+        assert!(v.extract(i) != v.extract(0));
+    }
+}
+
+#[test]
+fn lane_range_floats() {
+    let low = f64x4::new(-1., 0., 10., -1e9);
+    let high = f64x4::new(1., 1e-3, 11., 1e9);
+    let mut range = LaneRange::new(low, high);
+    let mut rng = rng();
+    for _ in 0..1000 {
+        let v = range.ind_sample(&mut rng);
+        assert_eq!(v.ge(low), i64x4::splat(-1));
+        assert_eq!(v.lt(high), i64x4::splat(-1));
+        let v = range.sample(&mut rng);
+        assert_eq!(v.ge(low), i64x4::splat(-1));
+        assert_eq!(v.lt(high), i64x4::splat(-1));
+    }
+}
+
+#[test]
+fn lane_range_integers() {
+    let low = i8x16::splat(-3);
+    let high = i8x16::new(-2, -1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 127);
+    let range = LaneRange::new(low, high);
+    let mut rng = rng();
+    let mut seen_low = i8x16::splat(0);
+    for _ in 0..1000 {
+        let v = range.ind_sample(&mut rng);
+        assert_eq!(v.ge(low), i8x16::splat(-1));
+        assert_eq!(v.lt(high), i8x16::splat(-1));
+        seen_low = seen_low | v.eq(low);
+    }
+    // Every lane can produce its lower bound.
+    assert_eq!(seen_low, i8x16::splat(-1));
+    // A single-value range always produces that value.
+    assert_eq!(range.ind_sample(&mut rng).extract(0), -3);
+}
+
+#[test]
+#[should_panic]
+fn lane_range_empty_lane_panics() {
+    LaneRange::new(u32x4::new(0, 1, 2, 3), u32x4::new(1, 2, 2, 4));
+}
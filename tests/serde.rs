@@ -0,0 +1,75 @@
+//! Round-trips the portable vector types through `serde` formats.
+#![cfg(feature = "serde")]
+#![cfg_attr(feature = "strict", deny(warnings))]
+#![cfg_attr(feature = "cargo-clippy", allow(option_unwrap_used))]
+
+extern crate bincode;
+extern crate serde_json;
+extern crate stdsimd;
+
+use stdsimd::simd::*;
+
+macro_rules! round_trip {
+    ($($v:expr),+) => {
+        $(
+            let v = $v;
+
+            let json = serde_json::to_string(&v).unwrap();
+            assert_eq!(serde_json::from_str(&json).ok(), Some(v));
+
+            let bytes = bincode::serialize(&v).unwrap();
+            assert_eq!(bytes.len(), ::std::mem::size_of_val(&v));
+            assert_eq!(bincode::deserialize(&bytes).ok(), Some(v));
+        )+
+    };
+}
+
+#[test]
+fn round_trip_integers() {
+    round_trip!(
+        i8x8::new(-1, 2, -3, 4, -5, 6, -7, 8),
+        u16x4::new(1, 2, 3, 0xffff),
+        i32x2::new(::std::i32::MIN, ::std::i32::MAX),
+        u8x16::splat(0xa5),
+        i64x2::new(-1, 1),
+        u128x1::new(::std::u128::MAX),
+        i32x8::new(1, -2, 3, -4, 5, -6, 7, -8),
+        i128x2::new(::std::i128::MIN, 42),
+        u64x8::splat(0xdead_beef),
+        i16x32::splat(-7),
+        u128x4::new(0, 1, 2, 3)
+    );
+}
+
+#[test]
+fn round_trip_floats() {
+    round_trip!(
+        f32x2::new(1.5, -2.25),
+        f64x2::new(0.1, -0.0),
+        f32x4::new(1., 2., 3., 4.),
+        f64x4::new(1e300, -1e-300, 0.5, 3.),
+        f32x16::splat(0.125),
+        f64x8::splat(-6.5),
+        f16x8::from_f32x8(f32x8::new(0., 1., -2., 0.5, 65504., 3., 4., 5.)),
+        c32x2::new(1., 2., 3., 4.),
+        c64x2::new(-1., 0.5, 2., 1e10)
+    );
+}
+
+#[test]
+fn json_representation() {
+    let v = i32x4::new(1, -2, 3, -4);
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1,-2,3,-4]");
+    let a: [i32; 4] = serde_json::from_str("[1,-2,3,-4]").unwrap();
+    assert_eq!(i32x4::load(&a, 0), v);
+
+    let c = c32x2::new(1., 2., 3., 4.);
+    assert_eq!(serde_json::to_string(&c).unwrap(), "[1.0,2.0,3.0,4.0]");
+}
+
+#[test]
+fn wrong_length_is_an_error() {
+    assert!(serde_json::from_str::<i32x4>("[1,2,3]").is_err());
+    assert!(serde_json::from_str::<i32x4>("[1,2,3,4,5]").is_err());
+    assert!(serde_json::from_str::<u8x8>("[1,2,3,4,5,6,7,256]").is_err());
+}